@@ -5,6 +5,9 @@ use std::fmt;
 use descriptor::*;
 use misc::*;
 use core::*;
+use descriptorx::find_enum_by_proto_name;
+use descriptorx::find_message_rust_names;
+use descriptorx::find_enum_rust_names;
 use rt;
 use paginate::PaginatableIterator;
 use strx::*;
@@ -79,7 +82,6 @@ impl RustType {
             RustRepeatedField(..)              => "::protobuf::RepeatedField::new()".to_string(),
             RustMessage(ref name)              => format!("{}::new()", name),
             RustRef(box RustMessage(ref name)) => format!("{}::default_instance()", name),
-            // TODO: use proper constant
             RustEnum(ref name)                 => format!("{}::new(0)", name),
             _ => fail!("cannot create default value for: {}", *self),
         }
@@ -158,9 +160,9 @@ fn protobuf_name(field_type: FieldDescriptorProto_Type) -> &'static str {
         FieldDescriptorProto_TYPE_BOOL     => "bool",
         FieldDescriptorProto_TYPE_STRING   => "string",
         FieldDescriptorProto_TYPE_BYTES    => "bytes",
-        FieldDescriptorProto_TYPE_ENUM |
-        FieldDescriptorProto_TYPE_GROUP |
-        FieldDescriptorProto_TYPE_MESSAGE => fail!()
+        FieldDescriptorProto_TYPE_ENUM     => "enum",
+        FieldDescriptorProto_TYPE_GROUP    => "group",
+        FieldDescriptorProto_TYPE_MESSAGE  => "message",
     }
 }
 
@@ -184,7 +186,16 @@ fn field_type_wire_type(field_type: FieldDescriptorProto_Type) -> wire_format::W
         FieldDescriptorProto_TYPE_STRING   => WireTypeLengthDelimited,
         FieldDescriptorProto_TYPE_BYTES    => WireTypeLengthDelimited,
         FieldDescriptorProto_TYPE_MESSAGE  => WireTypeLengthDelimited,
-        FieldDescriptorProto_TYPE_GROUP    => fail!()
+        FieldDescriptorProto_TYPE_GROUP    => WireTypeStartGroup,
+    }
+}
+
+// group is a message, encoded with start and end group tags
+fn is_message_or_group(field_type: FieldDescriptorProto_Type) -> bool {
+    match field_type {
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP => true,
+        _ => false,
     }
 }
 
@@ -197,20 +208,26 @@ fn field_type_size(field_type: FieldDescriptorProto_Type) -> Option<u32> {
     }
 }
 
+// Rust name of message or enum by fully qualified protobuf name like `.pkg.Outer.Inner`
+fn rust_type_name(proto_name: &str, pkg: &str) -> String {
+    let current_pkg_prefix = if pkg.is_empty() {
+        ".".to_string()
+    } else {
+        format!(".{}.", pkg)
+    };
+    (if proto_name.starts_with(current_pkg_prefix.as_slice()) {
+        remove_prefix(proto_name, current_pkg_prefix.as_slice()).to_string()
+    } else {
+        remove_to(proto_name, '.').to_string()
+    }).replace(".", "_")
+}
+
 fn field_type_name(field: &FieldDescriptorProto, pkg: &str) -> RustType {
     if field.has_type_name() {
-        let current_pkg_prefix = if pkg.is_empty() {
-            ".".to_string()
-        } else {
-            format!(".{}.", pkg)
-        };
-        let name = (if field.get_type_name().starts_with(current_pkg_prefix.as_slice()) {
-            remove_prefix(field.get_type_name(), current_pkg_prefix.as_slice()).to_string()
-        } else {
-            remove_to(field.get_type_name(), '.').to_string()
-        }).replace(".", "_");
+        let name = rust_type_name(field.get_type_name(), pkg);
         match field.get_field_type() {
-            FieldDescriptorProto_TYPE_MESSAGE => RustMessage(name),
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP   => RustMessage(name),
             FieldDescriptorProto_TYPE_ENUM    => RustEnum(name),
             _ => fail!("unknown named type: {}", field.get_field_type()),
         }
@@ -221,6 +238,74 @@ fn field_type_name(field: &FieldDescriptorProto, pkg: &str) -> RustType {
     }
 }
 
+// expression for value of singular field when it is not set:
+// `[default = ...]` value, or first value for enum fields
+fn field_default_value(field: &FieldDescriptorProto, type_name: &RustType,
+        root_scope: &[FileDescriptorProto]) -> Option<String>
+{
+    if field.get_label() == FieldDescriptorProto_LABEL_REPEATED {
+        return None;
+    }
+    if field.get_field_type() == FieldDescriptorProto_TYPE_ENUM {
+        let files: Vec<&FileDescriptorProto> = root_scope.iter().collect();
+        let en = match find_enum_by_proto_name(files.as_slice(), field.get_type_name()) {
+            Some(en) => en,
+            None => fail!("enum not found: {}", field.get_type_name()),
+        };
+        let value_name = if field.has_default_value() {
+            field.get_default_value()
+        } else {
+            en.get_value()[0].get_name()
+        };
+        let prefix = match *type_name {
+            RustEnum(ref name) => remove_suffix(name.as_slice(), en.get_name()),
+            _ => fail!("not an enum: {}", *type_name),
+        };
+        return Some(prefix.to_string().append(value_name));
+    }
+    if !field.has_default_value() {
+        return None;
+    }
+    let value = field.get_default_value();
+    Some(match field.get_field_type() {
+        FieldDescriptorProto_TYPE_FLOAT |
+        FieldDescriptorProto_TYPE_DOUBLE => {
+            let bits = match field.get_field_type() {
+                FieldDescriptorProto_TYPE_FLOAT => 32u,
+                _ => 64u,
+            };
+            match value {
+                "inf"  => format!("::std::f{}::INFINITY", bits),
+                "-inf" => format!("::std::f{}::NEG_INFINITY", bits),
+                "nan"  => format!("::std::f{}::NAN", bits),
+                _ if value.contains_char('.') || value.contains_char('e') => value.to_string(),
+                _ => format!("{}.", value),
+            }
+        }
+        FieldDescriptorProto_TYPE_STRING => format!("\"{}\"", value.escape_default()),
+        FieldDescriptorProto_TYPE_BYTES => {
+            let mut escaped = String::new();
+            for &b in unescape_c(value).iter() {
+                match b as char {
+                    '"' | '\\' => {
+                        escaped.push_char('\\');
+                        escaped.push_char(b as char);
+                    }
+                    ' '..'~' => escaped.push_char(b as char),
+                    _ => {
+                        escaped.push_char('\\');
+                        escaped.push_str(format!("x{:02x}", b).as_slice());
+                    }
+                }
+            }
+            format!("b\"{}\"", escaped)
+        }
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP => fail!("default value for message field: {}", field.get_name()),
+        _ => value.to_string(),
+    })
+}
+
 #[deriving(Clone)]
 enum RepeatMode {
     Single,
@@ -239,11 +324,13 @@ struct Field {
     repeated: bool,
     packed: bool,
     repeat_mode: RepeatMode,
+    default_value: Option<String>,
 }
 
 impl Field {
-    fn parse(field: &FieldDescriptorProto, pkg: &str) -> Option<Field> {
+    fn parse(field: &FieldDescriptorProto, pkg: &str, root_scope: &[FileDescriptorProto]) -> Option<Field> {
         let type_name = field_type_name(field, pkg);
+        let default_value = field_default_value(field, &type_name, root_scope);
         let repeated = match field.get_label() {
             FieldDescriptorProto_LABEL_REPEATED => true,
             FieldDescriptorProto_LABEL_OPTIONAL |
@@ -275,12 +362,31 @@ impl Field {
             repeated: repeated,
             packed: packed,
             repeat_mode: repeat_mode,
+            default_value: default_value,
         })
     }
 
+    // value returned by getter when field is not set
+    fn get_xxx_default_value(&self) -> String {
+        match self.default_value {
+            Some(ref v) => v.clone(),
+            None => self.get_xxx_return_type().default_value(),
+        }
+    }
+
+    // value stored by `mut_xxx` when field is not set
+    fn storage_default_value(&self) -> String {
+        match (&self.default_value, &self.type_name) {
+            (&Some(ref v), &RustString) => format!("{}.to_string()", v),
+            (&Some(ref v), &RustVec(..)) => format!("Vec::from_slice({})", v),
+            (&Some(ref v), _) => v.clone(),
+            (&None, t) => t.default_value(),
+        }
+    }
+
     fn full_storage_type(&self) -> RustType {
         let c = box self.type_name.clone();
-        match (self.repeated, self.field_type == FieldDescriptorProto_TYPE_MESSAGE) {
+        match (self.repeated, is_message_or_group(self.field_type)) {
             (true, true)   => RustRepeatedField(c),
             (false, true)  => RustSingularField(c),
             (true, false)  => RustVec(c),
@@ -291,6 +397,7 @@ impl Field {
     fn get_xxx_return_ref(&self) -> bool {
         self.repeated || match self.field_type {
             FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP |
             FieldDescriptorProto_TYPE_STRING |
             FieldDescriptorProto_TYPE_BYTES => true,
             _ => false,
@@ -330,21 +437,23 @@ struct Message {
 }
 
 impl<'a> Message {
-    fn parse(proto_message: &DescriptorProto, pkg: &str, prefix: &str) -> Message {
+    fn parse(proto_message: &DescriptorProto, pkg: &str, prefix: &str,
+            root_scope: &[FileDescriptorProto]) -> Message
+    {
         Message {
             proto_message: proto_message.clone(),
             pkg: pkg.to_string(),
             prefix: prefix.to_string(),
             type_name: prefix.to_string().append(proto_message.get_name()),
             fields: proto_message.get_field().iter().flat_map(|field| {
-                Field::parse(field, pkg).move_iter()
+                Field::parse(field, pkg, root_scope).move_iter()
             }).collect(),
         }
     }
 
     fn has_any_message_field(&self) -> bool {
         for field in self.fields.iter() {
-            if field.field_type == FieldDescriptorProto_TYPE_MESSAGE {
+            if is_message_or_group(field.field_type) {
                 return true;
             }
         }
@@ -363,9 +472,6 @@ impl<'a> Message {
 }
 
 struct Enum {
-    proto: EnumDescriptorProto,
-    pkg: String,
-    prefix: String,
     type_name: String,
     values: Vec<EnumValue>,
 }
@@ -376,15 +482,27 @@ struct EnumValue {
 }
 
 impl Enum {
-    fn parse(proto: &EnumDescriptorProto, pkg: &str, prefix: &str) -> Enum {
+    fn parse(proto: &EnumDescriptorProto, prefix: &str) -> Enum {
         Enum {
-            proto: proto.clone(),
-            pkg: pkg.to_string(),
-            prefix: prefix.to_string(),
             type_name: prefix.to_string().append(proto.get_name()),
             values: proto.get_value().iter().map(|p| EnumValue::parse(p, prefix)).collect(),
         }
     }
+
+    // first declared value with the same number
+    fn first_value_with_number<'a>(&'a self, number: i32) -> &'a EnumValue {
+        self.values.iter().find(|v| v.number() == number).unwrap()
+    }
+
+    // value with number of previously declared value, allowed by `allow_alias` option
+    fn is_alias(&self, value: &EnumValue) -> bool {
+        self.first_value_with_number(value.number()) as *EnumValue != value as *EnumValue
+    }
+
+    // values which are Rust enum variants
+    fn values_without_aliases<'a>(&'a self) -> Vec<&'a EnumValue> {
+        self.values.iter().filter(|v| !self.is_alias(*v)).collect()
+    }
 }
 
 impl EnumValue {
@@ -545,10 +663,10 @@ impl<'a> IndentWriter<'a> {
 
     fn self_field_assign_default(&self) {
         assert!(!self.field().repeated);
-        if self.field().field_type == FieldDescriptorProto_TYPE_MESSAGE {
+        if is_message_or_group(self.field().field_type) {
             self.write_line(format!("{:s}.set_default();", self.self_field()));
         } else {
-            self.self_field_assign_some(self.field().type_name.default_value());
+            self.self_field_assign_some(self.field().storage_default_value());
         }
     }
 
@@ -778,29 +896,61 @@ impl<'a> IndentWriter<'a> {
     }
 
     fn clear_field(&self) {
-        if self.field().repeated || self.field().field_type == FieldDescriptorProto_TYPE_MESSAGE {
+        if self.field().repeated || is_message_or_group(self.field().field_type) {
             self.write_line(format!("{:s}.clear();", self.self_field()));
         } else {
             self.self_field_assign_none();
         }
     }
+
+    fn read_unknown_field(&self) {
+        self.write_line("let unknown = try!(is.read_unknown(field_number, wire_type));");
+        self.write_line("self.mut_unknown_fields().add_value(field_number, unknown);");
+    }
+
+    // field with unexpected wire type is stored in unknown fields
+    fn wire_type_check(&self, wire_type: wire_format::WireType) {
+        self.if_stmt(format!("wire_type != ::protobuf::wire_format::{:?}", wire_type), |w| {
+            w.read_unknown_field();
+            w.write_line("continue;");
+        });
+    }
 }
 
 fn write_merge_from_field_repeated_message(w: &mut IndentWriter) {
     let field = w.field();
-    w.write_line(format!("assert_eq!(::protobuf::wire_format::{:?}, wire_type);",
-            wire_format::WireTypeLengthDelimited));
+    w.wire_type_check(field.wire_type);
     if field.repeated {
         w.write_line(format!("let tmp = {}.push_default();", w.self_field()));
     } else {
         w.write_line(format!("let tmp = {}.set_default();", w.self_field()));
     }
-    w.write_line(format!("is.merge_message(tmp)"));
+    if field.field_type == FieldDescriptorProto_TYPE_GROUP {
+        w.write_line(format!("try!(is.merge_group(field_number, tmp));"));
+    } else {
+        w.write_line(format!("try!(is.merge_message(tmp));"));
+    }
+}
+
+// unknown enum values are stored in unknown fields
+fn write_merge_from_field_enum_value(w: &mut IndentWriter, read_proc: &str) {
+    let field = w.field();
+    w.write_line(format!("let tmp = {:s};", read_proc));
+    w.match_block(format!("{}::from_i32(tmp)", field.type_name), |w| {
+        w.case_block("Some(tmp)", |w| {
+            if field.repeated {
+                w.self_field_push("tmp");
+            } else {
+                w.self_field_assign_some("tmp");
+            }
+        });
+        w.case_expr("None", "self.mut_unknown_fields().add_varint(field_number, tmp as u64)");
+    });
 }
 
 fn write_merge_from_field(w: &mut IndentWriter) {
     let field = w.field();
-    if field.field_type == FieldDescriptorProto_TYPE_MESSAGE {
+    if is_message_or_group(field.field_type) {
         write_merge_from_field_repeated_message(w);
     } else {
         let wire_type = field_type_wire_type(field.field_type);
@@ -815,16 +965,21 @@ fn write_merge_from_field(w: &mut IndentWriter) {
                 Single
             };
 
+        let is_enum = field.field_type == FieldDescriptorProto_TYPE_ENUM;
         let read_proc0 = match field.field_type {
-            FieldDescriptorProto_TYPE_ENUM => format!("{}::new(is.read_int32())", field.type_name),
-            FieldDescriptorProto_TYPE_STRING => "is.read_string()".to_string(),
-            t => format!("is.read_{}()", protobuf_name(t)),
+            FieldDescriptorProto_TYPE_ENUM => "try!(is.read_int32())".to_string(),
+            FieldDescriptorProto_TYPE_STRING => "try!(is.read_string())".to_string(),
+            t => format!("try!(is.read_{}())", protobuf_name(t)),
         };
         let read_proc = read_proc0.as_slice();
 
         match repeat_mode {
+            Single | RepeatRegular if is_enum => {
+                w.wire_type_check(wire_type);
+                write_merge_from_field_enum_value(w, read_proc);
+            },
             Single | RepeatRegular => {
-                w.write_line(format!("assert_eq!(::protobuf::wire_format::{:?}, wire_type);", wire_type));
+                w.wire_type_check(wire_type);
                 w.write_line(format!("let tmp = {:s};", read_proc));
                 match repeat_mode {
                     Single => w.self_field_assign_some("tmp"),
@@ -835,17 +990,25 @@ fn write_merge_from_field(w: &mut IndentWriter) {
             RepeatPacked => {
                 w.write_line(format!("if wire_type == ::protobuf::wire_format::{:?} \\{", wire_format::WireTypeLengthDelimited));
                 w.indented(|w| {
-                    w.write_line("let len = is.read_raw_varint32();");
-                    w.write_line("let old_limit = is.push_limit(len);");
-                    w.while_block("!is.eof()", |w| {
-                        w.self_field_push(read_proc);
+                    w.write_line("let len = try!(is.read_raw_varint32());");
+                    w.write_line("let old_limit = try!(is.push_limit(len));");
+                    w.while_block("!try!(is.eof())", |w| {
+                        if is_enum {
+                            write_merge_from_field_enum_value(w, read_proc);
+                        } else {
+                            w.self_field_push(read_proc);
+                        }
                     });
-                    w.write_line("is.pop_limit(old_limit);");
+                    w.write_line("try!(is.pop_limit(old_limit));");
                 });
                 w.write_line("} else {");
                 w.indented(|w| {
-                    w.write_line(format!("assert_eq!(::protobuf::wire_format::{:?}, wire_type);", wire_type));
-                    w.self_field_push(read_proc);
+                    w.wire_type_check(wire_type);
+                    if is_enum {
+                        write_merge_from_field_enum_value(w, read_proc);
+                    } else {
+                        w.self_field_push(read_proc);
+                    }
                 });
                 w.write_line("}");
             },
@@ -906,6 +1069,12 @@ fn write_message_compute_sizes(w: &mut IndentWriter) {
                                                 "my_size += {:u} + ::protobuf::rt::compute_raw_varint32_size(len) + len;",
                                                 w.self_field_tag_size() as uint));
                                     },
+                                    FieldDescriptorProto_TYPE_GROUP => {
+                                        // start and end tags
+                                        w.write_line(format!(
+                                                "my_size += {:u} + value.compute_sizes(sizes);",
+                                                (w.self_field_tag_size() * 2) as uint));
+                                    },
                                     FieldDescriptorProto_TYPE_BYTES => {
                                         w.write_line(format!(
                                                 "my_size += ::protobuf::rt::bytes_size({:d}, value.as_slice());",
@@ -949,14 +1118,11 @@ fn write_message_compute_sizes(w: &mut IndentWriter) {
 fn write_message_write_field(w: &mut IndentWriter) {
     let field = w.field();
     let field_type = field.field_type;
-    let write_method_suffix = match field_type {
-        FieldDescriptorProto_TYPE_MESSAGE => "message",
-        FieldDescriptorProto_TYPE_ENUM => "enum",
-        t => protobuf_name(t),
-    };
+    let write_method_suffix = protobuf_name(field_type);
     let field_number = field.proto_field.get_number();
     let vv = match field.field_type {
-        FieldDescriptorProto_TYPE_MESSAGE => "v", // TODO: as &Message
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP => "v", // TODO: as &Message
         FieldDescriptorProto_TYPE_ENUM => "*v as i32",
         FieldDescriptorProto_TYPE_BYTES |
         FieldDescriptorProto_TYPE_STRING => "v.as_slice()",
@@ -970,6 +1136,14 @@ fn write_message_write_field(w: &mut IndentWriter) {
             format!("*sizes_pos += 1;"),
             format!("v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);"),
         ],
+        FieldDescriptorProto_TYPE_GROUP => ~[
+            format!("os.write_tag({:d}, ::protobuf::wire_format::{:?});",
+                    field_number as int, wire_format::WireTypeStartGroup),
+            format!("*sizes_pos += 1;"),
+            format!("v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);"),
+            format!("os.write_tag({:d}, ::protobuf::wire_format::{:?});",
+                    field_number as int, wire_format::WireTypeEndGroup),
+        ],
         _ => ~[
             format!("os.write_{:s}({:d}, {:s});", write_method_suffix, field_number as int, vv),
         ],
@@ -977,7 +1151,7 @@ fn write_message_write_field(w: &mut IndentWriter) {
     match field.repeat_mode {
         Single => {
             let match_what =
-                if field.field_type == FieldDescriptorProto_TYPE_MESSAGE {
+                if is_message_or_group(field.field_type) {
                     format!("{}.as_ref()", w.self_field())
                 } else {
                     w.self_field()
@@ -1104,7 +1278,7 @@ fn write_message_field_accessors(w: &mut IndentWriter) {
         w.pub_fn(format!("get_{:s}({:s}) -> {:s}", w.field().name, self_param, get_xxx_return_type_str),
         |w| {
             if !w.field().repeated {
-                if w.field().field_type == FieldDescriptorProto_TYPE_MESSAGE {
+                if is_message_or_group(w.field().field_type) {
                     w.write_line(format!("{:s}.as_ref().unwrap_or_else(|| {}::default_instance())",
                             w.self_field(), w.field().type_name));
                 } else {
@@ -1116,13 +1290,13 @@ fn write_message_field_accessors(w: &mut IndentWriter) {
                             );
                             w.case_expr(
                                 "None",
-                                w.field().get_xxx_return_type().default_value()
+                                w.field().get_xxx_default_value()
                             );
                         });
                     } else {
                         w.write_line(format!(
                                 "{:s}.unwrap_or_else(|| {:s})",
-                                w.self_field(), w.field().get_xxx_return_type().default_value()));
+                                w.self_field(), w.field().get_xxx_default_value()));
                     }
                 }
             } else {
@@ -1180,9 +1354,13 @@ fn write_message_unknown_fields(w: &mut IndentWriter) {
 }
 
 fn write_message_merge_from(w: &mut IndentWriter) {
-    w.def_fn(format!("merge_from(&mut self, is: &mut ::protobuf::CodedInputStream)"), |w| {
-        w.while_block("!is.eof()", |w| {
-            w.write_line(format!("let (field_number, wire_type) = is.read_tag_unpack();"));
+    w.def_fn(format!("merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()>"), |w| {
+        w.while_block("!try!(is.eof())", |w| {
+            w.write_line(format!("let (field_number, wire_type) = try!(is.read_tag_unpack());"));
+            // message is parsed as group
+            w.if_stmt(format!("wire_type == ::protobuf::wire_format::{:?}", wire_format::WireTypeEndGroup), |w| {
+                w.write_line("return is.end_group(field_number);");
+            });
             w.match_block("field_number", |w| {
                 w.fields(|w| {
                     w.case_block(w.field().number.to_str(), |w| {
@@ -1190,11 +1368,51 @@ fn write_message_merge_from(w: &mut IndentWriter) {
                     });
                 });
                 w.case_block("_", |w| {
-                    w.write_line("let unknown = is.read_unknown(wire_type);");
-                    w.write_line("self.mut_unknown_fields().add_value(field_number, unknown);");
+                    w.read_unknown_field();
                 });
             });
         });
+        w.write_line("Ok(())");
+    });
+}
+
+fn write_message_merge_from_message(w: &mut IndentWriter) {
+    let msg = w.msg.unwrap();
+    w.def_fn(format!("merge_from_message(&mut self, other: &{})", msg.type_name), |w| {
+        if msg.has_any_message_field() {
+            w.write_line("use protobuf::{Message};");
+        }
+        w.fields(|w| {
+            let field = w.field();
+            let other_field = format!("other.{}", field.name);
+            match (field.repeated, is_message_or_group(field.field_type)) {
+                (false, false) => {
+                    w.if_stmt(format!("{}.is_some()", other_field), |w| {
+                        w.self_field_assign(format!("{}.clone()", other_field));
+                    });
+                },
+                (false, true) => {
+                    w.if_stmt(format!("{}.is_some()", other_field), |w| {
+                        w.if_self_field_is_none(|w| {
+                            w.write_line(format!("{}.set_default();", w.self_field()));
+                        });
+                        w.write_line(format!("{}.get_mut_ref().merge_from_message({}.get_ref());",
+                                w.self_field(), other_field));
+                    });
+                },
+                (true, false) => {
+                    w.write_line(format!("{}.push_all({}.as_slice());", w.self_field(), other_field));
+                },
+                (true, true) => {
+                    w.for_stmt(format!("{}.iter()", other_field), "v", |w| {
+                        w.self_field_push("v.clone()");
+                    });
+                },
+            }
+        });
+        w.if_stmt("other.unknown_fields.is_some()", |w| {
+            w.write_line("self.mut_unknown_fields().merge_from(other.get_unknown_fields());");
+        });
     });
 }
 
@@ -1206,20 +1424,35 @@ fn write_message_impl_message(w: &mut IndentWriter) {
         });
         w.write_line("");
         w.def_fn(format!("is_initialized(&self) -> bool"), |w| {
+            if msg.has_any_message_field() {
+                w.write_line("use protobuf::{Message};");
+            }
             w.required_fields(|w| {
                 w.if_self_field_is_none(|w| {
                     w.write_line("return false;");
                 });
             });
+            // nested messages are checked recursively
+            w.fields(|w| {
+                if is_message_or_group(w.field().field_type) {
+                    w.for_self_field("v", |w| {
+                        w.if_stmt("!v.is_initialized()", |w| {
+                            w.write_line("return false;");
+                        });
+                    });
+                }
+            });
             w.write_line("true");
         });
         w.write_line("");
         write_message_merge_from(w);
         w.write_line("");
+        write_message_merge_from_message(w);
+        w.write_line("");
         write_message_compute_sizes(w);
         w.write_line("");
         w.def_fn("write_to(&self, os: &mut ::protobuf::CodedOutputStream)", |w| {
-            w.write_line("self.check_initialized();");
+            w.write_line("self.check_initialized().unwrap();");
             w.write_line("let mut sizes: Vec<u32> = Vec::new();");
             w.write_line("self.compute_sizes(&mut sizes);");
             w.write_line("let mut sizes_pos = 1; // first element is self");
@@ -1247,7 +1480,8 @@ fn write_message_impl_message(w: &mut IndentWriter) {
                 w.indented(|w| {
                     w.write_line(format!("\"{}\",", msg.type_name));
                     w.write_line("fields,");
-                    w.write_line("file_descriptor_proto()");
+                    w.write_line("file_descriptor_proto(),");
+                    w.write_line("file_descriptor");
                 });
                 w.write_line(")");
             });
@@ -1279,10 +1513,28 @@ fn write_message_descriptor_field(w: &mut IndentWriter) {
     w.impl_for_block(
             format!("::protobuf::reflect::FieldAccessor<{}>", msg.type_name), accessor_name,
     |w| {
+        // name in .proto, Rust name may differ, e. g. `field_type` for `type`
         w.def_fn("name(&self) -> &'static str", |w| {
-            w.write_line(format!("\"{}\"", field.name));
+            w.write_line(format!("\"{}\"", field.proto_field.get_name()));
         });
 
+        match field.field_type {
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP => {
+                w.write_line("");
+                w.def_fn("message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor", |w| {
+                    w.write_line(format!("::protobuf::reflect::MessageDescriptor::for_type::<{}>()", field.type_name));
+                });
+            },
+            FieldDescriptorProto_TYPE_ENUM => {
+                w.write_line("");
+                w.def_fn("enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor", |w| {
+                    w.write_line(format!("::protobuf::reflect::EnumDescriptor::for_type::<{}>()", field.type_name));
+                });
+            },
+            _ => {},
+        };
+
         w.write_line("");
         if field.repeated {
             w.def_fn(format!("len_field(&self, m: &{}) -> uint", msg.type_name), |w| {
@@ -1295,7 +1547,8 @@ fn write_message_descriptor_field(w: &mut IndentWriter) {
         }
 
         let name_suffix = match field.field_type {
-            FieldDescriptorProto_TYPE_MESSAGE => "message".to_string(),
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP   => "message".to_string(),
             FieldDescriptorProto_TYPE_ENUM    => "enum".to_string(),
             FieldDescriptorProto_TYPE_STRING  => "str".to_string(),
             FieldDescriptorProto_TYPE_BYTES   => "bytes".to_string(),
@@ -1305,7 +1558,8 @@ fn write_message_descriptor_field(w: &mut IndentWriter) {
         w.write_line("");
         if field.repeated {
             match field.field_type {
-                FieldDescriptorProto_TYPE_MESSAGE => {
+                FieldDescriptorProto_TYPE_MESSAGE |
+                FieldDescriptorProto_TYPE_GROUP => {
                     w.def_fn(format!("get_rep_message_item<'a>(&self, m: &'a {}, index: uint) -> &'a ::protobuf::Message",
                             msg.type_name),
                     |w| {
@@ -1340,7 +1594,8 @@ fn write_message_descriptor_field(w: &mut IndentWriter) {
                 false => format!("{}", w.field().get_xxx_return_type()),
             };
             match field.field_type {
-                FieldDescriptorProto_TYPE_MESSAGE => {
+                FieldDescriptorProto_TYPE_MESSAGE |
+                FieldDescriptorProto_TYPE_GROUP => {
                     w.def_fn(format!("get_message<'a>(&self, m: &'a {}) -> &'a ::protobuf::Message",
                             msg.type_name),
                     |w| {
@@ -1368,6 +1623,84 @@ fn write_message_descriptor_field(w: &mut IndentWriter) {
                 },
             };
         }
+
+        w.write_line("");
+        w.def_fn(format!("clear_field(&self, m: &mut {})", msg.type_name), |w| {
+            w.write_line(format!("m.{}();", w.clear_field_func()));
+        });
+
+        w.write_line("");
+        if field.repeated {
+            match field.field_type {
+                FieldDescriptorProto_TYPE_MESSAGE |
+                FieldDescriptorProto_TYPE_GROUP => {
+                    w.def_fn(format!("add_message<'a>(&self, m: &'a mut {}) -> &'a mut ::protobuf::Message",
+                            msg.type_name),
+                    |w| {
+                        w.write_line(format!("m.mut_{}().push_default() as &'a mut ::protobuf::Message", field.name));
+                    });
+                    w.write_line("");
+                    w.def_fn(format!("mut_rep_message_item<'a>(&self, m: &'a mut {}, index: uint) -> &'a mut ::protobuf::Message",
+                            msg.type_name),
+                    |w| {
+                        w.write_line(format!("m.mut_{}().get_mut(index) as &'a mut ::protobuf::Message", field.name));
+                    });
+                },
+                FieldDescriptorProto_TYPE_ENUM => {
+                    w.def_fn(format!("add_enum(&self, m: &mut {}, v: &::protobuf::reflect::EnumValueDescriptor)",
+                            msg.type_name),
+                    |w| {
+                        // value is checked by `FieldDescriptor` to be declared in this enum
+                        w.match_expr(format!("{}::from_i32(v.value())", field.type_name), |w| {
+                            w.case_expr("Some(e)", format!("m.add_{}(e)", field.name));
+                            w.case_expr("None", format!("fail!(\"unknown value \\{\\} of enum {}\", v.value())", field.type_name));
+                        });
+                    });
+                },
+                _ => {
+                    w.def_fn(format!("add_{}(&self, m: &mut {}, v: {})",
+                            name_suffix, msg.type_name, field.type_name),
+                    |w| {
+                        w.write_line(format!("m.add_{}(v);", field.name));
+                    });
+                    w.write_line("");
+                    w.def_fn(format!("mut_rep_{}<'a>(&self, m: &'a mut {}) -> &'a mut {}",
+                            name_suffix, msg.type_name, field.full_storage_type()),
+                    |w| {
+                        w.write_line(format!("m.mut_{}()", field.name));
+                    });
+                },
+            };
+        } else {
+            match field.field_type {
+                FieldDescriptorProto_TYPE_MESSAGE |
+                FieldDescriptorProto_TYPE_GROUP => {
+                    w.def_fn(format!("mut_message<'a>(&self, m: &'a mut {}) -> &'a mut ::protobuf::Message",
+                            msg.type_name),
+                    |w| {
+                        w.write_line(format!("m.mut_{}() as &'a mut ::protobuf::Message", field.name));
+                    });
+                },
+                FieldDescriptorProto_TYPE_ENUM => {
+                    w.def_fn(format!("set_enum(&self, m: &mut {}, v: &::protobuf::reflect::EnumValueDescriptor)",
+                            msg.type_name),
+                    |w| {
+                        // value is checked by `FieldDescriptor` to be declared in this enum
+                        w.match_expr(format!("{}::from_i32(v.value())", field.type_name), |w| {
+                            w.case_expr("Some(e)", format!("m.set_{}(e)", field.name));
+                            w.case_expr("None", format!("fail!(\"unknown value \\{\\} of enum {}\", v.value())", field.type_name));
+                        });
+                    });
+                },
+                _ => {
+                    w.def_fn(format!("set_{}(&self, m: &mut {}, v: {})",
+                            name_suffix, msg.type_name, field.type_name),
+                    |w| {
+                        w.write_line(format!("m.set_{}(v);", field.name));
+                    });
+                },
+            };
+        }
     });
 }
 
@@ -1392,7 +1725,7 @@ fn write_message_impl_clear(w: &mut IndentWriter) {
     });
 }
 
-fn write_message(msg: &Message, w: &mut IndentWriter) {
+fn write_message(msg: &Message, root_scope: &[FileDescriptorProto], w: &mut IndentWriter) {
     let pkg = msg.pkg.as_slice();
     let message_type = &msg.proto_message;
 
@@ -1411,20 +1744,24 @@ fn write_message(msg: &Message, w: &mut IndentWriter) {
 
         for nested_type in message_type.get_nested_type().iter() {
             w.write_line("");
-            write_message(&Message::parse(nested_type, pkg.as_slice(), msg.type_name.to_string().append("_").as_slice()), w);
+            let nested = Message::parse(nested_type, pkg.as_slice(),
+                    msg.type_name.to_string().append("_").as_slice(), root_scope);
+            write_message(&nested, root_scope, w);
         }
 
         for enum_type in message_type.get_enum_type().iter() {
             w.write_line("");
-            write_enum(&Enum::parse(enum_type, pkg, msg.type_name.to_string().append("_").as_slice()), w);
+            write_enum(&Enum::parse(enum_type, msg.type_name.to_string().append("_").as_slice()), w);
         }
+
+        write_extensions(message_type.get_extension(), pkg, msg.type_name.to_string().append("_").as_slice(), w);
     });
 }
 
 fn write_enum_struct(w: &mut IndentWriter) {
     w.deriving(["Clone", "PartialEq", "Eq", "Show"]);
     w.write_line(format!("pub enum {:s} \\{", w.en().type_name));
-    for value in w.en().values.iter() {
+    for value in w.en().values_without_aliases().iter() {
         w.write_line(format!("    {:s} = {:i},", value.rust_name(), value.number()));
     }
     w.write_line(format!("\\}"));
@@ -1433,11 +1770,18 @@ fn write_enum_struct(w: &mut IndentWriter) {
 fn write_enum_impl(w: &mut IndentWriter) {
     w.impl_block(w.en().type_name.as_slice(), |w| {
         w.pub_fn(format!("new(value: i32) -> {:s}", w.en().type_name), |w| {
+            w.match_expr(format!("{:s}::from_i32(value)", w.en().type_name), |w| {
+                w.case_expr("Some(v)", "v");
+                w.case_expr("None", format!("fail!(\"unknown value \\{\\} of enum {:s}\", value)", w.en().type_name));
+            });
+        });
+        w.write_line("");
+        w.pub_fn(format!("from_i32(value: i32) -> Option<{:s}>", w.en().type_name), |w| {
             w.match_expr("value", |w| {
-                for value in w.en().values.iter() {
-                    w.write_line(format!("{:d} => {:s},", value.number(), value.rust_name()));
+                for value in w.en().values_without_aliases().iter() {
+                    w.write_line(format!("{:d} => Some({:s}),", value.number(), value.rust_name()));
                 }
-                w.write_line(format!("_ => fail!()"));
+                w.write_line(format!("_ => None"));
             });
         });
     });
@@ -1449,9 +1793,13 @@ fn write_enum_impl_enum(w: &mut IndentWriter) {
             w.write_line("*self as i32")
         });
         w.write_line("");
+        w.def_fn(format!("from_i32(value: i32) -> Option<{}>", w.en().type_name), |w| {
+            w.write_line(format!("{}::from_i32(value)", w.en().type_name))
+        });
+        w.write_line("");
         w.def_fn(format!("enum_descriptor_static(_: Option<{}>) -> &'static ::protobuf::reflect::EnumDescriptor", w.en().type_name), |w| {
             w.lazy_static_decl_get("descriptor", "::protobuf::reflect::EnumDescriptor", |w| {
-                w.write_line(format!("::protobuf::reflect::EnumDescriptor::new(\"{}\", file_descriptor_proto())", w.en().type_name));
+                w.write_line(format!("::protobuf::reflect::EnumDescriptor::new(\"{}\", file_descriptor_proto(), file_descriptor)", w.en().type_name));
             });
         });
     });
@@ -1464,6 +1812,169 @@ fn write_enum(en: &Enum, w: &mut IndentWriter) {
         write_enum_impl(w);
         w.write_line("");
         write_enum_impl_enum(w);
+        write_enum_aliases(w);
+    });
+}
+
+// aliases are constants equal to first value with the same number
+fn write_enum_aliases(w: &mut IndentWriter) {
+    for value in w.en().values.iter().filter(|v| w.en().is_alias(*v)) {
+        let first = w.en().first_value_with_number(value.number());
+        w.write_line("");
+        w.write_line("#[allow(non_uppercase_statics)]");
+        w.write_line(format!("pub static {}: {} = {};", value.rust_name(), w.en().type_name, first.rust_name()));
+    }
+}
+
+// `::protobuf::types` marker for extension field
+fn extension_field_type(field: &FieldDescriptorProto, pkg: &str) -> String {
+    match field.get_field_type() {
+        FieldDescriptorProto_TYPE_DOUBLE   => "ProtobufTypeDouble".to_string(),
+        FieldDescriptorProto_TYPE_FLOAT    => "ProtobufTypeFloat".to_string(),
+        FieldDescriptorProto_TYPE_INT32    => "ProtobufTypeInt32".to_string(),
+        FieldDescriptorProto_TYPE_INT64    => "ProtobufTypeInt64".to_string(),
+        FieldDescriptorProto_TYPE_UINT32   => "ProtobufTypeUint32".to_string(),
+        FieldDescriptorProto_TYPE_UINT64   => "ProtobufTypeUint64".to_string(),
+        FieldDescriptorProto_TYPE_SINT32   => "ProtobufTypeSint32".to_string(),
+        FieldDescriptorProto_TYPE_SINT64   => "ProtobufTypeSint64".to_string(),
+        FieldDescriptorProto_TYPE_FIXED32  => "ProtobufTypeFixed32".to_string(),
+        FieldDescriptorProto_TYPE_FIXED64  => "ProtobufTypeFixed64".to_string(),
+        FieldDescriptorProto_TYPE_SFIXED32 => "ProtobufTypeSfixed32".to_string(),
+        FieldDescriptorProto_TYPE_SFIXED64 => "ProtobufTypeSfixed64".to_string(),
+        FieldDescriptorProto_TYPE_BOOL     => "ProtobufTypeBool".to_string(),
+        FieldDescriptorProto_TYPE_STRING   => "ProtobufTypeString".to_string(),
+        FieldDescriptorProto_TYPE_BYTES    => "ProtobufTypeBytes".to_string(),
+        FieldDescriptorProto_TYPE_ENUM     =>
+            format!("ProtobufTypeEnum<{}>", rust_type_name(field.get_type_name(), pkg)),
+        FieldDescriptorProto_TYPE_MESSAGE  =>
+            format!("ProtobufTypeMessage<{}>", rust_type_name(field.get_type_name(), pkg)),
+        FieldDescriptorProto_TYPE_GROUP    =>
+            format!("ProtobufTypeGroup<{}>", rust_type_name(field.get_type_name(), pkg)),
+    }
+}
+
+fn write_extensions(extensions: &[FieldDescriptorProto], pkg: &str, prefix: &str, w: &mut IndentWriter) {
+    for field in extensions.iter() {
+        let field_type = extension_field_type(field, pkg);
+        let ext_type = match field.get_label() {
+            FieldDescriptorProto_LABEL_REPEATED => "ExtFieldRepeated",
+            FieldDescriptorProto_LABEL_OPTIONAL |
+            FieldDescriptorProto_LABEL_REQUIRED => "ExtFieldOptional",
+        };
+        // type parameters of marker are inferred
+        let field_type_value = match field_type.as_slice().find('<') {
+            Some(pos) => field_type.as_slice().slice_to(pos),
+            None => field_type.as_slice(),
+        };
+        w.write_line("");
+        w.stmt_block(format!("pub static {}{}: ::protobuf::ext::{}<{}, ::protobuf::types::{}> = ::protobuf::ext::{}",
+                prefix, field.get_name(), ext_type, rust_type_name(field.get_extendee(), pkg), field_type, ext_type),
+        |w| {
+            w.field_entry("field_number", field.get_number().to_str());
+            if field.get_label() == FieldDescriptorProto_LABEL_REPEATED {
+                w.field_entry("packed", field.get_options().get_packed().to_str());
+            }
+            w.field_entry("field_type", format!("::protobuf::types::{}", field_type_value));
+        });
+    }
+}
+
+fn write_service(service: &ServiceDescriptorProto, pkg: &str, w: &mut IndentWriter) {
+    let name = service.get_name();
+    let full_name = if pkg.is_empty() { name.to_string() } else { format!("{}.{}", pkg, name) };
+    w.expr_block(format!("pub trait {}", name), |w| {
+        for (i, method) in service.get_method().iter().enumerate() {
+            if i != 0 {
+                w.write_line("");
+            }
+            w.write_line(format!("fn {}(&self, request: {}) -> ::protobuf::ProtobufResult<{}>;",
+                    camel_case_to_snake_case(method.get_name()),
+                    rust_type_name(method.get_input_type(), pkg),
+                    rust_type_name(method.get_output_type(), pkg)));
+        }
+    });
+    w.write_line("");
+    w.comment(format!("Calls methods of `{}` with serialized requests", name).as_slice());
+    w.pub_struct(format!("{}Server<S>", name), |w| {
+        w.field_entry("pub service", "S");
+    });
+    w.write_line("");
+    w.expr_block(format!("impl<S : {}> ::protobuf::service::ServiceHandler for {}Server<S>", name, name), |w| {
+        w.allow(["unused_unsafe", "unused_mut"]);
+        w.def_fn("descriptor(&self) -> &'static ::protobuf::reflect::ServiceDescriptor", |w| {
+            w.lazy_static_decl_get("descriptor", "::protobuf::reflect::ServiceDescriptor", |w| {
+                w.write_line(format!("::protobuf::reflect::ServiceDescriptor::new(\"{}\", file_descriptor_proto())", name));
+            });
+        });
+        w.write_line("");
+        w.def_fn("call(&self, method: &str, request: &[u8]) -> ::protobuf::ProtobufResult<Vec<u8>>", |w| {
+            w.write_line("use protobuf::{Message};");
+            w.match_expr("method", |w| {
+                for method in service.get_method().iter() {
+                    w.case_block(format!("\"{}\"", method.get_name()), |w| {
+                        w.write_line(format!("let request = try!(::protobuf::parse_from_bytes::<{}>(request));",
+                                rust_type_name(method.get_input_type(), pkg)));
+                        w.write_line(format!("let response = try!(self.service.{}(request));",
+                                camel_case_to_snake_case(method.get_name())));
+                        w.write_line("try!(::protobuf::service::check_response(&response));");
+                        w.write_line("Ok(response.write_to_bytes())");
+                    });
+                }
+                w.case_expr("_", "Err(::protobuf::error::UnknownMethod(method.to_string()))");
+            });
+        });
+    });
+    w.write_line("");
+    w.comment(format!("Client for `{}`, sends requests over `T`", name).as_slice());
+    w.pub_struct(format!("{}Client<T>", name), |w| {
+        w.field_entry("pub transport", "T");
+    });
+    w.write_line("");
+    w.expr_block(format!("impl<T : ::protobuf::rpc::Transport> {}Client<T>", name), |w| {
+        for (i, method) in service.get_method().iter().enumerate() {
+            if i != 0 {
+                w.write_line("");
+            }
+            w.pub_fn(format!("{}(&mut self, request: &{}) -> ::protobuf::ProtobufResult<{}>",
+                    camel_case_to_snake_case(method.get_name()),
+                    rust_type_name(method.get_input_type(), pkg),
+                    rust_type_name(method.get_output_type(), pkg)),
+            |w| {
+                w.write_line(format!("::protobuf::rpc::call(&mut self.transport, \"{}\", \"{}\", request)",
+                        full_name, method.get_name()));
+            });
+        }
+    });
+}
+
+fn write_file_descriptor(file: &FileDescriptorProto, w: &mut IndentWriter) {
+    w.lazy_static("file_descriptor_lazy", "::protobuf::reflect::FileDescriptor");
+    w.write_line("");
+    w.pub_fn("file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor", |w| {
+        w.unsafe_expr(|w| {
+            w.block("file_descriptor_lazy.get(|| {", "})", |w| {
+                w.write_line("::protobuf::reflect::FileDescriptor::new(");
+                w.indented(|w| {
+                    w.write_line("file_descriptor_proto(),");
+                    w.block("vec!(", "),", |w| {
+                        for dep in file.get_dependency().iter() {
+                            w.write_line(format!("::{}::file_descriptor(),", proto_path_to_rust_base(dep.as_slice())));
+                        }
+                    });
+                    w.block("vec!(", "),", |w| {
+                        for name in find_message_rust_names(file).iter() {
+                            w.write_line(format!("::protobuf::reflect::MessageDescriptor::for_type::<{}>(),", name));
+                        }
+                    });
+                    w.block("vec!(", "),", |w| {
+                        for name in find_enum_rust_names(file).iter() {
+                            w.write_line(format!("::protobuf::reflect::EnumDescriptor::for_type::<{}>(),", name));
+                        }
+                    });
+                });
+                w.write_line(")");
+            });
+        });
     });
 }
 
@@ -1516,7 +2027,7 @@ pub fn gen(files: &[FileDescriptorProto], _: &GenOptions) -> Vec<GenResult> {
                 w.lazy_static("file_descriptor_proto_lazy", "::protobuf::descriptor::FileDescriptorProto");
                 w.write_line("");
                 w.def_fn("parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto", |w| {
-                    w.write_line("::protobuf::parse_from_bytes(file_descriptor_proto_data)");
+                    w.write_line("::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()");
                 });
                 w.write_line("");
                 w.pub_fn("file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto", |w| {
@@ -1526,15 +2037,23 @@ pub fn gen(files: &[FileDescriptorProto], _: &GenOptions) -> Vec<GenResult> {
                         });
                     });
                 });
+
+                w.write_line("");
+                write_file_descriptor(file, &mut w);
             }
 
             for message_type in file.get_message_type().iter() {
                 w.write_line("");
-                write_message(&Message::parse(message_type, file.get_package(), ""), &mut w);
+                write_message(&Message::parse(message_type, file.get_package(), "", files), files, &mut w);
             }
             for enum_type in file.get_enum_type().iter() {
                 w.write_line("");
-                write_enum(&Enum::parse(enum_type, file.get_package(), ""), &mut w);
+                write_enum(&Enum::parse(enum_type, ""), &mut w);
+            }
+            write_extensions(file.get_extension(), file.get_package(), "", &mut w);
+            for service in file.get_service().iter() {
+                w.write_line("");
+                write_service(service, file.get_package(), &mut w);
             }
         }
 
@@ -1,6 +1,7 @@
 // TODO: drop all fail!
 
 use std::mem;
+use std::cmp;
 use std::raw;
 use std::str::from_utf8;
 use std::io::*;
@@ -17,16 +18,34 @@ use unknown::UnknownVarint;
 use unknown::UnknownFixed64;
 use unknown::UnknownFixed32;
 use unknown::UnknownLengthDelimited;
+use unknown::UnknownGroup;
 use unknown::UnknownValueRef;
 use unknown::UnknownVarintRef;
 use unknown::UnknownFixed64Ref;
 use unknown::UnknownFixed32Ref;
 use unknown::UnknownLengthDelimitedRef;
+use unknown::UnknownGroupRef;
 use unknown::UnknownFields;
 use clear::Clear;
+use ext::ExtFieldOptional;
+use ext::ExtFieldRepeated;
+use types::ProtobufType;
 use reflect::MessageDescriptor;
 use reflect::EnumDescriptor;
 use reflect::EnumValueDescriptor;
+use descriptor::FieldDescriptorProto_TYPE_MESSAGE;
+use descriptor::FieldDescriptorProto_TYPE_GROUP;
+use error::ProtobufResult;
+use error::ProtobufIoError;
+use error::TruncatedInput;
+use error::InvalidUtf8;
+use error::InvalidTag;
+use error::ExpectedEof;
+use error::MissingRequiredFields;
+use error::RecursionLimitExceeded;
+use error::TotalBytesLimitExceeded;
+use error::MalformedVarint;
+use error::UnexpectedEndGroup;
 
 pub mod wire_format {
     pub static TAG_TYPE_BITS: u32 = 3;
@@ -43,15 +62,15 @@ pub mod wire_format {
     }
 
     impl WireType {
-        pub fn new(n: u32) -> WireType {
+        pub fn new(n: u32) -> Option<WireType> {
             match n {
-                0 => WireTypeVarint,
-                1 => WireTypeFixed64,
-                2 => WireTypeLengthDelimited,
-                3 => WireTypeStartGroup,
-                4 => WireTypeEndGroup,
-                5 => WireTypeFixed32,
-                _ => fail!("unknown wire type: {}", n)
+                0 => Some(WireTypeVarint),
+                1 => Some(WireTypeFixed64),
+                2 => Some(WireTypeLengthDelimited),
+                3 => Some(WireTypeStartGroup),
+                4 => Some(WireTypeEndGroup),
+                5 => Some(WireTypeFixed32),
+                _ => None,
             }
         }
     }
@@ -59,6 +78,15 @@ pub mod wire_format {
     pub struct Tag(pub u32);
 
     impl Tag {
+        // None if field number is zero or wire type is unknown
+        pub fn new(value: u32) -> Option<Tag> {
+            if value >> TAG_TYPE_BITS == 0 || WireType::new(value & TAG_TYPE_MASK).is_none() {
+                None
+            } else {
+                Some(Tag(value))
+            }
+        }
+
         pub fn value(self) -> u32 {
             match self {
                 Tag(value) => value
@@ -74,7 +102,7 @@ pub mod wire_format {
         }
 
         fn wire_type(self) -> WireType {
-            WireType::new(self.value() & TAG_TYPE_MASK)
+            WireType::new(self.value() & TAG_TYPE_MASK).expect("unknown wire type")
         }
 
         pub fn field_number(self) -> u32 {
@@ -90,6 +118,13 @@ pub mod wire_format {
 
 }
 
+// Same as in C++ implementation
+pub static DEFAULT_RECURSION_LIMIT: u32 = 100;
+pub static DEFAULT_TOTAL_BYTES_LIMIT: u32 = 64 << 20;
+
+// Max length of 64-bit varint
+static MAX_VARINT_LEN: uint = 10;
+
 pub struct CodedInputStream<'a> {
     buffer: Vec<u8>,
     buffer_size: u32,
@@ -98,6 +133,16 @@ pub struct CodedInputStream<'a> {
     total_bytes_retired: u32,
     current_limit: u32,
     buffer_size_after_limit: u32,
+    // number of nested messages currently being parsed
+    recursion_level: u32,
+    recursion_limit: u32,
+    // error is returned when input reaches this position
+    total_bytes_limit: u32,
+    // field number of group currently being parsed,
+    // None when parsing message which is not a group
+    current_group: Option<u32>,
+    // set by `end_group`, checked and reset by `merge_group`
+    group_ended: bool,
 }
 
 impl<'a> CodedInputStream<'a> {
@@ -113,7 +158,39 @@ impl<'a> CodedInputStream<'a> {
             total_bytes_retired: 0,
             current_limit: Bounded::max_value(),
             buffer_size_after_limit: 0,
+            recursion_level: 0,
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            total_bytes_limit: DEFAULT_TOTAL_BYTES_LIMIT,
+            current_group: None,
+            group_ended: false,
+        }
+    }
+
+    // Max depth of nested messages, protects from stack overflow
+    pub fn set_recursion_limit(&mut self, limit: u32) {
+        self.recursion_limit = limit;
+    }
+
+    // Max number of bytes read from the stream, protects from allocating
+    // too much memory on large or malicious input.
+    // Cannot be set lower than current position.
+    pub fn set_total_bytes_limit(&mut self, limit: u32) {
+        self.total_bytes_limit = cmp::max(limit, self.pos());
+        self.recompute_buffer_size_after_limit();
+    }
+
+    // Must be called before parsing nested message,
+    // and followed by `decr_recursion` after it is parsed
+    pub fn incr_recursion(&mut self) -> ProtobufResult<()> {
+        if self.recursion_level >= self.recursion_limit {
+            return Err(RecursionLimitExceeded);
         }
+        self.recursion_level += 1;
+        Ok(())
+    }
+
+    pub fn decr_recursion(&mut self) {
+        self.recursion_level -= 1;
     }
 
     fn remaining_in_buffer(&self) -> u32 {
@@ -132,19 +209,30 @@ impl<'a> CodedInputStream<'a> {
         self.current_limit - self.pos()
     }
 
+    fn bytes_until_total_limit(&self) -> u32 {
+        self.total_bytes_limit - self.pos()
+    }
+
     // Refill buffer if buffer is empty.
     // Fails if buffer is not empty.
     // Returns false on EOF, or if limit reached.
     // Otherwize returns true.
-    fn refill_buffer(&mut self) -> bool {
+    fn refill_buffer(&mut self) -> ProtobufResult<bool> {
         if self.buffer_pos < self.buffer_size {
             fail!("called when buffer is not empty");
         }
         if self.pos() == self.current_limit {
-            return false;
+            return Ok(false);
+        }
+        if self.pos() == self.total_bytes_limit {
+            // input ending exactly at the limit is not an error
+            if self.buffer_size_after_limit > 0 || try!(self.reader_has_more()) {
+                return Err(TotalBytesLimitExceeded);
+            }
+            return Ok(false);
         }
         if self.reader.is_none() {
-            false
+            Ok(false)
         } else {
             match self.reader {
                 Some(ref mut reader) => {
@@ -154,8 +242,8 @@ impl<'a> CodedInputStream<'a> {
 
                     let r = reader.read(self.buffer.as_mut_slice());
                     self.buffer_size = match r {
-                        Err(ref e) if e.kind == EndOfFile => return false,
-                        Err(_) => fail!(),
+                        Err(ref e) if e.kind == EndOfFile => return Ok(false),
+                        Err(e) => return Err(ProtobufIoError(e)),
                         Ok(x) => x as u32,
                     };
                     assert!(self.buffer_size > 0);
@@ -163,193 +251,304 @@ impl<'a> CodedInputStream<'a> {
                 None => fail!(),
             }
             self.recompute_buffer_size_after_limit();
-            true
+            Ok(true)
         }
     }
 
-    fn refill_buffer_really(&mut self) {
-        if !self.refill_buffer() {
-            fail!("at EOF");
+    // Probe reader for data past total bytes limit, probed byte is lost
+    fn reader_has_more(&mut self) -> ProtobufResult<bool> {
+        match self.reader {
+            Some(ref mut reader) => {
+                let mut byte = [0u8, ..1];
+                match reader.read(byte) {
+                    Err(ref e) if e.kind == EndOfFile => Ok(false),
+                    Err(e) => Err(ProtobufIoError(e)),
+                    Ok(..) => Ok(true),
+                }
+            },
+            None => Ok(false),
         }
     }
 
+    fn refill_buffer_really(&mut self) -> ProtobufResult<()> {
+        if !try!(self.refill_buffer()) {
+            return Err(TruncatedInput);
+        }
+        Ok(())
+    }
+
     fn recompute_buffer_size_after_limit(&mut self) {
         self.buffer_size += self.buffer_size_after_limit;
         let buffer_end = self.total_bytes_retired + self.buffer_size;
-        if buffer_end > self.current_limit {
+        let limit = cmp::min(self.current_limit, self.total_bytes_limit);
+        if buffer_end > limit {
             // limit is in current buffer
-            self.buffer_size_after_limit = buffer_end - self.current_limit;
+            self.buffer_size_after_limit = buffer_end - limit;
             self.buffer_size -= self.buffer_size_after_limit;
         } else {
             self.buffer_size_after_limit = 0;
         }
     }
 
-    pub fn push_limit(&mut self, limit: u32) -> u32 {
+    pub fn push_limit(&mut self, limit: u32) -> ProtobufResult<u32> {
         let old_limit = self.current_limit;
-        let new_limit = self.pos() + limit;
-        if new_limit > old_limit {
-            fail!("truncated message");
+        // compare lengths rather than positions to avoid overflow
+        if limit > self.bytes_until_total_limit() {
+            return Err(TotalBytesLimitExceeded);
+        }
+        if limit > self.bytes_until_limit() {
+            return Err(TruncatedInput);
         }
-        self.current_limit = new_limit;
+        self.current_limit = self.pos() + limit;
         self.recompute_buffer_size_after_limit();
-        old_limit
+        Ok(old_limit)
     }
 
-    pub fn pop_limit(&mut self, old_limit: u32) {
+    // Restore limit returned by `push_limit`.
+    // Error if input ended before current limit was reached.
+    pub fn pop_limit(&mut self, old_limit: u32) -> ProtobufResult<()> {
         if self.bytes_until_limit() != 0 {
-            fail!("must pop only at current limit")
+            return Err(TruncatedInput);
         }
         self.current_limit = old_limit;
         self.recompute_buffer_size_after_limit();
+        Ok(())
     }
 
-    pub fn eof(&mut self) -> bool {
-        return self.buffer_pos == self.buffer_size && !self.refill_buffer()
+    pub fn eof(&mut self) -> ProtobufResult<bool> {
+        if self.buffer_pos != self.buffer_size {
+            return Ok(false);
+        }
+        Ok(!try!(self.refill_buffer()))
     }
 
-    pub fn read_raw_byte(&mut self) -> u8 {
+    pub fn check_eof(&mut self) -> ProtobufResult<()> {
+        if !try!(self.eof()) {
+            return Err(ExpectedEof);
+        }
+        Ok(())
+    }
+
+    pub fn read_raw_byte(&mut self) -> ProtobufResult<u8> {
         if self.buffer_pos == self.buffer_size {
-            self.refill_buffer_really();
+            try!(self.refill_buffer_really());
         }
         assert!(self.buffer_pos < self.buffer_size);
         let &r = self.buffer.get(self.buffer_pos as uint);
         self.buffer_pos += 1;
-        r
+        Ok(r)
     }
 
-    pub fn read_raw_varint64(&mut self) -> u64 {
+    // Fast path: whole varint is in buffer, decode without refilling
+    fn read_raw_varint64_from_buffer(&mut self) -> ProtobufResult<u64> {
         let mut r: u64 = 0;
-        let mut i = 0;
-        loop {
-            let b = self.read_raw_byte();
-            // Stop undefined behaviour
-            if i <= 9 {
+        let mut i = 0u;
+        {
+            let buf = self.remaining_in_buffer_slice();
+            loop {
+                if i == MAX_VARINT_LEN {
+                    return Err(MalformedVarint);
+                }
+                let b = buf[i];
                 r = r | (((b & 0x7f) as u64) << (i * 7));
                 i += 1;
+                if b < 0x80 {
+                    break;
+                }
             }
+        }
+        self.buffer_pos += i as u32;
+        Ok(r)
+    }
+
+    pub fn read_raw_varint64(&mut self) -> ProtobufResult<u64> {
+        if self.remaining_in_buffer() >= MAX_VARINT_LEN as u32 {
+            return self.read_raw_varint64_from_buffer();
+        }
+        let mut r: u64 = 0;
+        let mut i = 0u;
+        loop {
+            if i == MAX_VARINT_LEN {
+                return Err(MalformedVarint);
+            }
+            let b = try!(self.read_raw_byte());
+            r = r | (((b & 0x7f) as u64) << (i * 7));
+            i += 1;
             if b < 0x80 {
-                return r;
+                return Ok(r);
             }
         }
     }
 
-    pub fn read_raw_varint32(&mut self) -> u32 {
-        self.read_raw_varint64() as u32
+    pub fn read_raw_varint32(&mut self) -> ProtobufResult<u32> {
+        let v = try!(self.read_raw_varint64());
+        // negative int32 values are sign-extended to 64 bits,
+        // anything else above 32 bits is garbage
+        if v > 0xffffffff && v < 0xffffffff80000000 {
+            return Err(MalformedVarint);
+        }
+        Ok(v as u32)
     }
 
-    pub fn read_raw_little_endian32(&mut self) -> u32 {
+    pub fn read_raw_little_endian32(&mut self) -> ProtobufResult<u32> {
         let mut bytes = [0u32, ..4];
         for i in range(0u, 4) {
-            bytes[i] = self.read_raw_byte() as u32;
+            bytes[i] = try!(self.read_raw_byte()) as u32;
         }
-        (bytes[0]      ) |
-        (bytes[1] <<  8) |
-        (bytes[2] << 16) |
-        (bytes[3] << 24)
+        Ok(
+            (bytes[0]      ) |
+            (bytes[1] <<  8) |
+            (bytes[2] << 16) |
+            (bytes[3] << 24)
+        )
     }
 
-    pub fn read_raw_little_endian64(&mut self) -> u64 {
+    pub fn read_raw_little_endian64(&mut self) -> ProtobufResult<u64> {
         let mut bytes = [0u64, ..8];
         for i in range(0u, 8) {
-            bytes[i] = self.read_raw_byte() as u64;
+            bytes[i] = try!(self.read_raw_byte()) as u64;
+        }
+        Ok(
+            (bytes[0]      ) |
+            (bytes[1] <<  8) |
+            (bytes[2] << 16) |
+            (bytes[3] << 24) |
+            (bytes[4] << 32) |
+            (bytes[5] << 40) |
+            (bytes[6] << 48) |
+            (bytes[7] << 56)
+        )
+    }
+
+    pub fn read_tag(&mut self) -> ProtobufResult<wire_format::Tag> {
+        let v = try!(self.read_raw_varint32());
+        match wire_format::Tag::new(v) {
+            Some(tag) => Ok(tag),
+            None => Err(InvalidTag(v)),
         }
-        (bytes[0]      ) |
-        (bytes[1] <<  8) |
-        (bytes[2] << 16) |
-        (bytes[3] << 24) |
-        (bytes[4] << 32) |
-        (bytes[5] << 40) |
-        (bytes[6] << 48) |
-        (bytes[7] << 56)
-    }
-
-    pub fn read_tag(&mut self) -> wire_format::Tag {
-        wire_format::Tag(self.read_raw_varint32())
     }
 
     // Read tag, return it is pair (field number, wire type)
-    pub fn read_tag_unpack(&mut self) -> (u32, wire_format::WireType) {
-        self.read_tag().unpack()
+    pub fn read_tag_unpack(&mut self) -> ProtobufResult<(u32, wire_format::WireType)> {
+        self.read_tag().map(|tag| tag.unpack())
     }
 
-    pub fn read_double(&mut self) -> f64 {
-        let bits = self.read_raw_little_endian64();
+    pub fn read_double(&mut self) -> ProtobufResult<f64> {
+        let bits = try!(self.read_raw_little_endian64());
         unsafe {
-            mem::transmute::<u64, f64>(bits)
+            Ok(mem::transmute::<u64, f64>(bits))
         }
     }
 
-    pub fn read_float(&mut self) -> f32 {
-        let bits = self.read_raw_little_endian32();
+    pub fn read_float(&mut self) -> ProtobufResult<f32> {
+        let bits = try!(self.read_raw_little_endian32());
         unsafe {
-            mem::transmute::<u32, f32>(bits)
+            Ok(mem::transmute::<u32, f32>(bits))
         }
     }
 
-    pub fn read_int64(&mut self) -> i64 {
-        self.read_raw_varint64() as i64
+    pub fn read_int64(&mut self) -> ProtobufResult<i64> {
+        self.read_raw_varint64().map(|v| v as i64)
     }
 
-    pub fn read_int32(&mut self) -> i32 {
-        self.read_raw_varint32() as i32
+    pub fn read_int32(&mut self) -> ProtobufResult<i32> {
+        self.read_raw_varint32().map(|v| v as i32)
     }
 
-    pub fn read_uint64(&mut self) -> u64 {
+    pub fn read_uint64(&mut self) -> ProtobufResult<u64> {
         self.read_raw_varint64()
     }
 
-    pub fn read_uint32(&mut self) -> u32 {
+    pub fn read_uint32(&mut self) -> ProtobufResult<u32> {
         self.read_raw_varint32()
     }
 
-    pub fn read_sint64(&mut self) -> i64 {
-        decode_zig_zag_64(self.read_uint64())
+    pub fn read_sint64(&mut self) -> ProtobufResult<i64> {
+        self.read_uint64().map(decode_zig_zag_64)
     }
 
-    pub fn read_sint32(&mut self) -> i32 {
-        decode_zig_zag_32(self.read_uint32())
+    pub fn read_sint32(&mut self) -> ProtobufResult<i32> {
+        self.read_uint32().map(decode_zig_zag_32)
     }
 
-    pub fn read_fixed64(&mut self) -> u64 {
+    pub fn read_fixed64(&mut self) -> ProtobufResult<u64> {
         self.read_raw_little_endian64()
     }
 
-    pub fn read_fixed32(&mut self) -> u32 {
+    pub fn read_fixed32(&mut self) -> ProtobufResult<u32> {
         self.read_raw_little_endian32()
     }
 
-    pub fn read_sfixed64(&mut self) -> i64 {
-        self.read_raw_little_endian64() as i64
+    pub fn read_sfixed64(&mut self) -> ProtobufResult<i64> {
+        self.read_raw_little_endian64().map(|v| v as i64)
     }
 
-    pub fn read_sfixed32(&mut self) -> i32 {
-        self.read_raw_little_endian32() as i32
+    pub fn read_sfixed32(&mut self) -> ProtobufResult<i32> {
+        self.read_raw_little_endian32().map(|v| v as i32)
     }
 
-    pub fn read_bool(&mut self) -> bool {
-        self.read_raw_varint32() != 0
+    pub fn read_bool(&mut self) -> ProtobufResult<bool> {
+        self.read_raw_varint32().map(|v| v != 0)
     }
 
-    pub fn read_unknown(&mut self, wire_type: wire_format::WireType) -> UnknownValue {
+    pub fn read_unknown(&mut self, field_number: u32, wire_type: wire_format::WireType)
+        -> ProtobufResult<UnknownValue>
+    {
         match wire_type {
-            wire_format::WireTypeVarint => { UnknownVarint(self.read_raw_varint64()) },
-            wire_format::WireTypeFixed64 => { UnknownFixed64(self.read_fixed64()) },
-            wire_format::WireTypeFixed32 => { UnknownFixed32(self.read_fixed32()) } ,
+            wire_format::WireTypeVarint => { self.read_raw_varint64().map(|v| UnknownVarint(v)) },
+            wire_format::WireTypeFixed64 => { self.read_fixed64().map(|v| UnknownFixed64(v)) },
+            wire_format::WireTypeFixed32 => { self.read_fixed32().map(|v| UnknownFixed32(v)) } ,
             wire_format::WireTypeLengthDelimited => {
-                let len = self.read_raw_varint32();
-                UnknownLengthDelimited(self.read_raw_bytes(len))
+                let len = try!(self.read_raw_varint32());
+                self.read_raw_bytes(len).map(|v| UnknownLengthDelimited(v))
             },
-            _ => fail!("unknown wire type: {:i}", wire_type as int)
+            wire_format::WireTypeStartGroup => {
+                let mut group: UnknownFields = Default::default();
+                try!(self.incr_recursion());
+                let r = self.read_unknown_group_no_recursion_check(field_number, &mut group);
+                self.decr_recursion();
+                try!(r);
+                Ok(UnknownGroup(group))
+            },
+            wire_format::WireTypeEndGroup => Err(UnexpectedEndGroup(field_number)),
+        }
+    }
+
+    // Read fields until end group tag
+    fn read_unknown_group_no_recursion_check(&mut self, field_number: u32, group: &mut UnknownFields)
+        -> ProtobufResult<()>
+    {
+        loop {
+            let (number, wire_type) = try!(self.read_tag_unpack());
+            if wire_type == wire_format::WireTypeEndGroup {
+                if number != field_number {
+                    return Err(UnexpectedEndGroup(number));
+                }
+                return Ok(());
+            }
+            let value = try!(self.read_unknown(number, wire_type));
+            group.add_value(number, value);
         }
     }
 
-    pub fn skip_field(&mut self, wire_type: wire_format::WireType) {
-        self.read_unknown(wire_type);
+    pub fn skip_field(&mut self, field_number: u32, wire_type: wire_format::WireType) -> ProtobufResult<()> {
+        self.read_unknown(field_number, wire_type).map(|_| ())
+    }
+
+    fn check_raw_bytes_count(&self, count: u32) -> ProtobufResult<()> {
+        if count > self.bytes_until_total_limit() {
+            return Err(TotalBytesLimitExceeded);
+        }
+        if count > self.bytes_until_limit() {
+            return Err(TruncatedInput);
+        }
+        Ok(())
     }
 
-    pub fn read_raw_bytes(&mut self, count: u32) -> Vec<u8> {
-        let mut r = Vec::with_capacity(count as uint);
+    pub fn read_raw_bytes(&mut self, count: u32) -> ProtobufResult<Vec<u8>> {
+        try!(self.check_raw_bytes_count(count));
+        // length is not trusted, vector grows as data is read
+        let mut r = Vec::with_capacity(cmp::min(count, self.remaining_in_buffer()) as uint);
         while r.len() < count as uint {
             let rem = count - r.len() as u32;
             if rem <= self.remaining_in_buffer() {
@@ -358,43 +557,95 @@ impl<'a> CodedInputStream<'a> {
             } else {
                 r.push_all(self.remaining_in_buffer_slice());
                 self.buffer_pos = self.buffer_size;
-                self.refill_buffer_really();
+                try!(self.refill_buffer_really());
             }
         }
-        r
+        Ok(r)
     }
 
-    pub fn skip_raw_bytes(&mut self, count: u32) {
-        self.read_raw_bytes(count);
+    pub fn skip_raw_bytes(&mut self, count: u32) -> ProtobufResult<()> {
+        try!(self.check_raw_bytes_count(count));
+        let mut rem = count;
+        while rem > self.remaining_in_buffer() {
+            rem -= self.remaining_in_buffer();
+            self.buffer_pos = self.buffer_size;
+            try!(self.refill_buffer_really());
+        }
+        self.buffer_pos += rem;
+        Ok(())
     }
 
-    pub fn read_bytes(&mut self) -> Vec<u8> {
-        let len = self.read_raw_varint32();
+    pub fn read_bytes(&mut self) -> ProtobufResult<Vec<u8>> {
+        let len = try!(self.read_raw_varint32());
         self.read_raw_bytes(len)
     }
 
     #[deprecated = "use read_string"]
-    pub fn read_strbuf(&mut self) -> String {
+    pub fn read_strbuf(&mut self) -> ProtobufResult<String> {
         self.read_string()
     }
 
-    pub fn read_string(&mut self) -> String {
-        String::from_utf8(self.read_bytes()).unwrap()
+    pub fn read_string(&mut self) -> ProtobufResult<String> {
+        let bytes = try!(self.read_bytes());
+        String::from_utf8(bytes).map_err(|_| InvalidUtf8)
     }
 
-    pub fn merge_message<M : Message>(&mut self, message: &mut M) {
-        let len = self.read_raw_varint32();
-        let old_limit = self.push_limit(len);
-        message.merge_from(self);
-        self.pop_limit(old_limit);
+    pub fn merge_message<M : Message>(&mut self, message: &mut M) -> ProtobufResult<()> {
+        try!(self.incr_recursion());
+        let r = self.merge_message_no_recursion_check(message);
+        self.decr_recursion();
+        r
     }
 
-    pub fn read_message<M : Message>(&mut self) -> M {
-        let mut r: M = Message::new();
-        self.merge_message(&mut r);
-        r.check_initialized();
+    fn merge_message_no_recursion_check<M : Message>(&mut self, message: &mut M) -> ProtobufResult<()> {
+        let len = try!(self.read_raw_varint32());
+        let old_limit = try!(self.push_limit(len));
+        // end group tags of enclosing group are not allowed inside message
+        let old_group = self.current_group.take();
+        let r = message.merge_from(self);
+        self.current_group = old_group;
+        try!(r);
+        self.pop_limit(old_limit)
+    }
+
+    // Parse group fields up to end group tag with the same field number
+    pub fn merge_group<M : Message>(&mut self, field_number: u32, message: &mut M) -> ProtobufResult<()> {
+        try!(self.incr_recursion());
+        let r = self.merge_group_no_recursion_check(field_number, message);
+        self.decr_recursion();
         r
     }
+
+    fn merge_group_no_recursion_check<M : Message>(&mut self, field_number: u32, message: &mut M)
+        -> ProtobufResult<()>
+    {
+        let old_group = mem::replace(&mut self.current_group, Some(field_number));
+        let r = message.merge_from(self);
+        self.current_group = old_group;
+        try!(r);
+        if !mem::replace(&mut self.group_ended, false) {
+            // input ended before end group tag
+            return Err(TruncatedInput);
+        }
+        Ok(())
+    }
+
+    // Called from `merge_from` when end group tag is read,
+    // `merge_from` must return after this call
+    pub fn end_group(&mut self, field_number: u32) -> ProtobufResult<()> {
+        if self.current_group != Some(field_number) {
+            return Err(UnexpectedEndGroup(field_number));
+        }
+        self.group_ended = true;
+        Ok(())
+    }
+
+    pub fn read_message<M : Message>(&mut self) -> ProtobufResult<M> {
+        let mut r: M = Message::new();
+        try!(self.merge_message(&mut r));
+        try!(r.check_initialized());
+        Ok(r)
+    }
 }
 
 trait WithCodedOutputStream {
@@ -419,23 +670,28 @@ fn with_coded_output_stream_to_bytes(cb: |&mut CodedOutputStream|) -> Vec<u8> {
 }
 
 trait WithCodedInputStream {
-    fn with_coded_input_stream<T>(self, cb: |&mut CodedInputStream| -> T) -> T;
+    fn with_coded_input_stream<T>(self, cb: |&mut CodedInputStream| -> ProtobufResult<T>)
+        -> ProtobufResult<T>;
 }
 
 impl<'a> WithCodedInputStream for &'a mut Reader {
-    fn with_coded_input_stream<T>(self, cb: |&mut CodedInputStream| -> T) -> T {
+    fn with_coded_input_stream<T>(self, cb: |&mut CodedInputStream| -> ProtobufResult<T>)
+        -> ProtobufResult<T>
+    {
         let mut is = CodedInputStream::new(self);
-        let r = cb(&mut is);
+        let r = try!(cb(&mut is));
         // reading from Reader requires all data to be read,
         // because CodedInputStream caches data, and otherwize
         // buffer would be discarded
-        assert!(is.eof());
-        r
+        try!(is.check_eof());
+        Ok(r)
     }
 }
 
 impl<'a> WithCodedInputStream for &'a [u8] {
-    fn with_coded_input_stream<T>(self, cb: |&mut CodedInputStream| -> T) -> T {
+    fn with_coded_input_stream<T>(self, cb: |&mut CodedInputStream| -> ProtobufResult<T>)
+        -> ProtobufResult<T>
+    {
         let mut reader = VecReader::new(Vec::from_slice(self));
         (&mut reader as &mut Reader).with_coded_input_stream(|is| {
             cb(is)
@@ -612,6 +868,8 @@ impl<'a> CodedOutputStream<'a> {
             UnknownFixed32Ref(fixed32) => self.write_raw_little_endian32(fixed32),
             UnknownVarintRef(varint) => self.write_raw_varint64(varint),
             UnknownLengthDelimitedRef(bytes) => self.write_bytes_no_tag(bytes),
+            // end group tag is written by `write_unknown`
+            UnknownGroupRef(fields) => self.write_unknown_fields(fields),
         }
     }
 
@@ -678,6 +936,15 @@ impl<'a> CodedOutputStream<'a> {
     pub fn write_unknown(&mut self, field_number: u32, value: UnknownValueRef) {
         self.write_tag(field_number, value.wire_type());
         self.write_unknown_no_tag(value);
+        if value.wire_type() == wire_format::WireTypeStartGroup {
+            self.write_tag(field_number, wire_format::WireTypeEndGroup);
+        }
+    }
+
+    pub fn write_group<M : Message>(&mut self, field_number: u32, msg: &M) {
+        self.write_tag(field_number, wire_format::WireTypeStartGroup);
+        msg.write_to(self);
+        self.write_tag(field_number, wire_format::WireTypeEndGroup);
     }
 
     pub fn write_unknown_fields(&mut self, fields: &UnknownFields) {
@@ -722,7 +989,10 @@ pub trait Message : PartialEq + Clone + Default + fmt::Show + Clear {
     fn new() -> Self;
     // all required fields set
     fn is_initialized(&self) -> bool;
-    fn merge_from(&mut self, is: &mut CodedInputStream);
+    fn merge_from(&mut self, is: &mut CodedInputStream) -> ProtobufResult<()>;
+    // Set singular fields of `other` overwrite fields of `self`, repeated fields are appended,
+    // message fields are merged recursively, unknown fields are appended
+    fn merge_from_message(&mut self, other: &Self);
     fn write_to(&self, os: &mut CodedOutputStream);
     fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32;
 
@@ -731,9 +1001,20 @@ pub trait Message : PartialEq + Clone + Default + fmt::Show + Clear {
         self.compute_sizes(&mut sizes)
     }
 
-    fn check_initialized(&self) {
-        // TODO: report which fields are not initialized
-        assert!(self.is_initialized());
+    fn check_initialized(&self) -> ProtobufResult<()> {
+        if !self.is_initialized() {
+            return Err(MissingRequiredFields(
+                self.descriptor().name().to_string(), self.find_initialization_errors()));
+        }
+        Ok(())
+    }
+
+    // Paths of missing required fields, including fields of nested messages,
+    // e. g. `c.a` or `items[2].id`
+    fn find_initialization_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        find_initialization_errors_to(self, "", &mut errors);
+        errors
     }
 
     fn write_to_writer(&self, w: &mut Writer) {
@@ -768,6 +1049,30 @@ pub trait Message : PartialEq + Clone + Default + fmt::Show + Clear {
     fn get_unknown_fields<'s>(&'s self) -> &'s UnknownFields;
     fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut UnknownFields;
 
+    fn get_extension<V, T : ProtobufType<V>>(&self, ext: &ExtFieldOptional<Self, T>) -> Option<V> {
+        ext.get(self)
+    }
+
+    fn has_extension<V, T : ProtobufType<V>>(&self, ext: &ExtFieldOptional<Self, T>) -> bool {
+        ext.has(self)
+    }
+
+    fn set_extension<V, T : ProtobufType<V>>(&mut self, ext: &ExtFieldOptional<Self, T>, value: V) {
+        ext.set(self, value)
+    }
+
+    fn clear_extension<V, T : ProtobufType<V>>(&mut self, ext: &ExtFieldOptional<Self, T>) {
+        ext.clear(self)
+    }
+
+    fn get_repeated_extension<V, T : ProtobufType<V>>(&self, ext: &ExtFieldRepeated<Self, T>) -> Vec<V> {
+        ext.get(self)
+    }
+
+    fn add_repeated_extension<V, T : ProtobufType<V>>(&mut self, ext: &ExtFieldRepeated<Self, T>, value: V) {
+        ext.add(self, value)
+    }
+
     fn descriptor(&self) -> &'static MessageDescriptor {
         Message::descriptor_static(None::<Self>)
     }
@@ -790,6 +1095,36 @@ pub trait Message : PartialEq + Clone + Default + fmt::Show + Clear {
     }
 }
 
+fn find_initialization_errors_to(m: &Message, path: &str, errors: &mut Vec<String>) {
+    for f in m.descriptor().fields().iter() {
+        let field_path =
+            if path.is_empty() {
+                f.name().to_string()
+            } else {
+                format!("{}.{}", path, f.name())
+            };
+        let is_message = match f.proto().get_field_type() {
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP => true,
+            _ => false,
+        };
+        if f.is_repeated() {
+            if is_message {
+                for i in range(0, f.len_field(m)) {
+                    let item_path = format!("{}[{}]", field_path, i);
+                    find_initialization_errors_to(f.get_rep_message_item(m, i), item_path.as_slice(), errors);
+                }
+            }
+        } else if f.has_field(m) {
+            if is_message {
+                find_initialization_errors_to(f.get_message(m), field_path.as_slice(), errors);
+            }
+        } else if f.is_required() {
+            errors.push(field_path);
+        }
+    }
+}
+
 pub fn message_is<M : 'static + Message>(m: &Message) -> bool {
     TypeId::of::<M>() == m.type_id()
 }
@@ -803,12 +1138,23 @@ pub fn message_down_cast<'a, M : 'static + Message>(m: &'a Message) -> &'a M {
     }
 }
 
+pub fn message_down_cast_mut<'a, M : 'static + Message>(m: &'a mut Message) -> &'a mut M {
+    assert!(message_is::<M>(m));
+    unsafe {
+        let r: raw::TraitObject = mem::transmute(m);
+        mem::transmute(r.data)
+    }
+}
+
 
 pub trait ProtobufEnum : Eq {
     fn value(&self) -> i32;
 
+    fn from_i32(value: i32) -> Option<Self>;
+
     fn descriptor(&self) -> &'static EnumValueDescriptor {
-        self.enum_descriptor().value_by_number(self.value())
+        // generated enum has variants only for values declared in descriptor
+        self.enum_descriptor().find_value_by_number(self.value()).unwrap()
     }
 
     fn enum_descriptor(&self) -> &'static EnumDescriptor {
@@ -821,37 +1167,37 @@ pub trait ProtobufEnum : Eq {
     }
 }
 
-pub fn parse_from<M : Message>(is: &mut CodedInputStream) -> M {
+pub fn parse_from<M : Message>(is: &mut CodedInputStream) -> ProtobufResult<M> {
     let mut r: M = Message::new();
-    r.merge_from(is);
-    r.check_initialized();
-    r
+    try!(r.merge_from(is));
+    try!(r.check_initialized());
+    Ok(r)
 }
 
-pub fn parse_from_reader<M : Message>(reader: &mut Reader) -> M {
+pub fn parse_from_reader<M : Message>(reader: &mut Reader) -> ProtobufResult<M> {
     reader.with_coded_input_stream(|is| {
         parse_from::<M>(is)
     })
 }
 
-pub fn parse_from_bytes<M : Message>(bytes: &[u8]) -> M {
+pub fn parse_from_bytes<M : Message>(bytes: &[u8]) -> ProtobufResult<M> {
     bytes.with_coded_input_stream(|is| {
         parse_from::<M>(is)
     })
 }
 
-pub fn parse_length_delimited_from<M : Message>(is: &mut CodedInputStream) -> M {
+pub fn parse_length_delimited_from<M : Message>(is: &mut CodedInputStream) -> ProtobufResult<M> {
     is.read_message::<M>()
 }
 
-pub fn parse_length_delimited_from_reader<M : Message>(r: &mut Reader) -> M {
+pub fn parse_length_delimited_from_reader<M : Message>(r: &mut Reader) -> ProtobufResult<M> {
     // TODO: wrong: we may read length first, and then read exact number of bytes needed
     r.with_coded_input_stream(|is| {
         is.read_message::<M>()
     })
 }
 
-pub fn parse_length_delimited_from_bytes<M : Message>(bytes: &[u8]) -> M {
+pub fn parse_length_delimited_from_bytes<M : Message>(bytes: &[u8]) -> ProtobufResult<M> {
     bytes.with_coded_input_stream(|is| {
         is.read_message::<M>()
     })
@@ -864,8 +1210,10 @@ mod test {
 
     use super::*;
     use std::io::*;
+    use std::io::util::ChainedReader;
     use misc::*;
     use hex::*;
+    use error::*;
 
     fn test_read(hex: &str, callback: |&mut CodedInputStream|) {
         let d = decode_hex(hex);
@@ -874,68 +1222,218 @@ mod test {
         let mut is = CodedInputStream::new(&mut reader as &mut Reader);
         assert_eq!(0, is.pos());
         callback(&mut is);
-        assert!(is.eof());
+        assert!(is.eof().unwrap());
         assert_eq!(len as u32, is.pos());
     }
 
     #[test]
     fn test_input_stream_read_raw_byte() {
         test_read("17", |is| {
-            assert_eq!(23, is.read_raw_byte());
+            assert_eq!(23, is.read_raw_byte().unwrap());
         });
     }
 
     #[test]
     fn test_input_stream_read_varint() {
         test_read("07", |reader| {
-            assert_eq!(7, reader.read_raw_varint32());
+            assert_eq!(7, reader.read_raw_varint32().unwrap());
         });
         test_read("07", |reader| {
-            assert_eq!(7, reader.read_raw_varint64());
+            assert_eq!(7, reader.read_raw_varint64().unwrap());
         });
         test_read("96 01", |reader| {
-            assert_eq!(150, reader.read_raw_varint32());
+            assert_eq!(150, reader.read_raw_varint32().unwrap());
         });
         test_read("96 01", |reader| {
-            assert_eq!(150, reader.read_raw_varint64());
+            assert_eq!(150, reader.read_raw_varint64().unwrap());
+        });
+        // fast path
+        test_read("96 01 07 00 00 00 00 00 00 00", |reader| {
+            assert_eq!(150, reader.read_raw_varint64().unwrap());
+            assert_eq!(7, reader.read_raw_varint64().unwrap());
+            reader.skip_raw_bytes(7).unwrap();
+        });
+        test_read("ff ff ff ff ff ff ff ff ff 01", |reader| {
+            assert_eq!(0xffffffffffffffff, reader.read_raw_varint64().unwrap());
+        });
+        // negative int32
+        test_read("ff ff ff ff ff ff ff ff ff 01", |reader| {
+            assert_eq!(-1, reader.read_int32().unwrap());
+        });
+        test_read("80 80 80 80 f8 ff ff ff ff 01", |reader| {
+            assert_eq!(0x80000000, reader.read_raw_varint32().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_malformed_varint() {
+        test_read_error("ff ff ff ff ff ff ff ff ff ff 01", MalformedVarint, |is| {
+            is.read_raw_varint64().map(|_| ())
+        });
+        // slow path: varint is split between reads
+        let readers = vec![
+            MemReader::new(vec![0xff, 0xff, 0xff]),
+            MemReader::new(Vec::from_elem(8, 0xffu8)),
+        ];
+        let mut reader = ChainedReader::new(readers.move_iter());
+        let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+        assert_eq!(Err(MalformedVarint), is.read_raw_varint64());
+
+        test_read_error("ff ff ff ff ff ff ff ff ff", TruncatedInput, |is| {
+            is.read_raw_varint64().map(|_| ())
+        });
+        // does not fit into 32 bits
+        test_read_error("80 80 80 80 10", MalformedVarint, |is| {
+            is.read_raw_varint32().map(|_| ())
+        });
+        test_read_error("80 80 80 80 f0 ff ff ff ff 01", MalformedVarint, |is| {
+            is.read_raw_varint32().map(|_| ())
         });
     }
 
     #[test]
     fn test_output_input_stream_read_float() {
         test_read("95 73 13 61", |is| {
-            assert_eq!(17e19, is.read_float());
+            assert_eq!(17e19, is.read_float().unwrap());
         });
     }
 
     #[test]
     fn test_input_stream_read_double() {
         test_read("40 d5 ab 68 b3 07 3d 46", |is| {
-            assert_eq!(23e29, is.read_double());
+            assert_eq!(23e29, is.read_double().unwrap());
         });
     }
 
     #[test]
     fn test_input_stream_skip_raw_bytes() {
         test_read("", |reader| {
-            reader.skip_raw_bytes(0);
+            reader.skip_raw_bytes(0).unwrap();
         });
         test_read("aa bb", |reader| {
-            reader.skip_raw_bytes(2);
+            reader.skip_raw_bytes(2).unwrap();
         });
         test_read("aa bb cc dd ee ff", |reader| {
-            reader.skip_raw_bytes(6);
+            reader.skip_raw_bytes(6).unwrap();
         });
     }
 
     #[test]
     fn test_input_stream_limits() {
         test_read("aa bb cc", |is| {
-            let old_limit = is.push_limit(1);
+            let old_limit = is.push_limit(1).unwrap();
             assert_eq!(1, is.bytes_until_limit());
-            assert_eq!(&[0xaa], is.read_raw_bytes(1).as_slice());
-            is.pop_limit(old_limit);
-            assert_eq!(&[0xbb, 0xcc], is.read_raw_bytes(2).as_slice());
+            assert_eq!(&[0xaa], is.read_raw_bytes(1).unwrap().as_slice());
+            is.pop_limit(old_limit).unwrap();
+            assert_eq!(&[0xbb, 0xcc], is.read_raw_bytes(2).unwrap().as_slice());
+        });
+    }
+
+    fn test_read_error(hex: &str, expected: ProtobufError,
+        callback: |&mut CodedInputStream| -> ProtobufResult<()>)
+    {
+        let d = decode_hex(hex);
+        let mut reader = MemReader::new(d);
+        let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+        assert_eq!(Err(expected), callback(&mut is));
+    }
+
+    #[test]
+    fn test_input_stream_truncated() {
+        test_read_error("96", TruncatedInput, |is| {
+            is.read_raw_varint64().map(|_| ())
+        });
+        test_read_error("aa bb", TruncatedInput, |is| {
+            is.read_raw_bytes(3).map(|_| ())
+        });
+        test_read_error("aa", TruncatedInput, |is| {
+            let old_limit = try!(is.push_limit(2));
+            try!(is.skip_raw_bytes(1));
+            is.pop_limit(old_limit)
+        });
+        test_read_error("aa bb", TruncatedInput, |is| {
+            try!(is.push_limit(1));
+            is.push_limit(2).map(|_| ())
+        });
+    }
+
+    #[test]
+    fn test_input_stream_invalid_utf8() {
+        test_read_error("02 c3 28", InvalidUtf8, |is| {
+            is.read_string().map(|_| ())
+        });
+    }
+
+    #[test]
+    fn test_input_stream_invalid_tag() {
+        test_read_error("07", InvalidTag(7), |is| {
+            is.read_tag().map(|_| ())
+        });
+        test_read_error("02", InvalidTag(2), |is| {
+            is.read_tag().map(|_| ())
+        });
+    }
+
+    #[test]
+    fn test_input_stream_huge_length() {
+        // 4G length must not be allocated upfront
+        test_read_error("ff ff ff ff 0f 01 02", TotalBytesLimitExceeded, |is| {
+            is.read_bytes().map(|_| ())
+        });
+        test_read_error("ff ff ff ff 0f 01 02", TotalBytesLimitExceeded, |is| {
+            is.read_raw_varint32().and_then(|len| is.skip_raw_bytes(len))
+        });
+        test_read_error("ff ff ff ff 0f 01 02", TotalBytesLimitExceeded, |is| {
+            let len = try!(is.read_raw_varint32());
+            is.push_limit(len).map(|_| ())
+        });
+        test_read_error("05 01 02 03 04 05", TruncatedInput, |is| {
+            try!(is.push_limit(3));
+            is.read_bytes().map(|_| ())
+        });
+        test_read_error("ff ff 03 01 02", TruncatedInput, |is| {
+            is.read_bytes().map(|_| ())
+        });
+    }
+
+    #[test]
+    fn test_input_stream_total_bytes_limit() {
+        test_read_error("01 02 03 04", TotalBytesLimitExceeded, |is| {
+            is.set_total_bytes_limit(3);
+            is.read_raw_bytes(4).map(|_| ())
+        });
+        test_read_error("01 02 03 04", TotalBytesLimitExceeded, |is| {
+            is.set_total_bytes_limit(3);
+            assert_eq!(vec![1, 2, 3], try!(is.read_raw_bytes(3)));
+            is.read_raw_byte().map(|_| ())
+        });
+        test_read_error("01 02 03 04", TotalBytesLimitExceeded, |is| {
+            is.set_total_bytes_limit(2);
+            is.read_raw_little_endian32().map(|_| ())
+        });
+        test_read_error("01 02 03 04", TruncatedInput, |is| {
+            is.set_total_bytes_limit(10);
+            is.read_raw_bytes(5).map(|_| ())
+        });
+        test_read_error("01 02 03 04", TruncatedInput, |is| {
+            is.set_total_bytes_limit(4);
+            try!(is.read_raw_bytes(4));
+            is.read_raw_byte().map(|_| ())
+        });
+    }
+
+    #[test]
+    fn test_input_stream_total_bytes_limit_at_eof() {
+        test_read("08 01 12 01 61", |is| {
+            is.set_total_bytes_limit(5);
+            assert_eq!(vec![8, 1, 0x12, 1, 0x61], is.read_raw_bytes(5).unwrap());
+            assert!(is.eof().unwrap());
+            is.check_eof().unwrap();
+        });
+        test_read_error("08 01 12 01 61 00", TotalBytesLimitExceeded, |is| {
+            is.set_total_bytes_limit(5);
+            try!(is.read_raw_bytes(5));
+            is.check_eof()
         });
     }
 
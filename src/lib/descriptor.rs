@@ -268,7 +268,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::descriptor::FileDescriptorProto };
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data)
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
@@ -279,6 +279,48 @@ pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescripto
     }
 }
 
+static mut file_descriptor_lazy: ::protobuf::lazy::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::FileDescriptor };
+
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    unsafe {
+        file_descriptor_lazy.get(|| {
+            ::protobuf::reflect::FileDescriptor::new(
+                file_descriptor_proto(),
+                vec!(
+                ),
+                vec!(
+                    ::protobuf::reflect::MessageDescriptor::for_type::<FileDescriptorSet>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<FileDescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<DescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<FieldDescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<EnumDescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<EnumValueDescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<ServiceDescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<MethodDescriptorProto>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<FileOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<MessageOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<FieldOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<EnumOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<EnumValueOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<ServiceOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<MethodOptions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<SourceCodeInfo>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<DescriptorProto_ExtensionRange>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption_NamePart>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<SourceCodeInfo_Location>(),
+                ),
+                vec!(
+                    ::protobuf::reflect::EnumDescriptor::for_type::<FieldDescriptorProto_Type>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<FieldDescriptorProto_Label>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<FileOptions_OptimizeMode>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<FieldOptions_CType>(),
+                ),
+            )
+        })
+    }
+}
+
 #[deriving(Clone,PartialEq,Default)]
 pub struct FileDescriptorSet {
     file: ::protobuf::RepeatedField<FileDescriptorProto>,
@@ -342,24 +384,48 @@ impl ::protobuf::Message for FileDescriptorSet {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.file.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.file.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &FileDescriptorSet) {
+        use protobuf::{Message};
+        for v in other.file.iter() {
+            self.file.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -379,7 +445,7 @@ impl ::protobuf::Message for FileDescriptorSet {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -413,7 +479,8 @@ impl ::protobuf::Message for FileDescriptorSet {
                 ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorSet>(
                     "FileDescriptorSet",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -446,6 +513,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorSet> for FileDescriptorSet
         "file"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FileDescriptorProto>()
+    }
+
     fn len_field(&self, m: &FileDescriptorSet) -> uint {
         m.get_file().len()
     }
@@ -453,6 +524,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorSet> for FileDescriptorSet
     fn get_rep_message_item<'a>(&self, m: &'a FileDescriptorSet, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_file()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorSet) {
+        m.clear_file();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FileDescriptorSet) -> &'a mut ::protobuf::Message {
+        m.mut_file().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FileDescriptorSet, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_file().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -837,90 +920,209 @@ impl ::protobuf::Message for FileDescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.message_type.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.enum_type.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.service.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.extension.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.source_code_info.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.package = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.dependency.push(tmp);
                 },
                 10 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.public_dependency.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.public_dependency.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.public_dependency.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.public_dependency.push(try!(is.read_int32()));
                     }
                 },
                 11 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.weak_dependency.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.weak_dependency.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.weak_dependency.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.weak_dependency.push(try!(is.read_int32()));
                     }
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.message_type.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.enum_type.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 6 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.service.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 7 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.extension.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 8 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 9 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.source_code_info.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &FileDescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        if other.package.is_some() {
+            self.package = other.package.clone();
+        };
+        self.dependency.push_all(other.dependency.as_slice());
+        self.public_dependency.push_all(other.public_dependency.as_slice());
+        self.weak_dependency.push_all(other.weak_dependency.as_slice());
+        for v in other.message_type.iter() {
+            self.message_type.push(v.clone());
+        };
+        for v in other.enum_type.iter() {
+            self.enum_type.push(v.clone());
+        };
+        for v in other.service.iter() {
+            self.service.push(v.clone());
+        };
+        for v in other.extension.iter() {
+            self.extension.push(v.clone());
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.source_code_info.is_some() {
+            if self.source_code_info.is_none() {
+                self.source_code_info.set_default();
+            };
+            self.source_code_info.get_mut_ref().merge_from_message(other.source_code_info.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -975,7 +1177,7 @@ impl ::protobuf::Message for FileDescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1019,7 +1221,8 @@ impl ::protobuf::Message for FileDescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorProto>(
                     "FileDescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1069,6 +1272,14 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_str<'a>(&self, m: &'a FileDescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut FileDescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1086,6 +1297,14 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_str<'a>(&self, m: &'a FileDescriptorProto) -> &'a str {
         m.get_package()
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_package();
+    }
+
+    fn set_str(&self, m: &mut FileDescriptorProto, v: String) {
+        m.set_package(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1103,6 +1322,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_str<'a>(&self, m: &'a FileDescriptorProto) -> &'a [String] {
         m.get_dependency()
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_dependency();
+    }
+
+    fn add_str(&self, m: &mut FileDescriptorProto, v: String) {
+        m.add_dependency(v);
+    }
+
+    fn mut_rep_str<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut Vec<String> {
+        m.mut_dependency()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1120,6 +1351,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_i32<'a>(&self, m: &'a FileDescriptorProto) -> &'a [i32] {
         m.get_public_dependency()
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_public_dependency();
+    }
+
+    fn add_i32(&self, m: &mut FileDescriptorProto, v: i32) {
+        m.add_public_dependency(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut Vec<i32> {
+        m.mut_public_dependency()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1137,6 +1380,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_i32<'a>(&self, m: &'a FileDescriptorProto) -> &'a [i32] {
         m.get_weak_dependency()
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_weak_dependency();
+    }
+
+    fn add_i32(&self, m: &mut FileDescriptorProto, v: i32) {
+        m.add_weak_dependency(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut Vec<i32> {
+        m.mut_weak_dependency()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1147,6 +1402,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
         "message_type"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<DescriptorProto>()
+    }
+
     fn len_field(&self, m: &FileDescriptorProto) -> uint {
         m.get_message_type().len()
     }
@@ -1154,6 +1413,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_message_item<'a>(&self, m: &'a FileDescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_message_type()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_message_type();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_message_type().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FileDescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_message_type().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1164,6 +1435,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
         "enum_type"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<EnumDescriptorProto>()
+    }
+
     fn len_field(&self, m: &FileDescriptorProto) -> uint {
         m.get_enum_type().len()
     }
@@ -1171,6 +1446,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_message_item<'a>(&self, m: &'a FileDescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_enum_type()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_enum_type();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_enum_type().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FileDescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_enum_type().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1181,6 +1468,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
         "service"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<ServiceDescriptorProto>()
+    }
+
     fn len_field(&self, m: &FileDescriptorProto) -> uint {
         m.get_service().len()
     }
@@ -1188,6 +1479,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_message_item<'a>(&self, m: &'a FileDescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_service()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_service();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_service().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FileDescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_service().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1198,6 +1501,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
         "extension"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FieldDescriptorProto>()
+    }
+
     fn len_field(&self, m: &FileDescriptorProto) -> uint {
         m.get_extension().len()
     }
@@ -1205,6 +1512,18 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_rep_message_item<'a>(&self, m: &'a FileDescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_extension()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_extension();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_extension().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FileDescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_extension().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1215,6 +1534,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FileOptions>()
+    }
+
     fn has_field(&self, m: &FileDescriptorProto) -> bool {
         m.has_options()
     }
@@ -1222,6 +1545,14 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_message<'a>(&self, m: &'a FileDescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1232,6 +1563,10 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
         "source_code_info"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<SourceCodeInfo>()
+    }
+
     fn has_field(&self, m: &FileDescriptorProto) -> bool {
         m.has_source_code_info()
     }
@@ -1239,6 +1574,14 @@ impl ::protobuf::reflect::FieldAccessor<FileDescriptorProto> for FileDescriptorP
     fn get_message<'a>(&self, m: &'a FileDescriptorProto) -> &'a ::protobuf::Message {
         m.get_source_code_info() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileDescriptorProto) {
+        m.clear_source_code_info();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut FileDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_source_code_info() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -1498,54 +1841,148 @@ impl ::protobuf::Message for DescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.field.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.extension.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.nested_type.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.enum_type.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.extension_range.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.field.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 6 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.extension.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.nested_type.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.enum_type.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.extension_range.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 7 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &DescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        for v in other.field.iter() {
+            self.field.push(v.clone());
+        };
+        for v in other.extension.iter() {
+            self.extension.push(v.clone());
+        };
+        for v in other.nested_type.iter() {
+            self.nested_type.push(v.clone());
+        };
+        for v in other.enum_type.iter() {
+            self.enum_type.push(v.clone());
+        };
+        for v in other.extension_range.iter() {
+            self.extension_range.push(v.clone());
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1588,7 +2025,7 @@ impl ::protobuf::Message for DescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1628,7 +2065,8 @@ impl ::protobuf::Message for DescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto>(
                     "DescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1674,6 +2112,14 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_nam
     fn get_str<'a>(&self, m: &'a DescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut DescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1684,6 +2130,10 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_fie
         "field"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FieldDescriptorProto>()
+    }
+
     fn len_field(&self, m: &DescriptorProto) -> uint {
         m.get_field().len()
     }
@@ -1691,6 +2141,18 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_fie
     fn get_rep_message_item<'a>(&self, m: &'a DescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_field()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_field();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut DescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_field().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut DescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_field().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1701,6 +2163,10 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_ext
         "extension"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FieldDescriptorProto>()
+    }
+
     fn len_field(&self, m: &DescriptorProto) -> uint {
         m.get_extension().len()
     }
@@ -1708,6 +2174,18 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_ext
     fn get_rep_message_item<'a>(&self, m: &'a DescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_extension()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_extension();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut DescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_extension().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut DescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_extension().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1718,6 +2196,10 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_nes
         "nested_type"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<DescriptorProto>()
+    }
+
     fn len_field(&self, m: &DescriptorProto) -> uint {
         m.get_nested_type().len()
     }
@@ -1725,6 +2207,18 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_nes
     fn get_rep_message_item<'a>(&self, m: &'a DescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_nested_type()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_nested_type();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut DescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_nested_type().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut DescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_nested_type().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1735,6 +2229,10 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_enu
         "enum_type"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<EnumDescriptorProto>()
+    }
+
     fn len_field(&self, m: &DescriptorProto) -> uint {
         m.get_enum_type().len()
     }
@@ -1742,6 +2240,18 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_enu
     fn get_rep_message_item<'a>(&self, m: &'a DescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_enum_type()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_enum_type();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut DescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_enum_type().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut DescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_enum_type().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1752,6 +2262,10 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_ext
         "extension_range"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<DescriptorProto_ExtensionRange>()
+    }
+
     fn len_field(&self, m: &DescriptorProto) -> uint {
         m.get_extension_range().len()
     }
@@ -1759,6 +2273,18 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_ext
     fn get_rep_message_item<'a>(&self, m: &'a DescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_extension_range()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_extension_range();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut DescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_extension_range().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut DescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_extension_range().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1769,6 +2295,10 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_opt
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<MessageOptions>()
+    }
+
     fn has_field(&self, m: &DescriptorProto) -> bool {
         m.has_options()
     }
@@ -1776,6 +2306,14 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto> for DescriptorProto_opt
     fn get_message<'a>(&self, m: &'a DescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut DescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -1883,26 +2421,50 @@ impl ::protobuf::Message for DescriptorProto_ExtensionRange {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.start = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.end = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &DescriptorProto_ExtensionRange) {
+        if other.start.is_some() {
+            self.start = other.start.clone();
+        };
+        if other.end.is_some() {
+            self.end = other.end.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1924,7 +2486,7 @@ impl ::protobuf::Message for DescriptorProto_ExtensionRange {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1959,7 +2521,8 @@ impl ::protobuf::Message for DescriptorProto_ExtensionRange {
                 ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto_ExtensionRange>(
                     "DescriptorProto_ExtensionRange",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -2000,6 +2563,14 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto_ExtensionRange> for Desc
     fn get_i32(&self, m: &DescriptorProto_ExtensionRange) -> i32 {
         m.get_start()
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto_ExtensionRange) {
+        m.clear_start();
+    }
+
+    fn set_i32(&self, m: &mut DescriptorProto_ExtensionRange, v: i32) {
+        m.set_start(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2017,6 +2588,14 @@ impl ::protobuf::reflect::FieldAccessor<DescriptorProto_ExtensionRange> for Desc
     fn get_i32(&self, m: &DescriptorProto_ExtensionRange) -> i32 {
         m.get_end()
     }
+
+    fn clear_field(&self, m: &mut DescriptorProto_ExtensionRange) {
+        m.clear_end();
+    }
+
+    fn set_i32(&self, m: &mut DescriptorProto_ExtensionRange, v: i32) {
+        m.set_end(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -2184,13 +2763,13 @@ impl<'a> FieldDescriptorProto {
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_label(&'a mut self) -> &'a mut FieldDescriptorProto_Label {
         if self.label.is_none() {
-            self.label = Some(FieldDescriptorProto_Label::new(0));
+            self.label = Some(FieldDescriptorProto_LABEL_OPTIONAL);
         };
         self.label.get_mut_ref()
     }
 
     pub fn get_label(&self) -> FieldDescriptorProto_Label {
-        self.label.unwrap_or_else(|| FieldDescriptorProto_Label::new(0))
+        self.label.unwrap_or_else(|| FieldDescriptorProto_LABEL_OPTIONAL)
     }
 
     pub fn clear_field_type(&mut self) {
@@ -2210,13 +2789,13 @@ impl<'a> FieldDescriptorProto {
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_field_type(&'a mut self) -> &'a mut FieldDescriptorProto_Type {
         if self.field_type.is_none() {
-            self.field_type = Some(FieldDescriptorProto_Type::new(0));
+            self.field_type = Some(FieldDescriptorProto_TYPE_DOUBLE);
         };
         self.field_type.get_mut_ref()
     }
 
     pub fn get_field_type(&self) -> FieldDescriptorProto_Type {
-        self.field_type.unwrap_or_else(|| FieldDescriptorProto_Type::new(0))
+        self.field_type.unwrap_or_else(|| FieldDescriptorProto_TYPE_DOUBLE)
     }
 
     pub fn clear_type_name(&mut self) {
@@ -2339,59 +2918,145 @@ impl ::protobuf::Message for FieldDescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.number = Some(tmp);
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = FieldDescriptorProto_Label::new(is.read_int32());
-                    self.label = Some(tmp);
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match FieldDescriptorProto_Label::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.label = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = FieldDescriptorProto_Type::new(is.read_int32());
-                    self.field_type = Some(tmp);
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match FieldDescriptorProto_Type::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.field_type = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
                 },
                 6 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.type_name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.extendee = Some(tmp);
                 },
                 7 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.default_value = Some(tmp);
                 },
                 8 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &FieldDescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        if other.number.is_some() {
+            self.number = other.number.clone();
+        };
+        if other.label.is_some() {
+            self.label = other.label.clone();
+        };
+        if other.field_type.is_some() {
+            self.field_type = other.field_type.clone();
+        };
+        if other.type_name.is_some() {
+            self.type_name = other.type_name.clone();
+        };
+        if other.extendee.is_some() {
+            self.extendee = other.extendee.clone();
+        };
+        if other.default_value.is_some() {
+            self.default_value = other.default_value.clone();
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -2432,7 +3097,7 @@ impl ::protobuf::Message for FieldDescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -2473,7 +3138,8 @@ impl ::protobuf::Message for FieldDescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<FieldDescriptorProto>(
                     "FieldDescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -2520,6 +3186,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
     fn get_str<'a>(&self, m: &'a FieldDescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut FieldDescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2537,6 +3211,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
     fn get_i32(&self, m: &FieldDescriptorProto) -> i32 {
         m.get_number()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_number();
+    }
+
+    fn set_i32(&self, m: &mut FieldDescriptorProto, v: i32) {
+        m.set_number(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2547,6 +3229,10 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
         "label"
     }
 
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<FieldDescriptorProto_Label>()
+    }
+
     fn has_field(&self, m: &FieldDescriptorProto) -> bool {
         m.has_label()
     }
@@ -2555,6 +3241,17 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
         use protobuf::{ProtobufEnum};
         m.get_label().descriptor()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_label();
+    }
+
+    fn set_enum(&self, m: &mut FieldDescriptorProto, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match FieldDescriptorProto_Label::from_i32(v.value()) {
+            Some(e) => m.set_label(e),
+            None => fail!("unknown value {} of enum FieldDescriptorProto_Label", v.value()),
+        }
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2562,7 +3259,11 @@ struct FieldDescriptorProto_field_type_acc;
 
 impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescriptorProto_field_type_acc {
     fn name(&self) -> &'static str {
-        "field_type"
+        "type"
+    }
+
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<FieldDescriptorProto_Type>()
     }
 
     fn has_field(&self, m: &FieldDescriptorProto) -> bool {
@@ -2573,6 +3274,17 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
         use protobuf::{ProtobufEnum};
         m.get_field_type().descriptor()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_field_type();
+    }
+
+    fn set_enum(&self, m: &mut FieldDescriptorProto, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match FieldDescriptorProto_Type::from_i32(v.value()) {
+            Some(e) => m.set_field_type(e),
+            None => fail!("unknown value {} of enum FieldDescriptorProto_Type", v.value()),
+        }
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2590,6 +3302,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
     fn get_str<'a>(&self, m: &'a FieldDescriptorProto) -> &'a str {
         m.get_type_name()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_type_name();
+    }
+
+    fn set_str(&self, m: &mut FieldDescriptorProto, v: String) {
+        m.set_type_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2607,6 +3327,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
     fn get_str<'a>(&self, m: &'a FieldDescriptorProto) -> &'a str {
         m.get_extendee()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_extendee();
+    }
+
+    fn set_str(&self, m: &mut FieldDescriptorProto, v: String) {
+        m.set_extendee(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2624,6 +3352,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
     fn get_str<'a>(&self, m: &'a FieldDescriptorProto) -> &'a str {
         m.get_default_value()
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_default_value();
+    }
+
+    fn set_str(&self, m: &mut FieldDescriptorProto, v: String) {
+        m.set_default_value(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2634,6 +3370,10 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FieldOptions>()
+    }
+
     fn has_field(&self, m: &FieldDescriptorProto) -> bool {
         m.has_options()
     }
@@ -2641,6 +3381,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldDescriptorProto> for FieldDescripto
     fn get_message<'a>(&self, m: &'a FieldDescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FieldDescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut FieldDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Eq,Show)]
@@ -2667,26 +3415,33 @@ pub enum FieldDescriptorProto_Type {
 
 impl FieldDescriptorProto_Type {
     pub fn new(value: i32) -> FieldDescriptorProto_Type {
+        match FieldDescriptorProto_Type::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum FieldDescriptorProto_Type", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<FieldDescriptorProto_Type> {
         match value {
-            1 => FieldDescriptorProto_TYPE_DOUBLE,
-            2 => FieldDescriptorProto_TYPE_FLOAT,
-            3 => FieldDescriptorProto_TYPE_INT64,
-            4 => FieldDescriptorProto_TYPE_UINT64,
-            5 => FieldDescriptorProto_TYPE_INT32,
-            6 => FieldDescriptorProto_TYPE_FIXED64,
-            7 => FieldDescriptorProto_TYPE_FIXED32,
-            8 => FieldDescriptorProto_TYPE_BOOL,
-            9 => FieldDescriptorProto_TYPE_STRING,
-            10 => FieldDescriptorProto_TYPE_GROUP,
-            11 => FieldDescriptorProto_TYPE_MESSAGE,
-            12 => FieldDescriptorProto_TYPE_BYTES,
-            13 => FieldDescriptorProto_TYPE_UINT32,
-            14 => FieldDescriptorProto_TYPE_ENUM,
-            15 => FieldDescriptorProto_TYPE_SFIXED32,
-            16 => FieldDescriptorProto_TYPE_SFIXED64,
-            17 => FieldDescriptorProto_TYPE_SINT32,
-            18 => FieldDescriptorProto_TYPE_SINT64,
-            _ => fail!()
+            1 => Some(FieldDescriptorProto_TYPE_DOUBLE),
+            2 => Some(FieldDescriptorProto_TYPE_FLOAT),
+            3 => Some(FieldDescriptorProto_TYPE_INT64),
+            4 => Some(FieldDescriptorProto_TYPE_UINT64),
+            5 => Some(FieldDescriptorProto_TYPE_INT32),
+            6 => Some(FieldDescriptorProto_TYPE_FIXED64),
+            7 => Some(FieldDescriptorProto_TYPE_FIXED32),
+            8 => Some(FieldDescriptorProto_TYPE_BOOL),
+            9 => Some(FieldDescriptorProto_TYPE_STRING),
+            10 => Some(FieldDescriptorProto_TYPE_GROUP),
+            11 => Some(FieldDescriptorProto_TYPE_MESSAGE),
+            12 => Some(FieldDescriptorProto_TYPE_BYTES),
+            13 => Some(FieldDescriptorProto_TYPE_UINT32),
+            14 => Some(FieldDescriptorProto_TYPE_ENUM),
+            15 => Some(FieldDescriptorProto_TYPE_SFIXED32),
+            16 => Some(FieldDescriptorProto_TYPE_SFIXED64),
+            17 => Some(FieldDescriptorProto_TYPE_SINT32),
+            18 => Some(FieldDescriptorProto_TYPE_SINT64),
+            _ => None
         }
     }
 }
@@ -2696,11 +3451,15 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Type {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<FieldDescriptorProto_Type> {
+        FieldDescriptorProto_Type::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<FieldDescriptorProto_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Type", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Type", file_descriptor_proto(), file_descriptor)
             })
         }
     }
@@ -2715,11 +3474,18 @@ pub enum FieldDescriptorProto_Label {
 
 impl FieldDescriptorProto_Label {
     pub fn new(value: i32) -> FieldDescriptorProto_Label {
+        match FieldDescriptorProto_Label::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum FieldDescriptorProto_Label", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<FieldDescriptorProto_Label> {
         match value {
-            1 => FieldDescriptorProto_LABEL_OPTIONAL,
-            2 => FieldDescriptorProto_LABEL_REQUIRED,
-            3 => FieldDescriptorProto_LABEL_REPEATED,
-            _ => fail!()
+            1 => Some(FieldDescriptorProto_LABEL_OPTIONAL),
+            2 => Some(FieldDescriptorProto_LABEL_REQUIRED),
+            3 => Some(FieldDescriptorProto_LABEL_REPEATED),
+            _ => None
         }
     }
 }
@@ -2729,11 +3495,15 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Label {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<FieldDescriptorProto_Label> {
+        FieldDescriptorProto_Label::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<FieldDescriptorProto_Label>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Label", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Label", file_descriptor_proto(), file_descriptor)
             })
         }
     }
@@ -2876,34 +3646,80 @@ impl ::protobuf::Message for EnumDescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.value.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.value.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &EnumDescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        for v in other.value.iter() {
+            self.value.push(v.clone());
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -2930,7 +3746,7 @@ impl ::protobuf::Message for EnumDescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -2966,7 +3782,8 @@ impl ::protobuf::Message for EnumDescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<EnumDescriptorProto>(
                     "EnumDescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -3008,6 +3825,14 @@ impl ::protobuf::reflect::FieldAccessor<EnumDescriptorProto> for EnumDescriptorP
     fn get_str<'a>(&self, m: &'a EnumDescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut EnumDescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut EnumDescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3018,6 +3843,10 @@ impl ::protobuf::reflect::FieldAccessor<EnumDescriptorProto> for EnumDescriptorP
         "value"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<EnumValueDescriptorProto>()
+    }
+
     fn len_field(&self, m: &EnumDescriptorProto) -> uint {
         m.get_value().len()
     }
@@ -3025,6 +3854,18 @@ impl ::protobuf::reflect::FieldAccessor<EnumDescriptorProto> for EnumDescriptorP
     fn get_rep_message_item<'a>(&self, m: &'a EnumDescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_value()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut EnumDescriptorProto) {
+        m.clear_value();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut EnumDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_value().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut EnumDescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_value().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3035,6 +3876,10 @@ impl ::protobuf::reflect::FieldAccessor<EnumDescriptorProto> for EnumDescriptorP
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<EnumOptions>()
+    }
+
     fn has_field(&self, m: &EnumDescriptorProto) -> bool {
         m.has_options()
     }
@@ -3042,6 +3887,14 @@ impl ::protobuf::reflect::FieldAccessor<EnumDescriptorProto> for EnumDescriptorP
     fn get_message<'a>(&self, m: &'a EnumDescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut EnumDescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut EnumDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -3185,34 +4038,75 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.number = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &EnumValueDescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        if other.number.is_some() {
+            self.number = other.number.clone();
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -3238,7 +4132,7 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -3274,7 +4168,8 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<EnumValueDescriptorProto>(
                     "EnumValueDescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -3316,6 +4211,14 @@ impl ::protobuf::reflect::FieldAccessor<EnumValueDescriptorProto> for EnumValueD
     fn get_str<'a>(&self, m: &'a EnumValueDescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut EnumValueDescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut EnumValueDescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3333,6 +4236,14 @@ impl ::protobuf::reflect::FieldAccessor<EnumValueDescriptorProto> for EnumValueD
     fn get_i32(&self, m: &EnumValueDescriptorProto) -> i32 {
         m.get_number()
     }
+
+    fn clear_field(&self, m: &mut EnumValueDescriptorProto) {
+        m.clear_number();
+    }
+
+    fn set_i32(&self, m: &mut EnumValueDescriptorProto, v: i32) {
+        m.set_number(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3343,6 +4254,10 @@ impl ::protobuf::reflect::FieldAccessor<EnumValueDescriptorProto> for EnumValueD
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<EnumValueOptions>()
+    }
+
     fn has_field(&self, m: &EnumValueDescriptorProto) -> bool {
         m.has_options()
     }
@@ -3350,6 +4265,14 @@ impl ::protobuf::reflect::FieldAccessor<EnumValueDescriptorProto> for EnumValueD
     fn get_message<'a>(&self, m: &'a EnumValueDescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut EnumValueDescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut EnumValueDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -3489,34 +4412,80 @@ impl ::protobuf::Message for ServiceDescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.method.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.method.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &ServiceDescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        for v in other.method.iter() {
+            self.method.push(v.clone());
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -3543,7 +4512,7 @@ impl ::protobuf::Message for ServiceDescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -3579,7 +4548,8 @@ impl ::protobuf::Message for ServiceDescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<ServiceDescriptorProto>(
                     "ServiceDescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -3621,6 +4591,14 @@ impl ::protobuf::reflect::FieldAccessor<ServiceDescriptorProto> for ServiceDescr
     fn get_str<'a>(&self, m: &'a ServiceDescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut ServiceDescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut ServiceDescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3631,6 +4609,10 @@ impl ::protobuf::reflect::FieldAccessor<ServiceDescriptorProto> for ServiceDescr
         "method"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<MethodDescriptorProto>()
+    }
+
     fn len_field(&self, m: &ServiceDescriptorProto) -> uint {
         m.get_method().len()
     }
@@ -3638,6 +4620,18 @@ impl ::protobuf::reflect::FieldAccessor<ServiceDescriptorProto> for ServiceDescr
     fn get_rep_message_item<'a>(&self, m: &'a ServiceDescriptorProto, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_method()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut ServiceDescriptorProto) {
+        m.clear_method();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut ServiceDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_method().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut ServiceDescriptorProto, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_method().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3648,6 +4642,10 @@ impl ::protobuf::reflect::FieldAccessor<ServiceDescriptorProto> for ServiceDescr
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<ServiceOptions>()
+    }
+
     fn has_field(&self, m: &ServiceDescriptorProto) -> bool {
         m.has_options()
     }
@@ -3655,6 +4653,14 @@ impl ::protobuf::reflect::FieldAccessor<ServiceDescriptorProto> for ServiceDescr
     fn get_message<'a>(&self, m: &'a ServiceDescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut ServiceDescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut ServiceDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -3838,39 +4844,87 @@ impl ::protobuf::Message for MethodDescriptorProto {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.options.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.input_type = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.output_type = Some(tmp);
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.options.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &MethodDescriptorProto) {
+        use protobuf::{Message};
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        if other.input_type.is_some() {
+            self.input_type = other.input_type.clone();
+        };
+        if other.output_type.is_some() {
+            self.output_type = other.output_type.clone();
+        };
+        if other.options.is_some() {
+            if self.options.is_none() {
+                self.options.set_default();
+            };
+            self.options.get_mut_ref().merge_from_message(other.options.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -3899,7 +4953,7 @@ impl ::protobuf::Message for MethodDescriptorProto {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -3936,7 +4990,8 @@ impl ::protobuf::Message for MethodDescriptorProto {
                 ::protobuf::reflect::MessageDescriptor::new::<MethodDescriptorProto>(
                     "MethodDescriptorProto",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -3979,6 +5034,14 @@ impl ::protobuf::reflect::FieldAccessor<MethodDescriptorProto> for MethodDescrip
     fn get_str<'a>(&self, m: &'a MethodDescriptorProto) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut MethodDescriptorProto) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut MethodDescriptorProto, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3996,6 +5059,14 @@ impl ::protobuf::reflect::FieldAccessor<MethodDescriptorProto> for MethodDescrip
     fn get_str<'a>(&self, m: &'a MethodDescriptorProto) -> &'a str {
         m.get_input_type()
     }
+
+    fn clear_field(&self, m: &mut MethodDescriptorProto) {
+        m.clear_input_type();
+    }
+
+    fn set_str(&self, m: &mut MethodDescriptorProto, v: String) {
+        m.set_input_type(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4013,6 +5084,14 @@ impl ::protobuf::reflect::FieldAccessor<MethodDescriptorProto> for MethodDescrip
     fn get_str<'a>(&self, m: &'a MethodDescriptorProto) -> &'a str {
         m.get_output_type()
     }
+
+    fn clear_field(&self, m: &mut MethodDescriptorProto) {
+        m.clear_output_type();
+    }
+
+    fn set_str(&self, m: &mut MethodDescriptorProto, v: String) {
+        m.set_output_type(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4023,6 +5102,10 @@ impl ::protobuf::reflect::FieldAccessor<MethodDescriptorProto> for MethodDescrip
         "options"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<MethodOptions>()
+    }
+
     fn has_field(&self, m: &MethodDescriptorProto) -> bool {
         m.has_options()
     }
@@ -4030,6 +5113,14 @@ impl ::protobuf::reflect::FieldAccessor<MethodDescriptorProto> for MethodDescrip
     fn get_message<'a>(&self, m: &'a MethodDescriptorProto) -> &'a ::protobuf::Message {
         m.get_options() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut MethodDescriptorProto) {
+        m.clear_options();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut MethodDescriptorProto) -> &'a mut ::protobuf::Message {
+        m.mut_options() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -4265,13 +5356,13 @@ impl<'a> FileOptions {
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_optimize_for(&'a mut self) -> &'a mut FileOptions_OptimizeMode {
         if self.optimize_for.is_none() {
-            self.optimize_for = Some(FileOptions_OptimizeMode::new(0));
+            self.optimize_for = Some(FileOptions_SPEED);
         };
         self.optimize_for.get_mut_ref()
     }
 
     pub fn get_optimize_for(&self) -> FileOptions_OptimizeMode {
-        self.optimize_for.unwrap_or_else(|| FileOptions_OptimizeMode::new(0))
+        self.optimize_for.unwrap_or_else(|| FileOptions_SPEED)
     }
 
     pub fn clear_go_package(&mut self) {
@@ -4410,69 +5501,161 @@ impl ::protobuf::Message for FileOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.java_package = Some(tmp);
                 },
                 8 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.java_outer_classname = Some(tmp);
                 },
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.java_multiple_files = Some(tmp);
                 },
                 20 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.java_generate_equals_and_hash = Some(tmp);
                 },
                 9 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = FileOptions_OptimizeMode::new(is.read_int32());
-                    self.optimize_for = Some(tmp);
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match FileOptions_OptimizeMode::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.optimize_for = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
                 },
                 11 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.go_package = Some(tmp);
                 },
                 16 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.cc_generic_services = Some(tmp);
                 },
                 17 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.java_generic_services = Some(tmp);
                 },
                 18 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.py_generic_services = Some(tmp);
                 },
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &FileOptions) {
+        use protobuf::{Message};
+        if other.java_package.is_some() {
+            self.java_package = other.java_package.clone();
+        };
+        if other.java_outer_classname.is_some() {
+            self.java_outer_classname = other.java_outer_classname.clone();
+        };
+        if other.java_multiple_files.is_some() {
+            self.java_multiple_files = other.java_multiple_files.clone();
+        };
+        if other.java_generate_equals_and_hash.is_some() {
+            self.java_generate_equals_and_hash = other.java_generate_equals_and_hash.clone();
+        };
+        if other.optimize_for.is_some() {
+            self.optimize_for = other.optimize_for.clone();
+        };
+        if other.go_package.is_some() {
+            self.go_package = other.go_package.clone();
+        };
+        if other.cc_generic_services.is_some() {
+            self.cc_generic_services = other.cc_generic_services.clone();
+        };
+        if other.java_generic_services.is_some() {
+            self.java_generic_services = other.java_generic_services.clone();
+        };
+        if other.py_generic_services.is_some() {
+            self.py_generic_services = other.py_generic_services.clone();
+        };
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -4519,7 +5702,7 @@ impl ::protobuf::Message for FileOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -4562,7 +5745,8 @@ impl ::protobuf::Message for FileOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<FileOptions>(
                     "FileOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -4611,6 +5795,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_java_packag
     fn get_str<'a>(&self, m: &'a FileOptions) -> &'a str {
         m.get_java_package()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_java_package();
+    }
+
+    fn set_str(&self, m: &mut FileOptions, v: String) {
+        m.set_java_package(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4628,6 +5820,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_java_outer_
     fn get_str<'a>(&self, m: &'a FileOptions) -> &'a str {
         m.get_java_outer_classname()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_java_outer_classname();
+    }
+
+    fn set_str(&self, m: &mut FileOptions, v: String) {
+        m.set_java_outer_classname(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4645,6 +5845,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_java_multip
     fn get_bool(&self, m: &FileOptions) -> bool {
         m.get_java_multiple_files()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_java_multiple_files();
+    }
+
+    fn set_bool(&self, m: &mut FileOptions, v: bool) {
+        m.set_java_multiple_files(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4662,6 +5870,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_java_genera
     fn get_bool(&self, m: &FileOptions) -> bool {
         m.get_java_generate_equals_and_hash()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_java_generate_equals_and_hash();
+    }
+
+    fn set_bool(&self, m: &mut FileOptions, v: bool) {
+        m.set_java_generate_equals_and_hash(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4672,6 +5888,10 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_optimize_fo
         "optimize_for"
     }
 
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<FileOptions_OptimizeMode>()
+    }
+
     fn has_field(&self, m: &FileOptions) -> bool {
         m.has_optimize_for()
     }
@@ -4680,6 +5900,17 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_optimize_fo
         use protobuf::{ProtobufEnum};
         m.get_optimize_for().descriptor()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_optimize_for();
+    }
+
+    fn set_enum(&self, m: &mut FileOptions, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match FileOptions_OptimizeMode::from_i32(v.value()) {
+            Some(e) => m.set_optimize_for(e),
+            None => fail!("unknown value {} of enum FileOptions_OptimizeMode", v.value()),
+        }
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4697,6 +5928,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_go_package_
     fn get_str<'a>(&self, m: &'a FileOptions) -> &'a str {
         m.get_go_package()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_go_package();
+    }
+
+    fn set_str(&self, m: &mut FileOptions, v: String) {
+        m.set_go_package(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4714,6 +5953,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_cc_generic_
     fn get_bool(&self, m: &FileOptions) -> bool {
         m.get_cc_generic_services()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_cc_generic_services();
+    }
+
+    fn set_bool(&self, m: &mut FileOptions, v: bool) {
+        m.set_cc_generic_services(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4731,6 +5978,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_java_generi
     fn get_bool(&self, m: &FileOptions) -> bool {
         m.get_java_generic_services()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_java_generic_services();
+    }
+
+    fn set_bool(&self, m: &mut FileOptions, v: bool) {
+        m.set_java_generic_services(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4748,6 +6003,14 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_py_generic_
     fn get_bool(&self, m: &FileOptions) -> bool {
         m.get_py_generic_services()
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_py_generic_services();
+    }
+
+    fn set_bool(&self, m: &mut FileOptions, v: bool) {
+        m.set_py_generic_services(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4758,6 +6021,10 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_uninterpret
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &FileOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -4765,6 +6032,18 @@ impl ::protobuf::reflect::FieldAccessor<FileOptions> for FileOptions_uninterpret
     fn get_rep_message_item<'a>(&self, m: &'a FileOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FileOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FileOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FileOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Eq,Show)]
@@ -4776,11 +6055,18 @@ pub enum FileOptions_OptimizeMode {
 
 impl FileOptions_OptimizeMode {
     pub fn new(value: i32) -> FileOptions_OptimizeMode {
+        match FileOptions_OptimizeMode::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum FileOptions_OptimizeMode", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<FileOptions_OptimizeMode> {
         match value {
-            1 => FileOptions_SPEED,
-            2 => FileOptions_CODE_SIZE,
-            3 => FileOptions_LITE_RUNTIME,
-            _ => fail!()
+            1 => Some(FileOptions_SPEED),
+            2 => Some(FileOptions_CODE_SIZE),
+            3 => Some(FileOptions_LITE_RUNTIME),
+            _ => None
         }
     }
 }
@@ -4790,11 +6076,15 @@ impl ::protobuf::ProtobufEnum for FileOptions_OptimizeMode {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<FileOptions_OptimizeMode> {
+        FileOptions_OptimizeMode::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<FileOptions_OptimizeMode>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FileOptions_OptimizeMode", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("FileOptions_OptimizeMode", file_descriptor_proto(), file_descriptor)
             })
         }
     }
@@ -4931,34 +6221,72 @@ impl ::protobuf::Message for MessageOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.message_set_wire_format = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.no_standard_descriptor_accessor = Some(tmp);
                 },
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &MessageOptions) {
+        use protobuf::{Message};
+        if other.message_set_wire_format.is_some() {
+            self.message_set_wire_format = other.message_set_wire_format.clone();
+        };
+        if other.no_standard_descriptor_accessor.is_some() {
+            self.no_standard_descriptor_accessor = other.no_standard_descriptor_accessor.clone();
+        };
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -4984,7 +6312,7 @@ impl ::protobuf::Message for MessageOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -5020,7 +6348,8 @@ impl ::protobuf::Message for MessageOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<MessageOptions>(
                     "MessageOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -5062,6 +6391,14 @@ impl ::protobuf::reflect::FieldAccessor<MessageOptions> for MessageOptions_messa
     fn get_bool(&self, m: &MessageOptions) -> bool {
         m.get_message_set_wire_format()
     }
+
+    fn clear_field(&self, m: &mut MessageOptions) {
+        m.clear_message_set_wire_format();
+    }
+
+    fn set_bool(&self, m: &mut MessageOptions, v: bool) {
+        m.set_message_set_wire_format(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5079,6 +6416,14 @@ impl ::protobuf::reflect::FieldAccessor<MessageOptions> for MessageOptions_no_st
     fn get_bool(&self, m: &MessageOptions) -> bool {
         m.get_no_standard_descriptor_accessor()
     }
+
+    fn clear_field(&self, m: &mut MessageOptions) {
+        m.clear_no_standard_descriptor_accessor();
+    }
+
+    fn set_bool(&self, m: &mut MessageOptions, v: bool) {
+        m.set_no_standard_descriptor_accessor(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5089,6 +6434,10 @@ impl ::protobuf::reflect::FieldAccessor<MessageOptions> for MessageOptions_unint
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &MessageOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -5096,6 +6445,18 @@ impl ::protobuf::reflect::FieldAccessor<MessageOptions> for MessageOptions_unint
     fn get_rep_message_item<'a>(&self, m: &'a MessageOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut MessageOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut MessageOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut MessageOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -5197,13 +6558,13 @@ impl<'a> FieldOptions {
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_ctype(&'a mut self) -> &'a mut FieldOptions_CType {
         if self.ctype.is_none() {
-            self.ctype = Some(FieldOptions_CType::new(0));
+            self.ctype = Some(FieldOptions_STRING);
         };
         self.ctype.get_mut_ref()
     }
 
     pub fn get_ctype(&self) -> FieldOptions_CType {
-        self.ctype.unwrap_or_else(|| FieldOptions_CType::new(0))
+        self.ctype.unwrap_or_else(|| FieldOptions_STRING)
     }
 
     pub fn clear_packed(&mut self) {
@@ -5368,54 +6729,125 @@ impl ::protobuf::Message for FieldOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = FieldOptions_CType::new(is.read_int32());
-                    self.ctype = Some(tmp);
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match FieldOptions_CType::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.ctype = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.packed = Some(tmp);
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.lazy = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.deprecated = Some(tmp);
                 },
                 9 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.experimental_map_key = Some(tmp);
                 },
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.weak = Some(tmp);
                 },
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &FieldOptions) {
+        use protobuf::{Message};
+        if other.ctype.is_some() {
+            self.ctype = other.ctype.clone();
+        };
+        if other.packed.is_some() {
+            self.packed = other.packed.clone();
+        };
+        if other.lazy.is_some() {
+            self.lazy = other.lazy.clone();
+        };
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        };
+        if other.experimental_map_key.is_some() {
+            self.experimental_map_key = other.experimental_map_key.clone();
+        };
+        if other.weak.is_some() {
+            self.weak = other.weak.clone();
+        };
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -5453,7 +6885,7 @@ impl ::protobuf::Message for FieldOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -5493,7 +6925,8 @@ impl ::protobuf::Message for FieldOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<FieldOptions>(
                     "FieldOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -5532,6 +6965,10 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_ctype_acc
         "ctype"
     }
 
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<FieldOptions_CType>()
+    }
+
     fn has_field(&self, m: &FieldOptions) -> bool {
         m.has_ctype()
     }
@@ -5540,6 +6977,17 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_ctype_acc
         use protobuf::{ProtobufEnum};
         m.get_ctype().descriptor()
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_ctype();
+    }
+
+    fn set_enum(&self, m: &mut FieldOptions, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match FieldOptions_CType::from_i32(v.value()) {
+            Some(e) => m.set_ctype(e),
+            None => fail!("unknown value {} of enum FieldOptions_CType", v.value()),
+        }
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5557,6 +7005,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_packed_ac
     fn get_bool(&self, m: &FieldOptions) -> bool {
         m.get_packed()
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_packed();
+    }
+
+    fn set_bool(&self, m: &mut FieldOptions, v: bool) {
+        m.set_packed(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5574,6 +7030,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_lazy_acc
     fn get_bool(&self, m: &FieldOptions) -> bool {
         m.get_lazy()
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_lazy();
+    }
+
+    fn set_bool(&self, m: &mut FieldOptions, v: bool) {
+        m.set_lazy(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5591,6 +7055,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_deprecate
     fn get_bool(&self, m: &FieldOptions) -> bool {
         m.get_deprecated()
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_deprecated();
+    }
+
+    fn set_bool(&self, m: &mut FieldOptions, v: bool) {
+        m.set_deprecated(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5608,6 +7080,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_experimen
     fn get_str<'a>(&self, m: &'a FieldOptions) -> &'a str {
         m.get_experimental_map_key()
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_experimental_map_key();
+    }
+
+    fn set_str(&self, m: &mut FieldOptions, v: String) {
+        m.set_experimental_map_key(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5625,6 +7105,14 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_weak_acc
     fn get_bool(&self, m: &FieldOptions) -> bool {
         m.get_weak()
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_weak();
+    }
+
+    fn set_bool(&self, m: &mut FieldOptions, v: bool) {
+        m.set_weak(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5635,6 +7123,10 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_uninterpr
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &FieldOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -5642,6 +7134,18 @@ impl ::protobuf::reflect::FieldAccessor<FieldOptions> for FieldOptions_uninterpr
     fn get_rep_message_item<'a>(&self, m: &'a FieldOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut FieldOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut FieldOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut FieldOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Eq,Show)]
@@ -5653,11 +7157,18 @@ pub enum FieldOptions_CType {
 
 impl FieldOptions_CType {
     pub fn new(value: i32) -> FieldOptions_CType {
+        match FieldOptions_CType::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum FieldOptions_CType", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<FieldOptions_CType> {
         match value {
-            0 => FieldOptions_STRING,
-            1 => FieldOptions_CORD,
-            2 => FieldOptions_STRING_PIECE,
-            _ => fail!()
+            0 => Some(FieldOptions_STRING),
+            1 => Some(FieldOptions_CORD),
+            2 => Some(FieldOptions_STRING_PIECE),
+            _ => None
         }
     }
 }
@@ -5667,11 +7178,15 @@ impl ::protobuf::ProtobufEnum for FieldOptions_CType {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<FieldOptions_CType> {
+        FieldOptions_CType::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<FieldOptions_CType>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldOptions_CType", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("FieldOptions_CType", file_descriptor_proto(), file_descriptor)
             })
         }
     }
@@ -5736,13 +7251,13 @@ impl<'a> EnumOptions {
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_allow_alias(&'a mut self) -> &'a mut bool {
         if self.allow_alias.is_none() {
-            self.allow_alias = Some(false);
+            self.allow_alias = Some(true);
         };
         self.allow_alias.get_mut_ref()
     }
 
     pub fn get_allow_alias(&self) -> bool {
-        self.allow_alias.unwrap_or_else(|| false)
+        self.allow_alias.unwrap_or_else(|| true)
     }
 
     pub fn clear_uninterpreted_option(&mut self) {
@@ -5774,29 +7289,60 @@ impl ::protobuf::Message for EnumOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.allow_alias = Some(tmp);
                 },
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &EnumOptions) {
+        use protobuf::{Message};
+        if other.allow_alias.is_some() {
+            self.allow_alias = other.allow_alias.clone();
+        };
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -5819,7 +7365,7 @@ impl ::protobuf::Message for EnumOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -5854,7 +7400,8 @@ impl ::protobuf::Message for EnumOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<EnumOptions>(
                     "EnumOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -5895,6 +7442,14 @@ impl ::protobuf::reflect::FieldAccessor<EnumOptions> for EnumOptions_allow_alias
     fn get_bool(&self, m: &EnumOptions) -> bool {
         m.get_allow_alias()
     }
+
+    fn clear_field(&self, m: &mut EnumOptions) {
+        m.clear_allow_alias();
+    }
+
+    fn set_bool(&self, m: &mut EnumOptions, v: bool) {
+        m.set_allow_alias(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5905,6 +7460,10 @@ impl ::protobuf::reflect::FieldAccessor<EnumOptions> for EnumOptions_uninterpret
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &EnumOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -5912,6 +7471,18 @@ impl ::protobuf::reflect::FieldAccessor<EnumOptions> for EnumOptions_uninterpret
     fn get_rep_message_item<'a>(&self, m: &'a EnumOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut EnumOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut EnumOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut EnumOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -5977,24 +7548,48 @@ impl ::protobuf::Message for EnumValueOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &EnumValueOptions) {
+        use protobuf::{Message};
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -6014,7 +7609,7 @@ impl ::protobuf::Message for EnumValueOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -6048,7 +7643,8 @@ impl ::protobuf::Message for EnumValueOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<EnumValueOptions>(
                     "EnumValueOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -6081,6 +7677,10 @@ impl ::protobuf::reflect::FieldAccessor<EnumValueOptions> for EnumValueOptions_u
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &EnumValueOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -6088,6 +7688,18 @@ impl ::protobuf::reflect::FieldAccessor<EnumValueOptions> for EnumValueOptions_u
     fn get_rep_message_item<'a>(&self, m: &'a EnumValueOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut EnumValueOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut EnumValueOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut EnumValueOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -6153,24 +7765,48 @@ impl ::protobuf::Message for ServiceOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &ServiceOptions) {
+        use protobuf::{Message};
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -6190,7 +7826,7 @@ impl ::protobuf::Message for ServiceOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -6224,7 +7860,8 @@ impl ::protobuf::Message for ServiceOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<ServiceOptions>(
                     "ServiceOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -6257,6 +7894,10 @@ impl ::protobuf::reflect::FieldAccessor<ServiceOptions> for ServiceOptions_unint
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &ServiceOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -6264,6 +7905,18 @@ impl ::protobuf::reflect::FieldAccessor<ServiceOptions> for ServiceOptions_unint
     fn get_rep_message_item<'a>(&self, m: &'a ServiceOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut ServiceOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut ServiceOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut ServiceOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -6329,24 +7982,48 @@ impl ::protobuf::Message for MethodOptions {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.uninterpreted_option.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 999 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.uninterpreted_option.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &MethodOptions) {
+        use protobuf::{Message};
+        for v in other.uninterpreted_option.iter() {
+            self.uninterpreted_option.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -6366,7 +8043,7 @@ impl ::protobuf::Message for MethodOptions {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -6400,7 +8077,8 @@ impl ::protobuf::Message for MethodOptions {
                 ::protobuf::reflect::MessageDescriptor::new::<MethodOptions>(
                     "MethodOptions",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -6433,6 +8111,10 @@ impl ::protobuf::reflect::FieldAccessor<MethodOptions> for MethodOptions_uninter
         "uninterpreted_option"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption>()
+    }
+
     fn len_field(&self, m: &MethodOptions) -> uint {
         m.get_uninterpreted_option().len()
     }
@@ -6440,6 +8122,18 @@ impl ::protobuf::reflect::FieldAccessor<MethodOptions> for MethodOptions_uninter
     fn get_rep_message_item<'a>(&self, m: &'a MethodOptions, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_uninterpreted_option()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut MethodOptions) {
+        m.clear_uninterpreted_option();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut MethodOptions) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut MethodOptions, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_uninterpreted_option().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -6718,54 +8412,120 @@ impl ::protobuf::Message for UninterpretedOption {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.name.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.name.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.identifier_value = Some(tmp);
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint64());
                     self.positive_int_value = Some(tmp);
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int64());
                     self.negative_int_value = Some(tmp);
                 },
                 6 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_double();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
                     self.double_value = Some(tmp);
                 },
                 7 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_bytes();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
                     self.string_value = Some(tmp);
                 },
                 8 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.aggregate_value = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &UninterpretedOption) {
+        use protobuf::{Message};
+        for v in other.name.iter() {
+            self.name.push(v.clone());
+        };
+        if other.identifier_value.is_some() {
+            self.identifier_value = other.identifier_value.clone();
+        };
+        if other.positive_int_value.is_some() {
+            self.positive_int_value = other.positive_int_value.clone();
+        };
+        if other.negative_int_value.is_some() {
+            self.negative_int_value = other.negative_int_value.clone();
+        };
+        if other.double_value.is_some() {
+            self.double_value = other.double_value.clone();
+        };
+        if other.string_value.is_some() {
+            self.string_value = other.string_value.clone();
+        };
+        if other.aggregate_value.is_some() {
+            self.aggregate_value = other.aggregate_value.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -6803,7 +8563,7 @@ impl ::protobuf::Message for UninterpretedOption {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -6843,7 +8603,8 @@ impl ::protobuf::Message for UninterpretedOption {
                 ::protobuf::reflect::MessageDescriptor::new::<UninterpretedOption>(
                     "UninterpretedOption",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -6882,6 +8643,10 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
         "name"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<UninterpretedOption_NamePart>()
+    }
+
     fn len_field(&self, m: &UninterpretedOption) -> uint {
         m.get_name().len()
     }
@@ -6889,6 +8654,18 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_rep_message_item<'a>(&self, m: &'a UninterpretedOption, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_name()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_name();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut UninterpretedOption) -> &'a mut ::protobuf::Message {
+        m.mut_name().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut UninterpretedOption, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_name().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -6906,6 +8683,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_str<'a>(&self, m: &'a UninterpretedOption) -> &'a str {
         m.get_identifier_value()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_identifier_value();
+    }
+
+    fn set_str(&self, m: &mut UninterpretedOption, v: String) {
+        m.set_identifier_value(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -6923,6 +8708,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_u64(&self, m: &UninterpretedOption) -> u64 {
         m.get_positive_int_value()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_positive_int_value();
+    }
+
+    fn set_u64(&self, m: &mut UninterpretedOption, v: u64) {
+        m.set_positive_int_value(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -6940,6 +8733,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_i64(&self, m: &UninterpretedOption) -> i64 {
         m.get_negative_int_value()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_negative_int_value();
+    }
+
+    fn set_i64(&self, m: &mut UninterpretedOption, v: i64) {
+        m.set_negative_int_value(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -6957,6 +8758,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_f64(&self, m: &UninterpretedOption) -> f64 {
         m.get_double_value()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_double_value();
+    }
+
+    fn set_f64(&self, m: &mut UninterpretedOption, v: f64) {
+        m.set_double_value(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -6974,6 +8783,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_bytes<'a>(&self, m: &'a UninterpretedOption) -> &'a [u8] {
         m.get_string_value()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_string_value();
+    }
+
+    fn set_bytes(&self, m: &mut UninterpretedOption, v: Vec<u8>) {
+        m.set_string_value(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -6991,6 +8808,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption> for UninterpretedOp
     fn get_str<'a>(&self, m: &'a UninterpretedOption) -> &'a str {
         m.get_aggregate_value()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption) {
+        m.clear_aggregate_value();
+    }
+
+    fn set_str(&self, m: &mut UninterpretedOption, v: String) {
+        m.set_aggregate_value(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -7107,26 +8932,50 @@ impl ::protobuf::Message for UninterpretedOption_NamePart {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name_part = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.is_extension = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &UninterpretedOption_NamePart) {
+        if other.name_part.is_some() {
+            self.name_part = other.name_part.clone();
+        };
+        if other.is_extension.is_some() {
+            self.is_extension = other.is_extension.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -7148,7 +8997,7 @@ impl ::protobuf::Message for UninterpretedOption_NamePart {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -7183,7 +9032,8 @@ impl ::protobuf::Message for UninterpretedOption_NamePart {
                 ::protobuf::reflect::MessageDescriptor::new::<UninterpretedOption_NamePart>(
                     "UninterpretedOption_NamePart",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -7224,6 +9074,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption_NamePart> for Uninte
     fn get_str<'a>(&self, m: &'a UninterpretedOption_NamePart) -> &'a str {
         m.get_name_part()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption_NamePart) {
+        m.clear_name_part();
+    }
+
+    fn set_str(&self, m: &mut UninterpretedOption_NamePart, v: String) {
+        m.set_name_part(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -7241,6 +9099,14 @@ impl ::protobuf::reflect::FieldAccessor<UninterpretedOption_NamePart> for Uninte
     fn get_bool(&self, m: &UninterpretedOption_NamePart) -> bool {
         m.get_is_extension()
     }
+
+    fn clear_field(&self, m: &mut UninterpretedOption_NamePart) {
+        m.clear_is_extension();
+    }
+
+    fn set_bool(&self, m: &mut UninterpretedOption_NamePart, v: bool) {
+        m.set_is_extension(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -7306,24 +9172,48 @@ impl ::protobuf::Message for SourceCodeInfo {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.location.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.location.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &SourceCodeInfo) {
+        use protobuf::{Message};
+        for v in other.location.iter() {
+            self.location.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -7343,7 +9233,7 @@ impl ::protobuf::Message for SourceCodeInfo {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -7377,7 +9267,8 @@ impl ::protobuf::Message for SourceCodeInfo {
                 ::protobuf::reflect::MessageDescriptor::new::<SourceCodeInfo>(
                     "SourceCodeInfo",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -7410,6 +9301,10 @@ impl ::protobuf::reflect::FieldAccessor<SourceCodeInfo> for SourceCodeInfo_locat
         "location"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<SourceCodeInfo_Location>()
+    }
+
     fn len_field(&self, m: &SourceCodeInfo) -> uint {
         m.get_location().len()
     }
@@ -7417,6 +9312,18 @@ impl ::protobuf::reflect::FieldAccessor<SourceCodeInfo> for SourceCodeInfo_locat
     fn get_rep_message_item<'a>(&self, m: &'a SourceCodeInfo, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_location()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut SourceCodeInfo) {
+        m.clear_location();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut SourceCodeInfo) -> &'a mut ::protobuf::Message {
+        m.mut_location().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut SourceCodeInfo, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_location().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -7592,52 +9499,86 @@ impl ::protobuf::Message for SourceCodeInfo_Location {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.path.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.path.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.path.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.path.push(try!(is.read_int32()));
                     }
                 },
                 2 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.span.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.span.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.span.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.span.push(try!(is.read_int32()));
                     }
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.leading_comments = Some(tmp);
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.trailing_comments = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &SourceCodeInfo_Location) {
+        self.path.push_all(other.path.as_slice());
+        self.span.push_all(other.span.as_slice());
+        if other.leading_comments.is_some() {
+            self.leading_comments = other.leading_comments.clone();
+        };
+        if other.trailing_comments.is_some() {
+            self.trailing_comments = other.trailing_comments.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -7665,7 +9606,7 @@ impl ::protobuf::Message for SourceCodeInfo_Location {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -7702,7 +9643,8 @@ impl ::protobuf::Message for SourceCodeInfo_Location {
                 ::protobuf::reflect::MessageDescriptor::new::<SourceCodeInfo_Location>(
                     "SourceCodeInfo_Location",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -7745,6 +9687,18 @@ impl ::protobuf::reflect::FieldAccessor<SourceCodeInfo_Location> for SourceCodeI
     fn get_rep_i32<'a>(&self, m: &'a SourceCodeInfo_Location) -> &'a [i32] {
         m.get_path()
     }
+
+    fn clear_field(&self, m: &mut SourceCodeInfo_Location) {
+        m.clear_path();
+    }
+
+    fn add_i32(&self, m: &mut SourceCodeInfo_Location, v: i32) {
+        m.add_path(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut SourceCodeInfo_Location) -> &'a mut Vec<i32> {
+        m.mut_path()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -7762,6 +9716,18 @@ impl ::protobuf::reflect::FieldAccessor<SourceCodeInfo_Location> for SourceCodeI
     fn get_rep_i32<'a>(&self, m: &'a SourceCodeInfo_Location) -> &'a [i32] {
         m.get_span()
     }
+
+    fn clear_field(&self, m: &mut SourceCodeInfo_Location) {
+        m.clear_span();
+    }
+
+    fn add_i32(&self, m: &mut SourceCodeInfo_Location, v: i32) {
+        m.add_span(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut SourceCodeInfo_Location) -> &'a mut Vec<i32> {
+        m.mut_span()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -7779,6 +9745,14 @@ impl ::protobuf::reflect::FieldAccessor<SourceCodeInfo_Location> for SourceCodeI
     fn get_str<'a>(&self, m: &'a SourceCodeInfo_Location) -> &'a str {
         m.get_leading_comments()
     }
+
+    fn clear_field(&self, m: &mut SourceCodeInfo_Location) {
+        m.clear_leading_comments();
+    }
+
+    fn set_str(&self, m: &mut SourceCodeInfo_Location, v: String) {
+        m.set_leading_comments(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -7796,4 +9770,12 @@ impl ::protobuf::reflect::FieldAccessor<SourceCodeInfo_Location> for SourceCodeI
     fn get_str<'a>(&self, m: &'a SourceCodeInfo_Location) -> &'a str {
         m.get_trailing_comments()
     }
+
+    fn clear_field(&self, m: &mut SourceCodeInfo_Location) {
+        m.clear_trailing_comments();
+    }
+
+    fn set_str(&self, m: &mut SourceCodeInfo_Location, v: String) {
+        m.set_trailing_comments(v);
+    }
 }
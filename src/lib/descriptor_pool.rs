@@ -0,0 +1,505 @@
+// Collection of descriptors loaded at runtime.
+//
+// Files are added with their imports resolved and validated,
+// then declarations can be looked up by fully qualified name.
+// Generated files are looked up with their generated descriptors,
+// and messages of any type from the pool can be created as `DynamicMessage`.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::mem;
+
+use descriptor::*;
+use descriptorx::find_messages_with_proto_names;
+use descriptorx::find_enums_with_proto_names;
+use descriptorx::find_extensions;
+use dynamic::DynamicMessage;
+use dynamic::DynamicMessageType;
+use error::ProtobufResult;
+use error::InvalidDescriptor;
+use misc::leak;
+use reflect::MessageDescriptor;
+use reflect::EnumDescriptor;
+use reflect::FieldDescriptor;
+use reflect::ServiceDescriptor;
+use reflect::ExtensionDescriptor;
+use reflect::FileDescriptor;
+use reflect::check_default_value;
+use repeated::RepeatedField;
+
+#[deriving(Clone,PartialEq,Show)]
+enum SymbolKind {
+    PackageSymbol,
+    MessageSymbol,
+    EnumSymbol,
+    ServiceSymbol,
+    ExtensionSymbol,
+}
+
+#[deriving(Clone)]
+struct Symbol {
+    kind: SymbolKind,
+    // name of file with declaration, packages may be declared in several files
+    file: String,
+}
+
+// field number is stored in 29 bits of tag,
+// numbers 19000 to 19999 are reserved for protobuf implementation
+static MAX_FIELD_NUMBER: i32 = (1 << 29) - 1;
+
+fn invalid<T>(message: String) -> ProtobufResult<T> {
+    Err(InvalidDescriptor(message))
+}
+
+// pool API accepts names with or without leading dot
+fn normalize_name(full_name: &str) -> String {
+    if full_name.starts_with(".") {
+        full_name.to_string()
+    } else {
+        format!(".{}", full_name)
+    }
+}
+
+// `.pkg.Outer.Inner` -> `.pkg.Outer`, `.pkg` -> ``
+fn parent_scope<'a>(scope: &'a str) -> &'a str {
+    match scope.rfind('.') {
+        Some(pos) => scope.slice_to(pos),
+        None => "",
+    }
+}
+
+fn package_scope(file: &FileDescriptorProto) -> String {
+    if file.get_package().is_empty() {
+        "".to_string()
+    } else {
+        format!(".{}", file.get_package())
+    }
+}
+
+// Declarations of file with kinds of declarations
+fn file_symbols(file: &FileDescriptorProto) -> Vec<(String, SymbolKind)> {
+    let mut r = Vec::new();
+    let package = package_scope(file);
+    let mut scope = package.as_slice();
+    while !scope.is_empty() {
+        r.push((scope.to_string(), PackageSymbol));
+        scope = parent_scope(scope);
+    }
+    for (name, _) in find_messages_with_proto_names(file).move_iter() {
+        r.push((name, MessageSymbol));
+    }
+    for (name, _) in find_enums_with_proto_names(file).move_iter() {
+        r.push((name, EnumSymbol));
+    }
+    for s in file.get_service().iter() {
+        r.push((format!("{}.{}", package, s.get_name()), ServiceSymbol));
+    }
+    for (name, _) in find_extensions(file).move_iter() {
+        r.push((name, ExtensionSymbol));
+    }
+    r
+}
+
+// Resolves type names in file being added to the pool
+struct Resolver<'a> {
+    symbols: &'a HashMap<String, Symbol>,
+    // names of file being added, its imports and files publicly imported by imports
+    visible_files: HashSet<String>,
+    // keyed by fully qualified names, enums of file being added are not in pool yet
+    pool_enums: &'a HashMap<String, &'static EnumDescriptor>,
+    file_enums: HashMap<String, EnumDescriptorProto>,
+}
+
+impl<'a> Resolver<'a> {
+    fn find(&self, full_name: &str) -> Option<SymbolKind> {
+        match self.symbols.find(&full_name.to_string()) {
+            Some(symbol) if self.visible_files.contains(&symbol.file) => Some(symbol.kind),
+            _ => None,
+        }
+    }
+
+    // Relative name is looked up in scope of reference, then in enclosing scopes,
+    // like protoc does. Returns fully qualified name.
+    fn resolve(&self, scope: &str, name: &str) -> Option<(String, SymbolKind)> {
+        if name.starts_with(".") {
+            return self.find(name).map(|kind| (name.to_string(), kind));
+        }
+        let mut scope = scope;
+        loop {
+            let full_name = format!("{}.{}", scope, name);
+            match self.find(full_name.as_slice()) {
+                Some(PackageSymbol) | None => {},
+                Some(kind) => return Some((full_name, kind)),
+            }
+            if scope.is_empty() {
+                return None;
+            }
+            scope = parent_scope(scope);
+        }
+    }
+
+    fn resolve_message_type(&self, scope: &str, name: &str, referenced_from: &str) -> ProtobufResult<String> {
+        match self.resolve(scope, name) {
+            Some((full_name, MessageSymbol)) => Ok(full_name),
+            Some((full_name, _)) => invalid(format!("{} is not a message type, referenced from {}", full_name, referenced_from)),
+            None => invalid(format!("type not found: {}, referenced from {}", name, referenced_from)),
+        }
+    }
+
+    fn resolve_field(&self, scope: &str, field: &mut FieldDescriptorProto) -> ProtobufResult<()> {
+        let field_name = format!("{}.{}", scope, field.get_name());
+
+        let number = field.get_number();
+        if number < 1 || number > MAX_FIELD_NUMBER || (number >= 19000 && number <= 19999) {
+            return invalid(format!("field {} has invalid number {}", field_name, number));
+        }
+
+        if field.has_extendee() {
+            let extendee = try!(self.resolve_message_type(scope, field.get_extendee(), field_name.as_slice()));
+            field.set_extendee(extendee);
+        }
+
+        if field.has_type_name() {
+            try!(self.resolve_field_type(scope, field_name.as_slice(), field));
+        } else if !field.has_field_type() {
+            return invalid(format!("field {} has no type", field_name));
+        }
+
+        // default value is parsed when descriptor is registered
+        let enum_proto = match field.get_field_type() {
+            FieldDescriptorProto_TYPE_ENUM => Some(self.find_enum(field.get_type_name())),
+            _ => None,
+        };
+        match check_default_value(field, enum_proto) {
+            Ok(()) => Ok(()),
+            Err(message) => invalid(format!("{} of field {}", message, field_name)),
+        }
+    }
+
+    fn resolve_field_type(&self, scope: &str, field_name: &str, field: &mut FieldDescriptorProto)
+        -> ProtobufResult<()>
+    {
+        let (full_name, kind) = match self.resolve(scope, field.get_type_name()) {
+            Some(r) => r,
+            None => return invalid(format!("type not found: {}, referenced from {}", field.get_type_name(), field_name)),
+        };
+        // protoc leaves field type unset when it cannot tell message from enum
+        let field_type = match (field.has_field_type(), kind) {
+            (false, MessageSymbol) => FieldDescriptorProto_TYPE_MESSAGE,
+            (false, EnumSymbol) => FieldDescriptorProto_TYPE_ENUM,
+            (true, _) => field.get_field_type(),
+            (false, _) => return invalid(format!("{} is not a type, referenced from {}", full_name, field_name)),
+        };
+        match (field_type, kind) {
+            (FieldDescriptorProto_TYPE_MESSAGE, MessageSymbol) |
+            (FieldDescriptorProto_TYPE_GROUP, MessageSymbol) |
+            (FieldDescriptorProto_TYPE_ENUM, EnumSymbol) => {},
+            _ => return invalid(format!("type {} does not match type of field {}", full_name, field_name)),
+        }
+        field.set_field_type(field_type);
+        field.set_type_name(full_name);
+        Ok(())
+    }
+
+    // `full_name` is resolved name of enum declared in pool or in file being added
+    fn find_enum<'b>(&'b self, full_name: &str) -> &'b EnumDescriptorProto {
+        let full_name = full_name.to_string();
+        match self.file_enums.find(&full_name) {
+            Some(en) => en,
+            None => self.pool_enums.find(&full_name).unwrap().proto(),
+        }
+    }
+
+    // `scope` is fully qualified name of enclosing message or package
+    fn check_enum(&self, scope: &str, en: &EnumDescriptorProto) -> ProtobufResult<()> {
+        // first value is default value of fields
+        if en.get_value().is_empty() {
+            return invalid(format!("enum {}.{} has no values", scope, en.get_name()));
+        }
+        if en.get_options().get_allow_alias() {
+            return Ok(());
+        }
+        let mut numbers = HashSet::new();
+        for value in en.get_value().iter() {
+            if !numbers.insert(value.get_number()) {
+                return invalid(format!("duplicate value number {} in {}.{}, allow_alias is not set",
+                        value.get_number(), scope, en.get_name()));
+            }
+        }
+        Ok(())
+    }
+
+    // `scope` is fully qualified name of message
+    fn resolve_message(&self, scope: &str, message: &mut DescriptorProto) -> ProtobufResult<()> {
+        let mut numbers = HashSet::new();
+        let mut names = HashSet::new();
+        for field in message.mut_field().mut_iter() {
+            if !numbers.insert(field.get_number()) {
+                return invalid(format!("duplicate field number {} in {}", field.get_number(), scope));
+            }
+            if !names.insert(field.get_name().to_string()) {
+                return invalid(format!("duplicate field name {} in {}", field.get_name(), scope));
+            }
+            try!(self.resolve_field(scope, field));
+        }
+        for field in message.mut_extension().mut_iter() {
+            try!(self.resolve_field(scope, field));
+        }
+        for en in message.get_enum_type().iter() {
+            try!(self.check_enum(scope, en));
+        }
+        for nested in message.mut_nested_type().mut_iter() {
+            let nested_scope = format!("{}.{}", scope, nested.get_name());
+            try!(self.resolve_message(nested_scope.as_slice(), nested));
+        }
+        Ok(())
+    }
+
+    fn resolve_file(&self, file: &mut FileDescriptorProto) -> ProtobufResult<()> {
+        let package = package_scope(file);
+        let scope = package.as_slice();
+        for message in file.mut_message_type().mut_iter() {
+            let message_scope = format!("{}.{}", scope, message.get_name());
+            try!(self.resolve_message(message_scope.as_slice(), message));
+        }
+        for field in file.mut_extension().mut_iter() {
+            try!(self.resolve_field(scope, field));
+        }
+        for en in file.get_enum_type().iter() {
+            try!(self.check_enum(scope, en));
+        }
+        for service in file.mut_service().mut_iter() {
+            let service_name = format!("{}.{}", scope, service.get_name());
+            for method in service.mut_method().mut_iter() {
+                let method_name = format!("{}.{}", service_name, method.get_name());
+                let input_type = try!(self.resolve_message_type(scope, method.get_input_type(), method_name.as_slice()));
+                let output_type = try!(self.resolve_message_type(scope, method.get_output_type(), method_name.as_slice()));
+                method.set_input_type(input_type);
+                method.set_output_type(output_type);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Descriptors added to the pool are never freed
+pub struct DescriptorPool {
+    files: Vec<&'static FileDescriptor>,
+    // keyed by fully qualified names like `.pkg.Message`
+    symbols: HashMap<String, Symbol>,
+    enums: HashMap<String, &'static EnumDescriptor>,
+    messages: HashMap<String, &'static MessageDescriptor>,
+    // also created for generated files, so dynamic messages can have fields of their types
+    dynamic_types: HashMap<String, &'static DynamicMessageType>,
+    services: HashMap<String, &'static ServiceDescriptor>,
+    extensions: HashMap<String, &'static ExtensionDescriptor>,
+}
+
+impl DescriptorPool {
+    pub fn new() -> DescriptorPool {
+        DescriptorPool {
+            files: Vec::new(),
+            symbols: HashMap::new(),
+            enums: HashMap::new(),
+            messages: HashMap::new(),
+            dynamic_types: HashMap::new(),
+            services: HashMap::new(),
+            extensions: HashMap::new(),
+        }
+    }
+
+    // Imports of file must be added before the file.
+    // Relative type names in file are replaced with fully qualified names.
+    pub fn add_file(&mut self, file: FileDescriptorProto) -> ProtobufResult<()> {
+        let mut file = file;
+        try!(self.resolve_file(&mut file));
+        self.register_file(leak(box file));
+        Ok(())
+    }
+
+    // Add file of generated code, like `shrug::file_descriptor()`.
+    // Types of generated file are already resolved by protoc, so they are only validated,
+    // and lookups return generated descriptors.
+    pub fn add_generated_file(&mut self, file: &'static FileDescriptor) -> ProtobufResult<()> {
+        try!(self.resolve_file(&mut file.proto().clone()));
+        for &e in file.all_enums().iter() {
+            self.enums.insert(normalize_name(e.full_name()), e);
+        }
+        self.add_dynamic_types(file.proto());
+        self.register_declarations(file);
+        Ok(())
+    }
+
+    // Files in set may be in any order, but all imports must be in the set or in the pool
+    pub fn add_file_descriptor_set(&mut self, set: FileDescriptorSet) -> ProtobufResult<()> {
+        let mut set = set;
+        let mut pending: Vec<FileDescriptorProto> =
+            mem::replace(set.mut_file(), RepeatedField::new()).move_iter().collect();
+        while !pending.is_empty() {
+            let ready = pending.iter().position(|f| self.check_imports(f).is_ok());
+            match ready {
+                Some(index) => try!(self.add_file(pending.remove(index).unwrap())),
+                None => return self.check_imports(pending.get(0)),
+            }
+        }
+        Ok(())
+    }
+
+    fn check_imports(&self, file: &FileDescriptorProto) -> ProtobufResult<()> {
+        for dependency in file.get_dependency().iter() {
+            if self.file_by_name(dependency.as_slice()).is_none() {
+                return invalid(format!("import not found: {}, imported from {}", dependency, file.get_name()));
+            }
+        }
+        Ok(())
+    }
+
+    // Files which declarations are visible from file: file itself, its imports,
+    // and files publicly imported by imports
+    fn visible_files(&self, file: &FileDescriptorProto) -> HashSet<String> {
+        let mut r = HashSet::new();
+        r.insert(file.get_name().to_string());
+        let mut queue: Vec<&'static FileDescriptor> = file.get_dependency().iter()
+                .map(|d| self.file_by_name(d.as_slice()).unwrap())
+                .collect();
+        while !queue.is_empty() {
+            let dependency = queue.pop().unwrap();
+            if r.insert(dependency.name().to_string()) {
+                for &index in dependency.proto().get_public_dependency().iter() {
+                    queue.push(dependency.dependencies()[index as uint]);
+                }
+            }
+        }
+        r
+    }
+
+    fn resolve_file(&mut self, file: &mut FileDescriptorProto) -> ProtobufResult<()> {
+        if self.file_by_name(file.get_name()).is_some() {
+            return invalid(format!("file is already added: {}", file.get_name()));
+        }
+        try!(self.check_imports(file));
+        // indices are used to find publicly imported files when resolving importing files
+        for &index in file.get_public_dependency().iter() {
+            if index < 0 || index as uint >= file.get_dependency().len() {
+                return invalid(format!("public dependency index {} is out of range in {}", index, file.get_name()));
+            }
+        }
+
+        let mut symbols = self.symbols.clone();
+        for (name, kind) in file_symbols(file).move_iter() {
+            match symbols.find(&name) {
+                Some(&Symbol { kind: PackageSymbol, .. }) if kind == PackageSymbol => continue,
+                Some(..) => return invalid(format!("duplicate symbol: {}", name)),
+                None => {},
+            }
+            symbols.insert(name, Symbol { kind: kind, file: file.get_name().to_string() });
+        }
+
+        {
+            let resolver = Resolver {
+                symbols: &symbols,
+                visible_files: self.visible_files(file),
+                pool_enums: &self.enums,
+                file_enums: find_enums_with_proto_names(file).move_iter()
+                        .map(|(name, en)| (name, en.clone()))
+                        .collect(),
+            };
+            try!(resolver.resolve_file(file));
+        }
+        self.symbols = symbols;
+        Ok(())
+    }
+
+    fn register_file(&mut self, file: &'static FileDescriptorProto) {
+        for (name, proto) in find_enums_with_proto_names(file).move_iter() {
+            let enum_descriptor = leak(box EnumDescriptor::new_from_proto(proto, name.as_slice()));
+            self.enums.insert(name, enum_descriptor);
+        }
+        let file_descriptor = self.add_dynamic_types(file);
+        self.register_declarations(file_descriptor);
+    }
+
+    // Create dynamic types of messages of file, enums of file must be already registered.
+    // Returned file descriptor contains descriptors of created types.
+    fn add_dynamic_types(&mut self, file: &'static FileDescriptorProto) -> &'static FileDescriptor {
+        let mut added = Vec::new();
+        for (name, proto) in find_messages_with_proto_names(file).move_iter() {
+            let message_type: &'static DynamicMessageType =
+                leak(box DynamicMessageType::new(proto, name.as_slice(), file, &self.enums));
+            self.dynamic_types.insert(name, message_type);
+            added.push(message_type);
+        }
+        for message_type in added.iter() {
+            message_type.link(&self.dynamic_types);
+        }
+
+        // `link_declarations` leaves generated enums linked to generated file
+        let file_descriptor: &'static FileDescriptor = leak(box FileDescriptor::new(
+            file,
+            file.get_dependency().iter()
+                    .map(|d| self.file_by_name(d.as_slice()).unwrap())
+                    .collect(),
+            added.iter().map(|t| t.descriptor()).collect(),
+            find_enums_with_proto_names(file).iter()
+                    .map(|&(ref name, _)| *self.enums.find(name).unwrap())
+                    .collect()));
+        file_descriptor.link_declarations();
+        file_descriptor
+    }
+
+    fn register_declarations(&mut self, file: &'static FileDescriptor) {
+        for &m in file.all_messages().iter() {
+            self.messages.insert(normalize_name(m.full_name()), m);
+        }
+        for s in file.services().iter() {
+            self.services.insert(normalize_name(s.full_name()), s);
+        }
+        for (name, proto) in find_extensions(file.proto()).move_iter() {
+            let enum_proto = self.enums.find(&proto.get_type_name().to_string()).map(|e| e.proto());
+            self.extensions.insert(name.clone(), leak(box ExtensionDescriptor::new(name, proto, enum_proto)));
+        }
+        self.files.push(file);
+    }
+
+    pub fn files<'a>(&'a self) -> &'a [&'static FileDescriptor] {
+        self.files.as_slice()
+    }
+
+    pub fn file_by_name(&self, name: &str) -> Option<&'static FileDescriptor> {
+        self.files.iter().find(|f| f.name() == name).map(|&f| f)
+    }
+
+    // Names below are fully qualified names like `.pkg.Message`, leading dot is optional
+
+    pub fn message_by_name(&self, full_name: &str) -> Option<&'static MessageDescriptor> {
+        self.messages.find(&normalize_name(full_name)).map(|&m| m)
+    }
+
+    pub fn enum_by_name(&self, full_name: &str) -> Option<&'static EnumDescriptor> {
+        self.enums.find(&normalize_name(full_name)).map(|&e| e)
+    }
+
+    // field name is qualified with message name, like `.pkg.Message.field`
+    pub fn field_by_name(&self, full_name: &str) -> Option<&'static FieldDescriptor> {
+        let full_name = normalize_name(full_name);
+        let message_name = parent_scope(full_name.as_slice());
+        let field_name = full_name.as_slice().slice_from(message_name.len() + 1);
+        match self.message_by_name(message_name) {
+            Some(message) => message.find_field_by_name(field_name),
+            None => None,
+        }
+    }
+
+    pub fn service_by_name(&self, full_name: &str) -> Option<&'static ServiceDescriptor> {
+        self.services.find(&normalize_name(full_name)).map(|&s| s)
+    }
+
+    pub fn extension_by_name(&self, full_name: &str) -> Option<&'static ExtensionDescriptor> {
+        self.extensions.find(&normalize_name(full_name)).map(|&e| e)
+    }
+
+    // message of generated type is created as `DynamicMessage` too
+    pub fn new_dynamic_message(&self, full_name: &str) -> Option<DynamicMessage> {
+        self.dynamic_types.find(&normalize_name(full_name)).map(|t| t.new_instance())
+    }
+}
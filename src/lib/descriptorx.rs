@@ -3,12 +3,28 @@
 use descriptor::FileDescriptorProto;
 use descriptor::DescriptorProto;
 use descriptor::EnumDescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::ServiceDescriptorProto;
 
 #[deriving(Clone)]
 struct MessagePath<'a> {
     path: Vec<&'a DescriptorProto>,
 }
 
+// fully qualified protobuf name prefix of nested declarations, e. g. `.pkg.Outer`
+fn proto_name_prefix(fd: &FileDescriptorProto, path: &[&DescriptorProto]) -> String {
+    let mut r = String::new();
+    if !fd.get_package().is_empty() {
+        r.push_str(".");
+        r.push_str(fd.get_package());
+    }
+    for m in path.iter() {
+        r.push_str(".");
+        r.push_str(m.get_name());
+    }
+    r
+}
+
 impl<'a> MessagePath<'a> {
     fn rust_prefix(&self) -> String {
         if self.path.is_empty() {
@@ -47,6 +63,10 @@ impl<'a> MessageWithPath<'a> {
         let v: Vec<&'a str> = self.path.iter().map(|m| m.get_name()).collect();
         v.connect("_")
     }
+
+    fn proto_name(&self, fd: &FileDescriptorProto) -> String {
+        proto_name_prefix(fd, self.path.as_slice())
+    }
 }
 
 struct EnumWithPath<'a> {
@@ -58,6 +78,11 @@ impl<'a> EnumWithPath<'a> {
     fn rust_name(&self) -> String {
         self.path.rust_prefix().append(self.en.get_name())
     }
+
+    // fully qualified name as in `FieldDescriptorProto.type_name`, e. g. `.pkg.Outer.Enum`
+    fn proto_name(&self, fd: &FileDescriptorProto) -> String {
+        proto_name_prefix(fd, self.path.path.as_slice()).append(".").append(self.en.get_name())
+    }
 }
 
 
@@ -106,6 +131,63 @@ pub fn find_message_by_rust_name<'a>(fd: &'a FileDescriptorProto, rust_name: &st
             .get_message()
 }
 
+pub fn find_enum_by_proto_name<'a>(fds: &[&'a FileDescriptorProto], proto_name: &str)
+    -> Option<&'a EnumDescriptorProto>
+{
+    for &fd in fds.iter() {
+        for e in find_enums(fd).iter() {
+            if e.proto_name(fd).as_slice() == proto_name {
+                return Some(e.en);
+            }
+        }
+    }
+    None
+}
+
+// extensions declared in file (at top level or nested in messages)
+// with their fully qualified names
+pub fn find_extensions<'a>(fd: &'a FileDescriptorProto) -> Vec<(String, &'a FieldDescriptorProto)> {
+    let mut r = Vec::new();
+    let prefix = proto_name_prefix(fd, []);
+    for e in fd.get_extension().iter() {
+        r.push((format!("{}.{}", prefix, e.get_name()), e));
+    }
+    for m in find_messages(fd).iter() {
+        let prefix = m.proto_name(fd);
+        for e in m.get_message().get_extension().iter() {
+            r.push((format!("{}.{}", prefix, e.get_name()), e));
+        }
+    }
+    r
+}
+
+// all messages declared in file with their fully qualified names
+pub fn find_messages_with_proto_names<'a>(fd: &'a FileDescriptorProto) -> Vec<(String, &'a DescriptorProto)> {
+    find_messages(fd).iter().map(|m| (m.proto_name(fd), m.get_message())).collect()
+}
+
+// all enums declared in file with their fully qualified names
+pub fn find_enums_with_proto_names<'a>(fd: &'a FileDescriptorProto) -> Vec<(String, &'a EnumDescriptorProto)> {
+    find_enums(fd).iter().map(|e| (e.proto_name(fd), e.en)).collect()
+}
+
+// Rust names of all messages declared in file, including nested
+pub fn find_message_rust_names(fd: &FileDescriptorProto) -> Vec<String> {
+    find_messages(fd).iter().map(|m| m.rust_name()).collect()
+}
+
+// Rust names of all enums declared in file, including nested
+pub fn find_enum_rust_names(fd: &FileDescriptorProto) -> Vec<String> {
+    find_enums(fd).iter().map(|e| e.rust_name()).collect()
+}
+
+pub fn find_message_proto_name_by_rust_name(fd: &FileDescriptorProto, rust_name: &str) -> String {
+    find_messages(fd).iter()
+            .find(|m| m.rust_name().as_slice() == rust_name)
+            .unwrap()
+            .proto_name(fd)
+}
+
 pub fn find_enum_by_rust_name<'a>(fd: &'a FileDescriptorProto, rust_name: &str)
     -> &'a EnumDescriptorProto
 {
@@ -114,3 +196,15 @@ pub fn find_enum_by_rust_name<'a>(fd: &'a FileDescriptorProto, rust_name: &str)
             .unwrap()
             .en
 }
+
+pub fn find_enum_proto_name_by_rust_name(fd: &FileDescriptorProto, rust_name: &str) -> String {
+    find_enums(fd).iter()
+            .find(|e| e.rust_name().as_slice() == rust_name)
+            .unwrap()
+            .proto_name(fd)
+}
+
+// fully qualified name of service declared in file, e. g. `.pkg.Service`
+pub fn service_proto_name(fd: &FileDescriptorProto, service: &ServiceDescriptorProto) -> String {
+    format!("{}.{}", proto_name_prefix(fd, []), service.get_name())
+}
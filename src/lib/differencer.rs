@@ -0,0 +1,271 @@
+// Reflective comparison of messages of the same type.
+//
+// Differences are reported per field with path from the root message,
+// like `inner.items[2].b`.
+
+use std::cmp;
+use std::fmt;
+
+use core::Message;
+use descriptor::FieldDescriptorProto;
+use reflect::MessageDescriptor;
+use reflect::FieldDescriptor;
+use reflect::ReflectValueRef;
+use reflect::ReflectMessageRef;
+use reflect::ReflectEnumRef;
+use reflect::ReflectStrRef;
+use reflect::ReflectBytesRef;
+use reflect::ReflectI32Ref;
+use reflect::ReflectI64Ref;
+use reflect::ReflectU32Ref;
+use reflect::ReflectU64Ref;
+use reflect::ReflectBoolRef;
+use reflect::ReflectF32Ref;
+use reflect::ReflectF64Ref;
+use text_format::value_to_str;
+use unknown::UnknownFields;
+
+#[deriving(Clone,PartialEq,Eq,Show)]
+pub enum DifferenceKind {
+    // field is set only in second message
+    DifferenceAdded,
+    // field is set only in first message
+    DifferenceRemoved,
+    DifferenceModified,
+}
+
+#[deriving(Clone,PartialEq,Eq)]
+pub struct Difference {
+    pub kind: DifferenceKind,
+    pub path: String,
+    // values in text format, None if field is not set
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+impl fmt::Show for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            DifferenceAdded =>
+                write!(f, "added: {}: {}", self.path, self.right.get_ref()),
+            DifferenceRemoved =>
+                write!(f, "removed: {}: {}", self.path, self.left.get_ref()),
+            DifferenceModified =>
+                write!(f, "modified: {}: {} -> {}", self.path, self.left.get_ref(), self.right.get_ref()),
+        }
+    }
+}
+
+fn field_path(prefix: &str, f: &FieldDescriptor) -> String {
+    format!("{}{}", prefix, f.name())
+}
+
+fn item_path(prefix: &str, f: &FieldDescriptor, index: uint) -> String {
+    format!("{}{}[{}]", prefix, f.name(), index)
+}
+
+fn field_values<'a>(f: &FieldDescriptor, m: &'a Message) -> Vec<ReflectValueRef<'a>> {
+    range(0u, f.len_field(m)).map(|i| f.get_rep_item(m, i)).collect()
+}
+
+pub struct MessageDifferencer {
+    ignored_fields: Vec<*FieldDescriptorProto>,
+    set_fields: Vec<*FieldDescriptorProto>,
+    float_margin: f64,
+    ignore_unknown_fields: bool,
+}
+
+impl MessageDifferencer {
+    // exact comparison of all fields, including unknown fields
+    pub fn new() -> MessageDifferencer {
+        MessageDifferencer {
+            ignored_fields: Vec::new(),
+            set_fields: Vec::new(),
+            float_margin: 0.,
+            ignore_unknown_fields: false,
+        }
+    }
+
+    pub fn ignore_field(&mut self, field: &FieldDescriptor) {
+        self.ignored_fields.push(field.proto() as *FieldDescriptorProto);
+    }
+
+    // order of elements of repeated field is ignored
+    pub fn treat_as_set(&mut self, field: &FieldDescriptor) {
+        assert!(field.is_repeated(), "field is not repeated: {}", field.name());
+        self.set_fields.push(field.proto() as *FieldDescriptorProto);
+    }
+
+    // float and double values are equal if they differ by no more than `margin`
+    pub fn set_float_margin(&mut self, margin: f64) {
+        self.float_margin = margin;
+    }
+
+    // unknown fields include extensions
+    pub fn set_ignore_unknown_fields(&mut self, ignore_unknown_fields: bool) {
+        self.ignore_unknown_fields = ignore_unknown_fields;
+    }
+
+    pub fn compare(&self, a: &Message, b: &Message) -> bool {
+        self.differences(a, b).is_empty()
+    }
+
+    // Messages must have the same descriptor
+    pub fn differences(&self, a: &Message, b: &Message) -> Vec<Difference> {
+        let mut r = Vec::new();
+        self.compare_messages("", a, b, &mut r);
+        r
+    }
+
+    fn is_ignored(&self, f: &FieldDescriptor) -> bool {
+        self.ignored_fields.contains(&(f.proto() as *FieldDescriptorProto))
+    }
+
+    fn is_set(&self, f: &FieldDescriptor) -> bool {
+        self.set_fields.contains(&(f.proto() as *FieldDescriptorProto))
+    }
+
+    fn compare_messages(&self, prefix: &str, a: &Message, b: &Message, r: &mut Vec<Difference>) {
+        let d = a.descriptor();
+        if d as *MessageDescriptor != b.descriptor() as *MessageDescriptor {
+            fail!("cannot compare messages of different types: {} and {}",
+                d.full_name(), b.descriptor().full_name());
+        }
+
+        for f in d.fields().iter() {
+            if self.is_ignored(f) {
+                continue;
+            }
+            if !f.is_repeated() {
+                let path = field_path(prefix, f);
+                match (f.get_singular(a), f.get_singular(b)) {
+                    (Some(va), Some(vb)) => self.compare_values(path, va, vb, r),
+                    (Some(va), None) => r.push(removed(path, va)),
+                    (None, Some(vb)) => r.push(added(path, vb)),
+                    (None, None) => {},
+                }
+            } else if self.is_set(f) {
+                self.compare_sets(prefix, f, a, b, r);
+            } else {
+                let va = field_values(f, a);
+                let vb = field_values(f, b);
+                for i in range(0, cmp::max(va.len(), vb.len())) {
+                    let path = item_path(prefix, f, i);
+                    if i >= vb.len() {
+                        r.push(removed(path, va.as_slice()[i]));
+                    } else if i >= va.len() {
+                        r.push(added(path, vb.as_slice()[i]));
+                    } else {
+                        self.compare_values(path, va.as_slice()[i], vb.as_slice()[i], r);
+                    }
+                }
+            }
+        }
+
+        if !self.ignore_unknown_fields {
+            compare_unknown_fields(prefix, a.get_unknown_fields(), b.get_unknown_fields(), r);
+        }
+    }
+
+    // Elements are matched to equal elements of other field.
+    // Unmatched elements are reported with their indices.
+    fn compare_sets(&self, prefix: &str, f: &FieldDescriptor, a: &Message, b: &Message, r: &mut Vec<Difference>) {
+        let va = field_values(f, a);
+        let vb = field_values(f, b);
+        let mut matched = Vec::from_elem(vb.len(), false);
+        for (i, &x) in va.iter().enumerate() {
+            let found = range(0, vb.len()).find(|&j| {
+                !*matched.get(j) && self.values_equal(x, vb.as_slice()[j])
+            });
+            match found {
+                Some(j) => *matched.get_mut(j) = true,
+                None => r.push(removed(item_path(prefix, f, i), x)),
+            }
+        }
+        for (j, &y) in vb.iter().enumerate() {
+            if !*matched.get(j) {
+                r.push(added(item_path(prefix, f, j), y));
+            }
+        }
+    }
+
+    fn values_equal(&self, a: ReflectValueRef, b: ReflectValueRef) -> bool {
+        let mut r = Vec::new();
+        self.compare_values(String::new(), a, b, &mut r);
+        r.is_empty()
+    }
+
+    fn compare_values(&self, path: String, a: ReflectValueRef, b: ReflectValueRef, r: &mut Vec<Difference>) {
+        let equal = match (a, b) {
+            (ReflectMessageRef(ma), ReflectMessageRef(mb)) => {
+                self.compare_messages(format!("{}.", path).as_slice(), ma, mb, r);
+                return;
+            },
+            (ReflectF32Ref(x), ReflectF32Ref(y)) => self.floats_equal(x as f64, y as f64),
+            (ReflectF64Ref(x), ReflectF64Ref(y)) => self.floats_equal(x, y),
+            (ReflectEnumRef(x), ReflectEnumRef(y)) => x.value() == y.value(),
+            (ReflectStrRef(x), ReflectStrRef(y)) => x == y,
+            (ReflectBytesRef(x), ReflectBytesRef(y)) => x == y,
+            (ReflectI32Ref(x), ReflectI32Ref(y)) => x == y,
+            (ReflectI64Ref(x), ReflectI64Ref(y)) => x == y,
+            (ReflectU32Ref(x), ReflectU32Ref(y)) => x == y,
+            (ReflectU64Ref(x), ReflectU64Ref(y)) => x == y,
+            (ReflectBoolRef(x), ReflectBoolRef(y)) => x == y,
+            _ => fail!("values of different types at {}", path),
+        };
+        if !equal {
+            r.push(Difference {
+                kind: DifferenceModified,
+                path: path,
+                left: Some(value_to_str(a)),
+                right: Some(value_to_str(b)),
+            });
+        }
+    }
+
+    fn floats_equal(&self, a: f64, b: f64) -> bool {
+        a == b || (a - b).abs() <= self.float_margin
+    }
+}
+
+fn added(path: String, value: ReflectValueRef) -> Difference {
+    Difference {
+        kind: DifferenceAdded,
+        path: path,
+        left: None,
+        right: Some(value_to_str(value)),
+    }
+}
+
+fn removed(path: String, value: ReflectValueRef) -> Difference {
+    Difference {
+        kind: DifferenceRemoved,
+        path: path,
+        left: Some(value_to_str(value)),
+        right: None,
+    }
+}
+
+// Unknown fields are compared by number, path ends with field number
+fn compare_unknown_fields(prefix: &str, a: &UnknownFields, b: &UnknownFields, r: &mut Vec<Difference>) {
+    let mut numbers: Vec<u32> = a.iter().map(|(n, _)| n).chain(b.iter().map(|(n, _)| n)).collect();
+    numbers.sort();
+    numbers.dedup();
+    for &number in numbers.iter() {
+        let va = a.get(number);
+        let vb = b.get(number);
+        if va == vb {
+            continue;
+        }
+        r.push(Difference {
+            kind: match (va, vb) {
+                (Some(..), None) => DifferenceRemoved,
+                (None, Some(..)) => DifferenceAdded,
+                _ => DifferenceModified,
+            },
+            path: format!("{}{}", prefix, number),
+            left: va.map(|v| format!("{}", v)),
+            right: vb.map(|v| format!("{}", v)),
+        });
+    }
+}
@@ -0,0 +1,1203 @@
+// Messages which types are not known at compile time.
+//
+// Types are created from descriptors loaded at runtime by `DescriptorPool`,
+// and field values are accessed with reflection through `MessageDescriptor`.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::default::Default;
+use std::fmt;
+use std::intrinsics::TypeId;
+
+use core::Message;
+use core::CodedInputStream;
+use core::CodedOutputStream;
+use core::wire_format;
+use clear::Clear;
+use descriptor::*;
+use error::ProtobufResult;
+use misc::leak;
+use reflect::MessageDescriptor;
+use reflect::EnumDescriptor;
+use reflect::EnumValueDescriptor;
+use reflect::FieldAccessor;
+use reflect::MessageFactory;
+use reflect::ReflectValueBox;
+use reflect::ReflectU32;
+use reflect::ReflectU64;
+use reflect::ReflectI32;
+use reflect::ReflectI64;
+use reflect::ReflectF32;
+use reflect::ReflectF64;
+use reflect::ReflectBool;
+use reflect::ReflectString;
+use reflect::ReflectBytes;
+use reflect::ReflectEnum;
+use reflect::parse_default_value;
+use rt::compute_raw_varint32_size;
+use rt::compute_raw_varint64_size;
+use rt::tag_size;
+use rt::unknown_fields_size;
+use unknown::UnknownFields;
+use unknown::UnknownVarint;
+use zigzag::encode_zig_zag_32;
+use zigzag::encode_zig_zag_64;
+
+// Values of field, singular field has zero or one value
+#[deriving(Clone,PartialEq)]
+enum DynamicValues {
+    DynamicU32(Vec<u32>),
+    DynamicU64(Vec<u64>),
+    DynamicI32(Vec<i32>),
+    DynamicI64(Vec<i64>),
+    DynamicF32(Vec<f32>),
+    DynamicF64(Vec<f64>),
+    DynamicBool(Vec<bool>),
+    DynamicString(Vec<String>),
+    DynamicBytes(Vec<Vec<u8>>),
+    // only values known to enum are stored, like in generated code
+    DynamicEnum(Vec<i32>),
+    DynamicMessages(Vec<DynamicMessage>),
+}
+
+impl DynamicValues {
+    fn new(field_type: FieldDescriptorProto_Type) -> DynamicValues {
+        match field_type {
+            FieldDescriptorProto_TYPE_UINT32 |
+            FieldDescriptorProto_TYPE_FIXED32  => DynamicU32(Vec::new()),
+            FieldDescriptorProto_TYPE_UINT64 |
+            FieldDescriptorProto_TYPE_FIXED64  => DynamicU64(Vec::new()),
+            FieldDescriptorProto_TYPE_INT32 |
+            FieldDescriptorProto_TYPE_SINT32 |
+            FieldDescriptorProto_TYPE_SFIXED32 => DynamicI32(Vec::new()),
+            FieldDescriptorProto_TYPE_INT64 |
+            FieldDescriptorProto_TYPE_SINT64 |
+            FieldDescriptorProto_TYPE_SFIXED64 => DynamicI64(Vec::new()),
+            FieldDescriptorProto_TYPE_FLOAT    => DynamicF32(Vec::new()),
+            FieldDescriptorProto_TYPE_DOUBLE   => DynamicF64(Vec::new()),
+            FieldDescriptorProto_TYPE_BOOL     => DynamicBool(Vec::new()),
+            FieldDescriptorProto_TYPE_STRING   => DynamicString(Vec::new()),
+            FieldDescriptorProto_TYPE_BYTES    => DynamicBytes(Vec::new()),
+            FieldDescriptorProto_TYPE_ENUM     => DynamicEnum(Vec::new()),
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP    => DynamicMessages(Vec::new()),
+        }
+    }
+
+    fn len(&self) -> uint {
+        match *self {
+            DynamicU32(ref v)      => v.len(),
+            DynamicU64(ref v)      => v.len(),
+            DynamicI32(ref v)      => v.len(),
+            DynamicI64(ref v)      => v.len(),
+            DynamicF32(ref v)      => v.len(),
+            DynamicF64(ref v)      => v.len(),
+            DynamicBool(ref v)     => v.len(),
+            DynamicString(ref v)   => v.len(),
+            DynamicBytes(ref v)    => v.len(),
+            DynamicEnum(ref v)     => v.len(),
+            DynamicMessages(ref v) => v.len(),
+        }
+    }
+
+    fn clear(&mut self) {
+        match *self {
+            DynamicU32(ref mut v)      => v.clear(),
+            DynamicU64(ref mut v)      => v.clear(),
+            DynamicI32(ref mut v)      => v.clear(),
+            DynamicI64(ref mut v)      => v.clear(),
+            DynamicF32(ref mut v)      => v.clear(),
+            DynamicF64(ref mut v)      => v.clear(),
+            DynamicBool(ref mut v)     => v.clear(),
+            DynamicString(ref mut v)   => v.clear(),
+            DynamicBytes(ref mut v)    => v.clear(),
+            DynamicEnum(ref mut v)     => v.clear(),
+            DynamicMessages(ref mut v) => v.clear(),
+        }
+    }
+
+    fn push(&mut self, value: ReflectValueBox) {
+        match (self, value) {
+            (&DynamicU32(ref mut v), ReflectU32(value))       => v.push(value),
+            (&DynamicU64(ref mut v), ReflectU64(value))       => v.push(value),
+            (&DynamicI32(ref mut v), ReflectI32(value))       => v.push(value),
+            (&DynamicI64(ref mut v), ReflectI64(value))       => v.push(value),
+            (&DynamicF32(ref mut v), ReflectF32(value))       => v.push(value),
+            (&DynamicF64(ref mut v), ReflectF64(value))       => v.push(value),
+            (&DynamicBool(ref mut v), ReflectBool(value))     => v.push(value),
+            (&DynamicString(ref mut v), ReflectString(ref value)) => v.push(value.clone()),
+            (&DynamicBytes(ref mut v), ReflectBytes(ref value))   => v.push(value.clone()),
+            (&DynamicEnum(ref mut v), ReflectEnum(value))     => v.push(value.value()),
+            _ => fail!("cannot push value of incompatible type"),
+        }
+    }
+
+    fn push_all(&mut self, other: &DynamicValues) {
+        match (self, other) {
+            (&DynamicU32(ref mut v), &DynamicU32(ref o))           => v.push_all(o.as_slice()),
+            (&DynamicU64(ref mut v), &DynamicU64(ref o))           => v.push_all(o.as_slice()),
+            (&DynamicI32(ref mut v), &DynamicI32(ref o))           => v.push_all(o.as_slice()),
+            (&DynamicI64(ref mut v), &DynamicI64(ref o))           => v.push_all(o.as_slice()),
+            (&DynamicF32(ref mut v), &DynamicF32(ref o))           => v.push_all(o.as_slice()),
+            (&DynamicF64(ref mut v), &DynamicF64(ref o))           => v.push_all(o.as_slice()),
+            (&DynamicBool(ref mut v), &DynamicBool(ref o))         => v.push_all(o.as_slice()),
+            (&DynamicString(ref mut v), &DynamicString(ref o))     => v.push_all(o.as_slice()),
+            (&DynamicBytes(ref mut v), &DynamicBytes(ref o))       => v.push_all(o.as_slice()),
+            (&DynamicEnum(ref mut v), &DynamicEnum(ref o))         => v.push_all(o.as_slice()),
+            (&DynamicMessages(ref mut v), &DynamicMessages(ref o)) => v.push_all(o.as_slice()),
+            _ => fail!("cannot push values of incompatible type"),
+        }
+    }
+
+    // keep only last value, like when parsing singular field
+    fn truncate_to_last(&mut self) {
+        match *self {
+            DynamicU32(ref mut v)      => { v.shift(); },
+            DynamicU64(ref mut v)      => { v.shift(); },
+            DynamicI32(ref mut v)      => { v.shift(); },
+            DynamicI64(ref mut v)      => { v.shift(); },
+            DynamicF32(ref mut v)      => { v.shift(); },
+            DynamicF64(ref mut v)      => { v.shift(); },
+            DynamicBool(ref mut v)     => { v.shift(); },
+            DynamicString(ref mut v)   => { v.shift(); },
+            DynamicBytes(ref mut v)    => { v.shift(); },
+            DynamicEnum(ref mut v)     => { v.shift(); },
+            DynamicMessages(ref mut v) => { v.shift(); },
+        }
+    }
+
+    // read value without tag, except messages, groups and enums
+    fn read_value(&mut self, field_type: FieldDescriptorProto_Type, is: &mut CodedInputStream)
+        -> ProtobufResult<()>
+    {
+        match (self, field_type) {
+            (&DynamicU32(ref mut v), FieldDescriptorProto_TYPE_UINT32)   => v.push(try!(is.read_uint32())),
+            (&DynamicU32(ref mut v), FieldDescriptorProto_TYPE_FIXED32)  => v.push(try!(is.read_fixed32())),
+            (&DynamicU64(ref mut v), FieldDescriptorProto_TYPE_UINT64)   => v.push(try!(is.read_uint64())),
+            (&DynamicU64(ref mut v), FieldDescriptorProto_TYPE_FIXED64)  => v.push(try!(is.read_fixed64())),
+            (&DynamicI32(ref mut v), FieldDescriptorProto_TYPE_INT32)    => v.push(try!(is.read_int32())),
+            (&DynamicI32(ref mut v), FieldDescriptorProto_TYPE_SINT32)   => v.push(try!(is.read_sint32())),
+            (&DynamicI32(ref mut v), FieldDescriptorProto_TYPE_SFIXED32) => v.push(try!(is.read_sfixed32())),
+            (&DynamicI64(ref mut v), FieldDescriptorProto_TYPE_INT64)    => v.push(try!(is.read_int64())),
+            (&DynamicI64(ref mut v), FieldDescriptorProto_TYPE_SINT64)   => v.push(try!(is.read_sint64())),
+            (&DynamicI64(ref mut v), FieldDescriptorProto_TYPE_SFIXED64) => v.push(try!(is.read_sfixed64())),
+            (&DynamicF32(ref mut v), _)    => v.push(try!(is.read_float())),
+            (&DynamicF64(ref mut v), _)    => v.push(try!(is.read_double())),
+            (&DynamicBool(ref mut v), _)   => v.push(try!(is.read_bool())),
+            (&DynamicString(ref mut v), _) => v.push(try!(is.read_string())),
+            (&DynamicBytes(ref mut v), _)  => v.push(try!(is.read_bytes())),
+            _ => fail!("cannot read value of type {:?}", field_type),
+        };
+        Ok(())
+    }
+
+    // write value without tag, except messages and groups
+    fn write_value(&self, index: uint, field_type: FieldDescriptorProto_Type, os: &mut CodedOutputStream) {
+        match (self, field_type) {
+            (&DynamicU32(ref v), FieldDescriptorProto_TYPE_UINT32)   => os.write_uint32_no_tag(*v.get(index)),
+            (&DynamicU32(ref v), FieldDescriptorProto_TYPE_FIXED32)  => os.write_fixed32_no_tag(*v.get(index)),
+            (&DynamicU64(ref v), FieldDescriptorProto_TYPE_UINT64)   => os.write_uint64_no_tag(*v.get(index)),
+            (&DynamicU64(ref v), FieldDescriptorProto_TYPE_FIXED64)  => os.write_fixed64_no_tag(*v.get(index)),
+            (&DynamicI32(ref v), FieldDescriptorProto_TYPE_INT32)    => os.write_int32_no_tag(*v.get(index)),
+            (&DynamicI32(ref v), FieldDescriptorProto_TYPE_SINT32)   => os.write_sint32_no_tag(*v.get(index)),
+            (&DynamicI32(ref v), FieldDescriptorProto_TYPE_SFIXED32) => os.write_sfixed32_no_tag(*v.get(index)),
+            (&DynamicI64(ref v), FieldDescriptorProto_TYPE_INT64)    => os.write_int64_no_tag(*v.get(index)),
+            (&DynamicI64(ref v), FieldDescriptorProto_TYPE_SINT64)   => os.write_sint64_no_tag(*v.get(index)),
+            (&DynamicI64(ref v), FieldDescriptorProto_TYPE_SFIXED64) => os.write_sfixed64_no_tag(*v.get(index)),
+            (&DynamicF32(ref v), _)    => os.write_float_no_tag(*v.get(index)),
+            (&DynamicF64(ref v), _)    => os.write_double_no_tag(*v.get(index)),
+            (&DynamicBool(ref v), _)   => os.write_bool_no_tag(*v.get(index)),
+            (&DynamicString(ref v), _) => os.write_string_no_tag(v.get(index).as_slice()),
+            (&DynamicBytes(ref v), _)  => os.write_bytes_no_tag(v.get(index).as_slice()),
+            (&DynamicEnum(ref v), _)   => os.write_enum_no_tag(*v.get(index)),
+            _ => fail!("cannot write value of type {:?}", field_type),
+        }
+    }
+
+    // size of value without tag, except messages and groups
+    fn value_size(&self, index: uint, field_type: FieldDescriptorProto_Type) -> u32 {
+        match (self, field_type) {
+            (&DynamicU32(ref v), FieldDescriptorProto_TYPE_UINT32)   => compute_raw_varint32_size(*v.get(index)),
+            (&DynamicU64(ref v), FieldDescriptorProto_TYPE_UINT64)   => compute_raw_varint64_size(*v.get(index)),
+            (&DynamicI32(ref v), FieldDescriptorProto_TYPE_INT32)    => compute_raw_varint64_size(*v.get(index) as u64),
+            (&DynamicI32(ref v), FieldDescriptorProto_TYPE_SINT32)   => compute_raw_varint32_size(encode_zig_zag_32(*v.get(index))),
+            (&DynamicI64(ref v), FieldDescriptorProto_TYPE_INT64)    => compute_raw_varint64_size(*v.get(index) as u64),
+            (&DynamicI64(ref v), FieldDescriptorProto_TYPE_SINT64)   => compute_raw_varint64_size(encode_zig_zag_64(*v.get(index))),
+            (&DynamicBool(..), _)      => 1,
+            (&DynamicString(ref v), _) => {
+                let len = v.get(index).len() as u32;
+                compute_raw_varint32_size(len) + len
+            },
+            (&DynamicBytes(ref v), _)  => {
+                let len = v.get(index).len() as u32;
+                compute_raw_varint32_size(len) + len
+            },
+            (&DynamicEnum(ref v), _)   => compute_raw_varint64_size(*v.get(index) as u64),
+            (&DynamicMessages(..), _)  => fail!("cannot compute size of message without sizes"),
+            _ => match wire_type(field_type) {
+                wire_format::WireTypeFixed32 => 4,
+                wire_format::WireTypeFixed64 => 8,
+                _ => fail!("cannot compute size of value of type {:?}", field_type),
+            },
+        }
+    }
+
+    // size of all values without tags, also size of data of packed field
+    fn values_size(&self, field_type: FieldDescriptorProto_Type) -> u32 {
+        range(0u, self.len()).map(|i| self.value_size(i, field_type)).fold(0, |a, b| a + b)
+    }
+}
+
+fn wire_type(field_type: FieldDescriptorProto_Type) -> wire_format::WireType {
+    match field_type {
+        FieldDescriptorProto_TYPE_FIXED32 |
+        FieldDescriptorProto_TYPE_SFIXED32 |
+        FieldDescriptorProto_TYPE_FLOAT    => wire_format::WireTypeFixed32,
+        FieldDescriptorProto_TYPE_FIXED64 |
+        FieldDescriptorProto_TYPE_SFIXED64 |
+        FieldDescriptorProto_TYPE_DOUBLE   => wire_format::WireTypeFixed64,
+        FieldDescriptorProto_TYPE_STRING |
+        FieldDescriptorProto_TYPE_BYTES |
+        FieldDescriptorProto_TYPE_MESSAGE  => wire_format::WireTypeLengthDelimited,
+        FieldDescriptorProto_TYPE_GROUP    => wire_format::WireTypeStartGroup,
+        _                                  => wire_format::WireTypeVarint,
+    }
+}
+
+// Value returned by getter when singular field is not set
+fn default_value(proto: &FieldDescriptorProto, enum_descriptor: Option<&'static EnumDescriptor>) -> DynamicValues {
+    let mut values = DynamicValues::new(proto.get_field_type());
+    match parse_default_value(proto, enum_descriptor) {
+        Some(value) => values.push(value),
+        None => {},
+    }
+    values
+}
+
+struct DynamicFieldType {
+    proto: &'static FieldDescriptorProto,
+    enum_descriptor: Option<&'static EnumDescriptor>,
+    // type containing this field, set by `DynamicMessageType::link`
+    owner: Cell<Option<&'static DynamicMessageType>>,
+    // set by `DynamicMessageType::link`
+    message_type: Cell<Option<&'static DynamicMessageType>>,
+    default_value: DynamicValues,
+}
+
+impl DynamicFieldType {
+    fn field_type(&self) -> FieldDescriptorProto_Type {
+        self.proto.get_field_type()
+    }
+
+    fn number(&self) -> u32 {
+        self.proto.get_number() as u32
+    }
+
+    fn is_repeated(&self) -> bool {
+        self.proto.get_label() == FieldDescriptorProto_LABEL_REPEATED
+    }
+
+    fn is_packable(&self) -> bool {
+        match wire_type(self.field_type()) {
+            wire_format::WireTypeLengthDelimited | wire_format::WireTypeStartGroup => false,
+            _ => true,
+        }
+    }
+
+    fn is_message(&self) -> bool {
+        match self.field_type() {
+            FieldDescriptorProto_TYPE_MESSAGE | FieldDescriptorProto_TYPE_GROUP => true,
+            _ => false,
+        }
+    }
+
+    fn message_type(&self) -> &'static DynamicMessageType {
+        match self.message_type.get() {
+            Some(t) => t,
+            None => fail!("message type of field {} is not linked", self.proto.get_name()),
+        }
+    }
+
+    fn is_enum_value_known(&self, value: i32) -> bool {
+        self.enum_descriptor.unwrap().proto().get_value().iter().any(|v| v.get_number() == value)
+    }
+}
+
+// Factory of `MessageDescriptor` of dynamic message,
+// message type is known only after it is linked
+struct DynamicMessageFactory {
+    message_type: Cell<Option<&'static DynamicMessageType>>,
+}
+
+impl MessageFactory for &'static DynamicMessageFactory {
+    fn new_instance(&self) -> Box<Message> {
+        box self.message_type.get().unwrap().new_instance() as Box<Message>
+    }
+}
+
+// Type of dynamic message, created from `DescriptorProto`
+pub struct DynamicMessageType {
+    descriptor: MessageDescriptor,
+    factory: &'static DynamicMessageFactory,
+    fields: Vec<&'static DynamicFieldType>,
+    index_by_number: HashMap<u32, uint>,
+    // set by `link`
+    default_instance: Cell<Option<&'static DynamicMessage>>,
+}
+
+impl DynamicMessageType {
+    // Types of enum fields must be in `enums`, keyed by fully qualified names.
+    // Message types are resolved later in `link`.
+    pub fn new(
+            proto: &'static DescriptorProto,
+            proto_name: &str,
+            file: &'static FileDescriptorProto,
+            enums: &HashMap<String, &'static EnumDescriptor>)
+        -> DynamicMessageType
+    {
+        let mut accessors: Vec<&'static FieldAccessor<DynamicMessage>> = Vec::new();
+        let mut fields = Vec::new();
+        let mut index_by_number = HashMap::new();
+        for (index, f) in proto.get_field().iter().enumerate() {
+            let enum_descriptor = match f.get_field_type() {
+                FieldDescriptorProto_TYPE_ENUM => match enums.find(&f.get_type_name().to_string()) {
+                    Some(&e) => Some(e),
+                    None => fail!("enum type is not found: {}", f.get_type_name()),
+                },
+                _ => None,
+            };
+            let field: &'static DynamicFieldType = leak(box DynamicFieldType {
+                proto: f,
+                enum_descriptor: enum_descriptor,
+                owner: Cell::new(None),
+                message_type: Cell::new(None),
+                default_value: default_value(f, enum_descriptor),
+            });
+            fields.push(field);
+
+            let accessor: &'static DynamicFieldAccessor = leak(box DynamicFieldAccessor {
+                field: field,
+                index: index,
+            });
+            accessors.push(accessor as &'static FieldAccessor<DynamicMessage>);
+            index_by_number.insert(f.get_number() as u32, index);
+        }
+        let factory: &'static DynamicMessageFactory = leak(box DynamicMessageFactory {
+            message_type: Cell::new(None),
+        });
+        DynamicMessageType {
+            descriptor: MessageDescriptor::new_from_proto(
+                proto, proto_name, box factory as Box<MessageFactory+'static>, accessors, file),
+            factory: factory,
+            fields: fields,
+            index_by_number: index_by_number,
+            default_instance: Cell::new(None),
+        }
+    }
+
+    // Resolve types of message fields. `messages` are keyed by fully qualified names.
+    pub fn link(&'static self, messages: &HashMap<String, &'static DynamicMessageType>) {
+        for f in self.fields.iter() {
+            f.owner.set(Some(self));
+            if f.is_message() {
+                match messages.find(&f.proto.get_type_name().to_string()) {
+                    Some(&t) => f.message_type.set(Some(t)),
+                    None => fail!("message type is not found: {}", f.proto.get_type_name()),
+                }
+            }
+        }
+        self.factory.message_type.set(Some(self));
+        self.default_instance.set(Some(leak(box self.new_instance())));
+    }
+
+    pub fn descriptor(&'static self) -> &'static MessageDescriptor {
+        &self.descriptor
+    }
+
+    pub fn new_instance(&'static self) -> DynamicMessage {
+        DynamicMessage {
+            message_type: self,
+            values: self.fields.iter().map(|f| DynamicValues::new(f.field_type())).collect(),
+            unknown_fields: Default::default(),
+        }
+    }
+
+    fn default_instance(&self) -> &'static DynamicMessage {
+        self.default_instance.get().unwrap()
+    }
+}
+
+// Message with values of fields stored in vectors.
+// Message can only be created by `DynamicMessageType::new_instance`,
+// because fields cannot be parsed without type.
+#[deriving(Clone)]
+pub struct DynamicMessage {
+    message_type: &'static DynamicMessageType,
+    values: Vec<DynamicValues>,
+    unknown_fields: UnknownFields,
+}
+
+impl DynamicMessage {
+    fn field_type(&self, index: uint) -> &'static DynamicFieldType {
+        *self.message_type.fields.get(index)
+    }
+
+    fn get_values<'a>(&'a self, index: uint) -> &'a DynamicValues {
+        self.values.get(index)
+    }
+
+    // values of singular field or default value
+    fn get_singular_values<'a>(&'a self, index: uint) -> &'a DynamicValues {
+        match self.values.get(index).len() {
+            0 => &self.field_type(index).default_value,
+            _ => self.values.get(index),
+        }
+    }
+
+    fn mut_values<'a>(&'a mut self, index: uint) -> &'a mut DynamicValues {
+        self.values.get_mut(index)
+    }
+
+    fn mut_messages<'a>(&'a mut self, index: uint) -> &'a mut Vec<DynamicMessage> {
+        match *self.mut_values(index) {
+            DynamicMessages(ref mut v) => v,
+            _ => fail!(),
+        }
+    }
+
+    // message of singular field, set to empty message if not set
+    fn mut_message<'a>(&'a mut self, index: uint) -> &'a mut DynamicMessage {
+        let message_type = self.field_type(index).message_type();
+        let v = self.mut_messages(index);
+        if v.is_empty() {
+            v.push(message_type.new_instance());
+        }
+        v.mut_last().unwrap()
+    }
+
+    fn add_message<'a>(&'a mut self, index: uint) -> &'a mut DynamicMessage {
+        let message_type = self.field_type(index).message_type();
+        let v = self.mut_messages(index);
+        v.push(message_type.new_instance());
+        v.mut_last().unwrap()
+    }
+
+    fn merge_field_from(&mut self, index: uint, wire_type: wire_format::WireType, is: &mut CodedInputStream)
+        -> ProtobufResult<()>
+    {
+        let field = self.field_type(index);
+        let field_number = field.number();
+        if wire_type == wire_format::WireTypeLengthDelimited && field.is_packable() {
+            let len = try!(is.read_raw_varint32());
+            let old_limit = try!(is.push_limit(len));
+            while !try!(is.eof()) {
+                try!(self.read_value(index, is));
+            }
+            return is.pop_limit(old_limit);
+        }
+        if wire_type != self::wire_type(field.field_type()) {
+            let unknown = try!(is.read_unknown(field_number, wire_type));
+            self.mut_unknown_fields().add_value(field_number, unknown);
+            return Ok(());
+        }
+        match (field.field_type(), field.is_repeated()) {
+            (FieldDescriptorProto_TYPE_MESSAGE, false) => is.merge_message(self.mut_message(index)),
+            (FieldDescriptorProto_TYPE_MESSAGE, true)  => is.merge_message(self.add_message(index)),
+            (FieldDescriptorProto_TYPE_GROUP, false)   => is.merge_group(field_number, self.mut_message(index)),
+            (FieldDescriptorProto_TYPE_GROUP, true)    => is.merge_group(field_number, self.add_message(index)),
+            _ => self.read_value(index, is),
+        }
+    }
+
+    fn read_value(&mut self, index: uint, is: &mut CodedInputStream) -> ProtobufResult<()> {
+        let field = self.field_type(index);
+        match field.field_type() {
+            FieldDescriptorProto_TYPE_ENUM => {
+                let value = try!(is.read_int32());
+                if !field.is_enum_value_known(value) {
+                    self.mut_unknown_fields().add_value(field.number(), UnknownVarint(value as i64 as u64));
+                    return Ok(());
+                }
+                match *self.mut_values(index) {
+                    DynamicEnum(ref mut v) => v.push(value),
+                    _ => fail!(),
+                };
+            },
+            field_type => try!(self.mut_values(index).read_value(field_type, is)),
+        };
+        if !field.is_repeated() && self.values.get(index).len() > 1 {
+            self.mut_values(index).truncate_to_last();
+        }
+        Ok(())
+    }
+
+    // like `write_to_with_computed_sizes` of generated messages,
+    // `sizes` are computed by `compute_sizes`, first element is size of self
+    fn write_to_with_computed_sizes(&self, os: &mut CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        for (index, field) in self.message_type.fields.iter().enumerate() {
+            let values = self.values.get(index);
+            let field_number = field.number();
+            if values.len() == 0 {
+                continue;
+            }
+            if field.proto.get_options().get_packed() && field.is_packable() {
+                os.write_tag(field_number, wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(values.values_size(field.field_type()));
+                for i in range(0u, values.len()) {
+                    values.write_value(i, field.field_type(), os);
+                }
+                continue;
+            }
+            match *values {
+                DynamicMessages(ref v) => {
+                    for m in v.iter() {
+                        if field.field_type() == FieldDescriptorProto_TYPE_GROUP {
+                            os.write_tag(field_number, wire_format::WireTypeStartGroup);
+                            *sizes_pos += 1;
+                            m.write_to_with_computed_sizes(os, sizes, sizes_pos);
+                            os.write_tag(field_number, wire_format::WireTypeEndGroup);
+                        } else {
+                            os.write_tag(field_number, wire_format::WireTypeLengthDelimited);
+                            os.write_raw_varint32(sizes[*sizes_pos]);
+                            *sizes_pos += 1;
+                            m.write_to_with_computed_sizes(os, sizes, sizes_pos);
+                        }
+                    }
+                },
+                _ => {
+                    for i in range(0u, values.len()) {
+                        os.write_tag(field_number, wire_type(field.field_type()));
+                        values.write_value(i, field.field_type(), os);
+                    }
+                },
+            }
+        }
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    fn get_message_or_default<'a>(&'a self, index: uint) -> &'a DynamicMessage {
+        match *self.get_values(index) {
+            DynamicMessages(ref v) if !v.is_empty() => v.last().unwrap(),
+            DynamicMessages(..) => self.field_type(index).message_type().default_instance(),
+            _ => fail!(),
+        }
+    }
+
+    // unknown values are stored in unknown fields, and setters check enum of value
+    fn enum_value(&self, index: uint, value: i32) -> &'static EnumValueDescriptor {
+        self.field_type(index).enum_descriptor.unwrap().find_value_by_number(value).unwrap()
+    }
+}
+
+// `Default` is required by `Message`, but message without type is useless
+impl Default for DynamicMessage {
+    fn default() -> DynamicMessage {
+        fail!("dynamic message must be created with DynamicMessageType::new_instance");
+    }
+}
+
+impl PartialEq for DynamicMessage {
+    fn eq(&self, other: &DynamicMessage) -> bool {
+        self.message_type as *DynamicMessageType == other.message_type as *DynamicMessageType
+            && self.values == other.values
+            && self.unknown_fields == other.unknown_fields
+    }
+}
+
+impl Clear for DynamicMessage {
+    fn clear(&mut self) {
+        for v in self.values.mut_iter() {
+            v.clear();
+        }
+        self.unknown_fields = Default::default();
+    }
+}
+
+impl fmt::Show for DynamicMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_impl(f)
+    }
+}
+
+impl Message for DynamicMessage {
+    fn new() -> DynamicMessage {
+        Default::default()
+    }
+
+    fn is_initialized(&self) -> bool {
+        for (index, field) in self.message_type.fields.iter().enumerate() {
+            match *self.values.get(index) {
+                DynamicMessages(ref v) => {
+                    if v.iter().any(|m| !m.is_initialized()) {
+                        return false;
+                    }
+                },
+                _ => {},
+            }
+            if field.proto.get_label() == FieldDescriptorProto_LABEL_REQUIRED && self.values.get(index).len() == 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut CodedInputStream) -> ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            }
+            match self.message_type.index_by_number.find(&field_number).map(|&index| index) {
+                Some(index) => try!(self.merge_field_from(index, wire_type, is)),
+                None => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &DynamicMessage) {
+        if self.message_type as *DynamicMessageType != other.message_type as *DynamicMessageType {
+            fail!("cannot merge dynamic messages of different types");
+        }
+        for (index, values) in other.values.iter().enumerate() {
+            if values.len() == 0 {
+                continue;
+            }
+            let field = self.field_type(index);
+            match *values {
+                DynamicMessages(ref v) if !field.is_repeated() => {
+                    self.mut_message(index).merge_from_message(v.last().unwrap());
+                },
+                _ => {
+                    if !field.is_repeated() {
+                        self.mut_values(index).clear();
+                    }
+                    self.mut_values(index).push_all(values);
+                },
+            }
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
+    fn write_to(&self, os: &mut CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+    }
+
+    // sizes of nested messages are appended to `sizes` in order they are written
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for (index, field) in self.message_type.fields.iter().enumerate() {
+            let values = self.values.get(index);
+            let field_number = field.number();
+            if values.len() == 0 {
+                continue;
+            }
+            if field.proto.get_options().get_packed() && field.is_packable() {
+                let data_size = values.values_size(field.field_type());
+                my_size += tag_size(field_number) + compute_raw_varint32_size(data_size) + data_size;
+                continue;
+            }
+            match *values {
+                DynamicMessages(ref v) => {
+                    for m in v.iter() {
+                        let len = m.compute_sizes(sizes);
+                        if field.field_type() == FieldDescriptorProto_TYPE_GROUP {
+                            my_size += 2 * tag_size(field_number) + len;
+                        } else {
+                            my_size += tag_size(field_number) + compute_raw_varint32_size(len) + len;
+                        }
+                    }
+                },
+                _ => {
+                    my_size += tag_size(field_number) * values.len() as u32 + values.values_size(field.field_type());
+                },
+            }
+        }
+        my_size += unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        my_size
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn descriptor(&self) -> &'static MessageDescriptor {
+        self.message_type.descriptor()
+    }
+
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<DynamicMessage>()
+    }
+}
+
+struct DynamicFieldAccessor {
+    field: &'static DynamicFieldType,
+    index: uint,
+}
+
+impl DynamicFieldAccessor {
+    // all dynamic messages have same `TypeId`, so `FieldDescriptor` cannot check type of message
+    fn check_owner(&self, m: &DynamicMessage) {
+        let owner = self.field.owner.get().unwrap();
+        if owner as *DynamicMessageType != m.message_type as *DynamicMessageType {
+            fail!("field {} of {} cannot be used with message {}",
+                self.field.proto.get_name(), owner.descriptor().full_name(), m.descriptor().full_name());
+        }
+    }
+
+    fn values<'a>(&self, m: &'a DynamicMessage) -> &'a DynamicValues {
+        self.check_owner(m);
+        m.get_values(self.index)
+    }
+
+    fn singular_values<'a>(&self, m: &'a DynamicMessage) -> &'a DynamicValues {
+        self.check_owner(m);
+        m.get_singular_values(self.index)
+    }
+
+    fn mut_values<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut DynamicValues {
+        self.check_owner(m);
+        m.mut_values(self.index)
+    }
+}
+
+impl FieldAccessor<DynamicMessage> for DynamicFieldAccessor {
+    fn name(&self) -> &'static str {
+        self.field.proto.get_name()
+    }
+
+    fn message_descriptor(&self) -> &'static MessageDescriptor {
+        self.field.message_type().descriptor()
+    }
+
+    fn enum_descriptor(&self) -> &'static EnumDescriptor {
+        self.field.enum_descriptor.unwrap()
+    }
+
+    fn has_field(&self, m: &DynamicMessage) -> bool {
+        self.values(m).len() != 0
+    }
+
+    fn len_field(&self, m: &DynamicMessage) -> uint {
+        self.values(m).len()
+    }
+
+    fn get_message<'a>(&self, m: &'a DynamicMessage) -> &'a Message {
+        self.check_owner(m);
+        m.get_message_or_default(self.index) as &'a Message
+    }
+
+    fn get_rep_message_item<'a>(&self, m: &'a DynamicMessage, index: uint) -> &'a Message {
+        match *self.values(m) {
+            DynamicMessages(ref v) => v.get(index) as &'a Message,
+            _ => fail!(),
+        }
+    }
+
+    fn get_enum(&self, m: &DynamicMessage) -> &'static EnumValueDescriptor {
+        match *self.singular_values(m) {
+            DynamicEnum(ref v) => m.enum_value(self.index, *v.last().unwrap()),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_enum_item(&self, m: &DynamicMessage, index: uint) -> &'static EnumValueDescriptor {
+        match *self.values(m) {
+            DynamicEnum(ref v) => m.enum_value(self.index, *v.get(index)),
+            _ => fail!(),
+        }
+    }
+
+    fn get_str<'a>(&self, m: &'a DynamicMessage) -> &'a str {
+        match *self.singular_values(m) {
+            DynamicString(ref v) => v.last().unwrap().as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_bytes<'a>(&self, m: &'a DynamicMessage) -> &'a [u8] {
+        match *self.singular_values(m) {
+            DynamicBytes(ref v) => v.last().unwrap().as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_str<'a>(&self, m: &'a DynamicMessage) -> &'a [String] {
+        match *self.values(m) {
+            DynamicString(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_bytes<'a>(&self, m: &'a DynamicMessage) -> &'a [Vec<u8>] {
+        match *self.values(m) {
+            DynamicBytes(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_u32(&self, m: &DynamicMessage) -> u32 {
+        match *self.singular_values(m) {
+            DynamicU32(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_u32<'a>(&self, m: &'a DynamicMessage) -> &'a [u32] {
+        match *self.values(m) {
+            DynamicU32(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_u64(&self, m: &DynamicMessage) -> u64 {
+        match *self.singular_values(m) {
+            DynamicU64(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_u64<'a>(&self, m: &'a DynamicMessage) -> &'a [u64] {
+        match *self.values(m) {
+            DynamicU64(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_i32(&self, m: &DynamicMessage) -> i32 {
+        match *self.singular_values(m) {
+            DynamicI32(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_i32<'a>(&self, m: &'a DynamicMessage) -> &'a [i32] {
+        match *self.values(m) {
+            DynamicI32(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_i64(&self, m: &DynamicMessage) -> i64 {
+        match *self.singular_values(m) {
+            DynamicI64(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_i64<'a>(&self, m: &'a DynamicMessage) -> &'a [i64] {
+        match *self.values(m) {
+            DynamicI64(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_bool(&self, m: &DynamicMessage) -> bool {
+        match *self.singular_values(m) {
+            DynamicBool(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_bool<'a>(&self, m: &'a DynamicMessage) -> &'a [bool] {
+        match *self.values(m) {
+            DynamicBool(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_f32(&self, m: &DynamicMessage) -> f32 {
+        match *self.singular_values(m) {
+            DynamicF32(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_f32<'a>(&self, m: &'a DynamicMessage) -> &'a [f32] {
+        match *self.values(m) {
+            DynamicF32(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_f64(&self, m: &DynamicMessage) -> f64 {
+        match *self.singular_values(m) {
+            DynamicF64(ref v) => *v.last().unwrap(),
+            _ => fail!(),
+        }
+    }
+
+    fn get_rep_f64<'a>(&self, m: &'a DynamicMessage) -> &'a [f64] {
+        match *self.values(m) {
+            DynamicF64(ref v) => v.as_slice(),
+            _ => fail!(),
+        }
+    }
+
+    fn clear_field(&self, m: &mut DynamicMessage) {
+        self.mut_values(m).clear();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Message {
+        self.check_owner(m);
+        m.mut_message(self.index) as &'a mut Message
+    }
+
+    fn add_message<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Message {
+        self.check_owner(m);
+        m.add_message(self.index) as &'a mut Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut DynamicMessage, index: uint) -> &'a mut Message {
+        self.check_owner(m);
+        m.mut_messages(self.index).get_mut(index) as &'a mut Message
+    }
+
+    fn set_enum(&self, m: &mut DynamicMessage, v: &EnumValueDescriptor) {
+        match *self.mut_values(m) {
+            DynamicEnum(ref mut values) => {
+                values.clear();
+                values.push(v.value());
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_enum(&self, m: &mut DynamicMessage, v: &EnumValueDescriptor) {
+        match *self.mut_values(m) {
+            DynamicEnum(ref mut values) => values.push(v.value()),
+            _ => fail!(),
+        }
+    }
+
+    fn set_str(&self, m: &mut DynamicMessage, v: String) {
+        match *self.mut_values(m) {
+            DynamicString(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_str(&self, m: &mut DynamicMessage, v: String) {
+        match *self.mut_values(m) {
+            DynamicString(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_str<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<String> {
+        match *self.mut_values(m) {
+            DynamicString(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_bytes(&self, m: &mut DynamicMessage, v: Vec<u8>) {
+        match *self.mut_values(m) {
+            DynamicBytes(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_bytes(&self, m: &mut DynamicMessage, v: Vec<u8>) {
+        match *self.mut_values(m) {
+            DynamicBytes(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_bytes<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<Vec<u8>> {
+        match *self.mut_values(m) {
+            DynamicBytes(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_u32(&self, m: &mut DynamicMessage, v: u32) {
+        match *self.mut_values(m) {
+            DynamicU32(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_u32(&self, m: &mut DynamicMessage, v: u32) {
+        match *self.mut_values(m) {
+            DynamicU32(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<u32> {
+        match *self.mut_values(m) {
+            DynamicU32(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_u64(&self, m: &mut DynamicMessage, v: u64) {
+        match *self.mut_values(m) {
+            DynamicU64(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_u64(&self, m: &mut DynamicMessage, v: u64) {
+        match *self.mut_values(m) {
+            DynamicU64(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<u64> {
+        match *self.mut_values(m) {
+            DynamicU64(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_i32(&self, m: &mut DynamicMessage, v: i32) {
+        match *self.mut_values(m) {
+            DynamicI32(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_i32(&self, m: &mut DynamicMessage, v: i32) {
+        match *self.mut_values(m) {
+            DynamicI32(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<i32> {
+        match *self.mut_values(m) {
+            DynamicI32(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_i64(&self, m: &mut DynamicMessage, v: i64) {
+        match *self.mut_values(m) {
+            DynamicI64(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_i64(&self, m: &mut DynamicMessage, v: i64) {
+        match *self.mut_values(m) {
+            DynamicI64(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<i64> {
+        match *self.mut_values(m) {
+            DynamicI64(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_bool(&self, m: &mut DynamicMessage, v: bool) {
+        match *self.mut_values(m) {
+            DynamicBool(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_bool(&self, m: &mut DynamicMessage, v: bool) {
+        match *self.mut_values(m) {
+            DynamicBool(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_bool<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<bool> {
+        match *self.mut_values(m) {
+            DynamicBool(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_f32(&self, m: &mut DynamicMessage, v: f32) {
+        match *self.mut_values(m) {
+            DynamicF32(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_f32(&self, m: &mut DynamicMessage, v: f32) {
+        match *self.mut_values(m) {
+            DynamicF32(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_f32<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<f32> {
+        match *self.mut_values(m) {
+            DynamicF32(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+
+    fn set_f64(&self, m: &mut DynamicMessage, v: f64) {
+        match *self.mut_values(m) {
+            DynamicF64(ref mut values) => {
+                values.clear();
+                values.push(v);
+            },
+            _ => fail!(),
+        }
+    }
+
+    fn add_f64(&self, m: &mut DynamicMessage, v: f64) {
+        match *self.mut_values(m) {
+            DynamicF64(ref mut values) => values.push(v),
+            _ => fail!(),
+        }
+    }
+
+    fn mut_rep_f64<'a>(&self, m: &'a mut DynamicMessage) -> &'a mut Vec<f64> {
+        match *self.mut_values(m) {
+            DynamicF64(ref mut values) => values,
+            _ => fail!(),
+        }
+    }
+}
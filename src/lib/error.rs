@@ -0,0 +1,69 @@
+use std::io::IoError;
+use std::fmt;
+
+use core::wire_format;
+
+// Error returned from parsing functions and CodedInputStream
+#[deriving(Clone,PartialEq)]
+pub enum ProtobufError {
+    // error reported by underlying reader
+    ProtobufIoError(IoError),
+    // input ended in the middle of value, or before length-delimited limit
+    TruncatedInput,
+    // varint is longer than 10 bytes, or 32-bit varint does not fit into 32 bits
+    MalformedVarint,
+    // string field contains invalid UTF-8
+    InvalidUtf8,
+    // tag with zero field number or unknown wire type
+    InvalidTag(u32),
+    // field is encoded with wire type incompatible with its type
+    UnexpectedWireType(wire_format::WireType),
+    // data found after message when whole input is expected to be consumed
+    ExpectedEof,
+    // message is missing required fields,
+    // params are message name and paths of missing fields
+    MissingRequiredFields(String, Vec<String>),
+    // nested messages are deeper than CodedInputStream recursion limit
+    RecursionLimitExceeded,
+    // input is larger than CodedInputStream total bytes limit
+    TotalBytesLimitExceeded,
+    // end group tag without matching start group tag, param is field number
+    UnexpectedEndGroup(u32),
+    // service or method is not found, param is service or method name
+    UnknownMethod(String),
+    // error reported by service implementation
+    RpcError(String),
+    // descriptor added to `DescriptorPool` has unresolved imports or types,
+    // or duplicate declarations
+    InvalidDescriptor(String),
+    // field path does not name field of message, or names field inside non-message field
+    InvalidFieldPath(String),
+    // value passed to reflection setter cannot be stored in field
+    InvalidFieldValue(String),
+}
+
+pub type ProtobufResult<T> = Result<T, ProtobufError>;
+
+impl fmt::Show for ProtobufError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProtobufIoError(ref e)           => write!(f, "IO error: {}", e),
+            TruncatedInput                   => write!(f, "truncated input"),
+            MalformedVarint                  => write!(f, "malformed varint"),
+            InvalidUtf8                      => write!(f, "invalid UTF-8 sequence"),
+            InvalidTag(tag)                  => write!(f, "invalid tag: {}", tag),
+            UnexpectedWireType(wire_type)    => write!(f, "unexpected wire type: {}", wire_type),
+            ExpectedEof                      => write!(f, "expecting EOF"),
+            MissingRequiredFields(ref name, ref paths) =>
+                write!(f, "message {} is missing required fields: {}", name, paths.connect(", ")),
+            RecursionLimitExceeded           => write!(f, "recursion limit exceeded"),
+            TotalBytesLimitExceeded          => write!(f, "total bytes limit exceeded"),
+            UnexpectedEndGroup(field_number) => write!(f, "unexpected end group tag, field number: {}", field_number),
+            UnknownMethod(ref name)          => write!(f, "unknown method: {}", name),
+            RpcError(ref message)            => write!(f, "RPC error: {}", message),
+            InvalidDescriptor(ref message)   => write!(f, "invalid descriptor: {}", message),
+            InvalidFieldPath(ref message)    => write!(f, "invalid field path: {}", message),
+            InvalidFieldValue(ref message)   => write!(f, "invalid field value: {}", message),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Extension field identifiers, generated for `extend` declarations.
+// Extension values are stored in unknown fields of extended message.
+
+use core::Message;
+use core::CodedOutputStream;
+use misc::VecWriter;
+use types::ProtobufType;
+use unknown::UnknownLengthDelimitedRef;
+
+pub struct ExtFieldOptional<M, T> {
+    pub field_number: u32,
+    pub field_type: T,
+}
+
+pub struct ExtFieldRepeated<M, T> {
+    pub field_number: u32,
+    // values are written as single length-delimited value
+    pub packed: bool,
+    pub field_type: T,
+}
+
+impl<M : Message, V, T : ProtobufType<V>> ExtFieldOptional<M, T> {
+    pub fn get(&self, m: &M) -> Option<V> {
+        match m.get_unknown_fields().get(self.field_number) {
+            Some(unknown) => self.field_type.get_from_unknown(unknown),
+            None => None,
+        }
+    }
+
+    pub fn has(&self, m: &M) -> bool {
+        self.get(m).is_some()
+    }
+
+    pub fn set(&self, m: &mut M, value: V) {
+        let unknown = self.field_type.to_unknown(value);
+        let fields = m.mut_unknown_fields();
+        fields.remove(self.field_number);
+        fields.add_value(self.field_number, unknown);
+    }
+
+    pub fn clear(&self, m: &mut M) {
+        m.mut_unknown_fields().remove(self.field_number);
+    }
+}
+
+impl<M : Message, V, T : ProtobufType<V>> ExtFieldRepeated<M, T> {
+    pub fn get(&self, m: &M) -> Vec<V> {
+        match m.get_unknown_fields().get(self.field_number) {
+            Some(unknown) => self.field_type.get_repeated_from_unknown(unknown),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn add(&self, m: &mut M, value: V) {
+        let unknown = self.field_type.to_unknown(value);
+        if !self.packed {
+            m.mut_unknown_fields().add_value(self.field_number, unknown);
+            return;
+        }
+        // existing values are copied as is, including values which cannot be decoded
+        let mut writer = VecWriter::new();
+        {
+            let mut os = CodedOutputStream::new(&mut writer as &mut Writer);
+            match m.get_unknown_fields().get(self.field_number) {
+                Some(values) => {
+                    for v in values.iter() {
+                        match v {
+                            UnknownLengthDelimitedRef(bytes) => os.write_raw_bytes(bytes),
+                            v => os.write_unknown_no_tag(v),
+                        }
+                    }
+                },
+                None => {},
+            }
+            os.write_unknown_no_tag(unknown.get_ref());
+            os.flush();
+        }
+        let fields = m.mut_unknown_fields();
+        fields.remove(self.field_number);
+        fields.add_length_delimited(self.field_number, writer.vec);
+    }
+
+    pub fn clear(&self, m: &mut M) {
+        m.mut_unknown_fields().remove(self.field_number);
+    }
+}
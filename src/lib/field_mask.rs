@@ -0,0 +1,213 @@
+// Field masks: sets of field paths like `inner.b`, used to select fields
+// of message to be updated or returned.
+//
+// Every segment of path except the last must name singular message field.
+
+use std::default::Default;
+
+use core::Message;
+use descriptor::FieldDescriptorProto_TYPE_MESSAGE;
+use descriptor::FieldDescriptorProto_TYPE_GROUP;
+use differencer::MessageDifferencer;
+use error::ProtobufResult;
+use error::InvalidFieldPath;
+use reflect::MessageDescriptor;
+use reflect::FieldDescriptor;
+use reflect::merge_field;
+
+// Fields of message covered by mask, by field number.
+// Field without children is covered entirely.
+#[deriving(Clone)]
+struct MaskNode {
+    children: Vec<(u32, MaskNode)>,
+}
+
+impl MaskNode {
+    fn new() -> MaskNode {
+        MaskNode {
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn child<'a>(&'a self, number: u32) -> Option<&'a MaskNode> {
+        self.children.iter().find(|&&(n, _)| n == number).map(|t| t.ref1())
+    }
+
+    fn add(&mut self, numbers: &[u32]) {
+        let number = numbers[0];
+        let last = numbers.len() == 1;
+        match self.children.iter().position(|&(n, _)| n == number) {
+            Some(index) => {
+                let child = self.children.get_mut(index).mut1();
+                if last {
+                    // whole field covers its subfields
+                    child.children.clear();
+                } else if !child.is_leaf() {
+                    child.add(numbers.slice_from(1));
+                }
+            },
+            None => {
+                let mut child = MaskNode::new();
+                if !last {
+                    child.add(numbers.slice_from(1));
+                }
+                self.children.push((number, child));
+            },
+        }
+    }
+
+    fn paths_to(&self, prefix: &str, d: &MessageDescriptor, paths: &mut Vec<String>) {
+        for &(number, ref child) in self.children.iter() {
+            let f = d.field_by_number(number);
+            let path = format!("{}{}", prefix, f.name());
+            if child.is_leaf() {
+                paths.push(path);
+            } else {
+                child.paths_to(format!("{}.", path).as_slice(), f.message_descriptor(), paths);
+            }
+        }
+    }
+
+    fn merge(&self, dst: &mut Message, src: &Message) {
+        let d = src.descriptor();
+        for &(number, ref child) in self.children.iter() {
+            let f = d.field_by_number(number);
+            if child.is_leaf() {
+                merge_field(f, dst, src);
+            } else if f.has_field(src) {
+                child.merge(f.mut_message(dst), f.get_message(src));
+            }
+        }
+    }
+
+    fn trim(&self, m: &mut Message) {
+        let d = m.descriptor();
+        for f in d.fields().iter() {
+            match self.child(f.number()) {
+                None => f.clear_field(m),
+                Some(child) if child.is_leaf() => {},
+                Some(child) => {
+                    if f.has_field(m) {
+                        child.trim(f.mut_message(m));
+                    }
+                },
+            }
+        }
+        if !m.get_unknown_fields().is_empty() {
+            *m.mut_unknown_fields() = Default::default();
+        }
+    }
+}
+
+fn is_singular_message(f: &FieldDescriptor) -> bool {
+    !f.is_repeated() && match f.proto().get_field_type() {
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP => true,
+        _ => false,
+    }
+}
+
+// Field numbers of path segments
+fn parse_path(descriptor: &'static MessageDescriptor, path: &str) -> ProtobufResult<Vec<u32>> {
+    let mut numbers = Vec::new();
+    let mut d = descriptor;
+    let segments: Vec<&str> = path.split('.').collect();
+    for (i, &segment) in segments.iter().enumerate() {
+        let f = match d.find_field_by_name(segment) {
+            Some(f) => f,
+            None => return Err(InvalidFieldPath(
+                format!("{}: field {} is not found in {}", path, segment, d.full_name()))),
+        };
+        numbers.push(f.number());
+        if i + 1 < segments.len() {
+            if !is_singular_message(f) {
+                return Err(InvalidFieldPath(
+                    format!("{}: field {} is not singular message", path, segment)));
+            }
+            d = f.message_descriptor();
+        }
+    }
+    Ok(numbers)
+}
+
+fn check_descriptor(mask: &FieldMask, m: &Message) {
+    if mask.descriptor as *MessageDescriptor != m.descriptor() as *MessageDescriptor {
+        fail!("field mask of {} is applied to {}", mask.descriptor.full_name(), m.descriptor().full_name());
+    }
+}
+
+#[deriving(Clone)]
+pub struct FieldMask {
+    descriptor: &'static MessageDescriptor,
+    root: MaskNode,
+}
+
+impl FieldMask {
+    // empty mask
+    pub fn new(descriptor: &'static MessageDescriptor) -> FieldMask {
+        FieldMask {
+            descriptor: descriptor,
+            root: MaskNode::new(),
+        }
+    }
+
+    pub fn from_paths(descriptor: &'static MessageDescriptor, paths: &[&str]) -> ProtobufResult<FieldMask> {
+        let mut r = FieldMask::new(descriptor);
+        for path in paths.iter() {
+            try!(r.add_path(*path));
+        }
+        Ok(r)
+    }
+
+    // Mask of fields which differ in messages of the same type.
+    // Repeated fields are included entirely, unknown fields are ignored.
+    pub fn diff(a: &Message, b: &Message) -> FieldMask {
+        let mut differencer = MessageDifferencer::new();
+        differencer.set_ignore_unknown_fields(true);
+        let mut r = FieldMask::new(a.descriptor());
+        for difference in differencer.differences(a, b).iter() {
+            // `items[1].b` becomes `items`
+            let path = difference.path.as_slice().split('[').next().unwrap();
+            r.add_path(path).unwrap();
+        }
+        r
+    }
+
+    pub fn add_path(&mut self, path: &str) -> ProtobufResult<()> {
+        let numbers = try!(parse_path(self.descriptor, path));
+        self.root.add(numbers.as_slice());
+        Ok(())
+    }
+
+    pub fn descriptor(&self) -> &'static MessageDescriptor {
+        self.descriptor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_leaf()
+    }
+
+    // Canonical paths: subpaths of masked fields are removed
+    pub fn paths(&self) -> Vec<String> {
+        let mut r = Vec::new();
+        self.root.paths_to("", self.descriptor, &mut r);
+        r
+    }
+
+    // Merge masked fields of `src` into `dst` with semantics of `Message::merge_from_message`
+    pub fn merge(&self, dst: &mut Message, src: &Message) {
+        check_descriptor(self, dst);
+        check_descriptor(self, src);
+        self.root.merge(dst, src);
+    }
+
+    // Clear all fields not covered by mask, including unknown fields
+    pub fn trim(&self, m: &mut Message) {
+        check_descriptor(self, m);
+        self.root.trim(m);
+    }
+}
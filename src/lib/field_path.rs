@@ -0,0 +1,215 @@
+// Access to nested fields by path expressions like `items[2].b`.
+//
+// Every segment except the last must name message field,
+// segment of repeated field must have index.
+
+use core::Message;
+use descriptor::FieldDescriptorProto_TYPE_MESSAGE;
+use descriptor::FieldDescriptorProto_TYPE_GROUP;
+use error::ProtobufResult;
+use error::InvalidFieldPath;
+use reflect::MessageDescriptor;
+use reflect::FieldDescriptor;
+use reflect::ReflectValueRef;
+use reflect::ReflectValueBox;
+use reflect::ReflectMessageRef;
+use reflect::ReflectMessage;
+
+struct Segment {
+    name: String,
+    index: Option<uint>,
+}
+
+pub struct FieldPath {
+    path: String,
+    segments: Vec<Segment>,
+}
+
+fn is_message(f: &FieldDescriptor) -> bool {
+    match f.proto().get_field_type() {
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP => true,
+        _ => false,
+    }
+}
+
+fn parse_segment(s: &str) -> Option<Segment> {
+    let (name, index) = match s.find('[') {
+        Some(pos) => {
+            if !s.ends_with("]") {
+                return None;
+            }
+            let index = match from_str::<uint>(s.slice(pos + 1, s.len() - 1)) {
+                Some(index) => index,
+                None => return None,
+            };
+            (s.slice_to(pos), Some(index))
+        },
+        None => (s, None),
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return None;
+    }
+    Some(Segment {
+        name: name.to_string(),
+        index: index,
+    })
+}
+
+impl FieldPath {
+    pub fn parse(path: &str) -> ProtobufResult<FieldPath> {
+        let mut segments = Vec::new();
+        for s in path.split('.') {
+            match parse_segment(s) {
+                Some(segment) => segments.push(segment),
+                None => return Err(InvalidFieldPath(format!("{}: malformed segment: {}", path, s))),
+            }
+        }
+        Ok(FieldPath {
+            path: path.to_string(),
+            segments: segments,
+        })
+    }
+
+    fn error<T>(&self, message: String) -> ProtobufResult<T> {
+        Err(InvalidFieldPath(format!("{}: {}", self.path, message)))
+    }
+
+    // Fields of segments, checked against descriptors only
+    fn resolve(&self, descriptor: &'static MessageDescriptor) -> ProtobufResult<Vec<&'static FieldDescriptor>> {
+        let mut r = Vec::new();
+        let mut d = descriptor;
+        for (i, segment) in self.segments.iter().enumerate() {
+            let name = segment.name.as_slice();
+            let f = match d.find_field_by_name(name) {
+                Some(f) => f,
+                None => return self.error(format!("field {} is not found in {}", name, d.full_name())),
+            };
+            match (f.is_repeated(), segment.index) {
+                (true, None) => return self.error(format!("field {} is repeated, index is required", name)),
+                (false, Some(..)) => return self.error(format!("field {} is not repeated", name)),
+                _ => {},
+            }
+            if i + 1 < self.segments.len() {
+                if !is_message(f) {
+                    return self.error(format!("field {} is not message", name));
+                }
+                d = f.message_descriptor();
+            }
+            r.push(f);
+        }
+        Ok(r)
+    }
+
+    fn index_error<T>(&self, f: &FieldDescriptor, index: uint, len: uint) -> ProtobufResult<T> {
+        self.error(format!("index {} is out of range, field {} has {} elements", index, f.name(), len))
+    }
+
+    // Value of field, default value if singular field is not set
+    pub fn get<'a>(&self, m: &'a Message) -> ProtobufResult<ReflectValueRef<'a>> {
+        let fields = try!(self.resolve(m.descriptor()));
+        let mut current = m;
+        for (f, segment) in fields.iter().zip(self.segments.iter()) {
+            let value = match segment.index {
+                Some(index) => {
+                    let len = f.len_field(current);
+                    if index >= len {
+                        return self.index_error(*f, index, len);
+                    }
+                    f.get_rep_item(current, index)
+                },
+                None => f.get_singular_field_or_default(current),
+            };
+            match value {
+                ReflectMessageRef(nested) => current = nested,
+                value => return Ok(value),
+            }
+        }
+        Ok(ReflectMessageRef(current))
+    }
+
+    // Set value of field, creating intermediate messages.
+    // Index equal to length of repeated field appends element.
+    pub fn set(&self, m: &mut Message, value: ReflectValueBox) -> ProtobufResult<()> {
+        let fields = try!(self.resolve(m.descriptor()));
+        let last = *fields.last().unwrap();
+        // enum and message values must have the same descriptor as field type
+        if !last.is_value_compatible(&value) {
+            return self.error(format!("value type is not compatible with field {}", last.name()));
+        }
+        // message with missing required fields would make `m` not initialized
+        match value {
+            ReflectMessage(ref v) => match v.check_initialized() {
+                Err(e) => return self.error(format!("{}", e)),
+                Ok(()) => {},
+            },
+            _ => {},
+        }
+        // indices are checked before message is modified
+        {
+            let current: &Message = m;
+            try!(self.check_indices(fields.as_slice(), Some(current)));
+        }
+        self.set_impl(fields.as_slice(), self.segments.as_slice(), m, value)
+    }
+
+    // `m` is None if message does not exist yet
+    fn check_indices(&self, fields: &[&'static FieldDescriptor], m: Option<&Message>) -> ProtobufResult<()> {
+        let f = fields[0];
+        let segment = &self.segments.as_slice()[self.segments.len() - fields.len()];
+        let nested = match segment.index {
+            Some(index) => {
+                let len = match m {
+                    Some(m) => f.len_field(m),
+                    None => 0,
+                };
+                // element may be appended
+                if index > len {
+                    return self.index_error(f, index, len);
+                }
+                match m {
+                    Some(m) if index < len && fields.len() > 1 => Some(f.get_rep_message_item(m, index)),
+                    _ => None,
+                }
+            },
+            None => match m {
+                Some(m) if fields.len() > 1 && f.has_field(m) => Some(f.get_message(m)),
+                _ => None,
+            },
+        };
+        if fields.len() > 1 {
+            self.check_indices(fields.slice_from(1), nested)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn set_impl(&self, fields: &[&'static FieldDescriptor], segments: &[Segment],
+        m: &mut Message, value: ReflectValueBox) -> ProtobufResult<()>
+    {
+        let f = fields[0];
+        if fields.len() == 1 {
+            return match segments[0].index {
+                Some(index) if index < f.len_field(m) => f.set_rep_item(m, index, value),
+                Some(..) => f.add_repeated(m, value),
+                None => f.set_singular(m, value),
+            };
+        }
+        let nested = match segments[0].index {
+            Some(index) if index < f.len_field(m) => f.mut_rep_message_item(m, index),
+            Some(..) => f.add_message(m),
+            None => f.mut_message(m),
+        };
+        self.set_impl(fields.slice_from(1), segments.slice_from(1), nested, value)
+    }
+}
+
+pub fn get<'a>(m: &'a Message, path: &str) -> ProtobufResult<ReflectValueRef<'a>> {
+    let path = try!(FieldPath::parse(path));
+    path.get(m)
+}
+
+pub fn set(m: &mut Message, path: &str, value: ReflectValueBox) -> ProtobufResult<()> {
+    let path = try!(FieldPath::parse(path));
+    path.set(m, value)
+}
@@ -2,6 +2,7 @@ use std::io::Writer;
 use std::io::Reader;
 use std::io;
 use std::slice;
+use std::mem;
 use std::result::Ok;
 use std::result::Err;
 
@@ -55,6 +56,14 @@ impl Reader for VecReader {
     }
 }
 
+// Value is never freed, used for descriptors loaded at runtime,
+// which must have the same lifetime as descriptors of generated messages
+pub fn leak<T>(value: Box<T>) -> &'static T {
+    unsafe {
+        mem::transmute(value)
+    }
+}
+
 
 
 #[cfg(test)]
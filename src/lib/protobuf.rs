@@ -21,6 +21,8 @@ pub use unknown::UnknownFieldIter;
 pub use repeated::RepeatedField;
 pub use singular::SingularField;
 pub use clear::Clear;
+pub use error::ProtobufError;
+pub use error::ProtobufResult;
 
 mod core;
 pub mod rt;
@@ -32,6 +34,16 @@ pub mod singular;
 pub mod clear;
 pub mod reflect;
 pub mod text_format;
+pub mod error;
+pub mod ext;
+pub mod types;
+pub mod service;
+pub mod rpc;
+pub mod dynamic;
+pub mod descriptor_pool;
+pub mod differencer;
+pub mod field_mask;
+pub mod field_path;
 mod misc;
 mod zigzag;
 mod hex;
@@ -59,6 +71,16 @@ pub mod protobuf {
     pub use core::*;
     pub use rt;
     pub use lazy;
+    pub use ext;
+    pub use types;
+    pub use service;
+    pub use rpc;
+    pub use dynamic;
+    pub use descriptor_pool;
+    pub use differencer;
+    pub use field_mask;
+    pub use field_path;
+    pub use error;
     pub use unknown::UnknownFields;
     pub use unknown::UnknownValues;
     pub use unknown::UnknownValue;
@@ -68,4 +90,6 @@ pub mod protobuf {
     pub use repeated::RepeatedField;
     pub use singular::SingularField;
     pub use clear::Clear;
+    pub use error::ProtobufError;
+    pub use error::ProtobufResult;
 }
@@ -1,16 +1,25 @@
 use core::message_down_cast;
+use core::message_down_cast_mut;
 use core::Message;
 use core::ProtobufEnum;
+use core::CodedInputStream;
 use std::default::Default;
-use descriptor::FileDescriptorProto;
-use descriptor::DescriptorProto;
-use descriptor::FieldDescriptorProto;
-use descriptor::EnumDescriptorProto;
-use descriptor::EnumValueDescriptorProto;
-use descriptor::FieldDescriptorProto_LABEL_REPEATED;
+use std::io::BufReader;
+use error::ProtobufResult;
+use error::InvalidFieldValue;
+use descriptor::*;
 use descriptorx::find_enum_by_rust_name;
+use descriptorx::find_enum_proto_name_by_rust_name;
 use descriptorx::find_message_by_rust_name;
+use descriptorx::find_message_proto_name_by_rust_name;
+use descriptorx::find_extensions;
+use descriptorx::find_enum_by_proto_name;
+use descriptorx::service_proto_name;
 use std::collections::HashMap;
+use std::cell::Cell;
+use std::f64;
+use strx::parse_c_escaped;
+use unknown::UnknownValueRef;
 
 
 /// this trait should not be used directly, use `FieldDescriptor` instead
@@ -19,6 +28,16 @@ pub trait FieldAccessor<M : Message> {
         fail!("TODO");
     }
 
+    // type of message field
+    fn message_descriptor(&self) -> &'static MessageDescriptor {
+        fail!();
+    }
+
+    // type of enum field
+    fn enum_descriptor(&self) -> &'static EnumDescriptor {
+        fail!();
+    }
+
     fn has_field(&self, _m: &M) -> bool {
         fail!();
     }
@@ -114,10 +133,144 @@ pub trait FieldAccessor<M : Message> {
     fn get_rep_f64<'a>(&self, _m: &'a M) -> &'a [f64] {
         fail!();
     }
+
+    fn clear_field(&self, _m: &mut M) {
+        fail!();
+    }
+
+    fn mut_message<'a>(&self, _m: &'a mut M) -> &'a mut Message {
+        fail!();
+    }
+
+    fn add_message<'a>(&self, _m: &'a mut M) -> &'a mut Message {
+        fail!();
+    }
+
+    fn mut_rep_message_item<'a>(&self, _m: &'a mut M, _index: uint) -> &'a mut Message {
+        fail!();
+    }
+
+    fn set_enum(&self, _m: &mut M, _v: &EnumValueDescriptor) {
+        fail!();
+    }
+
+    fn add_enum(&self, _m: &mut M, _v: &EnumValueDescriptor) {
+        fail!();
+    }
+
+    fn set_str(&self, _m: &mut M, _v: String) {
+        fail!();
+    }
+
+    fn add_str(&self, _m: &mut M, _v: String) {
+        fail!();
+    }
+
+    fn mut_rep_str<'a>(&self, _m: &'a mut M) -> &'a mut Vec<String> {
+        fail!();
+    }
+
+    fn set_bytes(&self, _m: &mut M, _v: Vec<u8>) {
+        fail!();
+    }
+
+    fn add_bytes(&self, _m: &mut M, _v: Vec<u8>) {
+        fail!();
+    }
+
+    fn mut_rep_bytes<'a>(&self, _m: &'a mut M) -> &'a mut Vec<Vec<u8>> {
+        fail!();
+    }
+
+    fn set_u32(&self, _m: &mut M, _v: u32) {
+        fail!();
+    }
+
+    fn add_u32(&self, _m: &mut M, _v: u32) {
+        fail!();
+    }
+
+    fn mut_rep_u32<'a>(&self, _m: &'a mut M) -> &'a mut Vec<u32> {
+        fail!();
+    }
+
+    fn set_u64(&self, _m: &mut M, _v: u64) {
+        fail!();
+    }
+
+    fn add_u64(&self, _m: &mut M, _v: u64) {
+        fail!();
+    }
+
+    fn mut_rep_u64<'a>(&self, _m: &'a mut M) -> &'a mut Vec<u64> {
+        fail!();
+    }
+
+    fn set_i32(&self, _m: &mut M, _v: i32) {
+        fail!();
+    }
+
+    fn add_i32(&self, _m: &mut M, _v: i32) {
+        fail!();
+    }
+
+    fn mut_rep_i32<'a>(&self, _m: &'a mut M) -> &'a mut Vec<i32> {
+        fail!();
+    }
+
+    fn set_i64(&self, _m: &mut M, _v: i64) {
+        fail!();
+    }
+
+    fn add_i64(&self, _m: &mut M, _v: i64) {
+        fail!();
+    }
+
+    fn mut_rep_i64<'a>(&self, _m: &'a mut M) -> &'a mut Vec<i64> {
+        fail!();
+    }
+
+    fn set_bool(&self, _m: &mut M, _v: bool) {
+        fail!();
+    }
+
+    fn add_bool(&self, _m: &mut M, _v: bool) {
+        fail!();
+    }
+
+    fn mut_rep_bool<'a>(&self, _m: &'a mut M) -> &'a mut Vec<bool> {
+        fail!();
+    }
+
+    fn set_f32(&self, _m: &mut M, _v: f32) {
+        fail!();
+    }
+
+    fn add_f32(&self, _m: &mut M, _v: f32) {
+        fail!();
+    }
+
+    fn mut_rep_f32<'a>(&self, _m: &'a mut M) -> &'a mut Vec<f32> {
+        fail!();
+    }
+
+    fn set_f64(&self, _m: &mut M, _v: f64) {
+        fail!();
+    }
+
+    fn add_f64(&self, _m: &mut M, _v: f64) {
+        fail!();
+    }
+
+    fn mut_rep_f64<'a>(&self, _m: &'a mut M) -> &'a mut Vec<f64> {
+        fail!();
+    }
 }
 
 
 trait FieldAccessorGeneric {
+    fn message_descriptor_generic(&self) -> &'static MessageDescriptor;
+    fn enum_descriptor_generic(&self) -> &'static EnumDescriptor;
     fn has_field_generic(&self, m: &Message) -> bool;
     fn len_field_generic(&self, m: &Message) -> uint;
     fn get_message_generic<'a>(&self, m: &'a Message) -> &'a Message;
@@ -142,6 +295,39 @@ trait FieldAccessorGeneric {
     fn get_rep_f32_generic<'a>(&self, m: &'a Message) -> &'a [f32];
     fn get_f64_generic(&self, m: &Message) -> f64;
     fn get_rep_f64_generic<'a>(&self, m: &'a Message) -> &'a [f64];
+    fn clear_field_generic(&self, m: &mut Message);
+    fn mut_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message;
+    fn add_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message;
+    fn mut_rep_message_item_generic<'a>(&self, m: &'a mut Message, index: uint) -> &'a mut Message;
+    fn set_enum_generic(&self, m: &mut Message, v: &EnumValueDescriptor);
+    fn add_enum_generic(&self, m: &mut Message, v: &EnumValueDescriptor);
+    fn set_str_generic(&self, m: &mut Message, v: String);
+    fn add_str_generic(&self, m: &mut Message, v: String);
+    fn mut_rep_str_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<String>;
+    fn set_bytes_generic(&self, m: &mut Message, v: Vec<u8>);
+    fn add_bytes_generic(&self, m: &mut Message, v: Vec<u8>);
+    fn mut_rep_bytes_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<Vec<u8>>;
+    fn set_u32_generic(&self, m: &mut Message, v: u32);
+    fn add_u32_generic(&self, m: &mut Message, v: u32);
+    fn mut_rep_u32_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<u32>;
+    fn set_u64_generic(&self, m: &mut Message, v: u64);
+    fn add_u64_generic(&self, m: &mut Message, v: u64);
+    fn mut_rep_u64_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<u64>;
+    fn set_i32_generic(&self, m: &mut Message, v: i32);
+    fn add_i32_generic(&self, m: &mut Message, v: i32);
+    fn mut_rep_i32_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<i32>;
+    fn set_i64_generic(&self, m: &mut Message, v: i64);
+    fn add_i64_generic(&self, m: &mut Message, v: i64);
+    fn mut_rep_i64_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<i64>;
+    fn set_bool_generic(&self, m: &mut Message, v: bool);
+    fn add_bool_generic(&self, m: &mut Message, v: bool);
+    fn mut_rep_bool_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<bool>;
+    fn set_f32_generic(&self, m: &mut Message, v: f32);
+    fn add_f32_generic(&self, m: &mut Message, v: f32);
+    fn mut_rep_f32_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<f32>;
+    fn set_f64_generic(&self, m: &mut Message, v: f64);
+    fn add_f64_generic(&self, m: &mut Message, v: f64);
+    fn mut_rep_f64_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<f64>;
 }
 
 struct FieldAccessorGenericImpl<M> {
@@ -157,6 +343,14 @@ impl<M : Message> FieldAccessorGenericImpl<M> {
 }
 
 impl<M : 'static + Message> FieldAccessorGeneric for FieldAccessorGenericImpl<M> {
+    fn message_descriptor_generic(&self) -> &'static MessageDescriptor {
+        self.accessor.message_descriptor()
+    }
+
+    fn enum_descriptor_generic(&self) -> &'static EnumDescriptor {
+        self.accessor.enum_descriptor()
+    }
+
     fn has_field_generic(&self, m: &Message) -> bool {
         self.accessor.has_field(message_down_cast(m))
     }
@@ -252,11 +446,426 @@ impl<M : 'static + Message> FieldAccessorGeneric for FieldAccessorGenericImpl<M>
     fn get_rep_f64_generic<'a>(&self, m: &'a Message) -> &'a [f64] {
         self.accessor.get_rep_f64(message_down_cast(m))
     }
+
+    fn clear_field_generic(&self, m: &mut Message) {
+        self.accessor.clear_field(message_down_cast_mut(m))
+    }
+
+    fn mut_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        self.accessor.mut_message(message_down_cast_mut(m))
+    }
+
+    fn add_message_generic<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        self.accessor.add_message(message_down_cast_mut(m))
+    }
+
+    fn mut_rep_message_item_generic<'a>(&self, m: &'a mut Message, index: uint) -> &'a mut Message {
+        self.accessor.mut_rep_message_item(message_down_cast_mut(m), index)
+    }
+
+    fn set_enum_generic(&self, m: &mut Message, v: &EnumValueDescriptor) {
+        self.accessor.set_enum(message_down_cast_mut(m), v)
+    }
+
+    fn add_enum_generic(&self, m: &mut Message, v: &EnumValueDescriptor) {
+        self.accessor.add_enum(message_down_cast_mut(m), v)
+    }
+
+    fn set_str_generic(&self, m: &mut Message, v: String) {
+        self.accessor.set_str(message_down_cast_mut(m), v)
+    }
+
+    fn add_str_generic(&self, m: &mut Message, v: String) {
+        self.accessor.add_str(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_str_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<String> {
+        self.accessor.mut_rep_str(message_down_cast_mut(m))
+    }
+
+    fn set_bytes_generic(&self, m: &mut Message, v: Vec<u8>) {
+        self.accessor.set_bytes(message_down_cast_mut(m), v)
+    }
+
+    fn add_bytes_generic(&self, m: &mut Message, v: Vec<u8>) {
+        self.accessor.add_bytes(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_bytes_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<Vec<u8>> {
+        self.accessor.mut_rep_bytes(message_down_cast_mut(m))
+    }
+
+    fn set_u32_generic(&self, m: &mut Message, v: u32) {
+        self.accessor.set_u32(message_down_cast_mut(m), v)
+    }
+
+    fn add_u32_generic(&self, m: &mut Message, v: u32) {
+        self.accessor.add_u32(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_u32_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<u32> {
+        self.accessor.mut_rep_u32(message_down_cast_mut(m))
+    }
+
+    fn set_u64_generic(&self, m: &mut Message, v: u64) {
+        self.accessor.set_u64(message_down_cast_mut(m), v)
+    }
+
+    fn add_u64_generic(&self, m: &mut Message, v: u64) {
+        self.accessor.add_u64(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_u64_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<u64> {
+        self.accessor.mut_rep_u64(message_down_cast_mut(m))
+    }
+
+    fn set_i32_generic(&self, m: &mut Message, v: i32) {
+        self.accessor.set_i32(message_down_cast_mut(m), v)
+    }
+
+    fn add_i32_generic(&self, m: &mut Message, v: i32) {
+        self.accessor.add_i32(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_i32_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<i32> {
+        self.accessor.mut_rep_i32(message_down_cast_mut(m))
+    }
+
+    fn set_i64_generic(&self, m: &mut Message, v: i64) {
+        self.accessor.set_i64(message_down_cast_mut(m), v)
+    }
+
+    fn add_i64_generic(&self, m: &mut Message, v: i64) {
+        self.accessor.add_i64(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_i64_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<i64> {
+        self.accessor.mut_rep_i64(message_down_cast_mut(m))
+    }
+
+    fn set_bool_generic(&self, m: &mut Message, v: bool) {
+        self.accessor.set_bool(message_down_cast_mut(m), v)
+    }
+
+    fn add_bool_generic(&self, m: &mut Message, v: bool) {
+        self.accessor.add_bool(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_bool_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<bool> {
+        self.accessor.mut_rep_bool(message_down_cast_mut(m))
+    }
+
+    fn set_f32_generic(&self, m: &mut Message, v: f32) {
+        self.accessor.set_f32(message_down_cast_mut(m), v)
+    }
+
+    fn add_f32_generic(&self, m: &mut Message, v: f32) {
+        self.accessor.add_f32(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_f32_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<f32> {
+        self.accessor.mut_rep_f32(message_down_cast_mut(m))
+    }
+
+    fn set_f64_generic(&self, m: &mut Message, v: f64) {
+        self.accessor.set_f64(message_down_cast_mut(m), v)
+    }
+
+    fn add_f64_generic(&self, m: &mut Message, v: f64) {
+        self.accessor.add_f64(message_down_cast_mut(m), v)
+    }
+
+    fn mut_rep_f64_generic<'a>(&self, m: &'a mut Message) -> &'a mut Vec<f64> {
+        self.accessor.mut_rep_f64(message_down_cast_mut(m))
+    }
+}
+
+// Value of singular field or element of repeated field
+pub enum ReflectValueRef<'a> {
+    ReflectU32Ref(u32),
+    ReflectU64Ref(u64),
+    ReflectI32Ref(i32),
+    ReflectI64Ref(i64),
+    ReflectF32Ref(f32),
+    ReflectF64Ref(f64),
+    ReflectBoolRef(bool),
+    ReflectStrRef(&'a str),
+    ReflectBytesRef(&'a [u8]),
+    ReflectEnumRef(&'static EnumValueDescriptor),
+    ReflectMessageRef(&'a Message),
+}
+
+// Owned value, passed to setters
+pub enum ReflectValueBox {
+    ReflectU32(u32),
+    ReflectU64(u64),
+    ReflectI32(i32),
+    ReflectI64(i64),
+    ReflectF32(f32),
+    ReflectF64(f64),
+    ReflectBool(bool),
+    ReflectString(String),
+    ReflectBytes(Vec<u8>),
+    ReflectEnum(&'static EnumValueDescriptor),
+    ReflectMessage(Box<Message>),
+}
+
+impl ReflectValueBox {
+    // can value be stored in field of given type
+    fn is_compatible_with(&self, field_type: FieldDescriptorProto_Type) -> bool {
+        match (self, field_type) {
+            (&ReflectU32(..), FieldDescriptorProto_TYPE_UINT32) |
+            (&ReflectU32(..), FieldDescriptorProto_TYPE_FIXED32) |
+            (&ReflectU64(..), FieldDescriptorProto_TYPE_UINT64) |
+            (&ReflectU64(..), FieldDescriptorProto_TYPE_FIXED64) |
+            (&ReflectI32(..), FieldDescriptorProto_TYPE_INT32) |
+            (&ReflectI32(..), FieldDescriptorProto_TYPE_SINT32) |
+            (&ReflectI32(..), FieldDescriptorProto_TYPE_SFIXED32) |
+            (&ReflectI64(..), FieldDescriptorProto_TYPE_INT64) |
+            (&ReflectI64(..), FieldDescriptorProto_TYPE_SINT64) |
+            (&ReflectI64(..), FieldDescriptorProto_TYPE_SFIXED64) |
+            (&ReflectF32(..), FieldDescriptorProto_TYPE_FLOAT) |
+            (&ReflectF64(..), FieldDescriptorProto_TYPE_DOUBLE) |
+            (&ReflectBool(..), FieldDescriptorProto_TYPE_BOOL) |
+            (&ReflectString(..), FieldDescriptorProto_TYPE_STRING) |
+            (&ReflectBytes(..), FieldDescriptorProto_TYPE_BYTES) |
+            (&ReflectEnum(..), FieldDescriptorProto_TYPE_ENUM) |
+            (&ReflectMessage(..), FieldDescriptorProto_TYPE_MESSAGE) |
+            (&ReflectMessage(..), FieldDescriptorProto_TYPE_GROUP) => true,
+            _ => false,
+        }
+    }
+}
+
+fn parse_float_default(s: &str) -> Option<f64> {
+    match s {
+        "inf"  => Some(f64::INFINITY),
+        "-inf" => Some(f64::NEG_INFINITY),
+        "nan"  => Some(f64::NAN),
+        _      => from_str(s),
+    }
+}
+
+// Parse `default_value` of field which is not enum or message, None if value is invalid
+fn parse_default_scalar(field_type: FieldDescriptorProto_Type, s: &str) -> Option<ReflectValueBox> {
+    match field_type {
+        FieldDescriptorProto_TYPE_UINT32 |
+        FieldDescriptorProto_TYPE_FIXED32  => from_str(s).map(|v| ReflectU32(v)),
+        FieldDescriptorProto_TYPE_UINT64 |
+        FieldDescriptorProto_TYPE_FIXED64  => from_str(s).map(|v| ReflectU64(v)),
+        FieldDescriptorProto_TYPE_INT32 |
+        FieldDescriptorProto_TYPE_SINT32 |
+        FieldDescriptorProto_TYPE_SFIXED32 => from_str(s).map(|v| ReflectI32(v)),
+        FieldDescriptorProto_TYPE_INT64 |
+        FieldDescriptorProto_TYPE_SINT64 |
+        FieldDescriptorProto_TYPE_SFIXED64 => from_str(s).map(|v| ReflectI64(v)),
+        FieldDescriptorProto_TYPE_FLOAT    => parse_float_default(s).map(|v| ReflectF32(v as f32)),
+        FieldDescriptorProto_TYPE_DOUBLE   => parse_float_default(s).map(|v| ReflectF64(v)),
+        FieldDescriptorProto_TYPE_BOOL     => match s {
+            "true"  => Some(ReflectBool(true)),
+            "false" => Some(ReflectBool(false)),
+            _       => None,
+        },
+        FieldDescriptorProto_TYPE_STRING   => Some(ReflectString(s.to_string())),
+        // bytes are C-escaped in descriptor
+        FieldDescriptorProto_TYPE_BYTES    => parse_c_escaped(s).ok().map(|v| ReflectBytes(v)),
+        FieldDescriptorProto_TYPE_ENUM |
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP    => None,
+    }
+}
+
+// Check that `parse_default_value` accepts `default_value` of field,
+// `enum_proto` is type of enum field. Returns error message.
+pub fn check_default_value(proto: &FieldDescriptorProto, enum_proto: Option<&EnumDescriptorProto>)
+    -> Result<(), String>
+{
+    let s = proto.get_default_value();
+    let valid = match proto.get_field_type() {
+        FieldDescriptorProto_TYPE_ENUM =>
+            !proto.has_default_value() || enum_proto.unwrap().get_value().iter().any(|v| v.get_name() == s),
+        field_type => !proto.has_default_value() || parse_default_scalar(field_type, s).is_some(),
+    };
+    if !valid {
+        return Err(format!("invalid default value {}", s));
+    }
+    Ok(())
+}
+
+// Value of singular field when it is not set: `default_value` from proto,
+// or zero value of type, or first value of enum. None for message fields.
+// Fails if `default_value` is invalid, see `check_default_value`.
+pub fn parse_default_value(proto: &FieldDescriptorProto, enum_descriptor: Option<&'static EnumDescriptor>)
+    -> Option<ReflectValueBox>
+{
+    let s = proto.get_default_value();
+    match proto.get_field_type() {
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP    => None,
+        FieldDescriptorProto_TYPE_ENUM     => {
+            let enum_descriptor = enum_descriptor.unwrap();
+            Some(ReflectEnum(if proto.has_default_value() {
+                match enum_descriptor.find_value_by_name(s) {
+                    Some(v) => v,
+                    None => fail!("invalid default value {} of field {}", s, proto.get_name()),
+                }
+            } else {
+                enum_descriptor.values.get(0)
+            }))
+        },
+        field_type if proto.has_default_value() => match parse_default_scalar(field_type, s) {
+            Some(value) => Some(value),
+            None => fail!("invalid default value {} of field {}", s, proto.get_name()),
+        },
+        field_type => Some(zero_value(field_type)),
+    }
+}
+
+fn zero_value(field_type: FieldDescriptorProto_Type) -> ReflectValueBox {
+    match field_type {
+        FieldDescriptorProto_TYPE_UINT32 |
+        FieldDescriptorProto_TYPE_FIXED32  => ReflectU32(0),
+        FieldDescriptorProto_TYPE_UINT64 |
+        FieldDescriptorProto_TYPE_FIXED64  => ReflectU64(0),
+        FieldDescriptorProto_TYPE_INT32 |
+        FieldDescriptorProto_TYPE_SINT32 |
+        FieldDescriptorProto_TYPE_SFIXED32 => ReflectI32(0),
+        FieldDescriptorProto_TYPE_INT64 |
+        FieldDescriptorProto_TYPE_SINT64 |
+        FieldDescriptorProto_TYPE_SFIXED64 => ReflectI64(0),
+        FieldDescriptorProto_TYPE_FLOAT    => ReflectF32(0.),
+        FieldDescriptorProto_TYPE_DOUBLE   => ReflectF64(0.),
+        FieldDescriptorProto_TYPE_BOOL     => ReflectBool(false),
+        FieldDescriptorProto_TYPE_STRING   => ReflectString(String::new()),
+        FieldDescriptorProto_TYPE_BYTES    => ReflectBytes(Vec::new()),
+        FieldDescriptorProto_TYPE_ENUM |
+        FieldDescriptorProto_TYPE_MESSAGE |
+        FieldDescriptorProto_TYPE_GROUP    => fail!("no zero value of type {:?}", field_type),
+    }
+}
+
+// Type of field values, with linked descriptor for enums and messages
+pub enum RuntimeType {
+    RuntimeTypeU32,
+    RuntimeTypeU64,
+    RuntimeTypeI32,
+    RuntimeTypeI64,
+    RuntimeTypeF32,
+    RuntimeTypeF64,
+    RuntimeTypeBool,
+    RuntimeTypeString,
+    RuntimeTypeBytes,
+    RuntimeTypeEnum(&'static EnumDescriptor),
+    RuntimeTypeMessage(&'static MessageDescriptor),
+}
+
+// Owned copy of value, message is copied with `merge`
+pub fn value_to_box(value: ReflectValueRef) -> ReflectValueBox {
+    match value {
+        ReflectU32Ref(v)     => ReflectU32(v),
+        ReflectU64Ref(v)     => ReflectU64(v),
+        ReflectI32Ref(v)     => ReflectI32(v),
+        ReflectI64Ref(v)     => ReflectI64(v),
+        ReflectF32Ref(v)     => ReflectF32(v),
+        ReflectF64Ref(v)     => ReflectF64(v),
+        ReflectBoolRef(v)    => ReflectBool(v),
+        ReflectStrRef(v)     => ReflectString(v.to_string()),
+        ReflectBytesRef(v)   => ReflectBytes(Vec::from_slice(v)),
+        ReflectEnumRef(v)    => ReflectEnum(v),
+        ReflectMessageRef(v) => {
+            let mut copy = v.descriptor().new_instance();
+            merge(copy, v);
+            ReflectMessage(copy)
+        },
+    }
+}
+
+// Merge `src` into `dst` with semantics of `Message::merge_from_message`,
+// messages must have the same descriptor
+pub fn merge(dst: &mut Message, src: &Message) {
+    let d = dst.descriptor();
+    if d as *MessageDescriptor != src.descriptor() as *MessageDescriptor {
+        fail!("cannot merge message {} into {}", src.descriptor().full_name(), d.full_name());
+    }
+    for f in d.fields().iter() {
+        merge_field(f, dst, src);
+    }
+    if !src.get_unknown_fields().is_empty() {
+        dst.mut_unknown_fields().merge_from(src.get_unknown_fields());
+    }
+}
+
+// Merge single field of `src` into `dst`, messages must have the same descriptor
+pub fn merge_field(f: &FieldDescriptor, dst: &mut Message, src: &Message) {
+    if f.is_repeated() {
+        for value in f.get_repeated(src) {
+            match value {
+                ReflectMessageRef(m) => merge(f.add_message(dst), m),
+                value => f.add_repeated(dst, value_to_box(value)).unwrap(),
+            }
+        }
+    } else {
+        match f.get_singular(src) {
+            Some(ReflectMessageRef(m)) => merge(f.mut_message(dst), m),
+            Some(value) => f.set_singular(dst, value_to_box(value)).unwrap(),
+            None => {},
+        }
+    }
+}
+
+// Callbacks of `walk`, all methods do nothing by default
+pub trait MessageVisitor {
+    // called for root message and for each nested message before its fields
+    fn enter_message(&mut self, _m: &Message) {}
+    fn leave_message(&mut self, _m: &Message) {}
+    // value of set singular field, message value is entered after this call
+    fn visit_field(&mut self, _f: &FieldDescriptor, _value: ReflectValueRef) {}
+    // element of repeated field, message element is entered after this call
+    fn visit_repeated_item(&mut self, _f: &FieldDescriptor, _index: uint, _value: ReflectValueRef) {}
+    // unknown fields, including extensions, are visited after known fields
+    fn visit_unknown_field(&mut self, _number: u32, _value: UnknownValueRef) {}
+}
+
+// Depth-first walk over fields of message in field declaration order,
+// unknown fields are visited in order of field numbers
+pub fn walk(m: &Message, visitor: &mut MessageVisitor) {
+    visitor.enter_message(m);
+    for f in m.descriptor().fields().iter() {
+        if f.is_repeated() {
+            for (index, value) in f.get_repeated(m).enumerate() {
+                visitor.visit_repeated_item(f, index, value);
+                match value {
+                    ReflectMessageRef(nested) => walk(nested, visitor),
+                    _ => {},
+                }
+            }
+        } else {
+            match f.get_singular(m) {
+                Some(value) => {
+                    visitor.visit_field(f, value);
+                    match value {
+                        ReflectMessageRef(nested) => walk(nested, visitor),
+                        _ => {},
+                    }
+                },
+                None => {},
+            }
+        }
+    }
+    let unknown_fields = m.get_unknown_fields();
+    let mut numbers: Vec<u32> = unknown_fields.iter().map(|(number, _)| number).collect();
+    numbers.sort();
+    for &number in numbers.iter() {
+        for value in unknown_fields.get(number).unwrap().iter() {
+            visitor.visit_unknown_field(number, value);
+        }
+    }
+    visitor.leave_message(m);
 }
 
 pub struct FieldDescriptor {
     proto: &'static FieldDescriptorProto,
-    accessor: Box<FieldAccessorGeneric>,
+    accessor: Box<FieldAccessorGeneric+'static>,
 }
 
 impl FieldDescriptor {
@@ -266,7 +875,7 @@ impl FieldDescriptor {
         assert_eq!(proto.get_name(), a.name());
         FieldDescriptor {
             proto: proto,
-            accessor: box FieldAccessorGenericImpl::new(a) as Box<FieldAccessorGeneric>,
+            accessor: box FieldAccessorGenericImpl::new(a) as Box<FieldAccessorGeneric+'static>,
         }
     }
 
@@ -282,6 +891,64 @@ impl FieldDescriptor {
         self.proto.get_label() == FieldDescriptorProto_LABEL_REPEATED
     }
 
+    pub fn is_required(&self) -> bool {
+        self.proto.get_label() == FieldDescriptorProto_LABEL_REQUIRED
+    }
+
+    pub fn number(&self) -> u32 {
+        self.proto.get_number() as u32
+    }
+
+    pub fn is_packed(&self) -> bool {
+        self.proto.get_options().get_packed()
+    }
+
+    pub fn runtime_type(&self) -> RuntimeType {
+        match self.proto.get_field_type() {
+            FieldDescriptorProto_TYPE_UINT32 |
+            FieldDescriptorProto_TYPE_FIXED32  => RuntimeTypeU32,
+            FieldDescriptorProto_TYPE_UINT64 |
+            FieldDescriptorProto_TYPE_FIXED64  => RuntimeTypeU64,
+            FieldDescriptorProto_TYPE_INT32 |
+            FieldDescriptorProto_TYPE_SINT32 |
+            FieldDescriptorProto_TYPE_SFIXED32 => RuntimeTypeI32,
+            FieldDescriptorProto_TYPE_INT64 |
+            FieldDescriptorProto_TYPE_SINT64 |
+            FieldDescriptorProto_TYPE_SFIXED64 => RuntimeTypeI64,
+            FieldDescriptorProto_TYPE_FLOAT    => RuntimeTypeF32,
+            FieldDescriptorProto_TYPE_DOUBLE   => RuntimeTypeF64,
+            FieldDescriptorProto_TYPE_BOOL     => RuntimeTypeBool,
+            FieldDescriptorProto_TYPE_STRING   => RuntimeTypeString,
+            FieldDescriptorProto_TYPE_BYTES    => RuntimeTypeBytes,
+            FieldDescriptorProto_TYPE_ENUM     => RuntimeTypeEnum(self.enum_descriptor()),
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP    => RuntimeTypeMessage(self.message_descriptor()),
+        }
+    }
+
+    // type of message field, fails if field is not message
+    pub fn message_descriptor(&self) -> &'static MessageDescriptor {
+        self.accessor.message_descriptor_generic()
+    }
+
+    // type of enum field, fails if field is not enum
+    pub fn enum_descriptor(&self) -> &'static EnumDescriptor {
+        self.accessor.enum_descriptor_generic()
+    }
+
+    // Value returned by getter when field is not set.
+    // None for repeated and message fields.
+    pub fn default_value(&self) -> Option<ReflectValueBox> {
+        if self.is_repeated() {
+            return None;
+        }
+        let enum_descriptor = match self.proto.get_field_type() {
+            FieldDescriptorProto_TYPE_ENUM => Some(self.enum_descriptor()),
+            _ => None,
+        };
+        parse_default_value(self.proto, enum_descriptor)
+    }
+
     pub fn has_field(&self, m: &Message) -> bool {
         self.accessor.has_field_generic(m)
     }
@@ -385,39 +1052,432 @@ impl FieldDescriptor {
     pub fn get_rep_f64<'a>(&self, m: &'a Message) -> &'a [f64] {
         self.accessor.get_rep_f64_generic(m)
     }
-}
 
+    pub fn clear_field(&self, m: &mut Message) {
+        self.accessor.clear_field_generic(m)
+    }
 
-trait MessageFactory {
-    fn new_instance() -> Box<Message>;
-}
+    pub fn mut_message<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        self.accessor.mut_message_generic(m)
+    }
 
-struct MessageFactoryTyped<M> {
-    dummy: ()
-}
+    pub fn add_message<'a>(&self, m: &'a mut Message) -> &'a mut Message {
+        self.accessor.add_message_generic(m)
+    }
 
-impl<M> MessageFactoryTyped<M> {
-    fn new() -> MessageFactoryTyped<M> {
-        MessageFactoryTyped {
-            dummy: ()
-        }
+    pub fn mut_rep_message_item<'a>(&self, m: &'a mut Message, index: uint) -> &'a mut Message {
+        self.accessor.mut_rep_message_item_generic(m, index)
     }
-}
 
-impl<M : 'static + Message> MessageFactory for MessageFactoryTyped<M> {
-    fn new_instance() -> Box<Message> {
-        let m: M = Default::default();
-        box m as Box<Message>
+    // fails if `v` is not a value of enum of this field
+    pub fn set_enum(&self, m: &mut Message, v: &EnumValueDescriptor) -> ProtobufResult<()> {
+        try!(self.check_enum_value(v));
+        self.accessor.set_enum_generic(m, v);
+        Ok(())
     }
-}
 
-pub struct MessageDescriptor {
-    proto: &'static DescriptorProto,
-    factory: Box<MessageFactory>,
-    fields: Vec<FieldDescriptor>,
+    pub fn add_enum(&self, m: &mut Message, v: &EnumValueDescriptor) -> ProtobufResult<()> {
+        try!(self.check_enum_value(v));
+        self.accessor.add_enum_generic(m, v);
+        Ok(())
+    }
 
-    index_by_name: HashMap<String, uint>,
-    index_by_number: HashMap<u32, uint>,
+    fn check_enum_value(&self, v: &EnumValueDescriptor) -> ProtobufResult<()> {
+        if !self.enum_descriptor().has_value(v) {
+            return Err(InvalidFieldValue(format!("value {} is not a value of enum {} of field {}",
+                    v.name(), self.enum_descriptor().full_name(), self.name())));
+        }
+        Ok(())
+    }
+
+    pub fn set_str(&self, m: &mut Message, v: String) {
+        self.accessor.set_str_generic(m, v)
+    }
+
+    pub fn add_str(&self, m: &mut Message, v: String) {
+        self.accessor.add_str_generic(m, v)
+    }
+
+    pub fn mut_rep_str<'a>(&self, m: &'a mut Message) -> &'a mut Vec<String> {
+        self.accessor.mut_rep_str_generic(m)
+    }
+
+    pub fn set_bytes(&self, m: &mut Message, v: Vec<u8>) {
+        self.accessor.set_bytes_generic(m, v)
+    }
+
+    pub fn add_bytes(&self, m: &mut Message, v: Vec<u8>) {
+        self.accessor.add_bytes_generic(m, v)
+    }
+
+    pub fn mut_rep_bytes<'a>(&self, m: &'a mut Message) -> &'a mut Vec<Vec<u8>> {
+        self.accessor.mut_rep_bytes_generic(m)
+    }
+
+    pub fn set_u32(&self, m: &mut Message, v: u32) {
+        self.accessor.set_u32_generic(m, v)
+    }
+
+    pub fn add_u32(&self, m: &mut Message, v: u32) {
+        self.accessor.add_u32_generic(m, v)
+    }
+
+    pub fn mut_rep_u32<'a>(&self, m: &'a mut Message) -> &'a mut Vec<u32> {
+        self.accessor.mut_rep_u32_generic(m)
+    }
+
+    pub fn set_u64(&self, m: &mut Message, v: u64) {
+        self.accessor.set_u64_generic(m, v)
+    }
+
+    pub fn add_u64(&self, m: &mut Message, v: u64) {
+        self.accessor.add_u64_generic(m, v)
+    }
+
+    pub fn mut_rep_u64<'a>(&self, m: &'a mut Message) -> &'a mut Vec<u64> {
+        self.accessor.mut_rep_u64_generic(m)
+    }
+
+    pub fn set_i32(&self, m: &mut Message, v: i32) {
+        self.accessor.set_i32_generic(m, v)
+    }
+
+    pub fn add_i32(&self, m: &mut Message, v: i32) {
+        self.accessor.add_i32_generic(m, v)
+    }
+
+    pub fn mut_rep_i32<'a>(&self, m: &'a mut Message) -> &'a mut Vec<i32> {
+        self.accessor.mut_rep_i32_generic(m)
+    }
+
+    pub fn set_i64(&self, m: &mut Message, v: i64) {
+        self.accessor.set_i64_generic(m, v)
+    }
+
+    pub fn add_i64(&self, m: &mut Message, v: i64) {
+        self.accessor.add_i64_generic(m, v)
+    }
+
+    pub fn mut_rep_i64<'a>(&self, m: &'a mut Message) -> &'a mut Vec<i64> {
+        self.accessor.mut_rep_i64_generic(m)
+    }
+
+    pub fn set_bool(&self, m: &mut Message, v: bool) {
+        self.accessor.set_bool_generic(m, v)
+    }
+
+    pub fn add_bool(&self, m: &mut Message, v: bool) {
+        self.accessor.add_bool_generic(m, v)
+    }
+
+    pub fn mut_rep_bool<'a>(&self, m: &'a mut Message) -> &'a mut Vec<bool> {
+        self.accessor.mut_rep_bool_generic(m)
+    }
+
+    pub fn set_f32(&self, m: &mut Message, v: f32) {
+        self.accessor.set_f32_generic(m, v)
+    }
+
+    pub fn add_f32(&self, m: &mut Message, v: f32) {
+        self.accessor.add_f32_generic(m, v)
+    }
+
+    pub fn mut_rep_f32<'a>(&self, m: &'a mut Message) -> &'a mut Vec<f32> {
+        self.accessor.mut_rep_f32_generic(m)
+    }
+
+    pub fn set_f64(&self, m: &mut Message, v: f64) {
+        self.accessor.set_f64_generic(m, v)
+    }
+
+    pub fn add_f64(&self, m: &mut Message, v: f64) {
+        self.accessor.add_f64_generic(m, v)
+    }
+
+    pub fn mut_rep_f64<'a>(&self, m: &'a mut Message) -> &'a mut Vec<f64> {
+        self.accessor.mut_rep_f64_generic(m)
+    }
+
+    // Value of singular field, None if field is not set
+    pub fn get_singular<'a>(&self, m: &'a Message) -> Option<ReflectValueRef<'a>> {
+        match self.has_field(m) {
+            true => Some(self.get_singular_field_or_default(m)),
+            false => None,
+        }
+    }
+
+    // Value of singular field, default value if field is not set
+    pub fn get_singular_field_or_default<'a>(&self, m: &'a Message) -> ReflectValueRef<'a> {
+        assert!(!self.is_repeated(), "field is repeated: {}", self.name());
+        match self.proto.get_field_type() {
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP    => ReflectMessageRef(self.get_message(m)),
+            FieldDescriptorProto_TYPE_ENUM     => ReflectEnumRef(self.get_enum(m)),
+            FieldDescriptorProto_TYPE_STRING   => ReflectStrRef(self.get_str(m)),
+            FieldDescriptorProto_TYPE_BYTES    => ReflectBytesRef(self.get_bytes(m)),
+            FieldDescriptorProto_TYPE_INT32 |
+            FieldDescriptorProto_TYPE_SINT32 |
+            FieldDescriptorProto_TYPE_SFIXED32 => ReflectI32Ref(self.get_i32(m)),
+            FieldDescriptorProto_TYPE_INT64 |
+            FieldDescriptorProto_TYPE_SINT64 |
+            FieldDescriptorProto_TYPE_SFIXED64 => ReflectI64Ref(self.get_i64(m)),
+            FieldDescriptorProto_TYPE_UINT32 |
+            FieldDescriptorProto_TYPE_FIXED32  => ReflectU32Ref(self.get_u32(m)),
+            FieldDescriptorProto_TYPE_UINT64 |
+            FieldDescriptorProto_TYPE_FIXED64  => ReflectU64Ref(self.get_u64(m)),
+            FieldDescriptorProto_TYPE_BOOL     => ReflectBoolRef(self.get_bool(m)),
+            FieldDescriptorProto_TYPE_FLOAT    => ReflectF32Ref(self.get_f32(m)),
+            FieldDescriptorProto_TYPE_DOUBLE   => ReflectF64Ref(self.get_f64(m)),
+        }
+    }
+
+    pub fn get_rep_item<'a>(&self, m: &'a Message, index: uint) -> ReflectValueRef<'a> {
+        assert!(self.is_repeated(), "field is not repeated: {}", self.name());
+        match self.proto.get_field_type() {
+            FieldDescriptorProto_TYPE_MESSAGE |
+            FieldDescriptorProto_TYPE_GROUP    => ReflectMessageRef(self.get_rep_message_item(m, index)),
+            FieldDescriptorProto_TYPE_ENUM     => ReflectEnumRef(self.get_rep_enum_item(m, index)),
+            FieldDescriptorProto_TYPE_STRING   => ReflectStrRef(self.get_rep_str_item(m, index)),
+            FieldDescriptorProto_TYPE_BYTES    => ReflectBytesRef(self.get_rep_bytes_item(m, index)),
+            FieldDescriptorProto_TYPE_INT32 |
+            FieldDescriptorProto_TYPE_SINT32 |
+            FieldDescriptorProto_TYPE_SFIXED32 => ReflectI32Ref(self.get_rep_i32(m)[index]),
+            FieldDescriptorProto_TYPE_INT64 |
+            FieldDescriptorProto_TYPE_SINT64 |
+            FieldDescriptorProto_TYPE_SFIXED64 => ReflectI64Ref(self.get_rep_i64(m)[index]),
+            FieldDescriptorProto_TYPE_UINT32 |
+            FieldDescriptorProto_TYPE_FIXED32  => ReflectU32Ref(self.get_rep_u32(m)[index]),
+            FieldDescriptorProto_TYPE_UINT64 |
+            FieldDescriptorProto_TYPE_FIXED64  => ReflectU64Ref(self.get_rep_u64(m)[index]),
+            FieldDescriptorProto_TYPE_BOOL     => ReflectBoolRef(self.get_rep_bool(m)[index]),
+            FieldDescriptorProto_TYPE_FLOAT    => ReflectF32Ref(self.get_rep_f32(m)[index]),
+            FieldDescriptorProto_TYPE_DOUBLE   => ReflectF64Ref(self.get_rep_f64(m)[index]),
+        }
+    }
+
+    pub fn get_repeated<'a>(&'a self, m: &'a Message) -> ReflectRepeatedIter<'a> {
+        ReflectRepeatedIter {
+            field: self,
+            m: m,
+            index: 0,
+            len: self.len_field(m),
+        }
+    }
+
+    // can value be stored in this field
+    pub fn is_value_compatible(&self, value: &ReflectValueBox) -> bool {
+        self.check_value(value).is_ok()
+    }
+
+    // value type must match field type, and enum or message value
+    // must have the same descriptor as field type
+    fn check_value(&self, value: &ReflectValueBox) -> ProtobufResult<()> {
+        if !value.is_compatible_with(self.proto.get_field_type()) {
+            return Err(InvalidFieldValue(format!("value type is not compatible with field {} of type {:?}",
+                    self.name(), self.proto.get_field_type())));
+        }
+        match *value {
+            ReflectEnum(v) => self.check_enum_value(v),
+            ReflectMessage(ref v)
+                    if v.descriptor() as *MessageDescriptor != self.message_descriptor() as *MessageDescriptor =>
+                Err(InvalidFieldValue(format!("message {} cannot be stored in field {} of type {}",
+                        v.descriptor().full_name(), self.name(), self.message_descriptor().full_name()))),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_repeated(&self, repeated: bool) -> ProtobufResult<()> {
+        match (self.is_repeated(), repeated) {
+            (true, false) => Err(InvalidFieldValue(format!("field is repeated: {}", self.name()))),
+            (false, true) => Err(InvalidFieldValue(format!("field is not repeated: {}", self.name()))),
+            _ => Ok(()),
+        }
+    }
+
+    // Message value is copied with `merge`, so it may be not initialized
+    pub fn set_singular(&self, m: &mut Message, value: ReflectValueBox) -> ProtobufResult<()> {
+        try!(self.check_repeated(false));
+        try!(self.check_value(&value));
+        match value {
+            ReflectU32(v)    => self.set_u32(m, v),
+            ReflectU64(v)    => self.set_u64(m, v),
+            ReflectI32(v)    => self.set_i32(m, v),
+            ReflectI64(v)    => self.set_i64(m, v),
+            ReflectF32(v)    => self.set_f32(m, v),
+            ReflectF64(v)    => self.set_f64(m, v),
+            ReflectBool(v)   => self.set_bool(m, v),
+            ReflectString(v) => self.set_str(m, v),
+            ReflectBytes(v)  => self.set_bytes(m, v),
+            ReflectEnum(v)   => try!(self.set_enum(m, v)),
+            ReflectMessage(v) => {
+                self.clear_field(m);
+                merge(self.mut_message(m), v);
+            },
+        }
+        Ok(())
+    }
+
+    pub fn add_repeated(&self, m: &mut Message, value: ReflectValueBox) -> ProtobufResult<()> {
+        try!(self.check_repeated(true));
+        try!(self.check_value(&value));
+        match value {
+            ReflectU32(v)    => self.add_u32(m, v),
+            ReflectU64(v)    => self.add_u64(m, v),
+            ReflectI32(v)    => self.add_i32(m, v),
+            ReflectI64(v)    => self.add_i64(m, v),
+            ReflectF32(v)    => self.add_f32(m, v),
+            ReflectF64(v)    => self.add_f64(m, v),
+            ReflectBool(v)   => self.add_bool(m, v),
+            ReflectString(v) => self.add_str(m, v),
+            ReflectBytes(v)  => self.add_bytes(m, v),
+            ReflectEnum(v)   => try!(self.add_enum(m, v)),
+            ReflectMessage(v) => merge(self.add_message(m), v),
+        }
+        Ok(())
+    }
+
+    // Replace element of repeated field
+    pub fn set_rep_item(&self, m: &mut Message, index: uint, value: ReflectValueBox) -> ProtobufResult<()> {
+        try!(self.check_repeated(true));
+        if index >= self.len_field(m) {
+            return Err(InvalidFieldValue(format!("index {} is out of range of field {}", index, self.name())));
+        }
+        try!(self.check_value(&value));
+        match value {
+            ReflectU32(v)    => *self.mut_rep_u32(m).get_mut(index) = v,
+            ReflectU64(v)    => *self.mut_rep_u64(m).get_mut(index) = v,
+            ReflectI32(v)    => *self.mut_rep_i32(m).get_mut(index) = v,
+            ReflectI64(v)    => *self.mut_rep_i64(m).get_mut(index) = v,
+            ReflectF32(v)    => *self.mut_rep_f32(m).get_mut(index) = v,
+            ReflectF64(v)    => *self.mut_rep_f64(m).get_mut(index) = v,
+            ReflectBool(v)   => *self.mut_rep_bool(m).get_mut(index) = v,
+            ReflectString(v) => *self.mut_rep_str(m).get_mut(index) = v,
+            ReflectBytes(v)  => *self.mut_rep_bytes(m).get_mut(index) = v,
+            ReflectEnum(v)   => {
+                // vector of enum values is not accessible by reflection, so field is rebuilt
+                let values: Vec<&'static EnumValueDescriptor> =
+                        range(0, self.len_field(m)).map(|i| self.get_rep_enum_item(m, i)).collect();
+                self.clear_field(m);
+                for (i, &e) in values.iter().enumerate() {
+                    try!(self.add_enum(m, if i == index { v } else { e }));
+                }
+            },
+            ReflectMessage(v) => {
+                let item = self.mut_rep_message_item(m, index);
+                item.clear();
+                merge(item, v);
+            },
+        }
+        Ok(())
+    }
+}
+
+pub struct ReflectRepeatedIter<'a> {
+    field: &'a FieldDescriptor,
+    m: &'a Message,
+    index: uint,
+    len: uint,
+}
+
+impl<'a> Iterator<ReflectValueRef<'a>> for ReflectRepeatedIter<'a> {
+    fn next(&mut self) -> Option<ReflectValueRef<'a>> {
+        if self.index == self.len {
+            return None;
+        }
+        let r = self.field.get_rep_item(self.m, self.index);
+        self.index += 1;
+        Some(r)
+    }
+}
+
+
+// Creates instances of message described by `MessageDescriptor`
+pub trait MessageFactory {
+    fn new_instance(&self) -> Box<Message>;
+}
+
+struct MessageFactoryTyped<M>;
+
+impl<M> MessageFactoryTyped<M> {
+    fn new() -> MessageFactoryTyped<M> {
+        MessageFactoryTyped
+    }
+}
+
+impl<M : 'static + Message> MessageFactory for MessageFactoryTyped<M> {
+    fn new_instance(&self) -> Box<Message> {
+        let m: M = Default::default();
+        box m as Box<Message>
+    }
+}
+
+// Extension field, `MessageDescriptor` contains only extensions
+// declared in the same file as extended message
+pub struct ExtensionDescriptor {
+    full_name: String,
+    proto: &'static FieldDescriptorProto,
+    enum_proto: Option<&'static EnumDescriptorProto>,
+}
+
+impl ExtensionDescriptor {
+    // `full_name` is fully qualified name like `.pkg.ext`
+    pub fn new(
+            full_name: String,
+            proto: &'static FieldDescriptorProto,
+            enum_proto: Option<&'static EnumDescriptorProto>)
+        -> ExtensionDescriptor
+    {
+        ExtensionDescriptor {
+            full_name: full_name,
+            proto: proto,
+            enum_proto: enum_proto,
+        }
+    }
+
+    // fully qualified name without leading dot, as printed in text format
+    pub fn full_name<'a>(&'a self) -> &'a str {
+        self.full_name.as_slice().slice_from(1)
+    }
+
+    pub fn proto(&self) -> &'static FieldDescriptorProto {
+        self.proto
+    }
+
+    pub fn number(&self) -> u32 {
+        self.proto.get_number() as u32
+    }
+
+    pub fn is_repeated(&self) -> bool {
+        self.proto.get_label() == FieldDescriptorProto_LABEL_REPEATED
+    }
+
+    // name of enum value, if field is enum and enum descriptor is known
+    pub fn enum_value_name(&self, value: i32) -> Option<&'static str> {
+        match self.enum_proto {
+            Some(e) => e.get_value().iter().find(|v| v.get_number() == value).map(|v| v.get_name()),
+            None => None,
+        }
+    }
+}
+
+// File descriptor of generated message is created lazily after message descriptor,
+// so it is obtained from generated function.
+// Descriptors of messages from `DescriptorPool` are linked to file after file is created.
+enum FileDescriptorLink {
+    GeneratedFile(fn() -> &'static FileDescriptor),
+    LinkedFile(Cell<Option<&'static FileDescriptor>>),
+}
+
+pub struct MessageDescriptor {
+    proto: &'static DescriptorProto,
+    full_name: String,
+    factory: Box<MessageFactory+'static>,
+    fields: Vec<FieldDescriptor>,
+    extensions: Vec<ExtensionDescriptor>,
+    file_descriptor: FileDescriptorLink,
+
+    index_by_name: HashMap<String, uint>,
+    index_by_number: HashMap<u32, uint>,
 }
 
 impl MessageDescriptor {
@@ -428,11 +1488,40 @@ impl MessageDescriptor {
     pub fn new<M : 'static + Message>(
             rust_name: &'static str,
             fields: Vec<&'static FieldAccessor<M>>,
-            file: &'static FileDescriptorProto
+            file: &'static FileDescriptorProto,
+            file_descriptor: fn() -> &'static FileDescriptor
         ) -> MessageDescriptor
     {
         let proto = find_message_by_rust_name(file, rust_name);
+        let proto_name = find_message_proto_name_by_rust_name(file, rust_name);
+        let factory = box MessageFactoryTyped::<M>::new() as Box<MessageFactory+'static>;
+        MessageDescriptor::new_impl(
+            proto, proto_name.as_slice(), factory, fields, file, GeneratedFile(file_descriptor))
+    }
+
+    // Used for messages which types are not known at compile time,
+    // `proto_name` is fully qualified name like `.pkg.Message`.
+    // Descriptor is linked to `FileDescriptor` by `FileDescriptor::link_declarations`.
+    pub fn new_from_proto<M : 'static + Message>(
+            proto: &'static DescriptorProto,
+            proto_name: &str,
+            factory: Box<MessageFactory+'static>,
+            fields: Vec<&'static FieldAccessor<M>>,
+            file: &'static FileDescriptorProto
+        ) -> MessageDescriptor
+    {
+        MessageDescriptor::new_impl(proto, proto_name, factory, fields, file, LinkedFile(Cell::new(None)))
+    }
 
+    fn new_impl<M : 'static + Message>(
+            proto: &'static DescriptorProto,
+            proto_name: &str,
+            factory: Box<MessageFactory+'static>,
+            fields: Vec<&'static FieldAccessor<M>>,
+            file: &'static FileDescriptorProto,
+            file_descriptor: FileDescriptorLink
+        ) -> MessageDescriptor
+    {
         let mut field_proto_by_name = HashMap::new();
         for field_proto in proto.get_field().iter() {
             field_proto_by_name.insert(field_proto.get_name(), field_proto);
@@ -445,34 +1534,122 @@ impl MessageDescriptor {
             index_by_name.insert(f.get_name().to_string(), i);
         }
 
+        let extensions = find_extensions(file).move_iter()
+                .filter(|&(_, e)| e.get_extendee() == proto_name)
+                .map(|(full_name, e)| {
+                    ExtensionDescriptor::new(full_name, e, find_enum_by_proto_name([file], e.get_type_name()))
+                })
+                .collect();
+
         MessageDescriptor {
             proto: proto,
-            factory: box MessageFactoryTyped::<M>::new() as Box<MessageFactory>,
+            full_name: proto_name.to_string(),
+            factory: factory,
             fields: fields.iter()
                     .map(|f| FieldDescriptor::new(*f, *field_proto_by_name.find(&f.name()).unwrap()))
                     .collect(),
+            extensions: extensions,
+            file_descriptor: file_descriptor,
             index_by_name: index_by_name,
             index_by_number: index_by_number,
         }
     }
 
+    pub fn proto(&self) -> &'static DescriptorProto {
+        self.proto
+    }
+
     pub fn name(&self) -> &'static str {
         self.proto.get_name()
     }
 
+    // fully qualified name without leading dot, e. g. `pkg.Outer.Inner`
+    pub fn full_name<'a>(&'a self) -> &'a str {
+        self.full_name.as_slice().slice_from(1)
+    }
+
+    pub fn file(&self) -> &'static FileDescriptor {
+        match self.file_descriptor {
+            GeneratedFile(file_descriptor) => file_descriptor(),
+            LinkedFile(ref cell) => match cell.get() {
+                Some(file) => file,
+                None => fail!("message {} is not linked to file", self.full_name()),
+            },
+        }
+    }
+
+    fn link_file(&self, file: &'static FileDescriptor) {
+        match self.file_descriptor {
+            GeneratedFile(..) => {},
+            LinkedFile(ref cell) => cell.set(Some(file)),
+        }
+    }
+
+    // message in which this message is declared
+    pub fn containing_type(&self) -> Option<&'static MessageDescriptor> {
+        self.file().all_messages().iter()
+                .find(|m| m.proto.get_nested_type().iter().any(|n| same_proto(n, self.proto)))
+                .map(|&m| m)
+    }
+
+    pub fn nested_messages(&self) -> Vec<&'static MessageDescriptor> {
+        self.proto.get_nested_type().iter()
+                .map(|n| *self.file().all_messages().iter().find(|m| same_proto(m.proto, n)).unwrap())
+                .collect()
+    }
+
+    pub fn nested_enums(&self) -> Vec<&'static EnumDescriptor> {
+        self.proto.get_enum_type().iter()
+                .map(|n| *self.file().all_enums().iter().find(|e| same_proto(e.proto, n)).unwrap())
+                .collect()
+    }
+
+    // new message with no fields set
+    pub fn new_instance(&self) -> Box<Message> {
+        self.factory.new_instance()
+    }
+
+    pub fn parse_from_bytes(&self, bytes: &[u8]) -> ProtobufResult<Box<Message>> {
+        let mut m = self.new_instance();
+        let mut reader = BufReader::new(bytes);
+        {
+            let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+            try!(m.merge_from(&mut is));
+            try!(is.check_eof());
+        }
+        try!(m.check_initialized());
+        Ok(m)
+    }
+
     pub fn fields<'a>(&'a self) -> &'a [FieldDescriptor] {
         self.fields.as_slice()
     }
 
-    pub fn field_by_name<'a>(&'a self, name: &str) -> &'a FieldDescriptor {
+    pub fn extensions<'a>(&'a self) -> &'a [ExtensionDescriptor] {
+        self.extensions.as_slice()
+    }
+
+    pub fn find_field_by_name<'a>(&'a self, name: &str) -> Option<&'a FieldDescriptor> {
         // TODO: clone is weird
-        let &index = self.index_by_name.find(&name.to_string()).unwrap();
-        self.fields.get(index)
+        self.index_by_name.find(&name.to_string()).map(|&index| self.fields.get(index))
+    }
+
+    pub fn find_field_by_number<'a>(&'a self, number: u32) -> Option<&'a FieldDescriptor> {
+        self.index_by_number.find(&number).map(|&index| self.fields.get(index))
+    }
+
+    pub fn field_by_name<'a>(&'a self, name: &str) -> &'a FieldDescriptor {
+        match self.find_field_by_name(name) {
+            Some(f) => f,
+            None => fail!("message {} has no field named {}", self.full_name(), name),
+        }
     }
 
     pub fn field_by_number<'a>(&'a self, number: u32) -> &'a FieldDescriptor {
-        let &index = self.index_by_number.find(&number).unwrap();
-        self.fields.get(index)
+        match self.find_field_by_number(number) {
+            Some(f) => f,
+            None => fail!("message {} has no field with number {}", self.full_name(), number),
+        }
     }
 }
 
@@ -492,45 +1669,295 @@ impl EnumValueDescriptor {
 
 pub struct EnumDescriptor {
     proto: &'static EnumDescriptorProto,
+    full_name: String,
     values: Vec<EnumValueDescriptor>,
+    file_descriptor: FileDescriptorLink,
 
     index_by_name: HashMap<String, uint>,
+    // first declared value of number, others are aliases
     index_by_number: HashMap<i32, uint>,
 }
 
 impl EnumDescriptor {
+    pub fn proto(&self) -> &'static EnumDescriptorProto {
+        self.proto
+    }
+
     pub fn name(&self) -> &'static str {
         self.proto.get_name()
     }
 
+    // fully qualified name without leading dot, e. g. `pkg.Outer.Enum`
+    pub fn full_name<'a>(&'a self) -> &'a str {
+        self.full_name.as_slice().slice_from(1)
+    }
+
     pub fn for_type<E : ProtobufEnum>() -> &'static EnumDescriptor {
         ProtobufEnum::enum_descriptor_static(None::<E>)
     }
 
-    pub fn new(rust_name: &'static str, file: &'static FileDescriptorProto) -> EnumDescriptor {
+    pub fn new(
+            rust_name: &'static str,
+            file: &'static FileDescriptorProto,
+            file_descriptor: fn() -> &'static FileDescriptor)
+        -> EnumDescriptor
+    {
         let proto = find_enum_by_rust_name(file, rust_name);
+        let proto_name = find_enum_proto_name_by_rust_name(file, rust_name);
+        EnumDescriptor::new_impl(proto, proto_name.as_slice(), GeneratedFile(file_descriptor))
+    }
+
+    // Used for enums which types are not known at compile time,
+    // `proto_name` is fully qualified name like `.pkg.Enum`.
+    // Descriptor is linked to `FileDescriptor` by `FileDescriptor::link_declarations`.
+    pub fn new_from_proto(proto: &'static EnumDescriptorProto, proto_name: &str) -> EnumDescriptor {
+        EnumDescriptor::new_impl(proto, proto_name, LinkedFile(Cell::new(None)))
+    }
+
+    fn new_impl(
+            proto: &'static EnumDescriptorProto,
+            proto_name: &str,
+            file_descriptor: FileDescriptorLink)
+        -> EnumDescriptor
+    {
         let mut index_by_name = HashMap::new();
         let mut index_by_number = HashMap::new();
         for (i, v) in proto.get_value().iter().enumerate() {
-            index_by_number.insert(v.get_number(), i);
+            index_by_number.find_or_insert(v.get_number(), i);
             index_by_name.insert(v.get_name().to_string(), i);
         }
         EnumDescriptor {
             proto: proto,
+            full_name: proto_name.to_string(),
             values: proto.get_value().iter().map(|v| EnumValueDescriptor { proto: v }).collect(),
+            file_descriptor: file_descriptor,
             index_by_name: index_by_name,
             index_by_number: index_by_number,
         }
     }
 
-    pub fn value_by_name<'a>(&'a self, name: &str) -> &'a EnumValueDescriptor {
+    pub fn file(&self) -> &'static FileDescriptor {
+        match self.file_descriptor {
+            GeneratedFile(file_descriptor) => file_descriptor(),
+            LinkedFile(ref cell) => match cell.get() {
+                Some(file) => file,
+                None => fail!("enum {} is not linked to file", self.full_name()),
+            },
+        }
+    }
+
+    fn link_file(&self, file: &'static FileDescriptor) {
+        match self.file_descriptor {
+            GeneratedFile(..) => {},
+            LinkedFile(ref cell) => cell.set(Some(file)),
+        }
+    }
+
+    // message in which this enum is declared
+    pub fn containing_type(&self) -> Option<&'static MessageDescriptor> {
+        self.file().all_messages().iter()
+                .find(|m| m.proto.get_enum_type().iter().any(|e| same_proto(e, self.proto)))
+                .map(|&m| m)
+    }
+
+    // multiple values may share number if enum has `allow_alias` option,
+    // option is true by default in descriptor.proto of this version
+    pub fn allow_alias(&self) -> bool {
+        self.proto.get_options().get_allow_alias()
+    }
+
+    // `value` is one of values of this enum, values of other enums
+    // are not accepted even if they have the same name and number
+    pub fn has_value(&self, value: &EnumValueDescriptor) -> bool {
+        self.values.iter().any(|v| v as *EnumValueDescriptor == value as *EnumValueDescriptor)
+    }
+
+    // in declaration order, including aliases
+    pub fn values<'a>(&'a self) -> &'a [EnumValueDescriptor] {
+        self.values.as_slice()
+    }
+
+    pub fn find_value_by_name<'a>(&'a self, name: &str) -> Option<&'a EnumValueDescriptor> {
         // TODO: clone is weird
-        let &index = self.index_by_name.find(&name.to_string()).unwrap();
-        self.values.get(index)
+        self.index_by_name.find(&name.to_string()).map(|&index| self.values.get(index))
+    }
+
+    // first declared value if number has aliases
+    pub fn find_value_by_number<'a>(&'a self, number: i32) -> Option<&'a EnumValueDescriptor> {
+        self.index_by_number.find(&number).map(|&index| self.values.get(index))
+    }
+
+    // value and its aliases in declaration order
+    pub fn values_by_number<'a>(&'a self, number: i32) -> Vec<&'a EnumValueDescriptor> {
+        self.values.iter().filter(|v| v.value() == number).collect()
+    }
+}
+
+pub struct MethodDescriptor {
+    proto: &'static MethodDescriptorProto,
+}
+
+impl MethodDescriptor {
+    pub fn proto(&self) -> &'static MethodDescriptorProto {
+        self.proto
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    // fully qualified name of request message, e. g. `.pkg.Request`
+    pub fn input_type(&self) -> &'static str {
+        self.proto.get_input_type()
+    }
+
+    // fully qualified name of response message
+    pub fn output_type(&self) -> &'static str {
+        self.proto.get_output_type()
+    }
+}
+
+pub struct ServiceDescriptor {
+    proto: &'static ServiceDescriptorProto,
+    full_name: String,
+    methods: Vec<MethodDescriptor>,
+
+    index_by_name: HashMap<String, uint>,
+}
+
+impl ServiceDescriptor {
+    pub fn new(name: &'static str, file: &'static FileDescriptorProto) -> ServiceDescriptor {
+        let proto = file.get_service().iter().find(|s| s.get_name() == name).unwrap();
+        ServiceDescriptor::new_from_proto(proto, service_proto_name(file, proto).as_slice())
+    }
+
+    // `proto_name` is fully qualified name like `.pkg.Service`
+    pub fn new_from_proto(proto: &'static ServiceDescriptorProto, proto_name: &str) -> ServiceDescriptor {
+        let mut index_by_name = HashMap::new();
+        for (i, m) in proto.get_method().iter().enumerate() {
+            index_by_name.insert(m.get_name().to_string(), i);
+        }
+        ServiceDescriptor {
+            proto: proto,
+            full_name: proto_name.to_string(),
+            methods: proto.get_method().iter().map(|m| MethodDescriptor { proto: m }).collect(),
+            index_by_name: index_by_name,
+        }
+    }
+
+    pub fn proto(&self) -> &'static ServiceDescriptorProto {
+        self.proto
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    // fully qualified name without leading dot, e. g. `pkg.Service`
+    pub fn full_name<'a>(&'a self) -> &'a str {
+        self.full_name.as_slice().slice_from(1)
+    }
+
+    pub fn methods<'a>(&'a self) -> &'a [MethodDescriptor] {
+        self.methods.as_slice()
+    }
+
+    pub fn method_by_name<'a>(&'a self, name: &str) -> Option<&'a MethodDescriptor> {
+        self.index_by_name.find(&name.to_string()).map(|&index| self.methods.get(index))
+    }
+}
+
+// descriptors are matched by address of proto, because names of nested declarations
+// are not stored in protos
+fn same_proto<T>(a: &T, b: &T) -> bool {
+    a as *T == b as *T
+}
+
+pub struct FileDescriptor {
+    proto: &'static FileDescriptorProto,
+    dependencies: Vec<&'static FileDescriptor>,
+    // including nested declarations
+    messages: Vec<&'static MessageDescriptor>,
+    enums: Vec<&'static EnumDescriptor>,
+    services: Vec<ServiceDescriptor>,
+}
+
+impl FileDescriptor {
+    // `messages` and `enums` are all declarations of file, including nested
+    pub fn new(
+            proto: &'static FileDescriptorProto,
+            dependencies: Vec<&'static FileDescriptor>,
+            messages: Vec<&'static MessageDescriptor>,
+            enums: Vec<&'static EnumDescriptor>)
+        -> FileDescriptor
+    {
+        FileDescriptor {
+            proto: proto,
+            dependencies: dependencies,
+            messages: messages,
+            enums: enums,
+            services: proto.get_service().iter()
+                    .map(|s| ServiceDescriptor::new_from_proto(s, service_proto_name(proto, s).as_slice()))
+                    .collect(),
+        }
+    }
+
+    // Link descriptors created with `MessageDescriptor::new_from_proto`
+    // and `EnumDescriptor::new_from_proto` to this file
+    pub fn link_declarations(&'static self) {
+        for m in self.messages.iter() {
+            m.link_file(self);
+        }
+        for e in self.enums.iter() {
+            e.link_file(self);
+        }
+    }
+
+    pub fn proto(&self) -> &'static FileDescriptorProto {
+        self.proto
+    }
+
+    // path of .proto file, e. g. `google/protobuf/descriptor.proto`
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    pub fn package(&self) -> &'static str {
+        self.proto.get_package()
+    }
+
+    // descriptor.proto of this version has no syntax field, only proto2 is supported
+    pub fn syntax(&self) -> &'static str {
+        "proto2"
+    }
+
+    pub fn dependencies<'a>(&'a self) -> &'a [&'static FileDescriptor] {
+        self.dependencies.as_slice()
+    }
+
+    // top-level messages
+    pub fn messages(&self) -> Vec<&'static MessageDescriptor> {
+        self.proto.get_message_type().iter()
+                .map(|p| *self.messages.iter().find(|m| same_proto(m.proto, p)).unwrap())
+                .collect()
+    }
+
+    // top-level enums
+    pub fn enums(&self) -> Vec<&'static EnumDescriptor> {
+        self.proto.get_enum_type().iter()
+                .map(|p| *self.enums.iter().find(|e| same_proto(e.proto, p)).unwrap())
+                .collect()
+    }
+
+    pub fn services<'a>(&'a self) -> &'a [ServiceDescriptor] {
+        self.services.as_slice()
+    }
+
+    pub fn all_messages<'a>(&'a self) -> &'a [&'static MessageDescriptor] {
+        self.messages.as_slice()
     }
 
-    pub fn value_by_number<'a>(&'a self, number: i32) -> &'a EnumValueDescriptor {
-        let &index = self.index_by_number.find(&number).unwrap();
-        self.values.get(index)
+    pub fn all_enums<'a>(&'a self) -> &'a [&'static EnumDescriptor] {
+        self.enums.as_slice()
     }
 }
@@ -0,0 +1,287 @@
+// Minimal RPC runtime for generated services.
+//
+// For each service codegen generates `<Service>Client` struct,
+// which serializes requests and sends them over `Transport`.
+// Transport delivers requests to `Dispatcher` on the other side.
+
+use std::cmp;
+use std::io::IoError;
+use std::io::IoResult;
+use std::io::EndOfFile;
+use std::io::BrokenPipe;
+use std::io::BufReader;
+
+use core::Message;
+use core::CodedInputStream;
+use core::CodedOutputStream;
+use core::DEFAULT_TOTAL_BYTES_LIMIT;
+use core::parse_length_delimited_from_bytes;
+use error::ProtobufResult;
+use error::ProtobufIoError;
+use error::TruncatedInput;
+use error::TotalBytesLimitExceeded;
+use error::InvalidUtf8;
+use error::UnknownMethod;
+use error::RpcError;
+use misc::VecWriter;
+use service::Dispatcher;
+
+pub trait Transport {
+    // send length-delimited request, return length-delimited response
+    fn call(&mut self, service: &str, method: &str, request: &[u8]) -> ProtobufResult<Vec<u8>>;
+}
+
+// Called from generated clients
+pub fn call<T : Transport, Req : Message, Resp : Message>(
+    transport: &mut T, service: &str, method: &str, request: &Req)
+        -> ProtobufResult<Resp>
+{
+    let request_bytes = with_coded_output_stream_to_bytes(|os| {
+        request.write_length_delimited_to(os);
+    });
+    let response_bytes = try!(transport.call(service, method, request_bytes.as_slice()));
+    parse_length_delimited_from_bytes::<Resp>(response_bytes.as_slice())
+}
+
+fn with_coded_output_stream_to_bytes(cb: |&mut CodedOutputStream|) -> Vec<u8> {
+    let mut writer = VecWriter::new();
+    {
+        let mut os = CodedOutputStream::new(&mut writer as &mut Writer);
+        cb(&mut os);
+        os.flush();
+    }
+    writer.vec
+}
+
+fn length_delimited(bytes: &[u8]) -> Vec<u8> {
+    with_coded_output_stream_to_bytes(|os| {
+        os.write_bytes_no_tag(bytes);
+    })
+}
+
+fn strip_length_delimited(bytes: &[u8]) -> ProtobufResult<Vec<u8>> {
+    let mut reader = BufReader::new(bytes);
+    let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+    let r = try!(is.read_bytes());
+    try!(is.check_eof());
+    Ok(r)
+}
+
+
+// Transport to `ChannelServer` running in another task
+
+struct ChannelCall {
+    service: String,
+    method: String,
+    request: Vec<u8>,
+    response: Sender<ProtobufResult<Vec<u8>>>,
+}
+
+#[deriving(Clone)]
+pub struct ChannelTransport {
+    sender: Sender<ChannelCall>,
+}
+
+pub struct ChannelServer {
+    receiver: Receiver<ChannelCall>,
+}
+
+pub fn channel_transport() -> (ChannelTransport, ChannelServer) {
+    let (sender, receiver) = channel();
+    (ChannelTransport { sender: sender }, ChannelServer { receiver: receiver })
+}
+
+fn channel_closed() -> IoError {
+    IoError {
+        kind: BrokenPipe,
+        desc: "RPC channel is closed",
+        detail: None,
+    }
+}
+
+impl Transport for ChannelTransport {
+    fn call(&mut self, service: &str, method: &str, request: &[u8]) -> ProtobufResult<Vec<u8>> {
+        let (sender, receiver) = channel();
+        let call = ChannelCall {
+            service: service.to_string(),
+            method: method.to_string(),
+            request: Vec::from_slice(request),
+            response: sender,
+        };
+        if self.sender.send_opt(call).is_err() {
+            return Err(ProtobufIoError(channel_closed()));
+        }
+        match receiver.recv_opt() {
+            Ok(r) => r,
+            Err(()) => Err(ProtobufIoError(channel_closed())),
+        }
+    }
+}
+
+impl ChannelServer {
+    // Serve requests until all transports are dropped
+    pub fn serve(&self, dispatcher: &Dispatcher) {
+        for call in self.receiver.iter() {
+            let response = strip_length_delimited(call.request.as_slice()).and_then(|request| {
+                dispatcher.call(call.service.as_slice(), call.method.as_slice(), request.as_slice())
+            }).map(|response| {
+                length_delimited(response.as_slice())
+            });
+            // client may be gone, nothing to do about it
+            let _ = call.response.send_opt(response);
+        }
+    }
+}
+
+
+// Transport over byte streams, like pipes or sockets.
+//
+// Request is service name, method name and request message,
+// all length-delimited.
+// Response is varint status followed by length-delimited response message
+// or error message.
+
+static STATUS_OK: u32 = 0;
+static STATUS_RPC_ERROR: u32 = 1;
+static STATUS_UNKNOWN_METHOD: u32 = 2;
+
+pub struct StreamTransport<R, W> {
+    pub reader: R,
+    pub writer: W,
+}
+
+// Lengths of names and messages in stream are not trusted,
+// larger frames are rejected before anything is allocated
+static MAX_FRAME_LEN: u32 = DEFAULT_TOTAL_BYTES_LIMIT;
+
+// Frame data is read and allocated in chunks of this size
+static READ_CHUNK_LEN: uint = 4096;
+
+// Returns at most one byte per call,
+// so `CodedInputStream` reads nothing from stream past varint
+struct ByteReader<'a> {
+    reader: &'a mut Reader,
+}
+
+impl<'a> Reader for ByteReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint> {
+        self.reader.read(buf.mut_slice_to(1))
+    }
+}
+
+// Returns None if stream ended before varint
+fn read_varint(reader: &mut Reader) -> ProtobufResult<Option<u32>> {
+    let mut byte_reader = ByteReader { reader: reader };
+    let mut is = CodedInputStream::new(&mut byte_reader as &mut Reader);
+    if try!(is.eof()) {
+        return Ok(None);
+    }
+    is.read_raw_varint32().map(|v| Some(v))
+}
+
+fn read_length_delimited(reader: &mut Reader) -> ProtobufResult<Option<Vec<u8>>> {
+    let len = match try!(read_varint(reader)) {
+        Some(len) => len,
+        None => return Ok(None),
+    };
+    if len > MAX_FRAME_LEN {
+        return Err(TotalBytesLimitExceeded);
+    }
+    let mut r = Vec::new();
+    while r.len() < len as uint {
+        let chunk_len = cmp::min(len as uint - r.len(), READ_CHUNK_LEN);
+        match reader.push_at_least(chunk_len, chunk_len, &mut r) {
+            Ok(..) => {},
+            Err(ref e) if e.kind == EndOfFile => return Err(TruncatedInput),
+            Err(e) => return Err(ProtobufIoError(e)),
+        };
+    }
+    Ok(Some(r))
+}
+
+fn read_length_delimited_really(reader: &mut Reader) -> ProtobufResult<Vec<u8>> {
+    match try!(read_length_delimited(reader)) {
+        Some(bytes) => Ok(bytes),
+        None => Err(TruncatedInput),
+    }
+}
+
+fn to_string(bytes: Vec<u8>) -> ProtobufResult<String> {
+    match String::from_utf8(bytes) {
+        Ok(s) => Ok(s),
+        Err(..) => Err(InvalidUtf8),
+    }
+}
+
+fn write_and_flush(writer: &mut Writer, bytes: &[u8]) -> ProtobufResult<()> {
+    match writer.write(bytes) {
+        Ok(()) => {},
+        Err(e) => return Err(ProtobufIoError(e)),
+    };
+    match writer.flush() {
+        Ok(()) => Ok(()),
+        Err(e) => Err(ProtobufIoError(e)),
+    }
+}
+
+impl<R : Reader, W : Writer> Transport for StreamTransport<R, W> {
+    fn call(&mut self, service: &str, method: &str, request: &[u8]) -> ProtobufResult<Vec<u8>> {
+        let bytes = with_coded_output_stream_to_bytes(|os| {
+            os.write_string_no_tag(service);
+            os.write_string_no_tag(method);
+            os.write_raw_bytes(request);
+        });
+        try!(write_and_flush(&mut self.writer as &mut Writer, bytes.as_slice()));
+
+        let reader = &mut self.reader as &mut Reader;
+        let status = match try!(read_varint(reader)) {
+            Some(status) => status,
+            None => return Err(TruncatedInput),
+        };
+        let payload = try!(read_length_delimited_really(reader));
+        if status == STATUS_OK {
+            Ok(length_delimited(payload.as_slice()))
+        } else if status == STATUS_UNKNOWN_METHOD {
+            Err(UnknownMethod(try!(to_string(payload))))
+        } else {
+            Err(RpcError(try!(to_string(payload))))
+        }
+    }
+}
+
+// Serve requests sent by `StreamTransport` until reader is closed
+pub fn serve_stream(dispatcher: &Dispatcher, reader: &mut Reader, writer: &mut Writer)
+    -> ProtobufResult<()>
+{
+    loop {
+        let service = match try!(read_length_delimited(reader)) {
+            Some(service) => try!(to_string(service)),
+            None => return Ok(()),
+        };
+        let method = try!(to_string(try!(read_length_delimited_really(reader))));
+        let request = try!(read_length_delimited_really(reader));
+
+        let response = dispatcher.call(service.as_slice(), method.as_slice(), request.as_slice());
+        let bytes = with_coded_output_stream_to_bytes(|os| {
+            match response {
+                Ok(ref response) => {
+                    os.write_raw_varint32(STATUS_OK);
+                    os.write_bytes_no_tag(response.as_slice());
+                },
+                Err(UnknownMethod(ref name)) => {
+                    os.write_raw_varint32(STATUS_UNKNOWN_METHOD);
+                    os.write_string_no_tag(name.as_slice());
+                },
+                Err(RpcError(ref message)) => {
+                    os.write_raw_varint32(STATUS_RPC_ERROR);
+                    os.write_string_no_tag(message.as_slice());
+                },
+                Err(ref e) => {
+                    os.write_raw_varint32(STATUS_RPC_ERROR);
+                    os.write_string_no_tag(format!("{}", e).as_slice());
+                },
+            }
+        });
+        try!(write_and_flush(writer, bytes.as_slice()));
+    }
+}
@@ -174,6 +174,11 @@ pub fn unknown_fields_size(unknown_fields: &UnknownFields) -> u32 {
         for bytes in values.length_delimited.iter() {
             r += bytes_size_no_tag(bytes.as_slice());
         }
+
+        r += tag_size(number) * 2 * values.group.len() as u32;
+        for group in values.group.iter() {
+            r += unknown_fields_size(group);
+        }
     }
     r
 }
@@ -0,0 +1,60 @@
+// Runtime for generated services.
+//
+// For each service codegen generates trait with a method per RPC,
+// and `<Service>Server` struct, which implements `ServiceHandler`
+// for any implementation of that trait.
+
+use std::collections::HashMap;
+
+use core::Message;
+use reflect::ServiceDescriptor;
+use error::ProtobufResult;
+use error::UnknownMethod;
+use error::RpcError;
+
+// Service which accepts serialized requests and returns serialized responses
+pub trait ServiceHandler {
+    fn descriptor(&self) -> &'static ServiceDescriptor;
+
+    // parse request, call method by name, serialize response
+    fn call(&self, method: &str, request: &[u8]) -> ProtobufResult<Vec<u8>>;
+}
+
+// Called from generated servers before response is serialized
+pub fn check_response(response: &Message) -> ProtobufResult<()> {
+    match response.check_initialized() {
+        Ok(()) => Ok(()),
+        Err(e) => Err(RpcError(format!("invalid response: {}", e))),
+    }
+}
+
+// Routes calls to registered services by fully qualified service name
+pub struct Dispatcher {
+    services: HashMap<String, Box<ServiceHandler>>,
+}
+
+impl Dispatcher {
+    pub fn new() -> Dispatcher {
+        Dispatcher {
+            services: HashMap::new(),
+        }
+    }
+
+    // error if service with the same name is already added
+    pub fn add_service(&mut self, service: Box<ServiceHandler>) -> ProtobufResult<()> {
+        let name = service.descriptor().full_name().to_string();
+        if self.services.contains_key(&name) {
+            return Err(RpcError(format!("service {} is already added", name)));
+        }
+        self.services.insert(name, service);
+        Ok(())
+    }
+
+    // `service` is fully qualified name like `pkg.Service`
+    pub fn call(&self, service: &str, method: &str, request: &[u8]) -> ProtobufResult<Vec<u8>> {
+        match self.services.find(&service.to_string()) {
+            Some(handler) => handler.call(method, request),
+            None => Err(UnknownMethod(format!("{}.{}", service, method))),
+        }
+    }
+}
@@ -18,106 +18,239 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x20, 0x03, 0x28, 0x05, 0x42, 0x02, 0x10, 0x01, 0x22, 0x18, 0x0a, 0x09, 0x54, 0x65, 0x73, 0x74,
     0x45, 0x6d, 0x70, 0x74, 0x79, 0x12, 0x0b, 0x0a, 0x03, 0x66, 0x6f, 0x6f, 0x18, 0x0a, 0x20, 0x01,
     0x28, 0x05, 0x22, 0x19, 0x0a, 0x0c, 0x54, 0x65, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x69, 0x72,
-    0x65, 0x64, 0x12, 0x09, 0x0a, 0x01, 0x62, 0x18, 0x05, 0x20, 0x02, 0x28, 0x08, 0x22, 0x1e, 0x0a,
-    0x11, 0x54, 0x65, 0x73, 0x74, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x46, 0x69, 0x65, 0x6c,
-    0x64, 0x73, 0x12, 0x09, 0x0a, 0x01, 0x61, 0x18, 0x01, 0x20, 0x02, 0x28, 0x05, 0x22, 0x5f, 0x0a,
-    0x11, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65, 0x6c, 0x66, 0x52, 0x65, 0x66, 0x65, 0x72, 0x65, 0x6e,
-    0x63, 0x65, 0x12, 0x24, 0x0a, 0x02, 0x72, 0x31, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x18,
-    0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65, 0x6c, 0x66, 0x52,
-    0x65, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x12, 0x24, 0x0a, 0x02, 0x72, 0x32, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73,
-    0x74, 0x53, 0x65, 0x6c, 0x66, 0x52, 0x65, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x22, 0x25,
-    0x0a, 0x18, 0x54, 0x65, 0x73, 0x74, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x49, 0x6e, 0x73,
-    0x74, 0x61, 0x6e, 0x63, 0x65, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x12, 0x09, 0x0a, 0x01, 0x73, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x09, 0x22, 0x45, 0x0a, 0x13, 0x54, 0x65, 0x73, 0x74, 0x44, 0x65, 0x66,
-    0x61, 0x75, 0x6c, 0x74, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x2e, 0x0a, 0x05,
-    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x73, 0x68,
-    0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x49,
-    0x6e, 0x73, 0x74, 0x61, 0x6e, 0x63, 0x65, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x22, 0x1f, 0x0a, 0x0e,
-    0x54, 0x65, 0x73, 0x74, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x6f, 0x72, 0x12, 0x0d,
-    0x0a, 0x05, 0x73, 0x74, 0x75, 0x66, 0x66, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x05, 0x22, 0xdd, 0x02,
-    0x0a, 0x11, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x53, 0x69, 0x6e, 0x67, 0x75,
-    0x6c, 0x61, 0x72, 0x12, 0x14, 0x0a, 0x0c, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69,
-    0x65, 0x6c, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x01, 0x12, 0x13, 0x0a, 0x0b, 0x66, 0x6c, 0x6f,
-    0x61, 0x74, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x02, 0x12, 0x13,
-    0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x05, 0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65,
-    0x6c, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74,
-    0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x14,
-    0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x06,
-    0x20, 0x01, 0x28, 0x04, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66,
-    0x69, 0x65, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x11, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x69,
-    0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x12,
-    0x12, 0x15, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c,
-    0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x07, 0x12, 0x15, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64,
-    0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x06, 0x12, 0x16,
-    0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
-    0x18, 0x0b, 0x20, 0x01, 0x28, 0x0f, 0x12, 0x16, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64,
-    0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x10, 0x12, 0x12,
-    0x0a, 0x0a, 0x62, 0x6f, 0x6f, 0x6c, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x01,
-    0x28, 0x08, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x66, 0x69, 0x65,
-    0x6c, 0x64, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x62, 0x79, 0x74, 0x65,
-    0x73, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x91, 0x03,
-    0x0a, 0x11, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52, 0x65, 0x70, 0x65, 0x61,
-    0x74, 0x65, 0x64, 0x12, 0x18, 0x0a, 0x0c, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69,
-    0x65, 0x6c, 0x64, 0x18, 0x01, 0x20, 0x03, 0x28, 0x01, 0x42, 0x02, 0x10, 0x00, 0x12, 0x17, 0x0a,
-    0x0b, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x03,
-    0x28, 0x02, 0x42, 0x02, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f,
-    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x03, 0x20, 0x03, 0x28, 0x05, 0x42, 0x02, 0x10, 0x00, 0x12,
-    0x17, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x04,
-    0x20, 0x03, 0x28, 0x03, 0x42, 0x02, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74,
-    0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0d, 0x42, 0x02,
-    0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65,
-    0x6c, 0x64, 0x18, 0x06, 0x20, 0x03, 0x28, 0x04, 0x42, 0x02, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c,
-    0x73, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x03,
-    0x28, 0x11, 0x42, 0x02, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34,
-    0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x08, 0x20, 0x03, 0x28, 0x12, 0x42, 0x02, 0x10, 0x00,
-    0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c,
-    0x64, 0x18, 0x09, 0x20, 0x03, 0x28, 0x07, 0x42, 0x02, 0x10, 0x00, 0x12, 0x19, 0x0a, 0x0d, 0x66,
-    0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x03,
-    0x28, 0x06, 0x42, 0x02, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64,
-    0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0b, 0x20, 0x03, 0x28, 0x0f, 0x42, 0x02,
-    0x10, 0x00, 0x12, 0x1a, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66,
-    0x69, 0x65, 0x6c, 0x64, 0x18, 0x0c, 0x20, 0x03, 0x28, 0x10, 0x42, 0x02, 0x10, 0x00, 0x12, 0x16,
-    0x0a, 0x0a, 0x62, 0x6f, 0x6f, 0x6c, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x03,
-    0x28, 0x08, 0x42, 0x02, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
-    0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0e, 0x20, 0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b,
-    0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0f, 0x20, 0x03, 0x28,
-    0x0c, 0x22, 0x97, 0x03, 0x0a, 0x17, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52,
-    0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x50, 0x61, 0x63, 0x6b, 0x65, 0x64, 0x12, 0x18, 0x0a,
-    0x0c, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x01, 0x20,
-    0x03, 0x28, 0x01, 0x42, 0x02, 0x10, 0x01, 0x12, 0x17, 0x0a, 0x0b, 0x66, 0x6c, 0x6f, 0x61, 0x74,
-    0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x03, 0x28, 0x02, 0x42, 0x02, 0x10, 0x01,
-    0x12, 0x17, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
-    0x03, 0x20, 0x03, 0x28, 0x05, 0x42, 0x02, 0x10, 0x01, 0x12, 0x17, 0x0a, 0x0b, 0x69, 0x6e, 0x74,
-    0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x04, 0x20, 0x03, 0x28, 0x03, 0x42, 0x02,
-    0x10, 0x01, 0x12, 0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65,
-    0x6c, 0x64, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0d, 0x42, 0x02, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x0c,
-    0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x06, 0x20, 0x03,
-    0x28, 0x04, 0x42, 0x02, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32,
-    0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x03, 0x28, 0x11, 0x42, 0x02, 0x10, 0x01,
-    0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
-    0x18, 0x08, 0x20, 0x03, 0x28, 0x12, 0x42, 0x02, 0x10, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69,
-    0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x09, 0x20, 0x03, 0x28,
-    0x07, 0x42, 0x02, 0x10, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34,
-    0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x03, 0x28, 0x06, 0x42, 0x02, 0x10, 0x01,
-    0x12, 0x1a, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65,
-    0x6c, 0x64, 0x18, 0x0b, 0x20, 0x03, 0x28, 0x0f, 0x42, 0x02, 0x10, 0x01, 0x12, 0x1a, 0x0a, 0x0e,
-    0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0c,
-    0x20, 0x03, 0x28, 0x10, 0x42, 0x02, 0x10, 0x01, 0x12, 0x16, 0x0a, 0x0a, 0x62, 0x6f, 0x6f, 0x6c,
-    0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x03, 0x28, 0x08, 0x42, 0x02, 0x10, 0x01,
-    0x12, 0x14, 0x0a, 0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
-    0x18, 0x0e, 0x20, 0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f,
-    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0f, 0x20, 0x03, 0x28, 0x0c, 0x2a, 0x32, 0x0a, 0x12, 0x54,
-    0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x6f,
-    0x72, 0x12, 0x07, 0x0a, 0x03, 0x52, 0x45, 0x44, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x4c,
-    0x55, 0x45, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x47, 0x52, 0x45, 0x45, 0x4e, 0x10, 0x03,
+    0x65, 0x64, 0x12, 0x09, 0x0a, 0x01, 0x62, 0x18, 0x05, 0x20, 0x02, 0x28, 0x08, 0x22, 0x5b, 0x0a,
+    0x11, 0x54, 0x65, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0x4f, 0x75, 0x74,
+    0x65, 0x72, 0x12, 0x22, 0x0a, 0x05, 0x69, 0x6e, 0x6e, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x13, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x52, 0x65,
+    0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0x12, 0x22, 0x0a, 0x05, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x18,
+    0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x13, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65,
+    0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0x22, 0x1e, 0x0a, 0x11, 0x54, 0x65,
+    0x73, 0x74, 0x55, 0x6e, 0x6b, 0x6e, 0x6f, 0x77, 0x6e, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x12,
+    0x09, 0x0a, 0x01, 0x61, 0x18, 0x01, 0x20, 0x02, 0x28, 0x05, 0x22, 0x5f, 0x0a, 0x11, 0x54, 0x65,
+    0x73, 0x74, 0x53, 0x65, 0x6c, 0x66, 0x52, 0x65, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x12,
+    0x24, 0x0a, 0x02, 0x72, 0x31, 0x18, 0x01, 0x20, 0x02, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x73, 0x68,
+    0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65, 0x6c, 0x66, 0x52, 0x65, 0x66, 0x65,
+    0x72, 0x65, 0x6e, 0x63, 0x65, 0x12, 0x24, 0x0a, 0x02, 0x72, 0x32, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x18, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65,
+    0x6c, 0x66, 0x52, 0x65, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x63, 0x65, 0x22, 0x25, 0x0a, 0x18, 0x54,
+    0x65, 0x73, 0x74, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6e,
+    0x63, 0x65, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x12, 0x09, 0x0a, 0x01, 0x73, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x09, 0x22, 0x45, 0x0a, 0x13, 0x54, 0x65, 0x73, 0x74, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c,
+    0x74, 0x49, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x2e, 0x0a, 0x05, 0x66, 0x69, 0x65,
+    0x6c, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67,
+    0x2e, 0x54, 0x65, 0x73, 0x74, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x49, 0x6e, 0x73, 0x74,
+    0x61, 0x6e, 0x63, 0x65, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x22, 0x1f, 0x0a, 0x0e, 0x54, 0x65, 0x73,
+    0x74, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x6f, 0x72, 0x12, 0x0d, 0x0a, 0x05, 0x73,
+    0x74, 0x75, 0x66, 0x66, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x05, 0x22, 0xdd, 0x02, 0x0a, 0x11, 0x54,
+    0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x53, 0x69, 0x6e, 0x67, 0x75, 0x6c, 0x61, 0x72,
+    0x12, 0x14, 0x0a, 0x0c, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x01, 0x12, 0x13, 0x0a, 0x0b, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x02, 0x12, 0x13, 0x0a, 0x0b, 0x69,
+    0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05,
+    0x12, 0x13, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x04, 0x20, 0x01, 0x28, 0x03, 0x12, 0x14, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x12, 0x14, 0x0a, 0x0c, 0x75,
+    0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28,
+    0x04, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x11, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x36,
+    0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x08, 0x20, 0x01, 0x28, 0x12, 0x12, 0x15, 0x0a,
+    0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x09,
+    0x20, 0x01, 0x28, 0x07, 0x12, 0x15, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x06, 0x12, 0x16, 0x0a, 0x0e, 0x73,
+    0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0b, 0x20,
+    0x01, 0x28, 0x0f, 0x12, 0x16, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x10, 0x12, 0x12, 0x0a, 0x0a, 0x62,
+    0x6f, 0x6f, 0x6c, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x12,
+    0x14, 0x0a, 0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x0e, 0x20, 0x01, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x66,
+    0x69, 0x65, 0x6c, 0x64, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x0c, 0x22, 0x91, 0x03, 0x0a, 0x11, 0x54,
+    0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x12, 0x18, 0x0a, 0x0c, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x01, 0x42, 0x02, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x0b, 0x66, 0x6c,
+    0x6f, 0x61, 0x74, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x03, 0x28, 0x02, 0x42,
+    0x02, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65,
+    0x6c, 0x64, 0x18, 0x03, 0x20, 0x03, 0x28, 0x05, 0x42, 0x02, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x0b,
+    0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x04, 0x20, 0x03, 0x28,
+    0x03, 0x42, 0x02, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0d, 0x42, 0x02, 0x10, 0x00, 0x12,
+    0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x06, 0x20, 0x03, 0x28, 0x04, 0x42, 0x02, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e,
+    0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x03, 0x28, 0x11, 0x42,
+    0x02, 0x10, 0x00, 0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x08, 0x20, 0x03, 0x28, 0x12, 0x42, 0x02, 0x10, 0x00, 0x12, 0x19, 0x0a,
+    0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x09,
+    0x20, 0x03, 0x28, 0x07, 0x42, 0x02, 0x10, 0x00, 0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65,
+    0x64, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x03, 0x28, 0x06, 0x42,
+    0x02, 0x10, 0x00, 0x12, 0x1a, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0b, 0x20, 0x03, 0x28, 0x0f, 0x42, 0x02, 0x10, 0x00, 0x12,
+    0x1a, 0x0a, 0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x18, 0x0c, 0x20, 0x03, 0x28, 0x10, 0x42, 0x02, 0x10, 0x00, 0x12, 0x16, 0x0a, 0x0a, 0x62,
+    0x6f, 0x6f, 0x6c, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x03, 0x28, 0x08, 0x42,
+    0x02, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x0e, 0x20, 0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x62, 0x79, 0x74,
+    0x65, 0x73, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0f, 0x20, 0x03, 0x28, 0x0c, 0x22, 0x97,
+    0x03, 0x0a, 0x17, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x52, 0x65, 0x70, 0x65,
+    0x61, 0x74, 0x65, 0x64, 0x50, 0x61, 0x63, 0x6b, 0x65, 0x64, 0x12, 0x18, 0x0a, 0x0c, 0x64, 0x6f,
+    0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x01, 0x20, 0x03, 0x28, 0x01,
+    0x42, 0x02, 0x10, 0x01, 0x12, 0x17, 0x0a, 0x0b, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x03, 0x28, 0x02, 0x42, 0x02, 0x10, 0x01, 0x12, 0x17, 0x0a,
+    0x0b, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x03, 0x20, 0x03,
+    0x28, 0x05, 0x42, 0x02, 0x10, 0x01, 0x12, 0x17, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x04, 0x20, 0x03, 0x28, 0x03, 0x42, 0x02, 0x10, 0x01, 0x12,
+    0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x05, 0x20, 0x03, 0x28, 0x0d, 0x42, 0x02, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x0c, 0x75, 0x69, 0x6e,
+    0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x06, 0x20, 0x03, 0x28, 0x04, 0x42,
+    0x02, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x03, 0x28, 0x11, 0x42, 0x02, 0x10, 0x01, 0x12, 0x18, 0x0a,
+    0x0c, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x08, 0x20,
+    0x03, 0x28, 0x12, 0x42, 0x02, 0x10, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64,
+    0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x09, 0x20, 0x03, 0x28, 0x07, 0x42, 0x02,
+    0x10, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x03, 0x28, 0x06, 0x42, 0x02, 0x10, 0x01, 0x12, 0x1a, 0x0a,
+    0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x0b, 0x20, 0x03, 0x28, 0x0f, 0x42, 0x02, 0x10, 0x01, 0x12, 0x1a, 0x0a, 0x0e, 0x73, 0x66, 0x69,
+    0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0c, 0x20, 0x03, 0x28,
+    0x10, 0x42, 0x02, 0x10, 0x01, 0x12, 0x16, 0x0a, 0x0a, 0x62, 0x6f, 0x6f, 0x6c, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x03, 0x28, 0x08, 0x42, 0x02, 0x10, 0x01, 0x12, 0x14, 0x0a,
+    0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0e, 0x20,
+    0x03, 0x28, 0x09, 0x12, 0x13, 0x0a, 0x0b, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x66, 0x69, 0x65,
+    0x6c, 0x64, 0x18, 0x0f, 0x20, 0x03, 0x28, 0x0c, 0x22, 0xde, 0x01, 0x0a, 0x09, 0x54, 0x65, 0x73,
+    0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x35, 0x0a, 0x0d, 0x6f, 0x70, 0x74, 0x69, 0x6f, 0x6e,
+    0x61, 0x6c, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0a, 0x32, 0x1e, 0x2e,
+    0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x2e,
+    0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x35, 0x0a,
+    0x0d, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x03,
+    0x20, 0x03, 0x28, 0x0a, 0x32, 0x1e, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73,
+    0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x2e, 0x52, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x47,
+    0x72, 0x6f, 0x75, 0x70, 0x12, 0x09, 0x0a, 0x01, 0x63, 0x18, 0x06, 0x20, 0x01, 0x28, 0x05, 0x1a,
+    0x1a, 0x0a, 0x0d, 0x4f, 0x70, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x47, 0x72, 0x6f, 0x75, 0x70,
+    0x12, 0x09, 0x0a, 0x01, 0x61, 0x18, 0x02, 0x20, 0x01, 0x28, 0x05, 0x1a, 0x3c, 0x0a, 0x0d, 0x52,
+    0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x09, 0x0a, 0x01,
+    0x62, 0x18, 0x04, 0x20, 0x01, 0x28, 0x05, 0x12, 0x20, 0x0a, 0x06, 0x6e, 0x65, 0x73, 0x74, 0x65,
+    0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e,
+    0x54, 0x65, 0x73, 0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x22, 0x85, 0x05, 0x0a, 0x11, 0x54, 0x65,
+    0x73, 0x74, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x12,
+    0x17, 0x0a, 0x0c, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x01, 0x3a, 0x01, 0x31, 0x12, 0x18, 0x0a, 0x0b, 0x66, 0x6c, 0x6f, 0x61,
+    0x74, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x02, 0x3a, 0x03, 0x32,
+    0x2e, 0x35, 0x12, 0x16, 0x0a, 0x0b, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x05, 0x3a, 0x01, 0x33, 0x12, 0x17, 0x0a, 0x0b, 0x69, 0x6e,
+    0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x03, 0x3a,
+    0x02, 0x2d, 0x34, 0x12, 0x17, 0x0a, 0x0c, 0x75, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x3a, 0x01, 0x35, 0x12, 0x17, 0x0a, 0x0c,
+    0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x04, 0x3a, 0x01, 0x36, 0x12, 0x18, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x11, 0x3a, 0x02, 0x2d, 0x37, 0x12,
+    0x17, 0x0a, 0x0c, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x08, 0x20, 0x01, 0x28, 0x12, 0x3a, 0x01, 0x38, 0x12, 0x18, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65,
+    0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x09, 0x20, 0x01, 0x28, 0x07, 0x3a,
+    0x01, 0x39, 0x12, 0x19, 0x0a, 0x0d, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69,
+    0x65, 0x6c, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x06, 0x3a, 0x02, 0x31, 0x30, 0x12, 0x1a, 0x0a,
+    0x0e, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18,
+    0x0b, 0x20, 0x01, 0x28, 0x0f, 0x3a, 0x02, 0x31, 0x31, 0x12, 0x1b, 0x0a, 0x0e, 0x73, 0x66, 0x69,
+    0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28,
+    0x10, 0x3a, 0x03, 0x2d, 0x31, 0x32, 0x12, 0x18, 0x0a, 0x0a, 0x62, 0x6f, 0x6f, 0x6c, 0x5f, 0x66,
+    0x69, 0x65, 0x6c, 0x64, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x08, 0x3a, 0x04, 0x74, 0x72, 0x75, 0x65,
+    0x12, 0x1b, 0x0a, 0x0c, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x3a, 0x05, 0x61, 0x62, 0x22, 0x63, 0x0a, 0x12, 0x23, 0x0a,
+    0x0b, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x18, 0x0f, 0x20, 0x01,
+    0x28, 0x0c, 0x3a, 0x0e, 0x64, 0x65, 0x5c, 0x6e, 0x5c, 0x30, 0x30, 0x30, 0x5c, 0x33, 0x37, 0x37,
+    0x5c, 0x5c, 0x12, 0x33, 0x0a, 0x0a, 0x65, 0x6e, 0x75, 0x6d, 0x5f, 0x66, 0x69, 0x65, 0x6c, 0x64,
+    0x18, 0x10, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1a, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x45,
+    0x6e, 0x75, 0x6d, 0x46, 0x6f, 0x72, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x56, 0x61, 0x6c,
+    0x75, 0x65, 0x3a, 0x03, 0x54, 0x57, 0x4f, 0x12, 0x3e, 0x0a, 0x1a, 0x65, 0x6e, 0x75, 0x6d, 0x5f,
+    0x66, 0x69, 0x65, 0x6c, 0x64, 0x5f, 0x77, 0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x5f, 0x64, 0x65,
+    0x66, 0x61, 0x75, 0x6c, 0x74, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1a, 0x2e, 0x73, 0x68,
+    0x72, 0x75, 0x67, 0x2e, 0x45, 0x6e, 0x75, 0x6d, 0x46, 0x6f, 0x72, 0x44, 0x65, 0x66, 0x61, 0x75,
+    0x6c, 0x74, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x17, 0x0a, 0x0a, 0x64, 0x6f, 0x75, 0x62, 0x6c,
+    0x65, 0x5f, 0x69, 0x6e, 0x66, 0x18, 0x12, 0x20, 0x01, 0x28, 0x01, 0x3a, 0x03, 0x69, 0x6e, 0x66,
+    0x12, 0x1b, 0x0a, 0x0d, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x5f, 0x6e, 0x65, 0x67, 0x5f, 0x69, 0x6e,
+    0x66, 0x18, 0x13, 0x20, 0x01, 0x28, 0x02, 0x3a, 0x04, 0x2d, 0x69, 0x6e, 0x66, 0x12, 0x17, 0x0a,
+    0x0a, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x6e, 0x61, 0x6e, 0x18, 0x14, 0x20, 0x01, 0x28,
+    0x01, 0x3a, 0x03, 0x6e, 0x61, 0x6e, 0x12, 0x19, 0x0a, 0x0a, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65,
+    0x5f, 0x65, 0x78, 0x70, 0x18, 0x15, 0x20, 0x01, 0x28, 0x01, 0x3a, 0x05, 0x31, 0x65, 0x2b, 0x32,
+    0x30, 0x22, 0x22, 0x0a, 0x0e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69,
+    0x6f, 0x6e, 0x73, 0x12, 0x09, 0x0a, 0x01, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x2a, 0x05,
+    0x08, 0x64, 0x10, 0xc8, 0x01, 0x22, 0x41, 0x0a, 0x14, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74,
+    0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x4e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x32, 0x29, 0x0a,
+    0x0a, 0x6e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x65, 0x78, 0x74, 0x18, 0x6e, 0x20, 0x01, 0x28,
+    0x0c, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78,
+    0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0x2a, 0x0a, 0x12, 0x54, 0x65, 0x73, 0x74,
+    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x09,
+    0x0a, 0x01, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x12, 0x09, 0x0a, 0x01, 0x62, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x05, 0x22, 0x22, 0x0a, 0x13, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x0b, 0x0a, 0x03, 0x73,
+    0x75, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x22, 0xc4, 0x01, 0x0a, 0x14, 0x54, 0x65, 0x73,
+    0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x6c, 0x69, 0x61, 0x73, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x12, 0x1f, 0x0a, 0x01, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x14, 0x2e, 0x73,
+    0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x6c, 0x69,
+    0x61, 0x73, 0x12, 0x24, 0x0a, 0x06, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03,
+    0x28, 0x0e, 0x32, 0x14, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45,
+    0x6e, 0x75, 0x6d, 0x41, 0x6c, 0x69, 0x61, 0x73, 0x12, 0x32, 0x0a, 0x06, 0x6e, 0x65, 0x73, 0x74,
+    0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x22, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67,
+    0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x6c, 0x69, 0x61, 0x73, 0x4d, 0x65,
+    0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x4e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x22, 0x31, 0x0a, 0x06,
+    0x4e, 0x65, 0x73, 0x74, 0x65, 0x64, 0x12, 0x10, 0x0a, 0x0c, 0x4e, 0x45, 0x53, 0x54, 0x45, 0x44,
+    0x5f, 0x46, 0x49, 0x52, 0x53, 0x54, 0x10, 0x00, 0x12, 0x11, 0x0a, 0x0d, 0x4e, 0x45, 0x53, 0x54,
+    0x45, 0x44, 0x5f, 0x53, 0x45, 0x43, 0x4f, 0x4e, 0x44, 0x10, 0x00, 0x1a, 0x02, 0x10, 0x01, 0x22,
+    0x15, 0x0a, 0x08, 0x45, 0x78, 0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x12, 0x09, 0x0a, 0x01, 0x61,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x2a, 0x32, 0x0a, 0x12, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e,
+    0x75, 0x6d, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x6f, 0x72, 0x12, 0x07, 0x0a, 0x03,
+    0x52, 0x45, 0x44, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x4c, 0x55, 0x45, 0x10, 0x02, 0x12,
+    0x09, 0x0a, 0x05, 0x47, 0x52, 0x45, 0x45, 0x4e, 0x10, 0x03, 0x2a, 0x32, 0x0a, 0x13, 0x45, 0x6e,
+    0x75, 0x6d, 0x46, 0x6f, 0x72, 0x44, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x56, 0x61, 0x6c, 0x75,
+    0x65, 0x12, 0x07, 0x0a, 0x03, 0x4f, 0x4e, 0x45, 0x10, 0x01, 0x12, 0x07, 0x0a, 0x03, 0x54, 0x57,
+    0x4f, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x54, 0x48, 0x52, 0x45, 0x45, 0x10, 0x03, 0x2a, 0x58,
+    0x0a, 0x0d, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x41, 0x6c, 0x69, 0x61, 0x73, 0x12,
+    0x0f, 0x0a, 0x0b, 0x41, 0x4c, 0x49, 0x41, 0x53, 0x5f, 0x46, 0x49, 0x52, 0x53, 0x54, 0x10, 0x01,
+    0x12, 0x10, 0x0a, 0x0c, 0x41, 0x4c, 0x49, 0x41, 0x53, 0x5f, 0x53, 0x45, 0x43, 0x4f, 0x4e, 0x44,
+    0x10, 0x01, 0x12, 0x0f, 0x0a, 0x0b, 0x41, 0x4c, 0x49, 0x41, 0x53, 0x5f, 0x4f, 0x54, 0x48, 0x45,
+    0x52, 0x10, 0x02, 0x12, 0x0f, 0x0a, 0x0b, 0x41, 0x4c, 0x49, 0x41, 0x53, 0x5f, 0x54, 0x48, 0x49,
+    0x52, 0x44, 0x10, 0x01, 0x1a, 0x02, 0x10, 0x01, 0x32, 0x91, 0x01, 0x0a, 0x0b, 0x54, 0x65, 0x73,
+    0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x43, 0x0a, 0x0a, 0x41, 0x64, 0x64, 0x4e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x12, 0x19, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54,
+    0x65, 0x73, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x1a, 0x1a, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3d, 0x0a,
+    0x04, 0x46, 0x61, 0x69, 0x6c, 0x12, 0x19, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65,
+    0x73, 0x74, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x1a, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x3a, 0x28, 0x0a, 0x09,
+    0x65, 0x78, 0x74, 0x5f, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x18, 0x64, 0x20, 0x01, 0x28, 0x05, 0x12,
+    0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65,
+    0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x29, 0x0a, 0x0a, 0x65, 0x78, 0x74, 0x5f, 0x73, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x18, 0x65, 0x20, 0x01, 0x28, 0x09, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72,
+    0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e,
+    0x73, 0x3a, 0x42, 0x0a, 0x08, 0x65, 0x78, 0x74, 0x5f, 0x65, 0x6e, 0x75, 0x6d, 0x18, 0x66, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x19, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74,
+    0x45, 0x6e, 0x75, 0x6d, 0x44, 0x65, 0x73, 0x63, 0x72, 0x69, 0x70, 0x74, 0x6f, 0x72, 0x12, 0x15,
+    0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e,
+    0x73, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x38, 0x0a, 0x0b, 0x65, 0x78, 0x74, 0x5f, 0x6d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x18, 0x67, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x73, 0x68, 0x72,
+    0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x31, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67,
+    0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x3a,
+    0x31, 0x0a, 0x12, 0x65, 0x78, 0x74, 0x5f, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70,
+    0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x68, 0x20, 0x03, 0x28, 0x05, 0x12, 0x15, 0x2e, 0x73, 0x68,
+    0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f,
+    0x6e, 0x73, 0x3a, 0x29, 0x0a, 0x0a, 0x65, 0x78, 0x74, 0x5f, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34,
+    0x18, 0x69, 0x20, 0x01, 0x28, 0x12, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54,
+    0x65, 0x73, 0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x29, 0x0a,
+    0x0a, 0x65, 0x78, 0x74, 0x5f, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x18, 0x6a, 0x20, 0x01, 0x28,
+    0x01, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x78,
+    0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x35, 0x0a, 0x12, 0x65, 0x78, 0x74, 0x5f,
+    0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x70, 0x61, 0x63, 0x6b, 0x65, 0x64, 0x18, 0x6b,
+    0x20, 0x03, 0x28, 0x07, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73,
+    0x74, 0x45, 0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73, 0x42, 0x02, 0x10, 0x01, 0x3a,
+    0x38, 0x0a, 0x08, 0x65, 0x78, 0x74, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x18, 0x6c, 0x20, 0x01, 0x28,
+    0x0a, 0x32, 0x0f, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x45, 0x78, 0x74, 0x47, 0x72, 0x6f,
+    0x75, 0x70, 0x12, 0x15, 0x2e, 0x73, 0x68, 0x72, 0x75, 0x67, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45,
+    0x78, 0x74, 0x65, 0x6e, 0x73, 0x69, 0x6f, 0x6e, 0x73,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::descriptor::FileDescriptorProto };
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data)
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
@@ -128,6 +261,54 @@ pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescripto
     }
 }
 
+static mut file_descriptor_lazy: ::protobuf::lazy::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::FileDescriptor };
+
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    unsafe {
+        file_descriptor_lazy.get(|| {
+            ::protobuf::reflect::FileDescriptor::new(
+                file_descriptor_proto(),
+                vec!(
+                ),
+                vec!(
+                    ::protobuf::reflect::MessageDescriptor::for_type::<Test1>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<Test2>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<Test3>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<Test4>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestPackedUnpacked>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestEmpty>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestRequired>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestRequiredOuter>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestUnknownFields>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestSelfReference>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestDefaultInstanceField>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestDefaultInstance>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestDescriptor>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestTypesSingular>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestTypesRepeated>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestTypesRepeatedPacked>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestGroup>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestDefaultValues>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestExtensions>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestExtensionsNested>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestServiceRequest>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestServiceResponse>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestEnumAliasMessage>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<ExtGroup>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestGroup_OptionalGroup>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestGroup_RepeatedGroup>(),
+                ),
+                vec!(
+                    ::protobuf::reflect::EnumDescriptor::for_type::<TestEnumDescriptor>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<EnumForDefaultValue>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<TestEnumAlias>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<TestEnumAliasMessage_Nested>(),
+                ),
+            )
+        })
+    }
+}
+
 #[deriving(Clone,PartialEq,Default)]
 pub struct Test1 {
     a: Option<i32>,
@@ -202,21 +383,38 @@ impl ::protobuf::Message for Test1 {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.a = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Test1) {
+        if other.a.is_some() {
+            self.a = other.a.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -235,7 +433,7 @@ impl ::protobuf::Message for Test1 {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -269,7 +467,8 @@ impl ::protobuf::Message for Test1 {
                 ::protobuf::reflect::MessageDescriptor::new::<Test1>(
                     "Test1",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -309,6 +508,14 @@ impl ::protobuf::reflect::FieldAccessor<Test1> for Test1_a_acc {
     fn get_i32(&self, m: &Test1) -> i32 {
         m.get_a()
     }
+
+    fn clear_field(&self, m: &mut Test1) {
+        m.clear_a();
+    }
+
+    fn set_i32(&self, m: &mut Test1, v: i32) {
+        m.set_a(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -388,21 +595,38 @@ impl ::protobuf::Message for Test2 {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.b = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Test2) {
+        if other.b.is_some() {
+            self.b = other.b.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -421,7 +645,7 @@ impl ::protobuf::Message for Test2 {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -455,7 +679,8 @@ impl ::protobuf::Message for Test2 {
                 ::protobuf::reflect::MessageDescriptor::new::<Test2>(
                     "Test2",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -495,6 +720,14 @@ impl ::protobuf::reflect::FieldAccessor<Test2> for Test2_b_acc {
     fn get_str<'a>(&self, m: &'a Test2) -> &'a str {
         m.get_b()
     }
+
+    fn clear_field(&self, m: &mut Test2) {
+        m.clear_b();
+    }
+
+    fn set_str(&self, m: &mut Test2, v: String) {
+        m.set_b(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -567,27 +800,54 @@ impl ::protobuf::Message for Test3 {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
         if self.c.is_none() {
             return false;
         };
+        for v in self.c.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.c.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Test3) {
+        use protobuf::{Message};
+        if other.c.is_some() {
+            if self.c.is_none() {
+                self.c.set_default();
+            };
+            self.c.get_mut_ref().merge_from_message(other.c.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -607,7 +867,7 @@ impl ::protobuf::Message for Test3 {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -641,7 +901,8 @@ impl ::protobuf::Message for Test3 {
                 ::protobuf::reflect::MessageDescriptor::new::<Test3>(
                     "Test3",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -674,6 +935,10 @@ impl ::protobuf::reflect::FieldAccessor<Test3> for Test3_c_acc {
         "c"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<Test1>()
+    }
+
     fn has_field(&self, m: &Test3) -> bool {
         m.has_c()
     }
@@ -681,6 +946,14 @@ impl ::protobuf::reflect::FieldAccessor<Test3> for Test3_c_acc {
     fn get_message<'a>(&self, m: &'a Test3) -> &'a ::protobuf::Message {
         m.get_c() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut Test3) {
+        m.clear_c();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut Test3) -> &'a mut ::protobuf::Message {
+        m.mut_c() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -751,29 +1024,44 @@ impl ::protobuf::Message for Test4 {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 4 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.d.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.d.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.d.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.d.push(try!(is.read_int32()));
                     }
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Test4) {
+        self.d.push_all(other.d.as_slice());
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -792,7 +1080,7 @@ impl ::protobuf::Message for Test4 {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -826,7 +1114,8 @@ impl ::protobuf::Message for Test4 {
                 ::protobuf::reflect::MessageDescriptor::new::<Test4>(
                     "Test4",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -866,6 +1155,18 @@ impl ::protobuf::reflect::FieldAccessor<Test4> for Test4_d_acc {
     fn get_rep_i32<'a>(&self, m: &'a Test4) -> &'a [i32] {
         m.get_d()
     }
+
+    fn clear_field(&self, m: &mut Test4) {
+        m.clear_d();
+    }
+
+    fn add_i32(&self, m: &mut Test4, v: i32) {
+        m.add_d(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut Test4) -> &'a mut Vec<i32> {
+        m.mut_d()
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -963,42 +1264,62 @@ impl ::protobuf::Message for TestPackedUnpacked {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 4 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.unpacked.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.unpacked.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.unpacked.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.unpacked.push(try!(is.read_int32()));
                     }
                 },
                 5 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.packed.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.packed.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.packed.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.packed.push(try!(is.read_int32()));
                     }
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestPackedUnpacked) {
+        self.unpacked.push_all(other.unpacked.as_slice());
+        self.packed.push_all(other.packed.as_slice());
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1020,7 +1341,7 @@ impl ::protobuf::Message for TestPackedUnpacked {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1055,7 +1376,8 @@ impl ::protobuf::Message for TestPackedUnpacked {
                 ::protobuf::reflect::MessageDescriptor::new::<TestPackedUnpacked>(
                     "TestPackedUnpacked",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1096,6 +1418,18 @@ impl ::protobuf::reflect::FieldAccessor<TestPackedUnpacked> for TestPackedUnpack
     fn get_rep_i32<'a>(&self, m: &'a TestPackedUnpacked) -> &'a [i32] {
         m.get_unpacked()
     }
+
+    fn clear_field(&self, m: &mut TestPackedUnpacked) {
+        m.clear_unpacked();
+    }
+
+    fn add_i32(&self, m: &mut TestPackedUnpacked, v: i32) {
+        m.add_unpacked(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestPackedUnpacked) -> &'a mut Vec<i32> {
+        m.mut_unpacked()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1113,6 +1447,18 @@ impl ::protobuf::reflect::FieldAccessor<TestPackedUnpacked> for TestPackedUnpack
     fn get_rep_i32<'a>(&self, m: &'a TestPackedUnpacked) -> &'a [i32] {
         m.get_packed()
     }
+
+    fn clear_field(&self, m: &mut TestPackedUnpacked) {
+        m.clear_packed();
+    }
+
+    fn add_i32(&self, m: &mut TestPackedUnpacked, v: i32) {
+        m.add_packed(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestPackedUnpacked) -> &'a mut Vec<i32> {
+        m.mut_packed()
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -1186,21 +1532,38 @@ impl ::protobuf::Message for TestEmpty {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.foo = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestEmpty) {
+        if other.foo.is_some() {
+            self.foo = other.foo.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1219,7 +1582,7 @@ impl ::protobuf::Message for TestEmpty {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1253,7 +1616,8 @@ impl ::protobuf::Message for TestEmpty {
                 ::protobuf::reflect::MessageDescriptor::new::<TestEmpty>(
                     "TestEmpty",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1293,6 +1657,14 @@ impl ::protobuf::reflect::FieldAccessor<TestEmpty> for TestEmpty_foo_acc {
     fn get_i32(&self, m: &TestEmpty) -> i32 {
         m.get_foo()
     }
+
+    fn clear_field(&self, m: &mut TestEmpty) {
+        m.clear_foo();
+    }
+
+    fn set_i32(&self, m: &mut TestEmpty, v: i32) {
+        m.set_foo(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -1369,21 +1741,38 @@ impl ::protobuf::Message for TestRequired {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.b = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestRequired) {
+        if other.b.is_some() {
+            self.b = other.b.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1402,7 +1791,7 @@ impl ::protobuf::Message for TestRequired {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1436,7 +1825,8 @@ impl ::protobuf::Message for TestRequired {
                 ::protobuf::reflect::MessageDescriptor::new::<TestRequired>(
                     "TestRequired",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1476,97 +1866,178 @@ impl ::protobuf::reflect::FieldAccessor<TestRequired> for TestRequired_b_acc {
     fn get_bool(&self, m: &TestRequired) -> bool {
         m.get_b()
     }
+
+    fn clear_field(&self, m: &mut TestRequired) {
+        m.clear_b();
+    }
+
+    fn set_bool(&self, m: &mut TestRequired, v: bool) {
+        m.set_b(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
-pub struct TestUnknownFields {
-    a: Option<i32>,
+pub struct TestRequiredOuter {
+    inner: ::protobuf::SingularField<TestRequired>,
+    items: ::protobuf::RepeatedField<TestRequired>,
     unknown_fields: Option<Box<::protobuf::UnknownFields>>,
 }
 
-impl<'a> TestUnknownFields {
-    pub fn new() -> TestUnknownFields {
+impl<'a> TestRequiredOuter {
+    pub fn new() -> TestRequiredOuter {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static TestUnknownFields {
-        static mut instance: ::protobuf::lazy::Lazy<TestUnknownFields> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestUnknownFields };
+    pub fn default_instance() -> &'static TestRequiredOuter {
+        static mut instance: ::protobuf::lazy::Lazy<TestRequiredOuter> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestRequiredOuter };
         unsafe {
             instance.get(|| {
-                TestUnknownFields {
-                    a: None,
+                TestRequiredOuter {
+                    inner: ::protobuf::SingularField::none(),
+                    items: ::protobuf::RepeatedField::new(),
                     unknown_fields: None,
                 }
             })
         }
     }
 
-    #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
         use protobuf::{Message};
-        match self.a {
+        match self.inner.as_ref() {
             Some(ref v) => {
-                os.write_int32(1, *v);
+                os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
             },
             None => {},
         };
+        for v in self.items.iter() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited);
+            os.write_raw_varint32(sizes[*sizes_pos]);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+        };
         os.write_unknown_fields(self.get_unknown_fields());
     }
 
-    pub fn clear_a(&mut self) {
-        self.a = None;
+    pub fn clear_inner(&mut self) {
+        self.inner.clear();
     }
 
-    pub fn has_a(&self) -> bool {
-        self.a.is_some()
+    pub fn has_inner(&self) -> bool {
+        self.inner.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_a(&mut self, v: i32) {
-        self.a = Some(v);
+    pub fn set_inner(&mut self, v: TestRequired) {
+        self.inner = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_a(&'a mut self) -> &'a mut i32 {
-        if self.a.is_none() {
-            self.a = Some(0);
+    pub fn mut_inner(&'a mut self) -> &'a mut TestRequired {
+        if self.inner.is_none() {
+            self.inner.set_default();
         };
-        self.a.get_mut_ref()
+        self.inner.get_mut_ref()
     }
 
-    pub fn get_a(&self) -> i32 {
-        self.a.unwrap_or_else(|| 0)
+    pub fn get_inner(&'a self) -> &'a TestRequired {
+        self.inner.as_ref().unwrap_or_else(|| TestRequired::default_instance())
+    }
+
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<TestRequired>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&'a mut self) -> &'a mut ::protobuf::RepeatedField<TestRequired> {
+        &mut self.items
+    }
+
+    pub fn get_items(&'a self) -> &'a [TestRequired] {
+        self.items.as_slice()
+    }
+
+    pub fn add_items(&mut self, v: TestRequired) {
+        self.items.push(v);
     }
 }
 
-impl ::protobuf::Message for TestUnknownFields {
-    fn new() -> TestUnknownFields {
-        TestUnknownFields::new()
+impl ::protobuf::Message for TestRequiredOuter {
+    fn new() -> TestRequiredOuter {
+        TestRequiredOuter::new()
     }
 
     fn is_initialized(&self) -> bool {
-        if self.a.is_none() {
-            return false;
+        use protobuf::{Message};
+        for v in self.inner.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.items.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
         };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
-                    self.a = Some(tmp);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.inner.set_default();
+                    try!(is.merge_message(tmp));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.items.push_default();
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestRequiredOuter) {
+        use protobuf::{Message};
+        if other.inner.is_some() {
+            if self.inner.is_none() {
+                self.inner.set_default();
+            };
+            self.inner.get_mut_ref().merge_from_message(other.inner.get_ref());
+        };
+        for v in other.items.iter() {
+            self.items.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1575,8 +2046,13 @@ impl ::protobuf::Message for TestUnknownFields {
         let pos = sizes.len();
         sizes.push(0);
         let mut my_size = 0;
-        for value in self.a.iter() {
-            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        for value in self.inner.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in self.items.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         *sizes.get_mut(pos) = my_size;
@@ -1585,7 +2061,7 @@ impl ::protobuf::Message for TestUnknownFields {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1610,33 +2086,36 @@ impl ::protobuf::Message for TestUnknownFields {
     }
 
     #[allow(unused_unsafe,unused_mut)]
-    fn descriptor_static(_: Option<TestUnknownFields>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: Option<TestRequiredOuter>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
         unsafe {
             descriptor.get(|| {
-                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestUnknownFields>> = Vec::new();
-                fields.push(unsafe { ::std::mem::transmute(&'static TestUnknownFields_a_acc as &::protobuf::reflect::FieldAccessor<TestUnknownFields>) });
-                ::protobuf::reflect::MessageDescriptor::new::<TestUnknownFields>(
-                    "TestUnknownFields",
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestRequiredOuter>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestRequiredOuter_inner_acc as &::protobuf::reflect::FieldAccessor<TestRequiredOuter>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestRequiredOuter_items_acc as &::protobuf::reflect::FieldAccessor<TestRequiredOuter>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestRequiredOuter>(
+                    "TestRequiredOuter",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
     }
 
     fn type_id(&self) -> ::std::intrinsics::TypeId {
-        ::std::intrinsics::TypeId::of::<TestUnknownFields>()
+        ::std::intrinsics::TypeId::of::<TestRequiredOuter>()
     }
 }
 
-impl ::protobuf::Clear for TestUnknownFields {
+impl ::protobuf::Clear for TestRequiredOuter {
     fn clear(&mut self) {
-        self.clear_a();
+        self.clear_inner();
+        self.clear_items();
     }
 }
 
-impl ::std::fmt::Show for TestUnknownFields {
+impl ::std::fmt::Show for TestRequiredOuter {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         use protobuf::{Message};
         self.fmt_impl(f)
@@ -1645,98 +2124,352 @@ impl ::std::fmt::Show for TestUnknownFields {
 
 
 #[allow(non_camel_case_types)]
-struct TestUnknownFields_a_acc;
+struct TestRequiredOuter_inner_acc;
 
-impl ::protobuf::reflect::FieldAccessor<TestUnknownFields> for TestUnknownFields_a_acc {
+impl ::protobuf::reflect::FieldAccessor<TestRequiredOuter> for TestRequiredOuter_inner_acc {
     fn name(&self) -> &'static str {
-        "a"
+        "inner"
     }
 
-    fn has_field(&self, m: &TestUnknownFields) -> bool {
-        m.has_a()
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestRequired>()
     }
 
-    fn get_i32(&self, m: &TestUnknownFields) -> i32 {
-        m.get_a()
+    fn has_field(&self, m: &TestRequiredOuter) -> bool {
+        m.has_inner()
+    }
+
+    fn get_message<'a>(&self, m: &'a TestRequiredOuter) -> &'a ::protobuf::Message {
+        m.get_inner() as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestRequiredOuter) {
+        m.clear_inner();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestRequiredOuter) -> &'a mut ::protobuf::Message {
+        m.mut_inner() as &'a mut ::protobuf::Message
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestRequiredOuter_items_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestRequiredOuter> for TestRequiredOuter_items_acc {
+    fn name(&self) -> &'static str {
+        "items"
+    }
+
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestRequired>()
+    }
+
+    fn len_field(&self, m: &TestRequiredOuter) -> uint {
+        m.get_items().len()
+    }
+
+    fn get_rep_message_item<'a>(&self, m: &'a TestRequiredOuter, index: uint) -> &'a ::protobuf::Message {
+        &'a m.get_items()[index] as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestRequiredOuter) {
+        m.clear_items();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut TestRequiredOuter) -> &'a mut ::protobuf::Message {
+        m.mut_items().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut TestRequiredOuter, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_items().get_mut(index) as &'a mut ::protobuf::Message
     }
 }
 
 #[deriving(Clone,PartialEq,Default)]
-pub struct TestSelfReference {
-    r1: ::protobuf::SingularField<TestSelfReference>,
-    r2: ::protobuf::SingularField<TestSelfReference>,
+pub struct TestUnknownFields {
+    a: Option<i32>,
     unknown_fields: Option<Box<::protobuf::UnknownFields>>,
 }
 
-impl<'a> TestSelfReference {
-    pub fn new() -> TestSelfReference {
+impl<'a> TestUnknownFields {
+    pub fn new() -> TestUnknownFields {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static TestSelfReference {
-        static mut instance: ::protobuf::lazy::Lazy<TestSelfReference> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestSelfReference };
+    pub fn default_instance() -> &'static TestUnknownFields {
+        static mut instance: ::protobuf::lazy::Lazy<TestUnknownFields> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestUnknownFields };
         unsafe {
             instance.get(|| {
-                TestSelfReference {
-                    r1: ::protobuf::SingularField::none(),
-                    r2: ::protobuf::SingularField::none(),
+                TestUnknownFields {
+                    a: None,
                     unknown_fields: None,
                 }
             })
         }
     }
 
+    #[allow(unused_variable)]
     pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
         use protobuf::{Message};
-        match self.r1.as_ref() {
-            Some(ref v) => {
-                os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
-            },
-            None => {},
-        };
-        match self.r2.as_ref() {
+        match self.a {
             Some(ref v) => {
-                os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited);
-                os.write_raw_varint32(sizes[*sizes_pos]);
-                *sizes_pos += 1;
-                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+                os.write_int32(1, *v);
             },
             None => {},
         };
         os.write_unknown_fields(self.get_unknown_fields());
     }
 
-    pub fn clear_r1(&mut self) {
-        self.r1.clear();
+    pub fn clear_a(&mut self) {
+        self.a = None;
     }
 
-    pub fn has_r1(&self) -> bool {
-        self.r1.is_some()
+    pub fn has_a(&self) -> bool {
+        self.a.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_r1(&mut self, v: TestSelfReference) {
-        self.r1 = ::protobuf::SingularField::some(v);
+    pub fn set_a(&mut self, v: i32) {
+        self.a = Some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_r1(&'a mut self) -> &'a mut TestSelfReference {
-        if self.r1.is_none() {
-            self.r1.set_default();
+    pub fn mut_a(&'a mut self) -> &'a mut i32 {
+        if self.a.is_none() {
+            self.a = Some(0);
         };
-        self.r1.get_mut_ref()
-    }
-
-    pub fn get_r1(&'a self) -> &'a TestSelfReference {
-        self.r1.as_ref().unwrap_or_else(|| TestSelfReference::default_instance())
+        self.a.get_mut_ref()
     }
 
-    pub fn clear_r2(&mut self) {
-        self.r2.clear();
+    pub fn get_a(&self) -> i32 {
+        self.a.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestUnknownFields {
+    fn new() -> TestUnknownFields {
+        TestUnknownFields::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        if self.a.is_none() {
+            return false;
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.a = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestUnknownFields) {
+        if other.a.is_some() {
+            self.a = other.a.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.a.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestUnknownFields>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestUnknownFields>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestUnknownFields_a_acc as &::protobuf::reflect::FieldAccessor<TestUnknownFields>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestUnknownFields>(
+                    "TestUnknownFields",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestUnknownFields>()
+    }
+}
+
+impl ::protobuf::Clear for TestUnknownFields {
+    fn clear(&mut self) {
+        self.clear_a();
+    }
+}
+
+impl ::std::fmt::Show for TestUnknownFields {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestUnknownFields_a_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestUnknownFields> for TestUnknownFields_a_acc {
+    fn name(&self) -> &'static str {
+        "a"
+    }
+
+    fn has_field(&self, m: &TestUnknownFields) -> bool {
+        m.has_a()
+    }
+
+    fn get_i32(&self, m: &TestUnknownFields) -> i32 {
+        m.get_a()
+    }
+
+    fn clear_field(&self, m: &mut TestUnknownFields) {
+        m.clear_a();
+    }
+
+    fn set_i32(&self, m: &mut TestUnknownFields, v: i32) {
+        m.set_a(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestSelfReference {
+    r1: ::protobuf::SingularField<TestSelfReference>,
+    r2: ::protobuf::SingularField<TestSelfReference>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestSelfReference {
+    pub fn new() -> TestSelfReference {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestSelfReference {
+        static mut instance: ::protobuf::lazy::Lazy<TestSelfReference> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestSelfReference };
+        unsafe {
+            instance.get(|| {
+                TestSelfReference {
+                    r1: ::protobuf::SingularField::none(),
+                    r2: ::protobuf::SingularField::none(),
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.r1.as_ref() {
+            Some(ref v) => {
+                os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+            },
+            None => {},
+        };
+        match self.r2.as_ref() {
+            Some(ref v) => {
+                os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_r1(&mut self) {
+        self.r1.clear();
+    }
+
+    pub fn has_r1(&self) -> bool {
+        self.r1.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_r1(&mut self, v: TestSelfReference) {
+        self.r1 = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_r1(&'a mut self) -> &'a mut TestSelfReference {
+        if self.r1.is_none() {
+            self.r1.set_default();
+        };
+        self.r1.get_mut_ref()
+    }
+
+    pub fn get_r1(&'a self) -> &'a TestSelfReference {
+        self.r1.as_ref().unwrap_or_else(|| TestSelfReference::default_instance())
+    }
+
+    pub fn clear_r2(&mut self) {
+        self.r2.clear();
     }
 
     pub fn has_r2(&self) -> bool {
@@ -1768,32 +2501,74 @@ impl ::protobuf::Message for TestSelfReference {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
         if self.r1.is_none() {
             return false;
         };
+        for v in self.r1.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.r2.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.r1.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.r2.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestSelfReference) {
+        use protobuf::{Message};
+        if other.r1.is_some() {
+            if self.r1.is_none() {
+                self.r1.set_default();
+            };
+            self.r1.get_mut_ref().merge_from_message(other.r1.get_ref());
+        };
+        if other.r2.is_some() {
+            if self.r2.is_none() {
+                self.r2.set_default();
+            };
+            self.r2.get_mut_ref().merge_from_message(other.r2.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1817,7 +2592,7 @@ impl ::protobuf::Message for TestSelfReference {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1852,7 +2627,8 @@ impl ::protobuf::Message for TestSelfReference {
                 ::protobuf::reflect::MessageDescriptor::new::<TestSelfReference>(
                     "TestSelfReference",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1886,6 +2662,10 @@ impl ::protobuf::reflect::FieldAccessor<TestSelfReference> for TestSelfReference
         "r1"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestSelfReference>()
+    }
+
     fn has_field(&self, m: &TestSelfReference) -> bool {
         m.has_r1()
     }
@@ -1893,6 +2673,14 @@ impl ::protobuf::reflect::FieldAccessor<TestSelfReference> for TestSelfReference
     fn get_message<'a>(&self, m: &'a TestSelfReference) -> &'a ::protobuf::Message {
         m.get_r1() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut TestSelfReference) {
+        m.clear_r1();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestSelfReference) -> &'a mut ::protobuf::Message {
+        m.mut_r1() as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1903,6 +2691,10 @@ impl ::protobuf::reflect::FieldAccessor<TestSelfReference> for TestSelfReference
         "r2"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestSelfReference>()
+    }
+
     fn has_field(&self, m: &TestSelfReference) -> bool {
         m.has_r2()
     }
@@ -1910,6 +2702,14 @@ impl ::protobuf::reflect::FieldAccessor<TestSelfReference> for TestSelfReference
     fn get_message<'a>(&self, m: &'a TestSelfReference) -> &'a ::protobuf::Message {
         m.get_r2() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut TestSelfReference) {
+        m.clear_r2();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestSelfReference) -> &'a mut ::protobuf::Message {
+        m.mut_r2() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -1986,21 +2786,38 @@ impl ::protobuf::Message for TestDefaultInstanceField {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.s = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestDefaultInstanceField) {
+        if other.s.is_some() {
+            self.s = other.s.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -2019,7 +2836,7 @@ impl ::protobuf::Message for TestDefaultInstanceField {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -2053,7 +2870,8 @@ impl ::protobuf::Message for TestDefaultInstanceField {
                 ::protobuf::reflect::MessageDescriptor::new::<TestDefaultInstanceField>(
                     "TestDefaultInstanceField",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -2093,6 +2911,14 @@ impl ::protobuf::reflect::FieldAccessor<TestDefaultInstanceField> for TestDefaul
     fn get_str<'a>(&self, m: &'a TestDefaultInstanceField) -> &'a str {
         m.get_s()
     }
+
+    fn clear_field(&self, m: &mut TestDefaultInstanceField) {
+        m.clear_s();
+    }
+
+    fn set_str(&self, m: &mut TestDefaultInstanceField, v: String) {
+        m.set_s(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -2165,24 +2991,51 @@ impl ::protobuf::Message for TestDefaultInstance {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.field.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.field.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestDefaultInstance) {
+        use protobuf::{Message};
+        if other.field.is_some() {
+            if self.field.is_none() {
+                self.field.set_default();
+            };
+            self.field.get_mut_ref().merge_from_message(other.field.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -2202,7 +3055,7 @@ impl ::protobuf::Message for TestDefaultInstance {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -2236,7 +3089,8 @@ impl ::protobuf::Message for TestDefaultInstance {
                 ::protobuf::reflect::MessageDescriptor::new::<TestDefaultInstance>(
                     "TestDefaultInstance",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -2269,6 +3123,10 @@ impl ::protobuf::reflect::FieldAccessor<TestDefaultInstance> for TestDefaultInst
         "field"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestDefaultInstanceField>()
+    }
+
     fn has_field(&self, m: &TestDefaultInstance) -> bool {
         m.has_field()
     }
@@ -2276,6 +3134,14 @@ impl ::protobuf::reflect::FieldAccessor<TestDefaultInstance> for TestDefaultInst
     fn get_message<'a>(&self, m: &'a TestDefaultInstance) -> &'a ::protobuf::Message {
         m.get_field() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut TestDefaultInstance) {
+        m.clear_field();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestDefaultInstance) -> &'a mut ::protobuf::Message {
+        m.mut_field() as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -2349,21 +3215,38 @@ impl ::protobuf::Message for TestDescriptor {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.stuff = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestDescriptor) {
+        if other.stuff.is_some() {
+            self.stuff = other.stuff.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -2382,7 +3265,7 @@ impl ::protobuf::Message for TestDescriptor {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -2416,7 +3299,8 @@ impl ::protobuf::Message for TestDescriptor {
                 ::protobuf::reflect::MessageDescriptor::new::<TestDescriptor>(
                     "TestDescriptor",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -2456,6 +3340,14 @@ impl ::protobuf::reflect::FieldAccessor<TestDescriptor> for TestDescriptor_stuff
     fn get_i32(&self, m: &TestDescriptor) -> i32 {
         m.get_stuff()
     }
+
+    fn clear_field(&self, m: &mut TestDescriptor) {
+        m.clear_stuff();
+    }
+
+    fn set_i32(&self, m: &mut TestDescriptor, v: i32) {
+        m.set_stuff(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -3011,91 +3903,206 @@ impl ::protobuf::Message for TestTypesSingular {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_double();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
                     self.double_field = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                    let tmp = is.read_float();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_float());
                     self.float_field = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.int32_field = Some(tmp);
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int64());
                     self.int64_field = Some(tmp);
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint32());
                     self.uint32_field = Some(tmp);
                 },
                 6 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint64());
                     self.uint64_field = Some(tmp);
                 },
                 7 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_sint32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sint32());
                     self.sint32_field = Some(tmp);
                 },
                 8 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_sint64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sint64());
                     self.sint64_field = Some(tmp);
                 },
                 9 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                    let tmp = is.read_fixed32();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_fixed32());
                     self.fixed32_field = Some(tmp);
                 },
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_fixed64();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_fixed64());
                     self.fixed64_field = Some(tmp);
                 },
                 11 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                    let tmp = is.read_sfixed32();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sfixed32());
                     self.sfixed32_field = Some(tmp);
                 },
                 12 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_sfixed64();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sfixed64());
                     self.sfixed64_field = Some(tmp);
                 },
                 13 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.bool_field = Some(tmp);
                 },
                 14 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.string_field = Some(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_bytes();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
                     self.bytes_field = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestTypesSingular) {
+        if other.double_field.is_some() {
+            self.double_field = other.double_field.clone();
+        };
+        if other.float_field.is_some() {
+            self.float_field = other.float_field.clone();
+        };
+        if other.int32_field.is_some() {
+            self.int32_field = other.int32_field.clone();
+        };
+        if other.int64_field.is_some() {
+            self.int64_field = other.int64_field.clone();
+        };
+        if other.uint32_field.is_some() {
+            self.uint32_field = other.uint32_field.clone();
+        };
+        if other.uint64_field.is_some() {
+            self.uint64_field = other.uint64_field.clone();
+        };
+        if other.sint32_field.is_some() {
+            self.sint32_field = other.sint32_field.clone();
+        };
+        if other.sint64_field.is_some() {
+            self.sint64_field = other.sint64_field.clone();
+        };
+        if other.fixed32_field.is_some() {
+            self.fixed32_field = other.fixed32_field.clone();
+        };
+        if other.fixed64_field.is_some() {
+            self.fixed64_field = other.fixed64_field.clone();
+        };
+        if other.sfixed32_field.is_some() {
+            self.sfixed32_field = other.sfixed32_field.clone();
+        };
+        if other.sfixed64_field.is_some() {
+            self.sfixed64_field = other.sfixed64_field.clone();
+        };
+        if other.bool_field.is_some() {
+            self.bool_field = other.bool_field.clone();
+        };
+        if other.string_field.is_some() {
+            self.string_field = other.string_field.clone();
+        };
+        if other.bytes_field.is_some() {
+            self.bytes_field = other.bytes_field.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -3156,7 +4163,7 @@ impl ::protobuf::Message for TestTypesSingular {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -3204,7 +4211,8 @@ impl ::protobuf::Message for TestTypesSingular {
                 ::protobuf::reflect::MessageDescriptor::new::<TestTypesSingular>(
                     "TestTypesSingular",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -3258,6 +4266,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_f64(&self, m: &TestTypesSingular) -> f64 {
         m.get_double_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_double_field();
+    }
+
+    fn set_f64(&self, m: &mut TestTypesSingular, v: f64) {
+        m.set_double_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3275,6 +4291,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_f32(&self, m: &TestTypesSingular) -> f32 {
         m.get_float_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_float_field();
+    }
+
+    fn set_f32(&self, m: &mut TestTypesSingular, v: f32) {
+        m.set_float_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3292,6 +4316,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_i32(&self, m: &TestTypesSingular) -> i32 {
         m.get_int32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_int32_field();
+    }
+
+    fn set_i32(&self, m: &mut TestTypesSingular, v: i32) {
+        m.set_int32_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3309,6 +4341,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_i64(&self, m: &TestTypesSingular) -> i64 {
         m.get_int64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_int64_field();
+    }
+
+    fn set_i64(&self, m: &mut TestTypesSingular, v: i64) {
+        m.set_int64_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3326,6 +4366,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_u32(&self, m: &TestTypesSingular) -> u32 {
         m.get_uint32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_uint32_field();
+    }
+
+    fn set_u32(&self, m: &mut TestTypesSingular, v: u32) {
+        m.set_uint32_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3343,6 +4391,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_u64(&self, m: &TestTypesSingular) -> u64 {
         m.get_uint64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_uint64_field();
+    }
+
+    fn set_u64(&self, m: &mut TestTypesSingular, v: u64) {
+        m.set_uint64_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3360,6 +4416,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_i32(&self, m: &TestTypesSingular) -> i32 {
         m.get_sint32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_sint32_field();
+    }
+
+    fn set_i32(&self, m: &mut TestTypesSingular, v: i32) {
+        m.set_sint32_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3377,6 +4441,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_i64(&self, m: &TestTypesSingular) -> i64 {
         m.get_sint64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_sint64_field();
+    }
+
+    fn set_i64(&self, m: &mut TestTypesSingular, v: i64) {
+        m.set_sint64_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3394,6 +4466,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_u32(&self, m: &TestTypesSingular) -> u32 {
         m.get_fixed32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_fixed32_field();
+    }
+
+    fn set_u32(&self, m: &mut TestTypesSingular, v: u32) {
+        m.set_fixed32_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3411,6 +4491,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_u64(&self, m: &TestTypesSingular) -> u64 {
         m.get_fixed64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_fixed64_field();
+    }
+
+    fn set_u64(&self, m: &mut TestTypesSingular, v: u64) {
+        m.set_fixed64_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3428,6 +4516,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_i32(&self, m: &TestTypesSingular) -> i32 {
         m.get_sfixed32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_sfixed32_field();
+    }
+
+    fn set_i32(&self, m: &mut TestTypesSingular, v: i32) {
+        m.set_sfixed32_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3445,6 +4541,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_i64(&self, m: &TestTypesSingular) -> i64 {
         m.get_sfixed64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_sfixed64_field();
+    }
+
+    fn set_i64(&self, m: &mut TestTypesSingular, v: i64) {
+        m.set_sfixed64_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3462,6 +4566,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_bool(&self, m: &TestTypesSingular) -> bool {
         m.get_bool_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_bool_field();
+    }
+
+    fn set_bool(&self, m: &mut TestTypesSingular, v: bool) {
+        m.set_bool_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3479,6 +4591,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_str<'a>(&self, m: &'a TestTypesSingular) -> &'a str {
         m.get_string_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_string_field();
+    }
+
+    fn set_str(&self, m: &mut TestTypesSingular, v: String) {
+        m.set_string_field(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -3496,6 +4616,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesSingular> for TestTypesSingular
     fn get_bytes<'a>(&self, m: &'a TestTypesSingular) -> &'a [u8] {
         m.get_bytes_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesSingular) {
+        m.clear_bytes_field();
+    }
+
+    fn set_bytes(&self, m: &mut TestTypesSingular, v: Vec<u8>) {
+        m.set_bytes_field(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -3940,195 +5068,280 @@ impl ::protobuf::Message for TestTypesRepeated {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.double_field.push(is.read_double());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.double_field.push(try!(is.read_double()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.double_field.push(is.read_double());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.double_field.push(try!(is.read_double()));
                     }
                 },
                 2 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.float_field.push(is.read_float());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.float_field.push(try!(is.read_float()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.float_field.push(is.read_float());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.float_field.push(try!(is.read_float()));
                     }
                 },
                 3 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.int32_field.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.int32_field.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.int32_field.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.int32_field.push(try!(is.read_int32()));
                     }
                 },
                 4 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.int64_field.push(is.read_int64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.int64_field.push(try!(is.read_int64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.int64_field.push(is.read_int64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.int64_field.push(try!(is.read_int64()));
                     }
                 },
                 5 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.uint32_field.push(is.read_uint32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.uint32_field.push(try!(is.read_uint32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.uint32_field.push(is.read_uint32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.uint32_field.push(try!(is.read_uint32()));
                     }
                 },
                 6 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.uint64_field.push(is.read_uint64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.uint64_field.push(try!(is.read_uint64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.uint64_field.push(is.read_uint64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.uint64_field.push(try!(is.read_uint64()));
                     }
                 },
                 7 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sint32_field.push(is.read_sint32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sint32_field.push(try!(is.read_sint32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.sint32_field.push(is.read_sint32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sint32_field.push(try!(is.read_sint32()));
                     }
                 },
                 8 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sint64_field.push(is.read_sint64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sint64_field.push(try!(is.read_sint64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.sint64_field.push(is.read_sint64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sint64_field.push(try!(is.read_sint64()));
                     }
                 },
                 9 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.fixed32_field.push(is.read_fixed32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.fixed32_field.push(try!(is.read_fixed32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.fixed32_field.push(is.read_fixed32());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.fixed32_field.push(try!(is.read_fixed32()));
                     }
                 },
                 10 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.fixed64_field.push(is.read_fixed64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.fixed64_field.push(try!(is.read_fixed64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.fixed64_field.push(is.read_fixed64());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.fixed64_field.push(try!(is.read_fixed64()));
                     }
                 },
                 11 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sfixed32_field.push(is.read_sfixed32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sfixed32_field.push(try!(is.read_sfixed32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.sfixed32_field.push(is.read_sfixed32());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sfixed32_field.push(try!(is.read_sfixed32()));
                     }
                 },
                 12 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sfixed64_field.push(is.read_sfixed64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sfixed64_field.push(try!(is.read_sfixed64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.sfixed64_field.push(is.read_sfixed64());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sfixed64_field.push(try!(is.read_sfixed64()));
                     }
                 },
                 13 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.bool_field.push(is.read_bool());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.bool_field.push(try!(is.read_bool()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.bool_field.push(is.read_bool());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.bool_field.push(try!(is.read_bool()));
                     }
                 },
                 14 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.string_field.push(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_bytes();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
                     self.bytes_field.push(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestTypesRepeated) {
+        self.double_field.push_all(other.double_field.as_slice());
+        self.float_field.push_all(other.float_field.as_slice());
+        self.int32_field.push_all(other.int32_field.as_slice());
+        self.int64_field.push_all(other.int64_field.as_slice());
+        self.uint32_field.push_all(other.uint32_field.as_slice());
+        self.uint64_field.push_all(other.uint64_field.as_slice());
+        self.sint32_field.push_all(other.sint32_field.as_slice());
+        self.sint64_field.push_all(other.sint64_field.as_slice());
+        self.fixed32_field.push_all(other.fixed32_field.as_slice());
+        self.fixed64_field.push_all(other.fixed64_field.as_slice());
+        self.sfixed32_field.push_all(other.sfixed32_field.as_slice());
+        self.sfixed64_field.push_all(other.sfixed64_field.as_slice());
+        self.bool_field.push_all(other.bool_field.as_slice());
+        self.string_field.push_all(other.string_field.as_slice());
+        self.bytes_field.push_all(other.bytes_field.as_slice());
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -4175,7 +5388,7 @@ impl ::protobuf::Message for TestTypesRepeated {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -4223,7 +5436,8 @@ impl ::protobuf::Message for TestTypesRepeated {
                 ::protobuf::reflect::MessageDescriptor::new::<TestTypesRepeated>(
                     "TestTypesRepeated",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -4277,6 +5491,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_f64<'a>(&self, m: &'a TestTypesRepeated) -> &'a [f64] {
         m.get_double_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_double_field();
+    }
+
+    fn add_f64(&self, m: &mut TestTypesRepeated, v: f64) {
+        m.add_double_field(v);
+    }
+
+    fn mut_rep_f64<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<f64> {
+        m.mut_double_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4294,6 +5520,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_f32<'a>(&self, m: &'a TestTypesRepeated) -> &'a [f32] {
         m.get_float_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_float_field();
+    }
+
+    fn add_f32(&self, m: &mut TestTypesRepeated, v: f32) {
+        m.add_float_field(v);
+    }
+
+    fn mut_rep_f32<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<f32> {
+        m.mut_float_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4311,6 +5549,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_i32<'a>(&self, m: &'a TestTypesRepeated) -> &'a [i32] {
         m.get_int32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_int32_field();
+    }
+
+    fn add_i32(&self, m: &mut TestTypesRepeated, v: i32) {
+        m.add_int32_field(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<i32> {
+        m.mut_int32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4328,6 +5578,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_i64<'a>(&self, m: &'a TestTypesRepeated) -> &'a [i64] {
         m.get_int64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_int64_field();
+    }
+
+    fn add_i64(&self, m: &mut TestTypesRepeated, v: i64) {
+        m.add_int64_field(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<i64> {
+        m.mut_int64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4345,6 +5607,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_u32<'a>(&self, m: &'a TestTypesRepeated) -> &'a [u32] {
         m.get_uint32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_uint32_field();
+    }
+
+    fn add_u32(&self, m: &mut TestTypesRepeated, v: u32) {
+        m.add_uint32_field(v);
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<u32> {
+        m.mut_uint32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4362,6 +5636,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_u64<'a>(&self, m: &'a TestTypesRepeated) -> &'a [u64] {
         m.get_uint64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_uint64_field();
+    }
+
+    fn add_u64(&self, m: &mut TestTypesRepeated, v: u64) {
+        m.add_uint64_field(v);
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<u64> {
+        m.mut_uint64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4379,6 +5665,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_i32<'a>(&self, m: &'a TestTypesRepeated) -> &'a [i32] {
         m.get_sint32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_sint32_field();
+    }
+
+    fn add_i32(&self, m: &mut TestTypesRepeated, v: i32) {
+        m.add_sint32_field(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<i32> {
+        m.mut_sint32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4396,6 +5694,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_i64<'a>(&self, m: &'a TestTypesRepeated) -> &'a [i64] {
         m.get_sint64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_sint64_field();
+    }
+
+    fn add_i64(&self, m: &mut TestTypesRepeated, v: i64) {
+        m.add_sint64_field(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<i64> {
+        m.mut_sint64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4413,6 +5723,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_u32<'a>(&self, m: &'a TestTypesRepeated) -> &'a [u32] {
         m.get_fixed32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_fixed32_field();
+    }
+
+    fn add_u32(&self, m: &mut TestTypesRepeated, v: u32) {
+        m.add_fixed32_field(v);
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<u32> {
+        m.mut_fixed32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4430,6 +5752,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_u64<'a>(&self, m: &'a TestTypesRepeated) -> &'a [u64] {
         m.get_fixed64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_fixed64_field();
+    }
+
+    fn add_u64(&self, m: &mut TestTypesRepeated, v: u64) {
+        m.add_fixed64_field(v);
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<u64> {
+        m.mut_fixed64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4447,6 +5781,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_i32<'a>(&self, m: &'a TestTypesRepeated) -> &'a [i32] {
         m.get_sfixed32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_sfixed32_field();
+    }
+
+    fn add_i32(&self, m: &mut TestTypesRepeated, v: i32) {
+        m.add_sfixed32_field(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<i32> {
+        m.mut_sfixed32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4464,6 +5810,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_i64<'a>(&self, m: &'a TestTypesRepeated) -> &'a [i64] {
         m.get_sfixed64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_sfixed64_field();
+    }
+
+    fn add_i64(&self, m: &mut TestTypesRepeated, v: i64) {
+        m.add_sfixed64_field(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<i64> {
+        m.mut_sfixed64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4481,6 +5839,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_bool<'a>(&self, m: &'a TestTypesRepeated) -> &'a [bool] {
         m.get_bool_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_bool_field();
+    }
+
+    fn add_bool(&self, m: &mut TestTypesRepeated, v: bool) {
+        m.add_bool_field(v);
+    }
+
+    fn mut_rep_bool<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<bool> {
+        m.mut_bool_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4498,6 +5868,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_str<'a>(&self, m: &'a TestTypesRepeated) -> &'a [String] {
         m.get_string_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_string_field();
+    }
+
+    fn add_str(&self, m: &mut TestTypesRepeated, v: String) {
+        m.add_string_field(v);
+    }
+
+    fn mut_rep_str<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<String> {
+        m.mut_string_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -4515,6 +5897,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeated> for TestTypesRepeated
     fn get_rep_bytes<'a>(&self, m: &'a TestTypesRepeated) -> &'a [Vec<u8>] {
         m.get_bytes_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeated) {
+        m.clear_bytes_field();
+    }
+
+    fn add_bytes(&self, m: &mut TestTypesRepeated, v: Vec<u8>) {
+        m.add_bytes_field(v);
+    }
+
+    fn mut_rep_bytes<'a>(&self, m: &'a mut TestTypesRepeated) -> &'a mut Vec<Vec<u8>> {
+        m.mut_bytes_field()
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -5011,195 +6405,280 @@ impl ::protobuf::Message for TestTypesRepeatedPacked {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.double_field.push(is.read_double());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.double_field.push(try!(is.read_double()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.double_field.push(is.read_double());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.double_field.push(try!(is.read_double()));
                     }
                 },
                 2 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.float_field.push(is.read_float());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.float_field.push(try!(is.read_float()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.float_field.push(is.read_float());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.float_field.push(try!(is.read_float()));
                     }
                 },
                 3 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.int32_field.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.int32_field.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.int32_field.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.int32_field.push(try!(is.read_int32()));
                     }
                 },
                 4 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.int64_field.push(is.read_int64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.int64_field.push(try!(is.read_int64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.int64_field.push(is.read_int64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.int64_field.push(try!(is.read_int64()));
                     }
                 },
                 5 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.uint32_field.push(is.read_uint32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.uint32_field.push(try!(is.read_uint32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.uint32_field.push(is.read_uint32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.uint32_field.push(try!(is.read_uint32()));
                     }
                 },
                 6 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.uint64_field.push(is.read_uint64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.uint64_field.push(try!(is.read_uint64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.uint64_field.push(is.read_uint64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.uint64_field.push(try!(is.read_uint64()));
                     }
                 },
                 7 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sint32_field.push(is.read_sint32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sint32_field.push(try!(is.read_sint32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.sint32_field.push(is.read_sint32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sint32_field.push(try!(is.read_sint32()));
                     }
                 },
                 8 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sint64_field.push(is.read_sint64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sint64_field.push(try!(is.read_sint64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.sint64_field.push(is.read_sint64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sint64_field.push(try!(is.read_sint64()));
                     }
                 },
                 9 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.fixed32_field.push(is.read_fixed32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.fixed32_field.push(try!(is.read_fixed32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.fixed32_field.push(is.read_fixed32());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.fixed32_field.push(try!(is.read_fixed32()));
                     }
                 },
                 10 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.fixed64_field.push(is.read_fixed64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.fixed64_field.push(try!(is.read_fixed64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.fixed64_field.push(is.read_fixed64());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.fixed64_field.push(try!(is.read_fixed64()));
                     }
                 },
                 11 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sfixed32_field.push(is.read_sfixed32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sfixed32_field.push(try!(is.read_sfixed32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.sfixed32_field.push(is.read_sfixed32());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sfixed32_field.push(try!(is.read_sfixed32()));
                     }
                 },
                 12 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sfixed64_field.push(is.read_sfixed64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sfixed64_field.push(try!(is.read_sfixed64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.sfixed64_field.push(is.read_sfixed64());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sfixed64_field.push(try!(is.read_sfixed64()));
                     }
                 },
                 13 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.bool_field.push(is.read_bool());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.bool_field.push(try!(is.read_bool()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.bool_field.push(is.read_bool());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.bool_field.push(try!(is.read_bool()));
                     }
                 },
                 14 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.string_field.push(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_bytes();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
                     self.bytes_field.push(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestTypesRepeatedPacked) {
+        self.double_field.push_all(other.double_field.as_slice());
+        self.float_field.push_all(other.float_field.as_slice());
+        self.int32_field.push_all(other.int32_field.as_slice());
+        self.int64_field.push_all(other.int64_field.as_slice());
+        self.uint32_field.push_all(other.uint32_field.as_slice());
+        self.uint64_field.push_all(other.uint64_field.as_slice());
+        self.sint32_field.push_all(other.sint32_field.as_slice());
+        self.sint64_field.push_all(other.sint64_field.as_slice());
+        self.fixed32_field.push_all(other.fixed32_field.as_slice());
+        self.fixed64_field.push_all(other.fixed64_field.as_slice());
+        self.sfixed32_field.push_all(other.sfixed32_field.as_slice());
+        self.sfixed64_field.push_all(other.sfixed64_field.as_slice());
+        self.bool_field.push_all(other.bool_field.as_slice());
+        self.string_field.push_all(other.string_field.as_slice());
+        self.bytes_field.push_all(other.bytes_field.as_slice());
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -5260,7 +6739,7 @@ impl ::protobuf::Message for TestTypesRepeatedPacked {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -5308,7 +6787,8 @@ impl ::protobuf::Message for TestTypesRepeatedPacked {
                 ::protobuf::reflect::MessageDescriptor::new::<TestTypesRepeatedPacked>(
                     "TestTypesRepeatedPacked",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -5362,6 +6842,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_f64<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [f64] {
         m.get_double_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_double_field();
+    }
+
+    fn add_f64(&self, m: &mut TestTypesRepeatedPacked, v: f64) {
+        m.add_double_field(v);
+    }
+
+    fn mut_rep_f64<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<f64> {
+        m.mut_double_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5379,6 +6871,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_f32<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [f32] {
         m.get_float_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_float_field();
+    }
+
+    fn add_f32(&self, m: &mut TestTypesRepeatedPacked, v: f32) {
+        m.add_float_field(v);
+    }
+
+    fn mut_rep_f32<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<f32> {
+        m.mut_float_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5396,6 +6900,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_i32<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [i32] {
         m.get_int32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_int32_field();
+    }
+
+    fn add_i32(&self, m: &mut TestTypesRepeatedPacked, v: i32) {
+        m.add_int32_field(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<i32> {
+        m.mut_int32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5413,6 +6929,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_i64<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [i64] {
         m.get_int64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_int64_field();
+    }
+
+    fn add_i64(&self, m: &mut TestTypesRepeatedPacked, v: i64) {
+        m.add_int64_field(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<i64> {
+        m.mut_int64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5430,6 +6958,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_u32<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [u32] {
         m.get_uint32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_uint32_field();
+    }
+
+    fn add_u32(&self, m: &mut TestTypesRepeatedPacked, v: u32) {
+        m.add_uint32_field(v);
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<u32> {
+        m.mut_uint32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5447,6 +6987,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_u64<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [u64] {
         m.get_uint64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_uint64_field();
+    }
+
+    fn add_u64(&self, m: &mut TestTypesRepeatedPacked, v: u64) {
+        m.add_uint64_field(v);
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<u64> {
+        m.mut_uint64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5464,6 +7016,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_i32<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [i32] {
         m.get_sint32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_sint32_field();
+    }
+
+    fn add_i32(&self, m: &mut TestTypesRepeatedPacked, v: i32) {
+        m.add_sint32_field(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<i32> {
+        m.mut_sint32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5481,6 +7045,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_i64<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [i64] {
         m.get_sint64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_sint64_field();
+    }
+
+    fn add_i64(&self, m: &mut TestTypesRepeatedPacked, v: i64) {
+        m.add_sint64_field(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<i64> {
+        m.mut_sint64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5498,6 +7074,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_u32<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [u32] {
         m.get_fixed32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_fixed32_field();
+    }
+
+    fn add_u32(&self, m: &mut TestTypesRepeatedPacked, v: u32) {
+        m.add_fixed32_field(v);
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<u32> {
+        m.mut_fixed32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5515,6 +7103,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_u64<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [u64] {
         m.get_fixed64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_fixed64_field();
+    }
+
+    fn add_u64(&self, m: &mut TestTypesRepeatedPacked, v: u64) {
+        m.add_fixed64_field(v);
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<u64> {
+        m.mut_fixed64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5532,6 +7132,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_i32<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [i32] {
         m.get_sfixed32_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_sfixed32_field();
+    }
+
+    fn add_i32(&self, m: &mut TestTypesRepeatedPacked, v: i32) {
+        m.add_sfixed32_field(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<i32> {
+        m.mut_sfixed32_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5549,6 +7161,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_i64<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [i64] {
         m.get_sfixed64_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_sfixed64_field();
+    }
+
+    fn add_i64(&self, m: &mut TestTypesRepeatedPacked, v: i64) {
+        m.add_sfixed64_field(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<i64> {
+        m.mut_sfixed64_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5566,6 +7190,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_bool<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [bool] {
         m.get_bool_field()
     }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_bool_field();
+    }
+
+    fn add_bool(&self, m: &mut TestTypesRepeatedPacked, v: bool) {
+        m.add_bool_field(v);
+    }
+
+    fn mut_rep_bool<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<bool> {
+        m.mut_bool_field()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -5583,22 +7219,4173 @@ impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRe
     fn get_rep_str<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [String] {
         m.get_string_field()
     }
-}
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_string_field();
+    }
+
+    fn add_str(&self, m: &mut TestTypesRepeatedPacked, v: String) {
+        m.add_string_field(v);
+    }
+
+    fn mut_rep_str<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<String> {
+        m.mut_string_field()
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestTypesRepeatedPacked_bytes_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRepeatedPacked_bytes_field_acc {
+    fn name(&self) -> &'static str {
+        "bytes_field"
+    }
+
+    fn len_field(&self, m: &TestTypesRepeatedPacked) -> uint {
+        m.get_bytes_field().len()
+    }
+
+    fn get_rep_bytes<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [Vec<u8>] {
+        m.get_bytes_field()
+    }
+
+    fn clear_field(&self, m: &mut TestTypesRepeatedPacked) {
+        m.clear_bytes_field();
+    }
+
+    fn add_bytes(&self, m: &mut TestTypesRepeatedPacked, v: Vec<u8>) {
+        m.add_bytes_field(v);
+    }
+
+    fn mut_rep_bytes<'a>(&self, m: &'a mut TestTypesRepeatedPacked) -> &'a mut Vec<Vec<u8>> {
+        m.mut_bytes_field()
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestGroup {
+    optionalgroup: ::protobuf::SingularField<TestGroup_OptionalGroup>,
+    repeatedgroup: ::protobuf::RepeatedField<TestGroup_RepeatedGroup>,
+    c: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestGroup {
+    pub fn new() -> TestGroup {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestGroup {
+        static mut instance: ::protobuf::lazy::Lazy<TestGroup> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestGroup };
+        unsafe {
+            instance.get(|| {
+                TestGroup {
+                    optionalgroup: ::protobuf::SingularField::none(),
+                    repeatedgroup: ::protobuf::RepeatedField::new(),
+                    c: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.optionalgroup.as_ref() {
+            Some(ref v) => {
+                os.write_tag(1, ::protobuf::wire_format::WireTypeStartGroup);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+                os.write_tag(1, ::protobuf::wire_format::WireTypeEndGroup);
+            },
+            None => {},
+        };
+        for v in self.repeatedgroup.iter() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeStartGroup);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+            os.write_tag(3, ::protobuf::wire_format::WireTypeEndGroup);
+        };
+        match self.c {
+            Some(ref v) => {
+                os.write_int32(6, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_optionalgroup(&mut self) {
+        self.optionalgroup.clear();
+    }
+
+    pub fn has_optionalgroup(&self) -> bool {
+        self.optionalgroup.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_optionalgroup(&mut self, v: TestGroup_OptionalGroup) {
+        self.optionalgroup = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_optionalgroup(&'a mut self) -> &'a mut TestGroup_OptionalGroup {
+        if self.optionalgroup.is_none() {
+            self.optionalgroup.set_default();
+        };
+        self.optionalgroup.get_mut_ref()
+    }
+
+    pub fn get_optionalgroup(&'a self) -> &'a TestGroup_OptionalGroup {
+        self.optionalgroup.as_ref().unwrap_or_else(|| TestGroup_OptionalGroup::default_instance())
+    }
+
+    pub fn clear_repeatedgroup(&mut self) {
+        self.repeatedgroup.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_repeatedgroup(&mut self, v: ::protobuf::RepeatedField<TestGroup_RepeatedGroup>) {
+        self.repeatedgroup = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_repeatedgroup(&'a mut self) -> &'a mut ::protobuf::RepeatedField<TestGroup_RepeatedGroup> {
+        &mut self.repeatedgroup
+    }
+
+    pub fn get_repeatedgroup(&'a self) -> &'a [TestGroup_RepeatedGroup] {
+        self.repeatedgroup.as_slice()
+    }
+
+    pub fn add_repeatedgroup(&mut self, v: TestGroup_RepeatedGroup) {
+        self.repeatedgroup.push(v);
+    }
+
+    pub fn clear_c(&mut self) {
+        self.c = None;
+    }
+
+    pub fn has_c(&self) -> bool {
+        self.c.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_c(&mut self, v: i32) {
+        self.c = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_c(&'a mut self) -> &'a mut i32 {
+        if self.c.is_none() {
+            self.c = Some(0);
+        };
+        self.c.get_mut_ref()
+    }
+
+    pub fn get_c(&self) -> i32 {
+        self.c.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestGroup {
+    fn new() -> TestGroup {
+        TestGroup::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.optionalgroup.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.repeatedgroup.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeStartGroup {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.optionalgroup.set_default();
+                    try!(is.merge_group(field_number, tmp));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeStartGroup {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.repeatedgroup.push_default();
+                    try!(is.merge_group(field_number, tmp));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.c = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestGroup) {
+        use protobuf::{Message};
+        if other.optionalgroup.is_some() {
+            if self.optionalgroup.is_none() {
+                self.optionalgroup.set_default();
+            };
+            self.optionalgroup.get_mut_ref().merge_from_message(other.optionalgroup.get_ref());
+        };
+        for v in other.repeatedgroup.iter() {
+            self.repeatedgroup.push(v.clone());
+        };
+        if other.c.is_some() {
+            self.c = other.c.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.optionalgroup.iter() {
+            my_size += 2 + value.compute_sizes(sizes);
+        };
+        for value in self.repeatedgroup.iter() {
+            my_size += 2 + value.compute_sizes(sizes);
+        };
+        for value in self.c.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestGroup>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestGroup>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestGroup_optionalgroup_acc as &::protobuf::reflect::FieldAccessor<TestGroup>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestGroup_repeatedgroup_acc as &::protobuf::reflect::FieldAccessor<TestGroup>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestGroup_c_acc as &::protobuf::reflect::FieldAccessor<TestGroup>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestGroup>(
+                    "TestGroup",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestGroup>()
+    }
+}
+
+impl ::protobuf::Clear for TestGroup {
+    fn clear(&mut self) {
+        self.clear_optionalgroup();
+        self.clear_repeatedgroup();
+        self.clear_c();
+    }
+}
+
+impl ::std::fmt::Show for TestGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestGroup_optionalgroup_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestGroup> for TestGroup_optionalgroup_acc {
+    fn name(&self) -> &'static str {
+        "optionalgroup"
+    }
+
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestGroup_OptionalGroup>()
+    }
+
+    fn has_field(&self, m: &TestGroup) -> bool {
+        m.has_optionalgroup()
+    }
+
+    fn get_message<'a>(&self, m: &'a TestGroup) -> &'a ::protobuf::Message {
+        m.get_optionalgroup() as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestGroup) {
+        m.clear_optionalgroup();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestGroup) -> &'a mut ::protobuf::Message {
+        m.mut_optionalgroup() as &'a mut ::protobuf::Message
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestGroup_repeatedgroup_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestGroup> for TestGroup_repeatedgroup_acc {
+    fn name(&self) -> &'static str {
+        "repeatedgroup"
+    }
+
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestGroup_RepeatedGroup>()
+    }
+
+    fn len_field(&self, m: &TestGroup) -> uint {
+        m.get_repeatedgroup().len()
+    }
+
+    fn get_rep_message_item<'a>(&self, m: &'a TestGroup, index: uint) -> &'a ::protobuf::Message {
+        &'a m.get_repeatedgroup()[index] as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestGroup) {
+        m.clear_repeatedgroup();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut TestGroup) -> &'a mut ::protobuf::Message {
+        m.mut_repeatedgroup().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut TestGroup, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_repeatedgroup().get_mut(index) as &'a mut ::protobuf::Message
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestGroup_c_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestGroup> for TestGroup_c_acc {
+    fn name(&self) -> &'static str {
+        "c"
+    }
+
+    fn has_field(&self, m: &TestGroup) -> bool {
+        m.has_c()
+    }
+
+    fn get_i32(&self, m: &TestGroup) -> i32 {
+        m.get_c()
+    }
+
+    fn clear_field(&self, m: &mut TestGroup) {
+        m.clear_c();
+    }
+
+    fn set_i32(&self, m: &mut TestGroup, v: i32) {
+        m.set_c(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestGroup_OptionalGroup {
+    a: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestGroup_OptionalGroup {
+    pub fn new() -> TestGroup_OptionalGroup {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestGroup_OptionalGroup {
+        static mut instance: ::protobuf::lazy::Lazy<TestGroup_OptionalGroup> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestGroup_OptionalGroup };
+        unsafe {
+            instance.get(|| {
+                TestGroup_OptionalGroup {
+                    a: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.a {
+            Some(ref v) => {
+                os.write_int32(2, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_a(&mut self) {
+        self.a = None;
+    }
+
+    pub fn has_a(&self) -> bool {
+        self.a.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_a(&mut self, v: i32) {
+        self.a = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_a(&'a mut self) -> &'a mut i32 {
+        if self.a.is_none() {
+            self.a = Some(0);
+        };
+        self.a.get_mut_ref()
+    }
+
+    pub fn get_a(&self) -> i32 {
+        self.a.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestGroup_OptionalGroup {
+    fn new() -> TestGroup_OptionalGroup {
+        TestGroup_OptionalGroup::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.a = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestGroup_OptionalGroup) {
+        if other.a.is_some() {
+            self.a = other.a.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.a.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestGroup_OptionalGroup>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestGroup_OptionalGroup>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestGroup_OptionalGroup_a_acc as &::protobuf::reflect::FieldAccessor<TestGroup_OptionalGroup>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestGroup_OptionalGroup>(
+                    "TestGroup_OptionalGroup",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestGroup_OptionalGroup>()
+    }
+}
+
+impl ::protobuf::Clear for TestGroup_OptionalGroup {
+    fn clear(&mut self) {
+        self.clear_a();
+    }
+}
+
+impl ::std::fmt::Show for TestGroup_OptionalGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestGroup_OptionalGroup_a_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestGroup_OptionalGroup> for TestGroup_OptionalGroup_a_acc {
+    fn name(&self) -> &'static str {
+        "a"
+    }
+
+    fn has_field(&self, m: &TestGroup_OptionalGroup) -> bool {
+        m.has_a()
+    }
+
+    fn get_i32(&self, m: &TestGroup_OptionalGroup) -> i32 {
+        m.get_a()
+    }
+
+    fn clear_field(&self, m: &mut TestGroup_OptionalGroup) {
+        m.clear_a();
+    }
+
+    fn set_i32(&self, m: &mut TestGroup_OptionalGroup, v: i32) {
+        m.set_a(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestGroup_RepeatedGroup {
+    b: Option<i32>,
+    nested: ::protobuf::SingularField<TestGroup>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestGroup_RepeatedGroup {
+    pub fn new() -> TestGroup_RepeatedGroup {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestGroup_RepeatedGroup {
+        static mut instance: ::protobuf::lazy::Lazy<TestGroup_RepeatedGroup> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestGroup_RepeatedGroup };
+        unsafe {
+            instance.get(|| {
+                TestGroup_RepeatedGroup {
+                    b: None,
+                    nested: ::protobuf::SingularField::none(),
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.b {
+            Some(ref v) => {
+                os.write_int32(4, *v);
+            },
+            None => {},
+        };
+        match self.nested.as_ref() {
+            Some(ref v) => {
+                os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited);
+                os.write_raw_varint32(sizes[*sizes_pos]);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_b(&mut self) {
+        self.b = None;
+    }
+
+    pub fn has_b(&self) -> bool {
+        self.b.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_b(&mut self, v: i32) {
+        self.b = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_b(&'a mut self) -> &'a mut i32 {
+        if self.b.is_none() {
+            self.b = Some(0);
+        };
+        self.b.get_mut_ref()
+    }
+
+    pub fn get_b(&self) -> i32 {
+        self.b.unwrap_or_else(|| 0)
+    }
+
+    pub fn clear_nested(&mut self) {
+        self.nested.clear();
+    }
+
+    pub fn has_nested(&self) -> bool {
+        self.nested.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested(&mut self, v: TestGroup) {
+        self.nested = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nested(&'a mut self) -> &'a mut TestGroup {
+        if self.nested.is_none() {
+            self.nested.set_default();
+        };
+        self.nested.get_mut_ref()
+    }
+
+    pub fn get_nested(&'a self) -> &'a TestGroup {
+        self.nested.as_ref().unwrap_or_else(|| TestGroup::default_instance())
+    }
+}
+
+impl ::protobuf::Message for TestGroup_RepeatedGroup {
+    fn new() -> TestGroup_RepeatedGroup {
+        TestGroup_RepeatedGroup::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.nested.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.b = Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.nested.set_default();
+                    try!(is.merge_message(tmp));
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestGroup_RepeatedGroup) {
+        use protobuf::{Message};
+        if other.b.is_some() {
+            self.b = other.b.clone();
+        };
+        if other.nested.is_some() {
+            if self.nested.is_none() {
+                self.nested.set_default();
+            };
+            self.nested.get_mut_ref().merge_from_message(other.nested.get_ref());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.b.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.nested.iter() {
+            let len = value.compute_sizes(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestGroup_RepeatedGroup>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestGroup_RepeatedGroup>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestGroup_RepeatedGroup_b_acc as &::protobuf::reflect::FieldAccessor<TestGroup_RepeatedGroup>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestGroup_RepeatedGroup_nested_acc as &::protobuf::reflect::FieldAccessor<TestGroup_RepeatedGroup>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestGroup_RepeatedGroup>(
+                    "TestGroup_RepeatedGroup",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestGroup_RepeatedGroup>()
+    }
+}
+
+impl ::protobuf::Clear for TestGroup_RepeatedGroup {
+    fn clear(&mut self) {
+        self.clear_b();
+        self.clear_nested();
+    }
+}
+
+impl ::std::fmt::Show for TestGroup_RepeatedGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestGroup_RepeatedGroup_b_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestGroup_RepeatedGroup> for TestGroup_RepeatedGroup_b_acc {
+    fn name(&self) -> &'static str {
+        "b"
+    }
+
+    fn has_field(&self, m: &TestGroup_RepeatedGroup) -> bool {
+        m.has_b()
+    }
+
+    fn get_i32(&self, m: &TestGroup_RepeatedGroup) -> i32 {
+        m.get_b()
+    }
+
+    fn clear_field(&self, m: &mut TestGroup_RepeatedGroup) {
+        m.clear_b();
+    }
+
+    fn set_i32(&self, m: &mut TestGroup_RepeatedGroup, v: i32) {
+        m.set_b(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestGroup_RepeatedGroup_nested_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestGroup_RepeatedGroup> for TestGroup_RepeatedGroup_nested_acc {
+    fn name(&self) -> &'static str {
+        "nested"
+    }
+
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestGroup>()
+    }
+
+    fn has_field(&self, m: &TestGroup_RepeatedGroup) -> bool {
+        m.has_nested()
+    }
+
+    fn get_message<'a>(&self, m: &'a TestGroup_RepeatedGroup) -> &'a ::protobuf::Message {
+        m.get_nested() as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestGroup_RepeatedGroup) {
+        m.clear_nested();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestGroup_RepeatedGroup) -> &'a mut ::protobuf::Message {
+        m.mut_nested() as &'a mut ::protobuf::Message
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestDefaultValues {
+    double_field: Option<f64>,
+    float_field: Option<f32>,
+    int32_field: Option<i32>,
+    int64_field: Option<i64>,
+    uint32_field: Option<u32>,
+    uint64_field: Option<u64>,
+    sint32_field: Option<i32>,
+    sint64_field: Option<i64>,
+    fixed32_field: Option<u32>,
+    fixed64_field: Option<u64>,
+    sfixed32_field: Option<i32>,
+    sfixed64_field: Option<i64>,
+    bool_field: Option<bool>,
+    string_field: Option<String>,
+    bytes_field: Option<Vec<u8>>,
+    enum_field: Option<EnumForDefaultValue>,
+    enum_field_without_default: Option<EnumForDefaultValue>,
+    double_inf: Option<f64>,
+    float_neg_inf: Option<f32>,
+    double_nan: Option<f64>,
+    double_exp: Option<f64>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestDefaultValues {
+    pub fn new() -> TestDefaultValues {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestDefaultValues {
+        static mut instance: ::protobuf::lazy::Lazy<TestDefaultValues> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestDefaultValues };
+        unsafe {
+            instance.get(|| {
+                TestDefaultValues {
+                    double_field: None,
+                    float_field: None,
+                    int32_field: None,
+                    int64_field: None,
+                    uint32_field: None,
+                    uint64_field: None,
+                    sint32_field: None,
+                    sint64_field: None,
+                    fixed32_field: None,
+                    fixed64_field: None,
+                    sfixed32_field: None,
+                    sfixed64_field: None,
+                    bool_field: None,
+                    string_field: None,
+                    bytes_field: None,
+                    enum_field: None,
+                    enum_field_without_default: None,
+                    double_inf: None,
+                    float_neg_inf: None,
+                    double_nan: None,
+                    double_exp: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.double_field {
+            Some(ref v) => {
+                os.write_double(1, *v);
+            },
+            None => {},
+        };
+        match self.float_field {
+            Some(ref v) => {
+                os.write_float(2, *v);
+            },
+            None => {},
+        };
+        match self.int32_field {
+            Some(ref v) => {
+                os.write_int32(3, *v);
+            },
+            None => {},
+        };
+        match self.int64_field {
+            Some(ref v) => {
+                os.write_int64(4, *v);
+            },
+            None => {},
+        };
+        match self.uint32_field {
+            Some(ref v) => {
+                os.write_uint32(5, *v);
+            },
+            None => {},
+        };
+        match self.uint64_field {
+            Some(ref v) => {
+                os.write_uint64(6, *v);
+            },
+            None => {},
+        };
+        match self.sint32_field {
+            Some(ref v) => {
+                os.write_sint32(7, *v);
+            },
+            None => {},
+        };
+        match self.sint64_field {
+            Some(ref v) => {
+                os.write_sint64(8, *v);
+            },
+            None => {},
+        };
+        match self.fixed32_field {
+            Some(ref v) => {
+                os.write_fixed32(9, *v);
+            },
+            None => {},
+        };
+        match self.fixed64_field {
+            Some(ref v) => {
+                os.write_fixed64(10, *v);
+            },
+            None => {},
+        };
+        match self.sfixed32_field {
+            Some(ref v) => {
+                os.write_sfixed32(11, *v);
+            },
+            None => {},
+        };
+        match self.sfixed64_field {
+            Some(ref v) => {
+                os.write_sfixed64(12, *v);
+            },
+            None => {},
+        };
+        match self.bool_field {
+            Some(ref v) => {
+                os.write_bool(13, *v);
+            },
+            None => {},
+        };
+        match self.string_field {
+            Some(ref v) => {
+                os.write_string(14, v.as_slice());
+            },
+            None => {},
+        };
+        match self.bytes_field {
+            Some(ref v) => {
+                os.write_bytes(15, v.as_slice());
+            },
+            None => {},
+        };
+        match self.enum_field {
+            Some(ref v) => {
+                os.write_enum(16, *v as i32);
+            },
+            None => {},
+        };
+        match self.enum_field_without_default {
+            Some(ref v) => {
+                os.write_enum(17, *v as i32);
+            },
+            None => {},
+        };
+        match self.double_inf {
+            Some(ref v) => {
+                os.write_double(18, *v);
+            },
+            None => {},
+        };
+        match self.float_neg_inf {
+            Some(ref v) => {
+                os.write_float(19, *v);
+            },
+            None => {},
+        };
+        match self.double_nan {
+            Some(ref v) => {
+                os.write_double(20, *v);
+            },
+            None => {},
+        };
+        match self.double_exp {
+            Some(ref v) => {
+                os.write_double(21, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_double_field(&mut self) {
+        self.double_field = None;
+    }
+
+    pub fn has_double_field(&self) -> bool {
+        self.double_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_double_field(&mut self, v: f64) {
+        self.double_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_double_field(&'a mut self) -> &'a mut f64 {
+        if self.double_field.is_none() {
+            self.double_field = Some(1.);
+        };
+        self.double_field.get_mut_ref()
+    }
+
+    pub fn get_double_field(&self) -> f64 {
+        self.double_field.unwrap_or_else(|| 1.)
+    }
+
+    pub fn clear_float_field(&mut self) {
+        self.float_field = None;
+    }
+
+    pub fn has_float_field(&self) -> bool {
+        self.float_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_float_field(&mut self, v: f32) {
+        self.float_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_float_field(&'a mut self) -> &'a mut f32 {
+        if self.float_field.is_none() {
+            self.float_field = Some(2.5);
+        };
+        self.float_field.get_mut_ref()
+    }
+
+    pub fn get_float_field(&self) -> f32 {
+        self.float_field.unwrap_or_else(|| 2.5)
+    }
+
+    pub fn clear_int32_field(&mut self) {
+        self.int32_field = None;
+    }
+
+    pub fn has_int32_field(&self) -> bool {
+        self.int32_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_int32_field(&mut self, v: i32) {
+        self.int32_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_int32_field(&'a mut self) -> &'a mut i32 {
+        if self.int32_field.is_none() {
+            self.int32_field = Some(3);
+        };
+        self.int32_field.get_mut_ref()
+    }
+
+    pub fn get_int32_field(&self) -> i32 {
+        self.int32_field.unwrap_or_else(|| 3)
+    }
+
+    pub fn clear_int64_field(&mut self) {
+        self.int64_field = None;
+    }
+
+    pub fn has_int64_field(&self) -> bool {
+        self.int64_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_int64_field(&mut self, v: i64) {
+        self.int64_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_int64_field(&'a mut self) -> &'a mut i64 {
+        if self.int64_field.is_none() {
+            self.int64_field = Some(-4);
+        };
+        self.int64_field.get_mut_ref()
+    }
+
+    pub fn get_int64_field(&self) -> i64 {
+        self.int64_field.unwrap_or_else(|| -4)
+    }
+
+    pub fn clear_uint32_field(&mut self) {
+        self.uint32_field = None;
+    }
+
+    pub fn has_uint32_field(&self) -> bool {
+        self.uint32_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uint32_field(&mut self, v: u32) {
+        self.uint32_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uint32_field(&'a mut self) -> &'a mut u32 {
+        if self.uint32_field.is_none() {
+            self.uint32_field = Some(5);
+        };
+        self.uint32_field.get_mut_ref()
+    }
+
+    pub fn get_uint32_field(&self) -> u32 {
+        self.uint32_field.unwrap_or_else(|| 5)
+    }
+
+    pub fn clear_uint64_field(&mut self) {
+        self.uint64_field = None;
+    }
+
+    pub fn has_uint64_field(&self) -> bool {
+        self.uint64_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_uint64_field(&mut self, v: u64) {
+        self.uint64_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_uint64_field(&'a mut self) -> &'a mut u64 {
+        if self.uint64_field.is_none() {
+            self.uint64_field = Some(6);
+        };
+        self.uint64_field.get_mut_ref()
+    }
+
+    pub fn get_uint64_field(&self) -> u64 {
+        self.uint64_field.unwrap_or_else(|| 6)
+    }
+
+    pub fn clear_sint32_field(&mut self) {
+        self.sint32_field = None;
+    }
+
+    pub fn has_sint32_field(&self) -> bool {
+        self.sint32_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sint32_field(&mut self, v: i32) {
+        self.sint32_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sint32_field(&'a mut self) -> &'a mut i32 {
+        if self.sint32_field.is_none() {
+            self.sint32_field = Some(-7);
+        };
+        self.sint32_field.get_mut_ref()
+    }
+
+    pub fn get_sint32_field(&self) -> i32 {
+        self.sint32_field.unwrap_or_else(|| -7)
+    }
+
+    pub fn clear_sint64_field(&mut self) {
+        self.sint64_field = None;
+    }
+
+    pub fn has_sint64_field(&self) -> bool {
+        self.sint64_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sint64_field(&mut self, v: i64) {
+        self.sint64_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sint64_field(&'a mut self) -> &'a mut i64 {
+        if self.sint64_field.is_none() {
+            self.sint64_field = Some(8);
+        };
+        self.sint64_field.get_mut_ref()
+    }
+
+    pub fn get_sint64_field(&self) -> i64 {
+        self.sint64_field.unwrap_or_else(|| 8)
+    }
+
+    pub fn clear_fixed32_field(&mut self) {
+        self.fixed32_field = None;
+    }
+
+    pub fn has_fixed32_field(&self) -> bool {
+        self.fixed32_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fixed32_field(&mut self, v: u32) {
+        self.fixed32_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fixed32_field(&'a mut self) -> &'a mut u32 {
+        if self.fixed32_field.is_none() {
+            self.fixed32_field = Some(9);
+        };
+        self.fixed32_field.get_mut_ref()
+    }
+
+    pub fn get_fixed32_field(&self) -> u32 {
+        self.fixed32_field.unwrap_or_else(|| 9)
+    }
+
+    pub fn clear_fixed64_field(&mut self) {
+        self.fixed64_field = None;
+    }
+
+    pub fn has_fixed64_field(&self) -> bool {
+        self.fixed64_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fixed64_field(&mut self, v: u64) {
+        self.fixed64_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fixed64_field(&'a mut self) -> &'a mut u64 {
+        if self.fixed64_field.is_none() {
+            self.fixed64_field = Some(10);
+        };
+        self.fixed64_field.get_mut_ref()
+    }
+
+    pub fn get_fixed64_field(&self) -> u64 {
+        self.fixed64_field.unwrap_or_else(|| 10)
+    }
+
+    pub fn clear_sfixed32_field(&mut self) {
+        self.sfixed32_field = None;
+    }
+
+    pub fn has_sfixed32_field(&self) -> bool {
+        self.sfixed32_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sfixed32_field(&mut self, v: i32) {
+        self.sfixed32_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sfixed32_field(&'a mut self) -> &'a mut i32 {
+        if self.sfixed32_field.is_none() {
+            self.sfixed32_field = Some(11);
+        };
+        self.sfixed32_field.get_mut_ref()
+    }
+
+    pub fn get_sfixed32_field(&self) -> i32 {
+        self.sfixed32_field.unwrap_or_else(|| 11)
+    }
+
+    pub fn clear_sfixed64_field(&mut self) {
+        self.sfixed64_field = None;
+    }
+
+    pub fn has_sfixed64_field(&self) -> bool {
+        self.sfixed64_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sfixed64_field(&mut self, v: i64) {
+        self.sfixed64_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sfixed64_field(&'a mut self) -> &'a mut i64 {
+        if self.sfixed64_field.is_none() {
+            self.sfixed64_field = Some(-12);
+        };
+        self.sfixed64_field.get_mut_ref()
+    }
+
+    pub fn get_sfixed64_field(&self) -> i64 {
+        self.sfixed64_field.unwrap_or_else(|| -12)
+    }
+
+    pub fn clear_bool_field(&mut self) {
+        self.bool_field = None;
+    }
+
+    pub fn has_bool_field(&self) -> bool {
+        self.bool_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bool_field(&mut self, v: bool) {
+        self.bool_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bool_field(&'a mut self) -> &'a mut bool {
+        if self.bool_field.is_none() {
+            self.bool_field = Some(true);
+        };
+        self.bool_field.get_mut_ref()
+    }
+
+    pub fn get_bool_field(&self) -> bool {
+        self.bool_field.unwrap_or_else(|| true)
+    }
+
+    pub fn clear_string_field(&mut self) {
+        self.string_field = None;
+    }
+
+    pub fn has_string_field(&self) -> bool {
+        self.string_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_string_field(&mut self, v: String) {
+        self.string_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_string_field(&'a mut self) -> &'a mut String {
+        if self.string_field.is_none() {
+            self.string_field = Some("ab\"c\n".to_string());
+        };
+        self.string_field.get_mut_ref()
+    }
+
+    pub fn get_string_field(&'a self) -> &'a str {
+        match self.string_field {
+            Some(ref v) => v.as_slice(),
+            None => "ab\"c\n",
+        }
+    }
+
+    pub fn clear_bytes_field(&mut self) {
+        self.bytes_field = None;
+    }
+
+    pub fn has_bytes_field(&self) -> bool {
+        self.bytes_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bytes_field(&mut self, v: Vec<u8>) {
+        self.bytes_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bytes_field(&'a mut self) -> &'a mut Vec<u8> {
+        if self.bytes_field.is_none() {
+            self.bytes_field = Some(Vec::from_slice(b"de\x0a\x00\xff\\"));
+        };
+        self.bytes_field.get_mut_ref()
+    }
+
+    pub fn get_bytes_field(&'a self) -> &'a [u8] {
+        match self.bytes_field {
+            Some(ref v) => v.as_slice(),
+            None => b"de\x0a\x00\xff\\",
+        }
+    }
+
+    pub fn clear_enum_field(&mut self) {
+        self.enum_field = None;
+    }
+
+    pub fn has_enum_field(&self) -> bool {
+        self.enum_field.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enum_field(&mut self, v: EnumForDefaultValue) {
+        self.enum_field = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_enum_field(&'a mut self) -> &'a mut EnumForDefaultValue {
+        if self.enum_field.is_none() {
+            self.enum_field = Some(TWO);
+        };
+        self.enum_field.get_mut_ref()
+    }
+
+    pub fn get_enum_field(&self) -> EnumForDefaultValue {
+        self.enum_field.unwrap_or_else(|| TWO)
+    }
+
+    pub fn clear_enum_field_without_default(&mut self) {
+        self.enum_field_without_default = None;
+    }
+
+    pub fn has_enum_field_without_default(&self) -> bool {
+        self.enum_field_without_default.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enum_field_without_default(&mut self, v: EnumForDefaultValue) {
+        self.enum_field_without_default = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_enum_field_without_default(&'a mut self) -> &'a mut EnumForDefaultValue {
+        if self.enum_field_without_default.is_none() {
+            self.enum_field_without_default = Some(ONE);
+        };
+        self.enum_field_without_default.get_mut_ref()
+    }
+
+    pub fn get_enum_field_without_default(&self) -> EnumForDefaultValue {
+        self.enum_field_without_default.unwrap_or_else(|| ONE)
+    }
+
+    pub fn clear_double_inf(&mut self) {
+        self.double_inf = None;
+    }
+
+    pub fn has_double_inf(&self) -> bool {
+        self.double_inf.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_double_inf(&mut self, v: f64) {
+        self.double_inf = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_double_inf(&'a mut self) -> &'a mut f64 {
+        if self.double_inf.is_none() {
+            self.double_inf = Some(::std::f64::INFINITY);
+        };
+        self.double_inf.get_mut_ref()
+    }
+
+    pub fn get_double_inf(&self) -> f64 {
+        self.double_inf.unwrap_or_else(|| ::std::f64::INFINITY)
+    }
+
+    pub fn clear_float_neg_inf(&mut self) {
+        self.float_neg_inf = None;
+    }
+
+    pub fn has_float_neg_inf(&self) -> bool {
+        self.float_neg_inf.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_float_neg_inf(&mut self, v: f32) {
+        self.float_neg_inf = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_float_neg_inf(&'a mut self) -> &'a mut f32 {
+        if self.float_neg_inf.is_none() {
+            self.float_neg_inf = Some(::std::f32::NEG_INFINITY);
+        };
+        self.float_neg_inf.get_mut_ref()
+    }
+
+    pub fn get_float_neg_inf(&self) -> f32 {
+        self.float_neg_inf.unwrap_or_else(|| ::std::f32::NEG_INFINITY)
+    }
+
+    pub fn clear_double_nan(&mut self) {
+        self.double_nan = None;
+    }
+
+    pub fn has_double_nan(&self) -> bool {
+        self.double_nan.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_double_nan(&mut self, v: f64) {
+        self.double_nan = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_double_nan(&'a mut self) -> &'a mut f64 {
+        if self.double_nan.is_none() {
+            self.double_nan = Some(::std::f64::NAN);
+        };
+        self.double_nan.get_mut_ref()
+    }
+
+    pub fn get_double_nan(&self) -> f64 {
+        self.double_nan.unwrap_or_else(|| ::std::f64::NAN)
+    }
+
+    pub fn clear_double_exp(&mut self) {
+        self.double_exp = None;
+    }
+
+    pub fn has_double_exp(&self) -> bool {
+        self.double_exp.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_double_exp(&mut self, v: f64) {
+        self.double_exp = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_double_exp(&'a mut self) -> &'a mut f64 {
+        if self.double_exp.is_none() {
+            self.double_exp = Some(1e+20);
+        };
+        self.double_exp.get_mut_ref()
+    }
+
+    pub fn get_double_exp(&self) -> f64 {
+        self.double_exp.unwrap_or_else(|| 1e+20)
+    }
+}
+
+impl ::protobuf::Message for TestDefaultValues {
+    fn new() -> TestDefaultValues {
+        TestDefaultValues::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
+                    self.double_field = Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_float());
+                    self.float_field = Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.int32_field = Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int64());
+                    self.int64_field = Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint32());
+                    self.uint32_field = Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint64());
+                    self.uint64_field = Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sint32());
+                    self.sint32_field = Some(tmp);
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sint64());
+                    self.sint64_field = Some(tmp);
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_fixed32());
+                    self.fixed32_field = Some(tmp);
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_fixed64());
+                    self.fixed64_field = Some(tmp);
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sfixed32());
+                    self.sfixed32_field = Some(tmp);
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sfixed64());
+                    self.sfixed64_field = Some(tmp);
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
+                    self.bool_field = Some(tmp);
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
+                    self.string_field = Some(tmp);
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
+                    self.bytes_field = Some(tmp);
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match EnumForDefaultValue::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.enum_field = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
+                },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match EnumForDefaultValue::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.enum_field_without_default = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
+                    self.double_inf = Some(tmp);
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_float());
+                    self.float_neg_inf = Some(tmp);
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
+                    self.double_nan = Some(tmp);
+                },
+                21 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
+                    self.double_exp = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestDefaultValues) {
+        if other.double_field.is_some() {
+            self.double_field = other.double_field.clone();
+        };
+        if other.float_field.is_some() {
+            self.float_field = other.float_field.clone();
+        };
+        if other.int32_field.is_some() {
+            self.int32_field = other.int32_field.clone();
+        };
+        if other.int64_field.is_some() {
+            self.int64_field = other.int64_field.clone();
+        };
+        if other.uint32_field.is_some() {
+            self.uint32_field = other.uint32_field.clone();
+        };
+        if other.uint64_field.is_some() {
+            self.uint64_field = other.uint64_field.clone();
+        };
+        if other.sint32_field.is_some() {
+            self.sint32_field = other.sint32_field.clone();
+        };
+        if other.sint64_field.is_some() {
+            self.sint64_field = other.sint64_field.clone();
+        };
+        if other.fixed32_field.is_some() {
+            self.fixed32_field = other.fixed32_field.clone();
+        };
+        if other.fixed64_field.is_some() {
+            self.fixed64_field = other.fixed64_field.clone();
+        };
+        if other.sfixed32_field.is_some() {
+            self.sfixed32_field = other.sfixed32_field.clone();
+        };
+        if other.sfixed64_field.is_some() {
+            self.sfixed64_field = other.sfixed64_field.clone();
+        };
+        if other.bool_field.is_some() {
+            self.bool_field = other.bool_field.clone();
+        };
+        if other.string_field.is_some() {
+            self.string_field = other.string_field.clone();
+        };
+        if other.bytes_field.is_some() {
+            self.bytes_field = other.bytes_field.clone();
+        };
+        if other.enum_field.is_some() {
+            self.enum_field = other.enum_field.clone();
+        };
+        if other.enum_field_without_default.is_some() {
+            self.enum_field_without_default = other.enum_field_without_default.clone();
+        };
+        if other.double_inf.is_some() {
+            self.double_inf = other.double_inf.clone();
+        };
+        if other.float_neg_inf.is_some() {
+            self.float_neg_inf = other.float_neg_inf.clone();
+        };
+        if other.double_nan.is_some() {
+            self.double_nan = other.double_nan.clone();
+        };
+        if other.double_exp.is_some() {
+            self.double_exp = other.double_exp.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        if self.double_field.is_some() {
+            my_size += 9;
+        };
+        if self.float_field.is_some() {
+            my_size += 5;
+        };
+        for value in self.int32_field.iter() {
+            my_size += ::protobuf::rt::value_size(3, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.int64_field.iter() {
+            my_size += ::protobuf::rt::value_size(4, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.uint32_field.iter() {
+            my_size += ::protobuf::rt::value_size(5, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.uint64_field.iter() {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.sint32_field.iter() {
+            my_size += ::protobuf::rt::value_size(7, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.sint64_field.iter() {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.fixed32_field.is_some() {
+            my_size += 5;
+        };
+        if self.fixed64_field.is_some() {
+            my_size += 9;
+        };
+        if self.sfixed32_field.is_some() {
+            my_size += 5;
+        };
+        if self.sfixed64_field.is_some() {
+            my_size += 9;
+        };
+        if self.bool_field.is_some() {
+            my_size += 2;
+        };
+        for value in self.string_field.iter() {
+            my_size += ::protobuf::rt::string_size(14, value.as_slice());
+        };
+        for value in self.bytes_field.iter() {
+            my_size += ::protobuf::rt::bytes_size(15, value.as_slice());
+        };
+        for value in self.enum_field.iter() {
+            my_size += ::protobuf::rt::enum_size(16, *value);
+        };
+        for value in self.enum_field_without_default.iter() {
+            my_size += ::protobuf::rt::enum_size(17, *value);
+        };
+        if self.double_inf.is_some() {
+            my_size += 10;
+        };
+        if self.float_neg_inf.is_some() {
+            my_size += 6;
+        };
+        if self.double_nan.is_some() {
+            my_size += 10;
+        };
+        if self.double_exp.is_some() {
+            my_size += 10;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestDefaultValues>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestDefaultValues>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_double_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_float_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_int32_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_int64_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_uint32_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_uint64_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_sint32_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_sint64_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_fixed32_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_fixed64_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_sfixed32_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_sfixed64_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_bool_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_string_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_bytes_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_enum_field_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_enum_field_without_default_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_double_inf_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_float_neg_inf_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_double_nan_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestDefaultValues_double_exp_acc as &::protobuf::reflect::FieldAccessor<TestDefaultValues>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestDefaultValues>(
+                    "TestDefaultValues",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestDefaultValues>()
+    }
+}
+
+impl ::protobuf::Clear for TestDefaultValues {
+    fn clear(&mut self) {
+        self.clear_double_field();
+        self.clear_float_field();
+        self.clear_int32_field();
+        self.clear_int64_field();
+        self.clear_uint32_field();
+        self.clear_uint64_field();
+        self.clear_sint32_field();
+        self.clear_sint64_field();
+        self.clear_fixed32_field();
+        self.clear_fixed64_field();
+        self.clear_sfixed32_field();
+        self.clear_sfixed64_field();
+        self.clear_bool_field();
+        self.clear_string_field();
+        self.clear_bytes_field();
+        self.clear_enum_field();
+        self.clear_enum_field_without_default();
+        self.clear_double_inf();
+        self.clear_float_neg_inf();
+        self.clear_double_nan();
+        self.clear_double_exp();
+    }
+}
+
+impl ::std::fmt::Show for TestDefaultValues {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_double_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_double_field_acc {
+    fn name(&self) -> &'static str {
+        "double_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_double_field()
+    }
+
+    fn get_f64(&self, m: &TestDefaultValues) -> f64 {
+        m.get_double_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_double_field();
+    }
+
+    fn set_f64(&self, m: &mut TestDefaultValues, v: f64) {
+        m.set_double_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_float_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_float_field_acc {
+    fn name(&self) -> &'static str {
+        "float_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_float_field()
+    }
+
+    fn get_f32(&self, m: &TestDefaultValues) -> f32 {
+        m.get_float_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_float_field();
+    }
+
+    fn set_f32(&self, m: &mut TestDefaultValues, v: f32) {
+        m.set_float_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_int32_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_int32_field_acc {
+    fn name(&self) -> &'static str {
+        "int32_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_int32_field()
+    }
+
+    fn get_i32(&self, m: &TestDefaultValues) -> i32 {
+        m.get_int32_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_int32_field();
+    }
+
+    fn set_i32(&self, m: &mut TestDefaultValues, v: i32) {
+        m.set_int32_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_int64_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_int64_field_acc {
+    fn name(&self) -> &'static str {
+        "int64_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_int64_field()
+    }
+
+    fn get_i64(&self, m: &TestDefaultValues) -> i64 {
+        m.get_int64_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_int64_field();
+    }
+
+    fn set_i64(&self, m: &mut TestDefaultValues, v: i64) {
+        m.set_int64_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_uint32_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_uint32_field_acc {
+    fn name(&self) -> &'static str {
+        "uint32_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_uint32_field()
+    }
+
+    fn get_u32(&self, m: &TestDefaultValues) -> u32 {
+        m.get_uint32_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_uint32_field();
+    }
+
+    fn set_u32(&self, m: &mut TestDefaultValues, v: u32) {
+        m.set_uint32_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_uint64_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_uint64_field_acc {
+    fn name(&self) -> &'static str {
+        "uint64_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_uint64_field()
+    }
+
+    fn get_u64(&self, m: &TestDefaultValues) -> u64 {
+        m.get_uint64_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_uint64_field();
+    }
+
+    fn set_u64(&self, m: &mut TestDefaultValues, v: u64) {
+        m.set_uint64_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_sint32_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_sint32_field_acc {
+    fn name(&self) -> &'static str {
+        "sint32_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_sint32_field()
+    }
+
+    fn get_i32(&self, m: &TestDefaultValues) -> i32 {
+        m.get_sint32_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_sint32_field();
+    }
+
+    fn set_i32(&self, m: &mut TestDefaultValues, v: i32) {
+        m.set_sint32_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_sint64_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_sint64_field_acc {
+    fn name(&self) -> &'static str {
+        "sint64_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_sint64_field()
+    }
+
+    fn get_i64(&self, m: &TestDefaultValues) -> i64 {
+        m.get_sint64_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_sint64_field();
+    }
+
+    fn set_i64(&self, m: &mut TestDefaultValues, v: i64) {
+        m.set_sint64_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_fixed32_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_fixed32_field_acc {
+    fn name(&self) -> &'static str {
+        "fixed32_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_fixed32_field()
+    }
+
+    fn get_u32(&self, m: &TestDefaultValues) -> u32 {
+        m.get_fixed32_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_fixed32_field();
+    }
+
+    fn set_u32(&self, m: &mut TestDefaultValues, v: u32) {
+        m.set_fixed32_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_fixed64_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_fixed64_field_acc {
+    fn name(&self) -> &'static str {
+        "fixed64_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_fixed64_field()
+    }
+
+    fn get_u64(&self, m: &TestDefaultValues) -> u64 {
+        m.get_fixed64_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_fixed64_field();
+    }
+
+    fn set_u64(&self, m: &mut TestDefaultValues, v: u64) {
+        m.set_fixed64_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_sfixed32_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_sfixed32_field_acc {
+    fn name(&self) -> &'static str {
+        "sfixed32_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_sfixed32_field()
+    }
+
+    fn get_i32(&self, m: &TestDefaultValues) -> i32 {
+        m.get_sfixed32_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_sfixed32_field();
+    }
+
+    fn set_i32(&self, m: &mut TestDefaultValues, v: i32) {
+        m.set_sfixed32_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_sfixed64_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_sfixed64_field_acc {
+    fn name(&self) -> &'static str {
+        "sfixed64_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_sfixed64_field()
+    }
+
+    fn get_i64(&self, m: &TestDefaultValues) -> i64 {
+        m.get_sfixed64_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_sfixed64_field();
+    }
+
+    fn set_i64(&self, m: &mut TestDefaultValues, v: i64) {
+        m.set_sfixed64_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_bool_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_bool_field_acc {
+    fn name(&self) -> &'static str {
+        "bool_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_bool_field()
+    }
+
+    fn get_bool(&self, m: &TestDefaultValues) -> bool {
+        m.get_bool_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_bool_field();
+    }
+
+    fn set_bool(&self, m: &mut TestDefaultValues, v: bool) {
+        m.set_bool_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_string_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_string_field_acc {
+    fn name(&self) -> &'static str {
+        "string_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_string_field()
+    }
+
+    fn get_str<'a>(&self, m: &'a TestDefaultValues) -> &'a str {
+        m.get_string_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_string_field();
+    }
+
+    fn set_str(&self, m: &mut TestDefaultValues, v: String) {
+        m.set_string_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_bytes_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_bytes_field_acc {
+    fn name(&self) -> &'static str {
+        "bytes_field"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_bytes_field()
+    }
+
+    fn get_bytes<'a>(&self, m: &'a TestDefaultValues) -> &'a [u8] {
+        m.get_bytes_field()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_bytes_field();
+    }
+
+    fn set_bytes(&self, m: &mut TestDefaultValues, v: Vec<u8>) {
+        m.set_bytes_field(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_enum_field_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_enum_field_acc {
+    fn name(&self) -> &'static str {
+        "enum_field"
+    }
+
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<EnumForDefaultValue>()
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_enum_field()
+    }
+
+    fn get_enum<'a>(&self, m: &TestDefaultValues) -> &'static ::protobuf::reflect::EnumValueDescriptor {
+        use protobuf::{ProtobufEnum};
+        m.get_enum_field().descriptor()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_enum_field();
+    }
+
+    fn set_enum(&self, m: &mut TestDefaultValues, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match EnumForDefaultValue::from_i32(v.value()) {
+            Some(e) => m.set_enum_field(e),
+            None => fail!("unknown value {} of enum EnumForDefaultValue", v.value()),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_enum_field_without_default_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_enum_field_without_default_acc {
+    fn name(&self) -> &'static str {
+        "enum_field_without_default"
+    }
+
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<EnumForDefaultValue>()
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_enum_field_without_default()
+    }
+
+    fn get_enum<'a>(&self, m: &TestDefaultValues) -> &'static ::protobuf::reflect::EnumValueDescriptor {
+        use protobuf::{ProtobufEnum};
+        m.get_enum_field_without_default().descriptor()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_enum_field_without_default();
+    }
+
+    fn set_enum(&self, m: &mut TestDefaultValues, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match EnumForDefaultValue::from_i32(v.value()) {
+            Some(e) => m.set_enum_field_without_default(e),
+            None => fail!("unknown value {} of enum EnumForDefaultValue", v.value()),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_double_inf_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_double_inf_acc {
+    fn name(&self) -> &'static str {
+        "double_inf"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_double_inf()
+    }
+
+    fn get_f64(&self, m: &TestDefaultValues) -> f64 {
+        m.get_double_inf()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_double_inf();
+    }
+
+    fn set_f64(&self, m: &mut TestDefaultValues, v: f64) {
+        m.set_double_inf(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_float_neg_inf_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_float_neg_inf_acc {
+    fn name(&self) -> &'static str {
+        "float_neg_inf"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_float_neg_inf()
+    }
+
+    fn get_f32(&self, m: &TestDefaultValues) -> f32 {
+        m.get_float_neg_inf()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_float_neg_inf();
+    }
+
+    fn set_f32(&self, m: &mut TestDefaultValues, v: f32) {
+        m.set_float_neg_inf(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_double_nan_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_double_nan_acc {
+    fn name(&self) -> &'static str {
+        "double_nan"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_double_nan()
+    }
+
+    fn get_f64(&self, m: &TestDefaultValues) -> f64 {
+        m.get_double_nan()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_double_nan();
+    }
+
+    fn set_f64(&self, m: &mut TestDefaultValues, v: f64) {
+        m.set_double_nan(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestDefaultValues_double_exp_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestDefaultValues> for TestDefaultValues_double_exp_acc {
+    fn name(&self) -> &'static str {
+        "double_exp"
+    }
+
+    fn has_field(&self, m: &TestDefaultValues) -> bool {
+        m.has_double_exp()
+    }
+
+    fn get_f64(&self, m: &TestDefaultValues) -> f64 {
+        m.get_double_exp()
+    }
+
+    fn clear_field(&self, m: &mut TestDefaultValues) {
+        m.clear_double_exp();
+    }
+
+    fn set_f64(&self, m: &mut TestDefaultValues, v: f64) {
+        m.set_double_exp(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestExtensions {
+    a: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestExtensions {
+    pub fn new() -> TestExtensions {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestExtensions {
+        static mut instance: ::protobuf::lazy::Lazy<TestExtensions> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestExtensions };
+        unsafe {
+            instance.get(|| {
+                TestExtensions {
+                    a: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.a {
+            Some(ref v) => {
+                os.write_int32(1, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_a(&mut self) {
+        self.a = None;
+    }
+
+    pub fn has_a(&self) -> bool {
+        self.a.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_a(&mut self, v: i32) {
+        self.a = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_a(&'a mut self) -> &'a mut i32 {
+        if self.a.is_none() {
+            self.a = Some(0);
+        };
+        self.a.get_mut_ref()
+    }
+
+    pub fn get_a(&self) -> i32 {
+        self.a.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestExtensions {
+    fn new() -> TestExtensions {
+        TestExtensions::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.a = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestExtensions) {
+        if other.a.is_some() {
+            self.a = other.a.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.a.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestExtensions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestExtensions>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestExtensions_a_acc as &::protobuf::reflect::FieldAccessor<TestExtensions>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestExtensions>(
+                    "TestExtensions",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestExtensions>()
+    }
+}
+
+impl ::protobuf::Clear for TestExtensions {
+    fn clear(&mut self) {
+        self.clear_a();
+    }
+}
+
+impl ::std::fmt::Show for TestExtensions {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestExtensions_a_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestExtensions> for TestExtensions_a_acc {
+    fn name(&self) -> &'static str {
+        "a"
+    }
+
+    fn has_field(&self, m: &TestExtensions) -> bool {
+        m.has_a()
+    }
+
+    fn get_i32(&self, m: &TestExtensions) -> i32 {
+        m.get_a()
+    }
+
+    fn clear_field(&self, m: &mut TestExtensions) {
+        m.clear_a();
+    }
+
+    fn set_i32(&self, m: &mut TestExtensions, v: i32) {
+        m.set_a(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestExtensionsNested {
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestExtensionsNested {
+    pub fn new() -> TestExtensionsNested {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestExtensionsNested {
+        static mut instance: ::protobuf::lazy::Lazy<TestExtensionsNested> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestExtensionsNested };
+        unsafe {
+            instance.get(|| {
+                TestExtensionsNested {
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+}
+
+impl ::protobuf::Message for TestExtensionsNested {
+    fn new() -> TestExtensionsNested {
+        TestExtensionsNested::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestExtensionsNested) {
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestExtensionsNested>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestExtensionsNested>> = Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<TestExtensionsNested>(
+                    "TestExtensionsNested",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestExtensionsNested>()
+    }
+}
+
+impl ::protobuf::Clear for TestExtensionsNested {
+    fn clear(&mut self) {
+    }
+}
+
+impl ::std::fmt::Show for TestExtensionsNested {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+pub static TestExtensionsNested_nested_ext: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeBytes> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 110,
+    field_type: ::protobuf::types::ProtobufTypeBytes,
+};
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestServiceRequest {
+    a: Option<i32>,
+    b: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestServiceRequest {
+    pub fn new() -> TestServiceRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestServiceRequest {
+        static mut instance: ::protobuf::lazy::Lazy<TestServiceRequest> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestServiceRequest };
+        unsafe {
+            instance.get(|| {
+                TestServiceRequest {
+                    a: None,
+                    b: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.a {
+            Some(ref v) => {
+                os.write_int32(1, *v);
+            },
+            None => {},
+        };
+        match self.b {
+            Some(ref v) => {
+                os.write_int32(2, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_a(&mut self) {
+        self.a = None;
+    }
+
+    pub fn has_a(&self) -> bool {
+        self.a.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_a(&mut self, v: i32) {
+        self.a = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_a(&'a mut self) -> &'a mut i32 {
+        if self.a.is_none() {
+            self.a = Some(0);
+        };
+        self.a.get_mut_ref()
+    }
+
+    pub fn get_a(&self) -> i32 {
+        self.a.unwrap_or_else(|| 0)
+    }
+
+    pub fn clear_b(&mut self) {
+        self.b = None;
+    }
+
+    pub fn has_b(&self) -> bool {
+        self.b.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_b(&mut self, v: i32) {
+        self.b = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_b(&'a mut self) -> &'a mut i32 {
+        if self.b.is_none() {
+            self.b = Some(0);
+        };
+        self.b.get_mut_ref()
+    }
+
+    pub fn get_b(&self) -> i32 {
+        self.b.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestServiceRequest {
+    fn new() -> TestServiceRequest {
+        TestServiceRequest::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.a = Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.b = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestServiceRequest) {
+        if other.a.is_some() {
+            self.a = other.a.clone();
+        };
+        if other.b.is_some() {
+            self.b = other.b.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.a.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in self.b.iter() {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestServiceRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestServiceRequest>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestServiceRequest_a_acc as &::protobuf::reflect::FieldAccessor<TestServiceRequest>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestServiceRequest_b_acc as &::protobuf::reflect::FieldAccessor<TestServiceRequest>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestServiceRequest>(
+                    "TestServiceRequest",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestServiceRequest>()
+    }
+}
+
+impl ::protobuf::Clear for TestServiceRequest {
+    fn clear(&mut self) {
+        self.clear_a();
+        self.clear_b();
+    }
+}
+
+impl ::std::fmt::Show for TestServiceRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestServiceRequest_a_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestServiceRequest> for TestServiceRequest_a_acc {
+    fn name(&self) -> &'static str {
+        "a"
+    }
+
+    fn has_field(&self, m: &TestServiceRequest) -> bool {
+        m.has_a()
+    }
+
+    fn get_i32(&self, m: &TestServiceRequest) -> i32 {
+        m.get_a()
+    }
+
+    fn clear_field(&self, m: &mut TestServiceRequest) {
+        m.clear_a();
+    }
+
+    fn set_i32(&self, m: &mut TestServiceRequest, v: i32) {
+        m.set_a(v);
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestServiceRequest_b_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestServiceRequest> for TestServiceRequest_b_acc {
+    fn name(&self) -> &'static str {
+        "b"
+    }
+
+    fn has_field(&self, m: &TestServiceRequest) -> bool {
+        m.has_b()
+    }
+
+    fn get_i32(&self, m: &TestServiceRequest) -> i32 {
+        m.get_b()
+    }
+
+    fn clear_field(&self, m: &mut TestServiceRequest) {
+        m.clear_b();
+    }
+
+    fn set_i32(&self, m: &mut TestServiceRequest, v: i32) {
+        m.set_b(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestServiceResponse {
+    sum: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestServiceResponse {
+    pub fn new() -> TestServiceResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestServiceResponse {
+        static mut instance: ::protobuf::lazy::Lazy<TestServiceResponse> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestServiceResponse };
+        unsafe {
+            instance.get(|| {
+                TestServiceResponse {
+                    sum: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.sum {
+            Some(ref v) => {
+                os.write_int32(1, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_sum(&mut self) {
+        self.sum = None;
+    }
+
+    pub fn has_sum(&self) -> bool {
+        self.sum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sum(&mut self, v: i32) {
+        self.sum = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sum(&'a mut self) -> &'a mut i32 {
+        if self.sum.is_none() {
+            self.sum = Some(0);
+        };
+        self.sum.get_mut_ref()
+    }
+
+    pub fn get_sum(&self) -> i32 {
+        self.sum.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestServiceResponse {
+    fn new() -> TestServiceResponse {
+        TestServiceResponse::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.sum = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestServiceResponse) {
+        if other.sum.is_some() {
+            self.sum = other.sum.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.sum.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestServiceResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestServiceResponse>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestServiceResponse_sum_acc as &::protobuf::reflect::FieldAccessor<TestServiceResponse>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestServiceResponse>(
+                    "TestServiceResponse",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestServiceResponse>()
+    }
+}
+
+impl ::protobuf::Clear for TestServiceResponse {
+    fn clear(&mut self) {
+        self.clear_sum();
+    }
+}
+
+impl ::std::fmt::Show for TestServiceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestServiceResponse_sum_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestServiceResponse> for TestServiceResponse_sum_acc {
+    fn name(&self) -> &'static str {
+        "sum"
+    }
+
+    fn has_field(&self, m: &TestServiceResponse) -> bool {
+        m.has_sum()
+    }
+
+    fn get_i32(&self, m: &TestServiceResponse) -> i32 {
+        m.get_sum()
+    }
+
+    fn clear_field(&self, m: &mut TestServiceResponse) {
+        m.clear_sum();
+    }
+
+    fn set_i32(&self, m: &mut TestServiceResponse, v: i32) {
+        m.set_sum(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestEnumAliasMessage {
+    e: Option<TestEnumAlias>,
+    values: Vec<TestEnumAlias>,
+    nested: Option<TestEnumAliasMessage_Nested>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestEnumAliasMessage {
+    pub fn new() -> TestEnumAliasMessage {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestEnumAliasMessage {
+        static mut instance: ::protobuf::lazy::Lazy<TestEnumAliasMessage> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestEnumAliasMessage };
+        unsafe {
+            instance.get(|| {
+                TestEnumAliasMessage {
+                    e: None,
+                    values: Vec::new(),
+                    nested: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.e {
+            Some(ref v) => {
+                os.write_enum(1, *v as i32);
+            },
+            None => {},
+        };
+        for v in self.values.iter() {
+            os.write_enum(2, *v as i32);
+        };
+        match self.nested {
+            Some(ref v) => {
+                os.write_enum(3, *v as i32);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_e(&mut self) {
+        self.e = None;
+    }
+
+    pub fn has_e(&self) -> bool {
+        self.e.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_e(&mut self, v: TestEnumAlias) {
+        self.e = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_e(&'a mut self) -> &'a mut TestEnumAlias {
+        if self.e.is_none() {
+            self.e = Some(ALIAS_FIRST);
+        };
+        self.e.get_mut_ref()
+    }
+
+    pub fn get_e(&self) -> TestEnumAlias {
+        self.e.unwrap_or_else(|| ALIAS_FIRST)
+    }
+
+    pub fn clear_values(&mut self) {
+        self.values.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_values(&mut self, v: Vec<TestEnumAlias>) {
+        self.values = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_values(&'a mut self) -> &'a mut Vec<TestEnumAlias> {
+        &mut self.values
+    }
+
+    pub fn get_values(&'a self) -> &'a [TestEnumAlias] {
+        self.values.as_slice()
+    }
+
+    pub fn add_values(&mut self, v: TestEnumAlias) {
+        self.values.push(v);
+    }
+
+    pub fn clear_nested(&mut self) {
+        self.nested = None;
+    }
+
+    pub fn has_nested(&self) -> bool {
+        self.nested.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested(&mut self, v: TestEnumAliasMessage_Nested) {
+        self.nested = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nested(&'a mut self) -> &'a mut TestEnumAliasMessage_Nested {
+        if self.nested.is_none() {
+            self.nested = Some(TestEnumAliasMessage_NESTED_FIRST);
+        };
+        self.nested.get_mut_ref()
+    }
+
+    pub fn get_nested(&self) -> TestEnumAliasMessage_Nested {
+        self.nested.unwrap_or_else(|| TestEnumAliasMessage_NESTED_FIRST)
+    }
+}
+
+impl ::protobuf::Message for TestEnumAliasMessage {
+    fn new() -> TestEnumAliasMessage {
+        TestEnumAliasMessage::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match TestEnumAlias::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.e = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
+                },
+                2 => {
+                    if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            let tmp = try!(is.read_int32());
+                            match TestEnumAlias::from_i32(tmp) {
+                                Some(tmp) => {
+                                    self.values.push(tmp);
+                                },
+                                None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                            };
+                        }
+                        try!(is.pop_limit(old_limit));
+                    } else {
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        let tmp = try!(is.read_int32());
+                        match TestEnumAlias::from_i32(tmp) {
+                            Some(tmp) => {
+                                self.values.push(tmp);
+                            },
+                            None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                        };
+                    }
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match TestEnumAliasMessage_Nested::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.nested = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestEnumAliasMessage) {
+        if other.e.is_some() {
+            self.e = other.e.clone();
+        };
+        self.values.push_all(other.values.as_slice());
+        if other.nested.is_some() {
+            self.nested = other.nested.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.e.iter() {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        for value in self.values.iter() {
+            my_size += ::protobuf::rt::enum_size(2, *value);
+        };
+        for value in self.nested.iter() {
+            my_size += ::protobuf::rt::enum_size(3, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestEnumAliasMessage>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestEnumAliasMessage>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestEnumAliasMessage_e_acc as &::protobuf::reflect::FieldAccessor<TestEnumAliasMessage>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestEnumAliasMessage_values_acc as &::protobuf::reflect::FieldAccessor<TestEnumAliasMessage>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestEnumAliasMessage_nested_acc as &::protobuf::reflect::FieldAccessor<TestEnumAliasMessage>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestEnumAliasMessage>(
+                    "TestEnumAliasMessage",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestEnumAliasMessage>()
+    }
+}
+
+impl ::protobuf::Clear for TestEnumAliasMessage {
+    fn clear(&mut self) {
+        self.clear_e();
+        self.clear_values();
+        self.clear_nested();
+    }
+}
+
+impl ::std::fmt::Show for TestEnumAliasMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestEnumAliasMessage_e_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestEnumAliasMessage> for TestEnumAliasMessage_e_acc {
+    fn name(&self) -> &'static str {
+        "e"
+    }
+
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<TestEnumAlias>()
+    }
+
+    fn has_field(&self, m: &TestEnumAliasMessage) -> bool {
+        m.has_e()
+    }
+
+    fn get_enum<'a>(&self, m: &TestEnumAliasMessage) -> &'static ::protobuf::reflect::EnumValueDescriptor {
+        use protobuf::{ProtobufEnum};
+        m.get_e().descriptor()
+    }
+
+    fn clear_field(&self, m: &mut TestEnumAliasMessage) {
+        m.clear_e();
+    }
+
+    fn set_enum(&self, m: &mut TestEnumAliasMessage, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match TestEnumAlias::from_i32(v.value()) {
+            Some(e) => m.set_e(e),
+            None => fail!("unknown value {} of enum TestEnumAlias", v.value()),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestEnumAliasMessage_values_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestEnumAliasMessage> for TestEnumAliasMessage_values_acc {
+    fn name(&self) -> &'static str {
+        "values"
+    }
+
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<TestEnumAlias>()
+    }
+
+    fn len_field(&self, m: &TestEnumAliasMessage) -> uint {
+        m.get_values().len()
+    }
+
+    fn get_rep_enum_item<'a>(&self, m: &TestEnumAliasMessage, index: uint) -> &'static ::protobuf::reflect::EnumValueDescriptor {
+        use protobuf::{ProtobufEnum};
+        m.get_values()[index].descriptor()
+    }
+
+    fn clear_field(&self, m: &mut TestEnumAliasMessage) {
+        m.clear_values();
+    }
+
+    fn add_enum(&self, m: &mut TestEnumAliasMessage, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match TestEnumAlias::from_i32(v.value()) {
+            Some(e) => m.add_values(e),
+            None => fail!("unknown value {} of enum TestEnumAlias", v.value()),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestEnumAliasMessage_nested_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestEnumAliasMessage> for TestEnumAliasMessage_nested_acc {
+    fn name(&self) -> &'static str {
+        "nested"
+    }
+
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<TestEnumAliasMessage_Nested>()
+    }
+
+    fn has_field(&self, m: &TestEnumAliasMessage) -> bool {
+        m.has_nested()
+    }
+
+    fn get_enum<'a>(&self, m: &TestEnumAliasMessage) -> &'static ::protobuf::reflect::EnumValueDescriptor {
+        use protobuf::{ProtobufEnum};
+        m.get_nested().descriptor()
+    }
+
+    fn clear_field(&self, m: &mut TestEnumAliasMessage) {
+        m.clear_nested();
+    }
+
+    fn set_enum(&self, m: &mut TestEnumAliasMessage, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match TestEnumAliasMessage_Nested::from_i32(v.value()) {
+            Some(e) => m.set_nested(e),
+            None => fail!("unknown value {} of enum TestEnumAliasMessage_Nested", v.value()),
+        }
+    }
+}
+
+#[deriving(Clone,PartialEq,Eq,Show)]
+pub enum TestEnumAliasMessage_Nested {
+    TestEnumAliasMessage_NESTED_FIRST = 0,
+}
+
+impl TestEnumAliasMessage_Nested {
+    pub fn new(value: i32) -> TestEnumAliasMessage_Nested {
+        match TestEnumAliasMessage_Nested::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum TestEnumAliasMessage_Nested", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<TestEnumAliasMessage_Nested> {
+        match value {
+            0 => Some(TestEnumAliasMessage_NESTED_FIRST),
+            _ => None
+        }
+    }
+}
+
+impl ::protobuf::ProtobufEnum for TestEnumAliasMessage_Nested {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> Option<TestEnumAliasMessage_Nested> {
+        TestEnumAliasMessage_Nested::from_i32(value)
+    }
+
+    fn enum_descriptor_static(_: Option<TestEnumAliasMessage_Nested>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("TestEnumAliasMessage_Nested", file_descriptor_proto(), file_descriptor)
+            })
+        }
+    }
+}
+
+#[allow(non_uppercase_statics)]
+pub static TestEnumAliasMessage_NESTED_SECOND: TestEnumAliasMessage_Nested = TestEnumAliasMessage_NESTED_FIRST;
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct ExtGroup {
+    a: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> ExtGroup {
+    pub fn new() -> ExtGroup {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ExtGroup {
+        static mut instance: ::protobuf::lazy::Lazy<ExtGroup> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *ExtGroup };
+        unsafe {
+            instance.get(|| {
+                ExtGroup {
+                    a: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.a {
+            Some(ref v) => {
+                os.write_int32(1, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_a(&mut self) {
+        self.a = None;
+    }
+
+    pub fn has_a(&self) -> bool {
+        self.a.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_a(&mut self, v: i32) {
+        self.a = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_a(&'a mut self) -> &'a mut i32 {
+        if self.a.is_none() {
+            self.a = Some(0);
+        };
+        self.a.get_mut_ref()
+    }
+
+    pub fn get_a(&self) -> i32 {
+        self.a.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for ExtGroup {
+    fn new() -> ExtGroup {
+        ExtGroup::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.a = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &ExtGroup) {
+        if other.a.is_some() {
+            self.a = other.a.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.a.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<ExtGroup>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<ExtGroup>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static ExtGroup_a_acc as &::protobuf::reflect::FieldAccessor<ExtGroup>) });
+                ::protobuf::reflect::MessageDescriptor::new::<ExtGroup>(
+                    "ExtGroup",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<ExtGroup>()
+    }
+}
+
+impl ::protobuf::Clear for ExtGroup {
+    fn clear(&mut self) {
+        self.clear_a();
+    }
+}
+
+impl ::std::fmt::Show for ExtGroup {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
 
 #[allow(non_camel_case_types)]
-struct TestTypesRepeatedPacked_bytes_field_acc;
+struct ExtGroup_a_acc;
 
-impl ::protobuf::reflect::FieldAccessor<TestTypesRepeatedPacked> for TestTypesRepeatedPacked_bytes_field_acc {
+impl ::protobuf::reflect::FieldAccessor<ExtGroup> for ExtGroup_a_acc {
     fn name(&self) -> &'static str {
-        "bytes_field"
+        "a"
     }
 
-    fn len_field(&self, m: &TestTypesRepeatedPacked) -> uint {
-        m.get_bytes_field().len()
+    fn has_field(&self, m: &ExtGroup) -> bool {
+        m.has_a()
     }
 
-    fn get_rep_bytes<'a>(&self, m: &'a TestTypesRepeatedPacked) -> &'a [Vec<u8>] {
-        m.get_bytes_field()
+    fn get_i32(&self, m: &ExtGroup) -> i32 {
+        m.get_a()
+    }
+
+    fn clear_field(&self, m: &mut ExtGroup) {
+        m.clear_a();
+    }
+
+    fn set_i32(&self, m: &mut ExtGroup, v: i32) {
+        m.set_a(v);
     }
 }
 
@@ -5611,11 +11398,18 @@ pub enum TestEnumDescriptor {
 
 impl TestEnumDescriptor {
     pub fn new(value: i32) -> TestEnumDescriptor {
+        match TestEnumDescriptor::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum TestEnumDescriptor", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<TestEnumDescriptor> {
         match value {
-            1 => RED,
-            2 => BLUE,
-            3 => GREEN,
-            _ => fail!()
+            1 => Some(RED),
+            2 => Some(BLUE),
+            3 => Some(GREEN),
+            _ => None
         }
     }
 }
@@ -5625,12 +11419,212 @@ impl ::protobuf::ProtobufEnum for TestEnumDescriptor {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<TestEnumDescriptor> {
+        TestEnumDescriptor::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<TestEnumDescriptor>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("TestEnumDescriptor", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("TestEnumDescriptor", file_descriptor_proto(), file_descriptor)
+            })
+        }
+    }
+}
+
+#[deriving(Clone,PartialEq,Eq,Show)]
+pub enum EnumForDefaultValue {
+    ONE = 1,
+    TWO = 2,
+    THREE = 3,
+}
+
+impl EnumForDefaultValue {
+    pub fn new(value: i32) -> EnumForDefaultValue {
+        match EnumForDefaultValue::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum EnumForDefaultValue", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<EnumForDefaultValue> {
+        match value {
+            1 => Some(ONE),
+            2 => Some(TWO),
+            3 => Some(THREE),
+            _ => None
+        }
+    }
+}
+
+impl ::protobuf::ProtobufEnum for EnumForDefaultValue {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> Option<EnumForDefaultValue> {
+        EnumForDefaultValue::from_i32(value)
+    }
+
+    fn enum_descriptor_static(_: Option<EnumForDefaultValue>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("EnumForDefaultValue", file_descriptor_proto(), file_descriptor)
+            })
+        }
+    }
+}
+
+#[deriving(Clone,PartialEq,Eq,Show)]
+pub enum TestEnumAlias {
+    ALIAS_FIRST = 1,
+    ALIAS_OTHER = 2,
+}
+
+impl TestEnumAlias {
+    pub fn new(value: i32) -> TestEnumAlias {
+        match TestEnumAlias::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum TestEnumAlias", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<TestEnumAlias> {
+        match value {
+            1 => Some(ALIAS_FIRST),
+            2 => Some(ALIAS_OTHER),
+            _ => None
+        }
+    }
+}
+
+impl ::protobuf::ProtobufEnum for TestEnumAlias {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> Option<TestEnumAlias> {
+        TestEnumAlias::from_i32(value)
+    }
+
+    fn enum_descriptor_static(_: Option<TestEnumAlias>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("TestEnumAlias", file_descriptor_proto(), file_descriptor)
+            })
+        }
+    }
+}
+
+#[allow(non_uppercase_statics)]
+pub static ALIAS_SECOND: TestEnumAlias = ALIAS_FIRST;
+
+#[allow(non_uppercase_statics)]
+pub static ALIAS_THIRD: TestEnumAlias = ALIAS_FIRST;
+
+pub static ext_int32: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeInt32> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 100,
+    field_type: ::protobuf::types::ProtobufTypeInt32,
+};
+
+pub static ext_string: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 101,
+    field_type: ::protobuf::types::ProtobufTypeString,
+};
+
+pub static ext_enum: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeEnum<TestEnumDescriptor>> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 102,
+    field_type: ::protobuf::types::ProtobufTypeEnum,
+};
+
+pub static ext_message: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeMessage<Test1>> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 103,
+    field_type: ::protobuf::types::ProtobufTypeMessage,
+};
+
+pub static ext_int32_repeated: ::protobuf::ext::ExtFieldRepeated<TestExtensions, ::protobuf::types::ProtobufTypeInt32> = ::protobuf::ext::ExtFieldRepeated {
+    field_number: 104,
+    packed: false,
+    field_type: ::protobuf::types::ProtobufTypeInt32,
+};
+
+pub static ext_sint64: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeSint64> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 105,
+    field_type: ::protobuf::types::ProtobufTypeSint64,
+};
+
+pub static ext_double: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeDouble> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 106,
+    field_type: ::protobuf::types::ProtobufTypeDouble,
+};
+
+pub static ext_fixed32_packed: ::protobuf::ext::ExtFieldRepeated<TestExtensions, ::protobuf::types::ProtobufTypeFixed32> = ::protobuf::ext::ExtFieldRepeated {
+    field_number: 107,
+    packed: true,
+    field_type: ::protobuf::types::ProtobufTypeFixed32,
+};
+
+pub static extgroup: ::protobuf::ext::ExtFieldOptional<TestExtensions, ::protobuf::types::ProtobufTypeGroup<ExtGroup>> = ::protobuf::ext::ExtFieldOptional {
+    field_number: 108,
+    field_type: ::protobuf::types::ProtobufTypeGroup,
+};
+
+pub trait TestService {
+    fn add_numbers(&self, request: TestServiceRequest) -> ::protobuf::ProtobufResult<TestServiceResponse>;
+
+    fn fail(&self, request: TestServiceRequest) -> ::protobuf::ProtobufResult<TestServiceResponse>;
+}
+
+// Calls methods of `TestService` with serialized requests
+pub struct TestServiceServer<S> {
+    pub service: S,
+}
+
+impl<S : TestService> ::protobuf::service::ServiceHandler for TestServiceServer<S> {
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor(&self) -> &'static ::protobuf::reflect::ServiceDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::ServiceDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::ServiceDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::ServiceDescriptor::new("TestService", file_descriptor_proto())
             })
         }
     }
+
+    fn call(&self, method: &str, request: &[u8]) -> ::protobuf::ProtobufResult<Vec<u8>> {
+        use protobuf::{Message};
+        match method {
+            "AddNumbers" => {
+                let request = try!(::protobuf::parse_from_bytes::<TestServiceRequest>(request));
+                let response = try!(self.service.add_numbers(request));
+                try!(::protobuf::service::check_response(&response));
+                Ok(response.write_to_bytes())
+            },
+            "Fail" => {
+                let request = try!(::protobuf::parse_from_bytes::<TestServiceRequest>(request));
+                let response = try!(self.service.fail(request));
+                try!(::protobuf::service::check_response(&response));
+                Ok(response.write_to_bytes())
+            },
+            _ => Err(::protobuf::error::UnknownMethod(method.to_string())),
+        }
+    }
+}
+
+// Client for `TestService`, sends requests over `T`
+pub struct TestServiceClient<T> {
+    pub transport: T,
+}
+
+impl<T : ::protobuf::rpc::Transport> TestServiceClient<T> {
+    pub fn add_numbers(&mut self, request: &TestServiceRequest) -> ::protobuf::ProtobufResult<TestServiceResponse> {
+        ::protobuf::rpc::call(&mut self.transport, "shrug.TestService", "AddNumbers", request)
+    }
+
+    pub fn fail(&mut self, request: &TestServiceRequest) -> ::protobuf::ProtobufResult<TestServiceResponse> {
+        ::protobuf::rpc::call(&mut self.transport, "shrug.TestService", "Fail", request)
+    }
 }
@@ -19,6 +19,91 @@ pub fn remove_prefix<'s>(s: &'s str, prefix: &str) -> &'s str {
     s.slice_from(prefix.len())
 }
 
+// `GetUserName` -> `get_user_name`
+pub fn camel_case_to_snake_case(s: &str) -> String {
+    let mut r = String::new();
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                r.push_char('_');
+            }
+            r.push_char(c.to_lowercase());
+        } else {
+            r.push_char(c);
+        }
+    }
+    r
+}
+
+// unescape string escaped like `FieldDescriptorProto.default_value` of bytes field
+pub fn unescape_c(s: &str) -> Vec<u8> {
+    match parse_c_escaped(s) {
+        Ok(r) => r,
+        Err(message) => fail!("{}", message),
+    }
+}
+
+// like `unescape_c`, but returns error message if escape sequence is invalid
+pub fn parse_c_escaped(s: &str) -> Result<Vec<u8>, String> {
+    let bytes = s.as_bytes();
+    let mut r = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != '\\' as u8 {
+            r.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        i += 1;
+        if i == bytes.len() {
+            return Err(format!("incomplete escape sequence: {}", s));
+        }
+        let c = bytes[i] as char;
+        i += 1;
+        match c {
+            'a'  => r.push(0x07),
+            'b'  => r.push(0x08),
+            'f'  => r.push(0x0c),
+            'n'  => r.push('\n' as u8),
+            'r'  => r.push('\r' as u8),
+            't'  => r.push('\t' as u8),
+            'v'  => r.push(0x0b),
+            '\\' | '\'' | '"' | '?' => r.push(c as u8),
+            '0'..'7' => {
+                let mut v = c.to_digit(8).unwrap();
+                let mut n = 1u;
+                while n < 3 && i < bytes.len() {
+                    match (bytes[i] as char).to_digit(8) {
+                        Some(d) => v = v * 8 + d,
+                        None => break,
+                    }
+                    i += 1;
+                    n += 1;
+                }
+                r.push(v as u8);
+            }
+            'x' | 'X' => {
+                let mut v = 0u;
+                let mut n = 0u;
+                while n < 2 && i < bytes.len() {
+                    match (bytes[i] as char).to_digit(16) {
+                        Some(d) => v = v * 16 + d,
+                        None => break,
+                    }
+                    i += 1;
+                    n += 1;
+                }
+                if n == 0 {
+                    return Err(format!("invalid hex escape: {}", s));
+                }
+                r.push(v as u8);
+            }
+            _ => return Err(format!("unknown escape sequence: {}", s)),
+        }
+    }
+    Ok(r)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -41,6 +126,34 @@ mod test {
         remove_prefix("aaa", "bbb");
     }
 
+    #[test]
+    fn test_camel_case_to_snake_case() {
+        assert_eq!("get", camel_case_to_snake_case("Get").as_slice());
+        assert_eq!("get_user_name", camel_case_to_snake_case("GetUserName").as_slice());
+        assert_eq!("get_name", camel_case_to_snake_case("get_name").as_slice());
+    }
+
+    #[test]
+    fn test_unescape_c() {
+        assert_eq!(Vec::from_slice(b"abc"), unescape_c("abc"));
+        assert_eq!(Vec::from_slice(b"a\x00\n\"\\"), unescape_c("a\\000\\n\\\"\\\\"));
+        assert_eq!(Vec::from_slice(b"\xff\x01z"), unescape_c("\\377\\x1z"));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_unescape_c_fail() {
+        unescape_c("a\\");
+    }
+
+    #[test]
+    fn test_parse_c_escaped() {
+        assert_eq!(Ok(Vec::from_slice(b"a\n")), parse_c_escaped("a\\n"));
+        assert_eq!(Err("incomplete escape sequence: a\\".to_string()), parse_c_escaped("a\\"));
+        assert_eq!(Err("invalid hex escape: \\xz".to_string()), parse_c_escaped("\\xz"));
+        assert_eq!(Err("unknown escape sequence: \\q".to_string()), parse_c_escaped("\\q"));
+    }
+
     #[test]
     fn test_remove_suffix() {
         assert_eq!("bbb", remove_suffix("bbbaaa", "aaa"));
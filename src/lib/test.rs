@@ -1,19 +1,44 @@
+use std::io::BufReader;
+use std::io::MemReader;
+use std::default::Default;
+use std::io::pipe::PipeStream;
+
 use core::*;
 use hex::*;
+use misc::VecWriter;
 use descriptor;
 use reflect;
+use text_format;
+use service;
+use service::ServiceHandler;
+use service::Dispatcher;
+use rpc;
+use dynamic::DynamicMessage;
+use descriptor_pool::DescriptorPool;
+use differencer;
+use differencer::MessageDifferencer;
+use field_mask::FieldMask;
+use field_path;
+use text_format_test_data;
+use test_nonunique_enum;
+use error;
+use error::ProtobufResult;
+use unknown::UnknownFields;
+use unknown::UnknownGroup;
+use unknown::UnknownValueRef;
+use unknown::UnknownVarintRef;
 
 use shrug::*;
 
 fn test_serialize_deserialize_length_delimited<M : Message>(msg: &M) {
     let serialized_bytes = msg.write_length_delimited_to_bytes();
-    let parsed = parse_length_delimited_from_bytes::<M>(serialized_bytes.as_slice());
+    let parsed = parse_length_delimited_from_bytes::<M>(serialized_bytes.as_slice()).unwrap();
     assert!(*msg == parsed);
 }
 
 fn test_serialize_deserialize_no_hex<M : Message>(msg: &M) {
     let serialized_bytes = msg.write_to_bytes();
-    let parsed = parse_from_bytes::<M>(serialized_bytes.as_slice());
+    let parsed = parse_from_bytes::<M>(serialized_bytes.as_slice()).unwrap();
     assert!(*msg == parsed);
 }
 
@@ -23,7 +48,7 @@ fn test_serialize_deserialize<M : Message>(hex: &str, msg: &M) {
     let serialized = msg.write_to_bytes();
     let serialized_hex = encode_hex(serialized.as_slice());
     assert_eq!(expected_hex, serialized_hex);
-    let parsed = parse_from_bytes::<M>(expected_bytes.as_slice());
+    let parsed = parse_from_bytes::<M>(expected_bytes.as_slice()).unwrap();
     assert!(*msg == parsed);
 
     assert_eq!(expected_bytes.len(), msg.serialized_size() as uint);
@@ -33,7 +58,7 @@ fn test_serialize_deserialize<M : Message>(hex: &str, msg: &M) {
 
 fn test_deserialize<M : Message>(hex: &str, msg: &M) {
     let bytes = decode_hex(hex);
-    let parsed = parse_from_bytes::<M>(bytes.as_slice());
+    let parsed = parse_from_bytes::<M>(bytes.as_slice()).unwrap();
     assert!(*msg == parsed);
 }
 
@@ -95,15 +120,166 @@ fn test_write_missing_required() {
 }
 
 #[test]
-#[should_fail]
 fn test_read_missing_required() {
-    parse_from_bytes::<TestRequired>([]);
+    assert!(parse_from_bytes::<TestRequired>([]).is_err());
+}
+
+#[test]
+fn test_nested_missing_required() {
+    let mut test3 = Test3::new();
+    test3.set_c(Test1::new());
+    assert!(!test3.is_initialized());
+    assert_eq!(vec!["c.a".to_string()], test3.find_initialization_errors());
+
+    test3.mut_c().set_a(10);
+    assert!(test3.is_initialized());
+    assert!(test3.find_initialization_errors().is_empty());
+}
+
+#[test]
+fn test_repeated_missing_required() {
+    let mut outer = TestRequiredOuter::new();
+    for b in range(0u, 3) {
+        let mut item = TestRequired::new();
+        if b != 2 {
+            item.set_b(true);
+        }
+        outer.mut_items().push(item);
+    }
+    outer.set_inner(TestRequired::new());
+    assert!(!outer.is_initialized());
+    assert_eq!(vec!["inner.b".to_string(), "items[2].b".to_string()], outer.find_initialization_errors());
+
+    match outer.check_initialized() {
+        Err(error::MissingRequiredFields(name, paths)) => {
+            assert_eq!("TestRequiredOuter", name.as_slice());
+            assert_eq!(2, paths.len());
+        }
+        r => fail!("unexpected: {}", r),
+    }
+
+    let bytes = decode_hex("0a 00");
+    match parse_from_bytes::<TestRequiredOuter>(bytes.as_slice()) {
+        Err(error::MissingRequiredFields(_, paths)) => {
+            assert_eq!(vec!["inner.b".to_string()], paths);
+        }
+        r => fail!("unexpected: {}", r),
+    }
 }
 
 #[test]
-#[should_fail]
 fn test_read_junk() {
-    parse_from_bytes::<Test1>(decode_hex("00").as_slice());
+    assert!(parse_from_bytes::<Test1>(decode_hex("00").as_slice()).is_err());
+}
+
+#[test]
+fn test_read_truncated() {
+    assert_eq!(Err(error::TruncatedInput), parse_from_bytes::<Test1>(decode_hex("08 96").as_slice()));
+    assert_eq!(Err(error::TruncatedInput), parse_from_bytes::<Test3>(decode_hex("1a 03 08 96").as_slice()));
+}
+
+#[test]
+fn test_read_wrong_wire_type() {
+    // field is stored in unknown fields, and written back unchanged
+    let bytes = decode_hex("55 01 02 03 04 52 02 aa bb");
+    let msg = parse_from_bytes::<TestEmpty>(bytes.as_slice()).unwrap();
+    assert!(!msg.has_foo());
+    let (number, values) = msg.get_unknown_fields().iter().next().unwrap();
+    assert_eq!(10, number);
+    assert_eq!(vec![0x04030201], values.fixed32);
+    assert_eq!(vec![vec![0xaa, 0xbb]], values.length_delimited);
+    assert_eq!(bytes, msg.write_to_bytes());
+}
+
+#[test]
+#[should_fail]
+fn test_enum_new_unknown_value() {
+    text_format_test_data::TestEnum::new(3);
+}
+
+#[test]
+fn test_enum_alias() {
+    assert_eq!(ALIAS_FIRST, ALIAS_SECOND);
+    assert_eq!(ALIAS_FIRST, ALIAS_THIRD);
+    assert_eq!(Some(ALIAS_FIRST), TestEnumAlias::from_i32(1));
+    assert_eq!(ALIAS_OTHER, TestEnumAlias::new(2));
+    assert_eq!("ALIAS_FIRST", ALIAS_THIRD.descriptor().name());
+    assert_eq!(TestEnumAliasMessage_NESTED_FIRST, TestEnumAliasMessage_NESTED_SECOND);
+
+    let d = reflect::EnumDescriptor::for_type::<TestEnumAlias>();
+    let names: Vec<&str> = d.values_by_number(1).iter().map(|v| v.name()).collect();
+    assert_eq!(vec!("ALIAS_FIRST", "ALIAS_SECOND", "ALIAS_THIRD"), names);
+
+    let mut m = TestEnumAliasMessage::new();
+    m.set_e(ALIAS_SECOND);
+    // value is set by alias name through reflection
+    m.descriptor().field_by_name("values").add_enum(&mut m, d.find_value_by_name("ALIAS_THIRD").unwrap()).unwrap();
+    assert_eq!(ALIAS_FIRST, m.get_e());
+    assert_eq!([ALIAS_FIRST].as_slice(), m.get_values());
+    test_serialize_deserialize("08 01 10 01", &m);
+}
+
+#[test]
+fn test_unknown_enum_value() {
+    use text_format_test_data::*;
+
+    assert_eq!(Some(LIGHT), TestEnum::from_i32(2));
+    assert_eq!(None, TestEnum::from_i32(3));
+    assert_eq!(LIGHT, TestEnum::new(2));
+
+    // singular 5, repeated 1, 7, 2, then packed 1, 8
+    let bytes = decode_hex("80 01 05 f0 02 01 f0 02 07 f0 02 02 f2 02 02 01 08");
+    let msg = parse_from_bytes::<TestTypes>(bytes.as_slice()).unwrap();
+    assert!(!msg.has_test_enum_singular());
+    assert_eq!([DARK, LIGHT, DARK].as_slice(), msg.get_test_enum_repeated());
+    assert_eq!(vec![5], msg.get_unknown_fields().get(16).unwrap().varint);
+    assert_eq!(vec![7, 8], msg.get_unknown_fields().get(46).unwrap().varint);
+
+    let reparsed = parse_from_bytes::<TestTypes>(msg.write_to_bytes().as_slice()).unwrap();
+    assert_eq!(msg, reparsed);
+}
+
+fn nested_self_reference_bytes(depth: uint) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for _ in range(0, depth) {
+        let mut outer = Vec::from_slice([0x12u8]); // field r2
+        let mut os = VecWriter::new();
+        {
+            let mut cos = CodedOutputStream::new(&mut os as &mut Writer);
+            cos.write_raw_varint32(bytes.len() as u32);
+            cos.flush();
+        }
+        outer.push_all(os.vec.as_slice());
+        outer.push_all(bytes.as_slice());
+        bytes = outer;
+    }
+    bytes
+}
+
+fn merge_self_reference(bytes: &[u8], recursion_limit: Option<u32>) -> ProtobufResult<()> {
+    let mut reader = BufReader::new(bytes);
+    let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+    for &limit in recursion_limit.iter() {
+        is.set_recursion_limit(limit);
+    }
+    let mut message = TestSelfReference::new();
+    message.merge_from(&mut is)
+}
+
+#[test]
+fn test_recursion_limit() {
+    assert!(merge_self_reference(nested_self_reference_bytes(100).as_slice(), None).is_ok());
+    assert_eq!(Err(error::RecursionLimitExceeded),
+        merge_self_reference(nested_self_reference_bytes(101).as_slice(), None));
+    assert_eq!(Err(error::RecursionLimitExceeded),
+        merge_self_reference(nested_self_reference_bytes(10000).as_slice(), None));
+}
+
+#[test]
+fn test_recursion_limit_custom() {
+    assert!(merge_self_reference(nested_self_reference_bytes(3).as_slice(), Some(3)).is_ok());
+    assert_eq!(Err(error::RecursionLimitExceeded),
+        merge_self_reference(nested_self_reference_bytes(4).as_slice(), Some(3)));
 }
 
 #[test]
@@ -123,6 +299,70 @@ fn test_unknown_fields_fixed32() {
     test_serialize_deserialize("08 96 01 25 04 03 02 01 25 A4 A3 A2 A1", &message);
 }
 
+#[test]
+fn test_unknown_fields_group() {
+    let mut group: UnknownFields = Default::default();
+    group.add_varint(5, 1);
+    let mut message = TestUnknownFields::new();
+    message.set_a(150);
+    message.mut_unknown_fields().add_value(4, UnknownGroup(group));
+    test_serialize_deserialize("08 96 01 23 28 01 24", &message);
+}
+
+#[test]
+fn test_unknown_group_nested() {
+    let bytes = decode_hex("0b 10 01 13 18 02 14 0c");
+    let message = parse_from_bytes::<TestEmpty>(bytes.as_slice()).unwrap();
+    assert_eq!(bytes, message.write_to_bytes());
+
+    assert_eq!(Err(error::UnexpectedEndGroup(2)),
+        parse_from_bytes::<TestEmpty>(decode_hex("0b 10 01 14").as_slice()));
+    assert_eq!(Err(error::TruncatedInput),
+        parse_from_bytes::<TestEmpty>(decode_hex("0b 10 01").as_slice()));
+    let mut deep = Vec::from_elem(200, 0x0bu8);
+    deep.push_all(Vec::from_elem(200, 0x0cu8).as_slice());
+    assert_eq!(Err(error::RecursionLimitExceeded),
+        parse_from_bytes::<TestEmpty>(deep.as_slice()));
+}
+
+#[test]
+fn test_group() {
+    let mut message = TestGroup::new();
+    message.mut_optionalgroup().set_a(150);
+    for b in range(1i32, 3) {
+        let mut group = TestGroup_RepeatedGroup::new();
+        group.set_b(b);
+        message.add_repeatedgroup(group);
+    }
+    message.set_c(6);
+    test_serialize_deserialize("0b 10 96 01 0c 1b 20 01 1c 1b 20 02 1c 30 06", &message);
+}
+
+#[test]
+fn test_group_nested_message() {
+    let mut group = TestGroup_RepeatedGroup::new();
+    group.mut_nested().mut_optionalgroup().set_a(1);
+    let mut message = TestGroup::new();
+    message.add_repeatedgroup(group);
+    test_serialize_deserialize("1b 2a 04 0b 10 01 0c 1c", &message);
+}
+
+#[test]
+fn test_group_malformed() {
+    // end tag is missing
+    assert_eq!(Err(error::TruncatedInput),
+        parse_from_bytes::<TestGroup>(decode_hex("0b 10 01").as_slice()));
+    // end tag of other field
+    assert_eq!(Err(error::UnexpectedEndGroup(3)),
+        parse_from_bytes::<TestGroup>(decode_hex("0b 10 01 1c").as_slice()));
+    // end tag outside of group
+    assert_eq!(Err(error::UnexpectedEndGroup(1)),
+        parse_from_bytes::<TestGroup>(decode_hex("0c").as_slice()));
+    // end tag of enclosing group inside nested message
+    assert_eq!(Err(error::UnexpectedEndGroup(3)),
+        parse_from_bytes::<TestGroup>(decode_hex("1b 2a 01 1c 1c").as_slice()));
+}
+
 #[test]
 fn test_types_singular() {
     let mut message = TestTypesSingular::new();
@@ -199,6 +439,194 @@ fn test_default_instance() {
     assert_eq!("", d.get_field().get_s());
 }
 
+#[test]
+fn test_default_values() {
+    let d = TestDefaultValues::new();
+    assert_eq!(1.0, d.get_double_field());
+    assert_eq!(2.5, d.get_float_field());
+    assert_eq!(3, d.get_int32_field());
+    assert_eq!(-4, d.get_int64_field());
+    assert_eq!(5, d.get_uint32_field());
+    assert_eq!(6, d.get_uint64_field());
+    assert_eq!(-7, d.get_sint32_field());
+    assert_eq!(8, d.get_sint64_field());
+    assert_eq!(9, d.get_fixed32_field());
+    assert_eq!(10, d.get_fixed64_field());
+    assert_eq!(11, d.get_sfixed32_field());
+    assert_eq!(-12, d.get_sfixed64_field());
+    assert_eq!(true, d.get_bool_field());
+    assert_eq!("ab\"c\n", d.get_string_field());
+    assert_eq!(b"de\n\x00\xff\\", d.get_bytes_field());
+    assert_eq!(TWO, d.get_enum_field());
+    assert_eq!(ONE, d.get_enum_field_without_default());
+    assert_eq!(::std::f64::INFINITY, d.get_double_inf());
+    assert_eq!(::std::f32::NEG_INFINITY, d.get_float_neg_inf());
+    assert!(d.get_double_nan().is_nan());
+    assert_eq!(1e20, d.get_double_exp());
+
+    // fields are still not set
+    assert!(!d.has_int32_field());
+    assert_eq!(Vec::new(), d.write_to_bytes());
+}
+
+#[test]
+fn test_default_values_mut() {
+    let mut d = TestDefaultValues::new();
+    d.mut_string_field().push_str("d");
+    assert_eq!("ab\"c\nd", d.get_string_field());
+    d.mut_bytes_field().push(1);
+    assert_eq!(b"de\n\x00\xff\\\x01", d.get_bytes_field());
+    assert_eq!(TWO, *d.mut_enum_field());
+    assert!(d.has_enum_field());
+}
+
+#[test]
+fn test_default_values_reflect() {
+    let d = TestDefaultValues::new();
+    let descriptor = d.descriptor();
+    assert_eq!(3, descriptor.field_by_name("int32_field").get_i32(&d));
+    assert_eq!("ab\"c\n", descriptor.field_by_name("string_field").get_str(&d));
+    assert_eq!("TWO", descriptor.field_by_name("enum_field").get_enum(&d).name());
+}
+
+#[test]
+fn test_field_descriptor_metadata() {
+    let d = reflect::MessageDescriptor::for_type::<TestPackedUnpacked>();
+    let packed = d.field_by_name("packed");
+    assert_eq!(5, packed.number());
+    assert!(packed.is_packed());
+    assert!(!d.field_by_name("unpacked").is_packed());
+    assert!(packed.default_value().is_none());
+
+    let d = reflect::MessageDescriptor::for_type::<TestRequiredOuter>();
+    let inner = d.field_by_name("inner");
+    assert!(!inner.is_required());
+    assert_eq!("TestRequired", inner.message_descriptor().name());
+    match inner.runtime_type() {
+        reflect::RuntimeTypeMessage(m) => assert_eq!("shrug.TestRequired", m.full_name()),
+        _ => fail!(),
+    }
+    assert!(inner.default_value().is_none());
+    assert!(inner.message_descriptor().field_by_name("b").is_required());
+}
+
+#[test]
+fn test_field_descriptor_default_value() {
+    let d = reflect::MessageDescriptor::for_type::<TestDefaultValues>();
+    match d.field_by_name("int64_field").default_value() {
+        Some(reflect::ReflectI64(v)) => assert_eq!(-4, v),
+        _ => fail!(),
+    }
+    match d.field_by_name("bytes_field").default_value() {
+        Some(reflect::ReflectBytes(v)) => assert_eq!(b"de\n\0\xff\\", v.as_slice()),
+        _ => fail!(),
+    }
+    let enum_field = d.field_by_name("enum_field");
+    match enum_field.runtime_type() {
+        reflect::RuntimeTypeEnum(e) => assert_eq!("EnumForDefaultValue", e.name()),
+        _ => fail!(),
+    }
+    match enum_field.default_value() {
+        Some(reflect::ReflectEnum(v)) => assert_eq!("TWO", v.name()),
+        _ => fail!(),
+    }
+}
+
+#[test]
+fn test_extensions() {
+    let mut m = TestExtensions::new();
+    assert!(!m.has_extension(&ext_int32));
+    assert_eq!(None, m.get_extension(&ext_int32));
+
+    m.set_extension(&ext_int32, -10);
+    m.set_extension(&ext_string, "abc".to_string());
+    m.set_extension(&ext_enum, GREEN);
+    m.set_extension(&ext_sint64, -3);
+    m.set_extension(&ext_double, 1.5);
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    m.set_extension(&ext_message, test1.clone());
+    m.add_repeated_extension(&ext_int32_repeated, 1);
+    m.add_repeated_extension(&ext_int32_repeated, 2);
+    m.set_extension(&TestExtensionsNested_nested_ext, vec!(1u8, 2));
+    let mut group = ExtGroup::new();
+    group.set_a(7);
+    m.set_extension(&extgroup, group.clone());
+    assert!(m.has_extension(&ext_int32));
+
+    let parsed = parse_from_bytes::<TestExtensions>(m.write_to_bytes().as_slice()).unwrap();
+    assert_eq!(Some(-10), parsed.get_extension(&ext_int32));
+    assert_eq!(Some("abc".to_string()), parsed.get_extension(&ext_string));
+    assert_eq!(Some(GREEN), parsed.get_extension(&ext_enum));
+    assert_eq!(Some(-3), parsed.get_extension(&ext_sint64));
+    assert_eq!(Some(1.5), parsed.get_extension(&ext_double));
+    assert_eq!(Some(test1), parsed.get_extension(&ext_message));
+    assert_eq!(vec!(1, 2), parsed.get_repeated_extension(&ext_int32_repeated));
+    assert_eq!(Some(vec!(1u8, 2)), parsed.get_extension(&TestExtensionsNested_nested_ext));
+    assert_eq!(Some(group), parsed.get_extension(&extgroup));
+
+    m.set_extension(&ext_int32, 20);
+    assert_eq!(Some(20), m.get_extension(&ext_int32));
+    m.clear_extension(&ext_int32);
+    assert!(!m.has_extension(&ext_int32));
+}
+
+#[test]
+fn test_extensions_parse() {
+    // ext_int32 twice, last wins; ext_message twice, merged; packed and unpacked ext_fixed32_packed
+    let bytes = decode_hex("a0 06 01 a0 06 02 ba 06 02 08 01 ba 06 00 da 06 08 01 00 00 00 02 00 00 00 dd 06 03 00 00 00");
+    let m = parse_from_bytes::<TestExtensions>(bytes.as_slice()).unwrap();
+    assert_eq!(Some(2), ext_int32.get(&m));
+    assert_eq!(1, ext_message.get(&m).unwrap().get_a());
+    assert_eq!(vec!(3, 1, 2), ext_fixed32_packed.get(&m));
+}
+
+#[test]
+fn test_extensions_packed() {
+    let mut m = TestExtensions::new();
+    m.add_repeated_extension(&ext_fixed32_packed, 1);
+    m.add_repeated_extension(&ext_fixed32_packed, 2);
+    assert_eq!("da 06 08 01 00 00 00 02 00 00 00", encode_hex(m.write_to_bytes().as_slice()).as_slice());
+    assert_eq!(vec!(1, 2), m.get_repeated_extension(&ext_fixed32_packed));
+
+    // unpacked value from input is appended to packed values
+    let mut m = parse_from_bytes::<TestExtensions>(decode_hex("dd 06 03 00 00 00").as_slice()).unwrap();
+    m.add_repeated_extension(&ext_fixed32_packed, 4);
+    assert_eq!("da 06 08 03 00 00 00 04 00 00 00", encode_hex(m.write_to_bytes().as_slice()).as_slice());
+}
+
+#[test]
+fn test_extensions_group() {
+    // group with a = 1 and a = 2, merged
+    let bytes = decode_hex("e3 06 08 01 e4 06 e3 06 08 02 e4 06");
+    let m = parse_from_bytes::<TestExtensions>(bytes.as_slice()).unwrap();
+    assert_eq!(2, m.get_extension(&extgroup).unwrap().get_a());
+    let mut group = ExtGroup::new();
+    group.set_a(3);
+    let mut m = TestExtensions::new();
+    m.set_extension(&extgroup, group);
+    assert_eq!("e3 06 08 03 e4 06", encode_hex(m.write_to_bytes().as_slice()).as_slice());
+}
+
+#[test]
+fn test_extensions_text_format() {
+    let mut m = TestExtensions::new();
+    m.set_a(1);
+    m.set_extension(&ext_int32, 10);
+    m.set_extension(&ext_enum, RED);
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    m.set_extension(&ext_message, test1);
+    m.add_repeated_extension(&ext_int32_repeated, 3);
+    m.add_repeated_extension(&ext_int32_repeated, 4);
+    m.set_extension(&TestExtensionsNested_nested_ext, vec!(0x61u8));
+    assert_eq!(
+        "a: 1 [shrug.ext_int32]: 10 [shrug.ext_enum]: RED [shrug.ext_message] {1: 150} \
+        [shrug.ext_int32_repeated]: 3 [shrug.ext_int32_repeated]: 4 \
+        [shrug.TestExtensionsNested.nested_ext]: \"a\"",
+        text_format::print_to_str(&m).as_slice());
+}
+
 #[test]
 fn test_message_descriptor() {
     assert_eq!("TestDescriptor", TestDescriptor::new().descriptor().name());
@@ -211,6 +639,253 @@ fn test_message_descriptor() {
 
     let field = d.field_by_name("stuff");
     assert_eq!(55, field.get_i32(&t));
+
+    assert_eq!("stuff", d.find_field_by_number(field.number()).unwrap().name());
+    assert!(d.find_field_by_name("nope").is_none());
+    assert!(d.find_field_by_number(1000).is_none());
+}
+
+#[test]
+fn test_message_descriptor_new_instance() {
+    let d = reflect::MessageDescriptor::for_type::<Test1>();
+    let m = d.new_instance();
+    assert!(message_is::<Test1>(m));
+    assert!(*message_down_cast::<Test1>(m) == Test1::new());
+
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    let parsed = d.parse_from_bytes(test1.write_to_bytes().as_slice()).unwrap();
+    assert!(*message_down_cast::<Test1>(parsed) == test1);
+    match d.parse_from_bytes([]) {
+        Err(error::MissingRequiredFields(name, paths)) => {
+            assert_eq!("Test1", name.as_slice());
+            assert_eq!(vec!("a".to_string()), paths);
+        },
+        _ => fail!(),
+    }
+}
+
+#[test]
+fn test_reflect_set_singular() {
+    let mut m = TestTypesSingular::new();
+    let d = reflect::MessageDescriptor::for_type::<TestTypesSingular>();
+    d.field_by_name("double_field").set_f64(&mut m, 1.5);
+    d.field_by_name("float_field").set_f32(&mut m, 2.5);
+    d.field_by_name("int32_field").set_i32(&mut m, -3);
+    d.field_by_name("int64_field").set_i64(&mut m, -4);
+    d.field_by_name("uint32_field").set_u32(&mut m, 5);
+    d.field_by_name("uint64_field").set_u64(&mut m, 6);
+    d.field_by_name("sint32_field").set_i32(&mut m, -7);
+    d.field_by_name("fixed64_field").set_u64(&mut m, 10);
+    d.field_by_name("bool_field").set_bool(&mut m, true);
+    d.field_by_name("string_field").set_str(&mut m, "abc".to_string());
+    d.field_by_name("bytes_field").set_bytes(&mut m, vec!(1, 2));
+
+    assert_eq!(1.5, m.get_double_field());
+    assert_eq!(2.5, m.get_float_field());
+    assert_eq!(-3, m.get_int32_field());
+    assert_eq!(-4, m.get_int64_field());
+    assert_eq!(5, m.get_uint32_field());
+    assert_eq!(6, m.get_uint64_field());
+    assert_eq!(-7, m.get_sint32_field());
+    assert_eq!(10, m.get_fixed64_field());
+    assert_eq!(true, m.get_bool_field());
+    assert_eq!("abc", m.get_string_field());
+    assert_eq!(&[1u8, 2], m.get_bytes_field());
+
+    d.field_by_name("string_field").clear_field(&mut m);
+    assert!(!m.has_string_field());
+    assert!(m.has_bytes_field());
+}
+
+#[test]
+fn test_reflect_set_enum() {
+    let mut m = TestDefaultValues::new();
+    let field = m.descriptor().field_by_name("enum_field");
+    let value = reflect::EnumDescriptor::for_type::<EnumForDefaultValue>().find_value_by_name("THREE").unwrap();
+    field.set_enum(&mut m, value).unwrap();
+    assert_eq!(THREE, m.get_enum_field());
+
+    // value of other enum is rejected, even if number is declared in field enum
+    let other = reflect::EnumDescriptor::for_type::<TestEnumDescriptor>().find_value_by_name("RED").unwrap();
+    assert!(field.set_enum(&mut m, other).is_err());
+    assert!(field.add_enum(&mut m, other).is_err());
+    assert_eq!(THREE, m.get_enum_field());
+}
+
+#[test]
+fn test_reflect_repeated() {
+    let mut m = TestTypesRepeated::new();
+    let d = reflect::MessageDescriptor::for_type::<TestTypesRepeated>();
+    d.field_by_name("int32_field").add_i32(&mut m, 1);
+    d.field_by_name("int32_field").add_i32(&mut m, 2);
+    d.field_by_name("int32_field").mut_rep_i32(&mut m).push(3);
+    d.field_by_name("string_field").add_str(&mut m, "a".to_string());
+    d.field_by_name("bytes_field").mut_rep_bytes(&mut m).push(vec!(4));
+    assert_eq!(&[1i32, 2, 3], m.get_int32_field());
+    assert_eq!(&["a".to_string()], m.get_string_field());
+    assert_eq!(&[vec!(4u8)], m.get_bytes_field());
+
+    d.field_by_name("int32_field").clear_field(&mut m);
+    assert_eq!(0, m.get_int32_field().len());
+}
+
+#[test]
+fn test_reflect_mut_message() {
+    let mut m = TestRequiredOuter::new();
+    let d = reflect::MessageDescriptor::for_type::<TestRequiredOuter>();
+    {
+        let inner = d.field_by_name("inner").mut_message(&mut m);
+        inner.descriptor().field_by_name("b").set_bool(inner, true);
+    }
+    assert!(m.get_inner().get_b());
+
+    let items = d.field_by_name("items");
+    {
+        let item = items.add_message(&mut m);
+        item.descriptor().field_by_name("b").set_bool(item, false);
+    }
+    items.add_message(&mut m);
+    {
+        let item = items.mut_rep_message_item(&mut m, 1);
+        item.descriptor().field_by_name("b").set_bool(item, true);
+    }
+    assert_eq!(2, m.get_items().len());
+    assert!(!m.get_items()[0].get_b());
+    assert!(m.get_items()[1].get_b());
+}
+
+#[test]
+fn test_reflect_get_singular() {
+    let mut m = TestTypesSingular::new();
+    m.set_int32_field(10);
+    m.set_string_field("abc".to_string());
+    let d = m.descriptor();
+
+    match d.field_by_name("int32_field").get_singular(&m) {
+        Some(reflect::ReflectI32Ref(10)) => {},
+        _ => fail!(),
+    };
+    match d.field_by_name("string_field").get_singular(&m) {
+        Some(reflect::ReflectStrRef("abc")) => {},
+        _ => fail!(),
+    };
+    assert!(d.field_by_name("uint64_field").get_singular(&m).is_none());
+    match d.field_by_name("uint64_field").get_singular_field_or_default(&m) {
+        reflect::ReflectU64Ref(0) => {},
+        _ => fail!(),
+    };
+}
+
+#[test]
+fn test_reflect_get_repeated() {
+    let mut m = TestRequiredOuter::new();
+    m.mut_items().push_default().set_b(true);
+    m.mut_items().push_default().set_b(false);
+    let d = m.descriptor();
+    let values: Vec<bool> = d.field_by_name("items").get_repeated(&m).map(|v| {
+        match v {
+            reflect::ReflectMessageRef(item) => {
+                match item.descriptor().field_by_name("b").get_singular(item) {
+                    Some(reflect::ReflectBoolRef(b)) => b,
+                    _ => fail!(),
+                }
+            },
+            _ => fail!(),
+        }
+    }).collect();
+    assert_eq!(vec!(true, false), values);
+}
+
+#[test]
+fn test_reflect_set_singular_box() {
+    let mut m = TestTypesSingular::new();
+    let d = reflect::MessageDescriptor::for_type::<TestTypesSingular>();
+    d.field_by_name("sint64_field").set_singular(&mut m, reflect::ReflectI64(-5)).unwrap();
+    d.field_by_name("string_field").set_singular(&mut m, reflect::ReflectString("x".to_string())).unwrap();
+    assert_eq!(-5, m.get_sint64_field());
+    assert_eq!("x", m.get_string_field());
+
+    let mut inner = TestRequired::new();
+    inner.set_b(true);
+    let mut outer = TestRequiredOuter::new();
+    let d = outer.descriptor();
+    d.field_by_name("inner").set_singular(&mut outer, reflect::ReflectMessage(box inner.clone())).unwrap();
+    d.field_by_name("items").add_repeated(&mut outer, reflect::ReflectMessage(box inner.clone())).unwrap();
+    assert!(outer.get_inner().get_b());
+    assert_eq!(1, outer.get_items().len());
+    assert!(outer.get_items()[0].get_b());
+}
+
+#[test]
+fn test_reflect_add_repeated_box() {
+    let mut m = TestTypesRepeated::new();
+    let d = reflect::MessageDescriptor::for_type::<TestTypesRepeated>();
+    d.field_by_name("double_field").add_repeated(&mut m, reflect::ReflectF64(1.5)).unwrap();
+    d.field_by_name("bytes_field").add_repeated(&mut m, reflect::ReflectBytes(vec!(1))).unwrap();
+    assert_eq!(&[1.5], m.get_double_field());
+    assert_eq!(&[vec!(1u8)], m.get_bytes_field());
+}
+
+#[test]
+fn test_reflect_value_to_box_message() {
+    let mut outer = TestRequiredOuter::new();
+    outer.set_inner(test_required(true));
+    let d = outer.descriptor();
+    let value = reflect::value_to_box(d.field_by_name("inner").get_singular(&outer).unwrap());
+    let mut copy = TestRequiredOuter::new();
+    d.field_by_name("items").add_repeated(&mut copy, value).unwrap();
+    assert_eq!(1, copy.get_items().len());
+    assert!(copy.get_items()[0].get_b());
+}
+
+#[test]
+fn test_reflect_set_rep_item() {
+    let mut m = TestTypesRepeated::new();
+    m.set_int32_field(vec!(1, 2));
+    let f = m.descriptor().field_by_name("int32_field");
+    f.set_rep_item(&mut m, 1, reflect::ReflectI32(3)).unwrap();
+    assert_eq!([1i32, 3].as_slice(), m.get_int32_field());
+    assert!(f.set_rep_item(&mut m, 2, reflect::ReflectI32(4)).is_err());
+    assert_eq!([1i32, 3].as_slice(), m.get_int32_field());
+}
+
+#[test]
+fn test_reflect_set_singular_box_not_initialized() {
+    // message is merged, not serialized, so required fields may be missing
+    let mut outer = TestRequiredOuter::new();
+    let d = outer.descriptor();
+    d.field_by_name("inner").set_singular(&mut outer, reflect::ReflectMessage(box TestRequired::new())).unwrap();
+    assert!(outer.has_inner());
+    assert!(!outer.get_inner().is_initialized());
+}
+
+#[test]
+fn test_reflect_set_singular_wrong_type() {
+    let mut m = TestTypesSingular::new();
+    let d = reflect::MessageDescriptor::for_type::<TestTypesSingular>();
+    let f = d.field_by_name("int32_field");
+    assert!(!f.is_value_compatible(&reflect::ReflectU32(1)));
+    assert!(f.set_singular(&mut m, reflect::ReflectU32(1)).is_err());
+    assert!(f.add_repeated(&mut m, reflect::ReflectI32(1)).is_err());
+    assert!(!m.has_int32_field());
+
+    // message of other type
+    let mut outer = TestRequiredOuter::new();
+    let d = outer.descriptor();
+    let mut other = TestTypesSingular::new();
+    other.set_int32_field(1);
+    assert!(d.field_by_name("inner").set_singular(&mut outer, reflect::ReflectMessage(box other.clone())).is_err());
+    assert!(d.field_by_name("items").add_repeated(&mut outer, reflect::ReflectMessage(box other)).is_err());
+    assert!(!outer.has_inner());
+    assert_eq!(0, outer.get_items().len());
+
+    // value of other enum
+    let mut m = TestDefaultValues::new();
+    let f = m.descriptor().field_by_name("enum_field");
+    let value = reflect::EnumDescriptor::for_type::<TestEnumDescriptor>().find_value_by_name("RED").unwrap();
+    assert!(f.set_singular(&mut m, reflect::ReflectEnum(value)).is_err());
+    assert!(!m.has_enum_field());
 }
 
 #[test]
@@ -218,5 +893,1017 @@ fn test_enum_descriptor() {
     let d = RED.enum_descriptor();
     assert_eq!("TestEnumDescriptor", d.name());
     assert_eq!("TestEnumDescriptor", reflect::EnumDescriptor::for_type::<TestEnumDescriptor>().name());
-    assert_eq!("GREEN", d.value_by_name("GREEN").name());
+    assert_eq!("GREEN", d.find_value_by_name("GREEN").unwrap().name());
+    assert_eq!("shrug.TestEnumDescriptor", d.full_name());
+    assert_eq!("shrug", d.file().package());
+    assert!(d.containing_type().is_none());
+    assert!(d.allow_alias());
+    let values: Vec<(&str, i32)> = d.values().iter().map(|v| (v.name(), v.value())).collect();
+    assert_eq!(vec!(("RED", 1), ("BLUE", 2), ("GREEN", 3)), values);
+    assert_eq!("BLUE", d.find_value_by_number(2).unwrap().name());
+    assert!(d.find_value_by_number(4).is_none());
+    assert_eq!(3, d.find_value_by_name("GREEN").unwrap().value());
+    assert!(d.find_value_by_name("YELLOW").is_none());
+}
+
+#[test]
+fn test_enum_descriptor_nested() {
+    let d = reflect::EnumDescriptor::for_type::<test_nonunique_enum::MessageA_EnumA>();
+    assert_eq!("MessageA.EnumA", d.full_name());
+    let message = reflect::MessageDescriptor::for_type::<test_nonunique_enum::MessageA>();
+    assert_eq!(message as *reflect::MessageDescriptor, d.containing_type().unwrap() as *reflect::MessageDescriptor);
+}
+
+struct TestServiceImpl;
+
+impl TestService for TestServiceImpl {
+    fn add_numbers(&self, request: TestServiceRequest) -> ProtobufResult<TestServiceResponse> {
+        let mut response = TestServiceResponse::new();
+        response.set_sum(request.get_a() + request.get_b());
+        Ok(response)
+    }
+
+    fn fail(&self, request: TestServiceRequest) -> ProtobufResult<TestServiceResponse> {
+        Err(error::RpcError(format!("failed with {}", request.get_a())))
+    }
+}
+
+#[test]
+fn test_service() {
+    let server = TestServiceServer { service: TestServiceImpl };
+
+    let mut request = TestServiceRequest::new();
+    request.set_a(10);
+    request.set_b(20);
+    let response_bytes = server.call("AddNumbers", request.write_to_bytes().as_slice()).unwrap();
+    let response = parse_from_bytes::<TestServiceResponse>(response_bytes.as_slice()).unwrap();
+    assert_eq!(30, response.get_sum());
+
+    assert_eq!(Err(error::RpcError("failed with 10".to_string())),
+            server.call("Fail", request.write_to_bytes().as_slice()));
+    assert_eq!(Err(error::UnknownMethod("Nope".to_string())),
+            server.call("Nope", request.write_to_bytes().as_slice()));
+}
+
+#[test]
+fn test_service_dispatcher() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.add_service(box TestServiceServer { service: TestServiceImpl }).unwrap();
+
+    let mut request = TestServiceRequest::new();
+    request.set_a(1);
+    request.set_b(2);
+    let response_bytes = dispatcher.call(
+        "shrug.TestService", "AddNumbers", request.write_to_bytes().as_slice()).unwrap();
+    let response = parse_from_bytes::<TestServiceResponse>(response_bytes.as_slice()).unwrap();
+    assert_eq!(3, response.get_sum());
+
+    assert_eq!(Err(error::UnknownMethod("OtherService.AddNumbers".to_string())),
+            dispatcher.call("OtherService", "AddNumbers", request.write_to_bytes().as_slice()));
+    // services are registered by fully qualified name
+    assert_eq!(Err(error::UnknownMethod("TestService.AddNumbers".to_string())),
+            dispatcher.call("TestService", "AddNumbers", request.write_to_bytes().as_slice()));
+}
+
+#[test]
+fn test_service_dispatcher_duplicate() {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.add_service(box TestServiceServer { service: TestServiceImpl }).unwrap();
+    assert_eq!(Err(error::RpcError("service shrug.TestService is already added".to_string())),
+            dispatcher.add_service(box TestServiceServer { service: TestServiceImpl }));
+}
+
+#[test]
+fn test_service_check_response() {
+    assert_eq!(Ok(()), service::check_response(&test_required(true)));
+    assert_eq!(Err(error::RpcError("invalid response: message TestRequired is missing required fields: b".to_string())),
+            service::check_response(&TestRequired::new()));
+}
+
+#[test]
+fn test_service_descriptor() {
+    let server = TestServiceServer { service: TestServiceImpl };
+    let d = server.descriptor();
+    assert_eq!("TestService", d.name());
+    assert_eq!("shrug.TestService", d.full_name());
+    assert_eq!(2, d.methods().len());
+    assert_eq!("AddNumbers", d.methods()[0].name());
+    assert_eq!(".shrug.TestServiceRequest", d.method_by_name("AddNumbers").unwrap().input_type());
+    assert_eq!(".shrug.TestServiceResponse", d.method_by_name("Fail").unwrap().output_type());
+    assert!(d.method_by_name("Nope").is_none());
+}
+
+fn test_service_client<T : rpc::Transport>(client: &mut TestServiceClient<T>) {
+    let mut request = TestServiceRequest::new();
+    request.set_a(5);
+    request.set_b(6);
+    assert_eq!(11, client.add_numbers(&request).unwrap().get_sum());
+    assert_eq!(Err(error::RpcError("failed with 5".to_string())), client.fail(&request));
+
+    request.set_a(7);
+    assert_eq!(13, client.add_numbers(&request).unwrap().get_sum());
+}
+
+fn test_dispatcher() -> Dispatcher {
+    let mut dispatcher = Dispatcher::new();
+    dispatcher.add_service(box TestServiceServer { service: TestServiceImpl }).unwrap();
+    dispatcher
+}
+
+#[test]
+fn test_rpc_channel_transport() {
+    let (transport, server) = rpc::channel_transport();
+    spawn(proc() {
+        server.serve(&test_dispatcher());
+    });
+
+    let mut client = TestServiceClient { transport: transport };
+    test_service_client(&mut client);
+
+    let mut transport = client.transport.clone();
+    assert_eq!(Err(error::UnknownMethod("Nope".to_string())),
+            rpc::call::<_, TestServiceRequest, TestServiceResponse>(
+                    &mut transport, "shrug.TestService", "Nope", &TestServiceRequest::new()));
+}
+
+#[test]
+fn test_rpc_stream_transport() {
+    let requests = PipeStream::pair().unwrap();
+    let responses = PipeStream::pair().unwrap();
+    let server_reader = requests.reader;
+    let server_writer = responses.writer;
+    spawn(proc() {
+        let mut server_reader = server_reader;
+        let mut server_writer = server_writer;
+        rpc::serve_stream(&test_dispatcher(), &mut server_reader, &mut server_writer).unwrap();
+    });
+
+    let mut client = TestServiceClient {
+        transport: rpc::StreamTransport {
+            reader: responses.reader,
+            writer: requests.writer,
+        }
+    };
+    test_service_client(&mut client);
+    assert_eq!(Err(error::UnknownMethod("Nope".to_string())),
+            rpc::call::<_, TestServiceRequest, TestServiceResponse>(
+                    &mut client.transport, "shrug.TestService", "Nope", &TestServiceRequest::new()));
+}
+
+fn serve_stream_bytes(hex: &str) -> ProtobufResult<()> {
+    let mut reader = MemReader::new(decode_hex(hex));
+    let mut writer = VecWriter::new();
+    rpc::serve_stream(&test_dispatcher(), &mut reader, &mut writer)
+}
+
+#[test]
+fn test_rpc_stream_invalid_input() {
+    // service "a", method "b", request of 4G length
+    assert_eq!(Err(error::TotalBytesLimitExceeded), serve_stream_bytes("01 61 01 62 ff ff ff ff 0f"));
+    // high bits of length do not fit into 32 bits
+    assert_eq!(Err(error::MalformedVarint), serve_stream_bytes("01 61 01 62 ff ff ff ff 7f"));
+    assert_eq!(Err(error::TruncatedInput), serve_stream_bytes("01 61 01 62 05 01 02"));
+    assert_eq!(Err(error::TruncatedInput), serve_stream_bytes("01 61 01"));
+    assert_eq!(Ok(()), serve_stream_bytes(""));
+}
+
+fn shrug_descriptor_pool() -> DescriptorPool {
+    let mut pool = DescriptorPool::new();
+    pool.add_generated_file(file_descriptor()).unwrap();
+    pool
+}
+
+// types of shrug.proto are looked up as dynamic types
+fn dynamic_shrug_descriptor_pool() -> DescriptorPool {
+    let mut pool = DescriptorPool::new();
+    pool.add_file(file_descriptor_proto().clone()).unwrap();
+    pool
+}
+
+fn merge_dynamic_from_bytes(m: &mut DynamicMessage, bytes: &[u8]) {
+    let mut reader = BufReader::new(bytes);
+    let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+    m.merge_from(&mut is).unwrap();
+}
+
+fn test_dynamic_round_trip<M : Message>(pool: &DescriptorPool, full_name: &str, msg: &M) {
+    let bytes = msg.write_to_bytes();
+    let mut dynamic = pool.new_dynamic_message(full_name).unwrap();
+    merge_dynamic_from_bytes(&mut dynamic, bytes.as_slice());
+    assert_eq!(bytes, dynamic.write_to_bytes());
+    assert_eq!(bytes.len() as u32, dynamic.serialized_size());
+    assert_eq!(text_format::print_to_str(msg), text_format::print_to_str(&dynamic));
+}
+
+#[test]
+fn test_dynamic_parse_serialize() {
+    let pool = shrug_descriptor_pool();
+
+    let mut singular = TestTypesSingular::new();
+    singular.set_double_field(1.5);
+    singular.set_sint64_field(-8);
+    singular.set_fixed32_field(9);
+    singular.set_bool_field(true);
+    singular.set_string_field("abc".to_string());
+    singular.set_bytes_field(vec!(1, 2));
+    test_dynamic_round_trip(&pool, ".shrug.TestTypesSingular", &singular);
+
+    let mut packed = TestTypesRepeatedPacked::new();
+    packed.set_int32_field(vec!(1, -2));
+    packed.set_float_field(vec!(2.5));
+    packed.set_string_field(vec!("a".to_string(), "b".to_string()));
+    test_dynamic_round_trip(&pool, ".shrug.TestTypesRepeatedPacked", &packed);
+
+    let mut outer = TestRequiredOuter::new();
+    outer.mut_inner().set_b(true);
+    outer.mut_items().push_default().set_b(false);
+    test_dynamic_round_trip(&pool, ".shrug.TestRequiredOuter", &outer);
+
+    let mut group = TestGroup::new();
+    group.mut_optionalgroup().set_a(1);
+    group.mut_repeatedgroup().push_default().mut_nested().set_c(2);
+    group.set_c(3);
+    test_dynamic_round_trip(&pool, ".shrug.TestGroup", &group);
+}
+
+#[test]
+fn test_dynamic_reflect() {
+    let pool = shrug_descriptor_pool();
+    let mut m = pool.new_dynamic_message(".shrug.TestRequiredOuter").unwrap();
+    assert!(m.is_initialized());
+    let d = m.descriptor();
+    assert_eq!("TestRequiredOuter", d.name());
+    {
+        let inner = d.field_by_name("inner").mut_message(&mut m);
+        inner.descriptor().field_by_name("b").set_bool(inner, false);
+    }
+    d.field_by_name("items").add_message(&mut m);
+    assert!(!m.is_initialized());
+    {
+        let item = d.field_by_name("items").mut_rep_message_item(&mut m, 0);
+        item.descriptor().field_by_name("b").set_bool(item, true);
+    }
+    assert!(m.is_initialized());
+
+    let parsed = parse_from_bytes::<TestRequiredOuter>(m.write_to_bytes().as_slice()).unwrap();
+    assert!(parsed.has_inner());
+    assert!(!parsed.get_inner().get_b());
+    assert_eq!(1, parsed.get_items().len());
+    assert!(parsed.get_items()[0].get_b());
+}
+
+#[test]
+fn test_dynamic_default_values() {
+    let pool = shrug_descriptor_pool();
+    let m = pool.new_dynamic_message(".shrug.TestDefaultValues").unwrap();
+    let d = m.descriptor();
+    let generated = TestDefaultValues::new();
+    assert_eq!(generated.get_int64_field(), d.field_by_name("int64_field").get_i64(&m));
+    assert_eq!(generated.get_float_field(), d.field_by_name("float_field").get_f32(&m));
+    assert_eq!(generated.get_double_inf(), d.field_by_name("double_inf").get_f64(&m));
+    assert_eq!(generated.get_string_field(), d.field_by_name("string_field").get_str(&m));
+    assert_eq!(generated.get_bytes_field(), d.field_by_name("bytes_field").get_bytes(&m));
+    assert_eq!("TWO", d.field_by_name("enum_field").get_enum(&m).name());
+    assert_eq!("ONE", d.field_by_name("enum_field_without_default").get_enum(&m).name());
+    assert!(!d.field_by_name("enum_field").has_field(&m));
+}
+
+#[test]
+fn test_dynamic_new_instance() {
+    let pool = dynamic_shrug_descriptor_pool();
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    // decode payload by type name, as in message with `type_name` and `bytes` fields
+    let d = pool.message_by_name("shrug.Test1").unwrap();
+    let parsed = d.parse_from_bytes(test1.write_to_bytes().as_slice()).unwrap();
+    assert!(message_is::<DynamicMessage>(parsed));
+    assert_eq!(150, d.field_by_name("a").get_i32(parsed));
+    assert_eq!(test1.write_to_bytes(), parsed.write_to_bytes());
+
+    let m = d.new_instance();
+    assert!(!d.field_by_name("a").has_field(m));
+    assert!(d.parse_from_bytes([]).is_err());
+}
+
+#[test]
+fn test_dynamic_field_descriptor_metadata() {
+    let pool = dynamic_shrug_descriptor_pool();
+    let d = pool.message_by_name(".shrug.TestRequiredOuter").unwrap();
+    let items = d.field_by_name("items");
+    assert_eq!(2, items.number());
+    assert!(items.default_value().is_none());
+    assert!(pool.message_by_name(".shrug.TestRequired").unwrap() as *reflect::MessageDescriptor
+        == items.message_descriptor() as *reflect::MessageDescriptor);
+
+    let d = pool.message_by_name(".shrug.TestDefaultValues").unwrap();
+    let enum_field = d.field_by_name("enum_field");
+    assert_eq!("EnumForDefaultValue", enum_field.enum_descriptor().name());
+    match enum_field.default_value() {
+        Some(reflect::ReflectEnum(v)) => assert_eq!("TWO", v.name()),
+        _ => fail!(),
+    }
+}
+
+#[test]
+fn test_dynamic_unknown_fields() {
+    let pool = shrug_descriptor_pool();
+    let mut m = pool.new_dynamic_message(".shrug.Test1").unwrap();
+    // field 1 is known, field 2 is not
+    let bytes = decode_hex("08 96 01 10 05");
+    merge_dynamic_from_bytes(&mut m, bytes.as_slice());
+    assert_eq!(150, m.descriptor().field_by_name("a").get_i32(&m));
+    assert_eq!(&[5u64], m.get_unknown_fields().get(2).unwrap().varint.as_slice());
+    assert_eq!(bytes, m.write_to_bytes());
+}
+
+#[test]
+#[should_fail]
+fn test_dynamic_field_of_other_type() {
+    let pool = dynamic_shrug_descriptor_pool();
+    let m = pool.new_dynamic_message(".shrug.Test2").unwrap();
+    pool.message_by_name(".shrug.Test1").unwrap().field_by_name("a").has_field(&m);
+}
+
+#[test]
+#[should_fail]
+fn test_dynamic_message_without_type() {
+    let bytes = decode_hex("08 96 01");
+    parse_from_bytes::<DynamicMessage>(bytes.as_slice()).unwrap();
+}
+
+#[test]
+fn test_descriptor_pool_lookup() {
+    let pool = shrug_descriptor_pool();
+    assert_eq!("Test1", pool.message_by_name(".shrug.Test1").unwrap().name());
+    assert_eq!("OptionalGroup", pool.message_by_name("shrug.TestGroup.OptionalGroup").unwrap().name());
+    assert!(pool.message_by_name(".shrug.Nope").is_none());
+    assert_eq!("EnumForDefaultValue", pool.enum_by_name(".shrug.EnumForDefaultValue").unwrap().name());
+    assert_eq!(3, pool.field_by_name(".shrug.Test3.c").unwrap().proto().get_number());
+    assert!(pool.field_by_name(".shrug.Test3.d").is_none());
+    assert!(pool.service_by_name(".shrug.TestService").unwrap().method_by_name("AddNumbers").is_some());
+    assert_eq!("shrug.TestService", pool.service_by_name("shrug.TestService").unwrap().full_name());
+    assert_eq!(100, pool.extension_by_name(".shrug.ext_int32").unwrap().number());
+    assert!(pool.file_by_name(file_descriptor_proto().get_name()).is_some());
+}
+
+#[test]
+fn test_descriptor_pool_generated_file() {
+    let pool = shrug_descriptor_pool();
+    assert!(file_descriptor() as *reflect::FileDescriptor
+        == pool.file_by_name(file_descriptor_proto().get_name()).unwrap() as *reflect::FileDescriptor);
+    assert!(reflect::EnumDescriptor::for_type::<EnumForDefaultValue>() as *reflect::EnumDescriptor
+        == pool.enum_by_name(".shrug.EnumForDefaultValue").unwrap() as *reflect::EnumDescriptor);
+
+    let d = pool.message_by_name(".shrug.Test1").unwrap();
+    assert!(reflect::MessageDescriptor::for_type::<Test1>() as *reflect::MessageDescriptor
+        == d as *reflect::MessageDescriptor);
+    let mut test1 = Test1::new();
+    test1.set_a(150);
+    assert_eq!(150, d.field_by_name("a").get_i32(&test1));
+    assert_eq!(150, pool.field_by_name(".shrug.Test1.a").unwrap().get_i32(&test1));
+    let parsed = d.parse_from_bytes(test1.write_to_bytes().as_slice()).unwrap();
+    assert!(message_is::<Test1>(parsed));
+}
+
+fn new_field(name: &str, number: i32, type_name: &str) -> descriptor::FieldDescriptorProto {
+    let mut field = descriptor::FieldDescriptorProto::new();
+    field.set_name(name.to_string());
+    field.set_number(number);
+    field.set_label(descriptor::FieldDescriptorProto_LABEL_OPTIONAL);
+    field.set_type_name(type_name.to_string());
+    field
+}
+
+fn new_file(name: &str, package: &str, dependency: Vec<String>, message: descriptor::DescriptorProto)
+    -> descriptor::FileDescriptorProto
+{
+    let mut file = descriptor::FileDescriptorProto::new();
+    file.set_name(name.to_string());
+    file.set_package(package.to_string());
+    file.set_dependency(dependency);
+    file.mut_message_type().push(message);
+    file
+}
+
+// a.proto declares `a.A` with nested enum `E`,
+// b.proto declares `b.B` referencing them with relative names
+fn test_file_descriptor_set() -> descriptor::FileDescriptorSet {
+    let mut a = descriptor::DescriptorProto::new();
+    a.set_name("A".to_string());
+    let mut x = descriptor::FieldDescriptorProto::new();
+    x.set_name("x".to_string());
+    x.set_number(1);
+    x.set_field_type(descriptor::FieldDescriptorProto_TYPE_INT32);
+    a.mut_field().push(x);
+    {
+        let e = a.mut_enum_type().push_default();
+        e.set_name("E".to_string());
+        let v = e.mut_value().push_default();
+        v.set_name("V".to_string());
+        v.set_number(3);
+    }
+
+    let mut b = descriptor::DescriptorProto::new();
+    b.set_name("B".to_string());
+    b.mut_field().push(new_field("a", 1, "a.A"));
+    b.mut_field().push(new_field("e", 2, "a.A.E"));
+
+    let mut set = descriptor::FileDescriptorSet::new();
+    set.add_file(new_file("b.proto", "b", vec!("a.proto".to_string()), b));
+    set.add_file(new_file("a.proto", "a", Vec::new(), a));
+    set
+}
+
+#[test]
+fn test_descriptor_pool_file_descriptor_set() {
+    let mut pool = DescriptorPool::new();
+    pool.add_file_descriptor_set(test_file_descriptor_set()).unwrap();
+
+    let a = pool.field_by_name(".b.B.a").unwrap();
+    assert_eq!(".a.A", a.proto().get_type_name());
+    assert_eq!(descriptor::FieldDescriptorProto_TYPE_MESSAGE, a.proto().get_field_type());
+    let e = pool.field_by_name(".b.B.e").unwrap();
+    assert_eq!(".a.A.E", e.proto().get_type_name());
+    assert_eq!(descriptor::FieldDescriptorProto_TYPE_ENUM, e.proto().get_field_type());
+
+    let mut m = pool.new_dynamic_message(".b.B").unwrap();
+    let bytes = decode_hex("0a 02 08 01 10 03");
+    merge_dynamic_from_bytes(&mut m, bytes.as_slice());
+    assert_eq!("V", e.get_enum(&m).name());
+    assert_eq!(bytes, m.write_to_bytes());
+
+    let a = pool.message_by_name(".a.A").unwrap();
+    assert_eq!("a.A", a.full_name());
+    assert_eq!("a.proto", a.file().name());
+    assert_eq!("E", a.nested_enums().get(0).name());
+    let en = pool.enum_by_name(".a.A.E").unwrap();
+    assert_eq!("a.A.E", en.full_name());
+    assert_eq!("a.proto", en.file().name());
+    assert_eq!("a.A", en.containing_type().unwrap().full_name());
+    let b = pool.file_by_name("b.proto").unwrap();
+    assert_eq!("b", b.package());
+    assert_eq!("a.proto", b.dependencies()[0].name());
+    assert_eq!("B", b.messages().get(0).name());
+}
+
+// enum `alias.E` with values `A = 1`, `B = 1`, `C = 2`
+fn alias_file(allow_alias: bool) -> descriptor::FileDescriptorProto {
+    let mut file = descriptor::FileDescriptorProto::new();
+    file.set_name("alias.proto".to_string());
+    file.set_package("alias".to_string());
+    {
+        let e = file.mut_enum_type().push_default();
+        e.set_name("E".to_string());
+        e.mut_options().set_allow_alias(allow_alias);
+        for &(name, number) in [("A", 1i32), ("B", 1), ("C", 2)].iter() {
+            let v = e.mut_value().push_default();
+            v.set_name(name.to_string());
+            v.set_number(number);
+        }
+    }
+    file
+}
+
+#[test]
+fn test_descriptor_pool_enum_alias() {
+    let mut pool = DescriptorPool::new();
+    pool.add_file(alias_file(true)).unwrap();
+    let d = pool.enum_by_name("alias.E").unwrap();
+    assert!(d.allow_alias());
+    assert_eq!(3, d.values().len());
+    assert_eq!("A", d.find_value_by_number(1).unwrap().name());
+    assert_eq!(1, d.find_value_by_name("B").unwrap().value());
+    let aliases: Vec<&str> = d.values_by_number(1).iter().map(|v| v.name()).collect();
+    assert_eq!(vec!("A", "B"), aliases);
+    assert_eq!(1, d.values_by_number(2).len());
+    assert_eq!(0, d.values_by_number(3).len());
+
+    assert_eq!(Err(error::InvalidDescriptor("duplicate value number 1 in .alias.E, allow_alias is not set".to_string())),
+            DescriptorPool::new().add_file(alias_file(false)));
+}
+
+#[test]
+fn test_descriptor_pool_invalid() {
+    let invalid = |message: &str| Err(error::InvalidDescriptor(message.to_string()));
+
+    let mut set = test_file_descriptor_set();
+    set.mut_file().remove(1);
+    assert_eq!(invalid("import not found: a.proto, imported from b.proto"),
+            DescriptorPool::new().add_file_descriptor_set(set));
+
+    let mut set = test_file_descriptor_set();
+    set.mut_file().get_mut(0).mut_message_type().get_mut(0).mut_field().get_mut(0).set_type_name("A".to_string());
+    assert_eq!(invalid("type not found: A, referenced from .b.B.a"),
+            DescriptorPool::new().add_file_descriptor_set(set));
+
+    let mut set = test_file_descriptor_set();
+    set.mut_file().get_mut(0).mut_message_type().get_mut(0).mut_field().get_mut(1).set_number(1);
+    assert_eq!(invalid("duplicate field number 1 in .b.B"),
+            DescriptorPool::new().add_file_descriptor_set(set));
+
+    let mut set = test_file_descriptor_set();
+    set.mut_file().get_mut(0).mut_message_type().get_mut(0).mut_field().get_mut(1).set_name("a".to_string());
+    assert_eq!(invalid("duplicate field name a in .b.B"),
+            DescriptorPool::new().add_file_descriptor_set(set));
+
+    for &index in [-1i32, 1].iter() {
+        let mut set = test_file_descriptor_set();
+        set.mut_file().get_mut(0).mut_public_dependency().push(index);
+        assert_eq!(invalid(format!("public dependency index {} is out of range in b.proto", index).as_slice()),
+                DescriptorPool::new().add_file_descriptor_set(set));
+    }
+    let mut set = test_file_descriptor_set();
+    set.mut_file().get_mut(0).mut_public_dependency().push(0);
+    DescriptorPool::new().add_file_descriptor_set(set).unwrap();
+
+    // defaults are checked like they are parsed
+    let check_default = |file_index: uint, field_index: uint, value: &str| {
+        let mut set = test_file_descriptor_set();
+        set.mut_file().get_mut(file_index).mut_message_type().get_mut(0).mut_field().get_mut(field_index)
+                .set_default_value(value.to_string());
+        DescriptorPool::new().add_file_descriptor_set(set)
+    };
+    assert_eq!(invalid("invalid default value abc of field .a.A.x"), check_default(1, 0, "abc"));
+    assert_eq!(invalid("invalid default value 3000000000 of field .a.A.x"), check_default(1, 0, "3000000000"));
+    assert_eq!(invalid("invalid default value NOPE of field .b.B.e"), check_default(0, 1, "NOPE"));
+    assert_eq!(Ok(()), check_default(1, 0, "-7"));
+    assert_eq!(Ok(()), check_default(0, 1, "V"));
+
+    let mut set = test_file_descriptor_set();
+    {
+        let a = set.mut_file().get_mut(1).mut_message_type().get_mut(0);
+        let mut y = new_field("y", 2, "E");
+        y.set_default_value("NOPE".to_string());
+        a.mut_field().push(y);
+        let mut z = descriptor::FieldDescriptorProto::new();
+        z.set_name("z".to_string());
+        z.set_number(3);
+        z.set_field_type(descriptor::FieldDescriptorProto_TYPE_DOUBLE);
+        z.set_default_value("inf".to_string());
+        a.mut_field().push(z.clone());
+        z.set_name("w".to_string());
+        z.set_number(4);
+        z.set_field_type(descriptor::FieldDescriptorProto_TYPE_BYTES);
+        z.set_default_value("\\x".to_string());
+        a.mut_field().push(z);
+    }
+    assert_eq!(invalid("invalid default value NOPE of field .a.A.y"),
+            DescriptorPool::new().add_file_descriptor_set(set.clone()));
+    set.mut_file().get_mut(1).mut_message_type().get_mut(0).mut_field().get_mut(1).set_default_value("V".to_string());
+    assert_eq!(invalid("invalid default value \\x of field .a.A.w"),
+            DescriptorPool::new().add_file_descriptor_set(set.clone()));
+    set.mut_file().get_mut(1).mut_message_type().get_mut(0).mut_field().get_mut(3).set_default_value("\\x01".to_string());
+    let mut pool = DescriptorPool::new();
+    pool.add_file_descriptor_set(set).unwrap();
+    match pool.field_by_name(".a.A.z").unwrap().default_value() {
+        Some(reflect::ReflectF64(v)) => assert!(v.is_infinite() && v > 0.),
+        _ => fail!(),
+    }
+
+    let mut set = test_file_descriptor_set();
+    set.mut_file().get_mut(1).mut_message_type().get_mut(0).mut_enum_type().get_mut(0).mut_value().clear();
+    assert_eq!(invalid("enum .a.A.E has no values"), DescriptorPool::new().add_file_descriptor_set(set));
+
+    for &number in [-5i32, 0, 19000, 19999, 1 << 29].iter() {
+        let mut set = test_file_descriptor_set();
+        set.mut_file().get_mut(1).mut_message_type().get_mut(0).mut_field().get_mut(0).set_number(number);
+        assert_eq!(invalid(format!("field .a.A.x has invalid number {}", number).as_slice()),
+                DescriptorPool::new().add_file_descriptor_set(set));
+    }
+    let mut set = test_file_descriptor_set();
+    set.mut_file().get_mut(1).mut_message_type().get_mut(0).mut_field().get_mut(0).set_number((1 << 29) - 1);
+    DescriptorPool::new().add_file_descriptor_set(set).unwrap();
+
+    let mut pool = shrug_descriptor_pool();
+    assert_eq!(invalid(format!("file is already added: {}", file_descriptor_proto().get_name()).as_slice()),
+            pool.add_generated_file(file_descriptor()));
+    let mut other = file_descriptor_proto().clone();
+    other.set_name("other.proto".to_string());
+    assert_eq!(invalid("duplicate symbol: .shrug.Test1"), pool.add_file(other));
+}
+
+#[test]
+fn test_file_descriptor() {
+    let file = file_descriptor();
+    assert_eq!("shrug", file.package());
+    assert_eq!("proto2", file.syntax());
+    assert_eq!(0, file.dependencies().len());
+    assert_eq!("Test1", file.messages().get(0).name());
+    assert!(!file.messages().iter().any(|m| m.name() == "OptionalGroup"));
+    let enums: Vec<&str> = file.enums().iter().map(|e| e.name()).collect();
+    assert_eq!(vec!("TestEnumDescriptor", "EnumForDefaultValue", "TestEnumAlias"), enums);
+    assert_eq!("TestService", file.services()[0].name());
+}
+
+#[test]
+fn test_message_descriptor_nested() {
+    let d = reflect::MessageDescriptor::for_type::<TestGroup>();
+    assert_eq!("shrug.TestGroup", d.full_name());
+    assert_eq!("shrug", d.file().package());
+    assert!(d.containing_type().is_none());
+    let nested: Vec<&str> = d.nested_messages().iter().map(|m| m.full_name()).collect();
+    assert_eq!(vec!("shrug.TestGroup.OptionalGroup", "shrug.TestGroup.RepeatedGroup"), nested);
+    assert_eq!(0, d.nested_enums().len());
+
+    let group = reflect::MessageDescriptor::for_type::<TestGroup_RepeatedGroup>();
+    assert_eq!(d as *reflect::MessageDescriptor, group.containing_type().unwrap() as *reflect::MessageDescriptor);
+}
+
+fn test_required(b: bool) -> TestRequired {
+    let mut r = TestRequired::new();
+    r.set_b(b);
+    r
+}
+
+#[test]
+fn test_differencer() {
+    let mut a = TestRequiredOuter::new();
+    a.set_inner(test_required(true));
+    a.add_items(test_required(true));
+    a.add_items(test_required(false));
+    let mut b = a.clone();
+    let differencer = MessageDifferencer::new();
+    assert!(differencer.compare(&a, &b));
+
+    b.mut_inner().set_b(false);
+    b.mut_items().get_mut(1).clear_b();
+    b.add_items(test_required(true));
+    let differences: Vec<String> = differencer.differences(&a, &b).iter().map(|d| d.to_str()).collect();
+    assert_eq!(vec!(
+            "modified: inner.b: true -> false".to_string(),
+            "removed: items[1].b: false".to_string(),
+            "added: items[2]: {b: true}".to_string()),
+        differences);
+    assert!(!differencer.compare(&a, &b));
+}
+
+#[test]
+fn test_differencer_options() {
+    let d = reflect::MessageDescriptor::for_type::<TestTypesRepeated>();
+    let mut a = TestTypesRepeated::new();
+    a.set_int32_field(vec!(1, 2, 3));
+    a.set_double_field(vec!(1.0));
+    a.set_string_field(vec!("x".to_string()));
+    let mut b = TestTypesRepeated::new();
+    b.set_int32_field(vec!(3, 1, 2));
+    b.set_double_field(vec!(1.001));
+    b.mut_unknown_fields().add_varint(100, 1);
+
+    let mut differencer = MessageDifferencer::new();
+    let paths: Vec<String> = differencer.differences(&a, &b).move_iter().map(|d| d.path).collect();
+    let expected = ["double_field[0]", "int32_field[0]", "int32_field[1]", "int32_field[2]",
+            "string_field[0]", "100"];
+    assert_eq!(expected.iter().map(|p| p.to_string()).collect::<Vec<String>>(), paths);
+
+    differencer.treat_as_set(d.field_by_name("int32_field"));
+    differencer.set_float_margin(0.01);
+    differencer.ignore_field(d.field_by_name("string_field"));
+    differencer.set_ignore_unknown_fields(true);
+    assert!(differencer.compare(&a, &b));
+
+    b.set_int32_field(vec!(3, 4, 1));
+    let differences = differencer.differences(&a, &b);
+    assert_eq!(2, differences.len());
+    assert_eq!(differencer::DifferenceRemoved, differences.get(0).kind);
+    assert_eq!("int32_field[1]", differences.get(0).path.as_slice());
+    assert_eq!(Some("2".to_string()), differences.get(0).left);
+    assert_eq!(differencer::DifferenceAdded, differences.get(1).kind);
+    assert_eq!("int32_field[1]", differences.get(1).path.as_slice());
+    assert_eq!(Some("4".to_string()), differences.get(1).right);
+}
+
+fn test_merge_source() -> (TestTypesSingular, TestTypesRepeated) {
+    let mut singular = TestTypesSingular::new();
+    singular.set_int32_field(2);
+    singular.set_string_field("b".to_string());
+    singular.mut_unknown_fields().add_varint(100, 7);
+    let mut repeated = TestTypesRepeated::new();
+    repeated.set_int32_field(vec!(3, 4));
+    (singular, repeated)
+}
+
+#[test]
+fn test_merge_from_message() {
+    let (src_singular, src_repeated) = test_merge_source();
+
+    let mut singular = TestTypesSingular::new();
+    singular.set_int32_field(1);
+    singular.set_bool_field(true);
+    singular.merge_from_message(&src_singular);
+    assert_eq!(2, singular.get_int32_field());
+    assert_eq!("b", singular.get_string_field());
+    assert!(singular.get_bool_field());
+    assert_eq!(vec!(7), singular.get_unknown_fields().get(100).unwrap().varint);
+
+    let mut repeated = TestTypesRepeated::new();
+    repeated.set_int32_field(vec!(1, 2));
+    repeated.merge_from_message(&src_repeated);
+    assert_eq!([1, 2, 3, 4].as_slice(), repeated.get_int32_field());
+
+    let mut outer = TestRequiredOuter::new();
+    outer.set_inner(test_required(false));
+    outer.add_items(test_required(false));
+    let mut src_outer = TestRequiredOuter::new();
+    src_outer.mut_inner().set_b(true);
+    src_outer.add_items(test_required(true));
+    outer.merge_from_message(&src_outer);
+    assert!(outer.get_inner().get_b());
+    assert_eq!(2, outer.get_items().len());
+    assert!(outer.get_items()[1].get_b());
+}
+
+#[test]
+fn test_reflect_merge() {
+    let (src_singular, src_repeated) = test_merge_source();
+    let mut expected = TestTypesSingular::new();
+    expected.set_bool_field(true);
+    let mut merged = expected.clone();
+    expected.merge_from_message(&src_singular);
+
+    let src: Box<Message> = box src_singular;
+    reflect::merge(&mut merged, src);
+    assert!(expected == merged);
+
+    let mut repeated = TestTypesRepeated::new();
+    repeated.set_int32_field(vec!(1, 2));
+    reflect::merge(&mut repeated, &src_repeated);
+    assert_eq!([1, 2, 3, 4].as_slice(), repeated.get_int32_field());
+
+    let mut outer = TestRequiredOuter::new();
+    outer.set_inner(test_required(false));
+    let mut src_outer = TestRequiredOuter::new();
+    src_outer.mut_inner().set_b(true);
+    src_outer.add_items(test_required(true));
+    let mut expected = outer.clone();
+    expected.merge_from_message(&src_outer);
+    reflect::merge(&mut outer, &src_outer);
+    assert!(expected == outer);
+}
+
+#[test]
+fn test_dynamic_merge() {
+    let pool = shrug_descriptor_pool();
+    let (src_singular, _) = test_merge_source();
+    let mut expected = TestTypesSingular::new();
+    expected.set_int32_field(1);
+    expected.set_bool_field(true);
+
+    let mut m = pool.new_dynamic_message("shrug.TestTypesSingular").unwrap();
+    merge_dynamic_from_bytes(&mut m, expected.write_to_bytes().as_slice());
+    let mut src = pool.new_dynamic_message("shrug.TestTypesSingular").unwrap();
+    merge_dynamic_from_bytes(&mut src, src_singular.write_to_bytes().as_slice());
+    expected.merge_from_message(&src_singular);
+
+    let mut reflect_merged = m.clone();
+    m.merge_from_message(&src);
+    assert_eq!(expected.write_to_bytes(), m.write_to_bytes());
+    reflect::merge(&mut reflect_merged, &src);
+    assert!(m == reflect_merged);
+}
+
+fn test_outer() -> TestRequiredOuter {
+    let mut outer = TestRequiredOuter::new();
+    outer.set_inner(test_required(true));
+    outer.add_items(test_required(false));
+    outer
+}
+
+#[test]
+fn test_field_mask_paths() {
+    let d = reflect::MessageDescriptor::for_type::<TestRequiredOuter>();
+    let mask = FieldMask::from_paths(d, ["inner.b", "items", "inner.b"]).unwrap();
+    assert_eq!(vec!("inner.b".to_string(), "items".to_string()), mask.paths());
+    let mask = FieldMask::from_paths(d, ["inner.b", "inner"]).unwrap();
+    assert_eq!(vec!("inner".to_string()), mask.paths());
+    assert!(FieldMask::new(d).is_empty());
+
+    match FieldMask::from_paths(d, ["inner.c"]) {
+        Err(error::InvalidFieldPath(message)) =>
+            assert_eq!("inner.c: field c is not found in shrug.TestRequired", message.as_slice()),
+        _ => fail!(),
+    }
+    match FieldMask::from_paths(d, ["items.b"]) {
+        Err(error::InvalidFieldPath(message)) =>
+            assert_eq!("items.b: field items is not singular message", message.as_slice()),
+        _ => fail!(),
+    }
+    assert!(FieldMask::from_paths(d, ["inner."]).is_err());
+}
+
+#[test]
+fn test_field_mask_merge() {
+    let d = reflect::MessageDescriptor::for_type::<TestRequiredOuter>();
+    let src = test_outer();
+    let mut dst = TestRequiredOuter::new();
+    dst.add_items(test_required(true));
+    FieldMask::from_paths(d, ["inner.b"]).unwrap().merge(&mut dst, &src);
+    assert!(dst.get_inner().get_b());
+    assert_eq!(1, dst.get_items().len());
+
+    FieldMask::from_paths(d, ["items"]).unwrap().merge(&mut dst, &src);
+    assert_eq!(2, dst.get_items().len());
+    assert!(!dst.get_items()[1].get_b());
+}
+
+#[test]
+fn test_field_mask_trim() {
+    let d = reflect::MessageDescriptor::for_type::<TestRequiredOuter>();
+    let mut m = test_outer();
+    m.mut_unknown_fields().add_varint(100, 1);
+    FieldMask::from_paths(d, ["inner"]).unwrap().trim(&mut m);
+    assert!(m.get_inner().get_b());
+    assert_eq!(0, m.get_items().len());
+    assert!(m.get_unknown_fields().is_empty());
+
+    let mut m = test_outer();
+    FieldMask::new(d).trim(&mut m);
+    assert!(!m.has_inner());
+}
+
+#[test]
+fn test_field_mask_diff() {
+    let a = test_outer();
+    let mut b = a.clone();
+    assert!(FieldMask::diff(&a, &b).is_empty());
+
+    b.mut_inner().set_b(false);
+    b.mut_items().get_mut(0).set_b(true);
+    b.mut_unknown_fields().add_varint(100, 1);
+    assert_eq!(vec!("inner.b".to_string(), "items".to_string()), FieldMask::diff(&a, &b).paths());
+
+    b.clear_inner();
+    assert_eq!(vec!("inner".to_string(), "items".to_string()), FieldMask::diff(&a, &b).paths());
+}
+
+struct EventsVisitor {
+    events: Vec<String>,
+}
+
+impl reflect::MessageVisitor for EventsVisitor {
+    fn enter_message(&mut self, m: &Message) {
+        self.events.push(format!("enter {}", m.descriptor().name()));
+    }
+
+    fn leave_message(&mut self, m: &Message) {
+        self.events.push(format!("leave {}", m.descriptor().name()));
+    }
+
+    fn visit_field(&mut self, f: &reflect::FieldDescriptor, value: reflect::ReflectValueRef) {
+        self.events.push(format!("field {}: {}", f.name(), text_format::value_to_str(value)));
+    }
+
+    fn visit_repeated_item(&mut self, f: &reflect::FieldDescriptor, index: uint, value: reflect::ReflectValueRef) {
+        self.events.push(format!("item {}[{}]: {}", f.name(), index, text_format::value_to_str(value)));
+    }
+
+    fn visit_unknown_field(&mut self, number: u32, value: UnknownValueRef) {
+        match value {
+            UnknownVarintRef(v) => self.events.push(format!("unknown {}: {}", number, v)),
+            _ => fail!(),
+        }
+    }
+}
+
+#[test]
+fn test_walk() {
+    let mut m = test_outer();
+    m.mut_unknown_fields().add_varint(100, 3);
+    let mut visitor = EventsVisitor { events: Vec::new() };
+    reflect::walk(&m, &mut visitor as &mut reflect::MessageVisitor);
+    let expected = [
+        "enter TestRequiredOuter",
+        "field inner: {b: true}",
+        "enter TestRequired",
+        "field b: true",
+        "leave TestRequired",
+        "item items[0]: {b: false}",
+        "enter TestRequired",
+        "field b: false",
+        "leave TestRequired",
+        "unknown 100: 3",
+        "leave TestRequiredOuter",
+    ];
+    assert_eq!(expected.iter().map(|e| e.to_string()).collect::<Vec<String>>(), visitor.events);
+}
+
+// counts all values, only callbacks needed are implemented
+struct CountVisitor {
+    count: uint,
+}
+
+impl reflect::MessageVisitor for CountVisitor {
+    fn visit_repeated_item(&mut self, _f: &reflect::FieldDescriptor, _index: uint, _value: reflect::ReflectValueRef) {
+        self.count += 1;
+    }
+}
+
+#[test]
+fn test_walk_default_callbacks() {
+    let mut m = TestTypesRepeated::new();
+    m.set_int32_field(vec!(1, 2, 3));
+    m.set_string_field(vec!("a".to_string()));
+    let mut visitor = CountVisitor { count: 0 };
+    reflect::walk(&m, &mut visitor as &mut reflect::MessageVisitor);
+    assert_eq!(4, visitor.count);
+}
+
+fn field_path_error<T>(r: ProtobufResult<T>) -> String {
+    match r {
+        Err(error::InvalidFieldPath(message)) => message,
+        _ => fail!(),
+    }
+}
+
+#[test]
+fn test_field_path_get() {
+    let mut m = text_format_test_data::TestTypes::new();
+    m.set_int32_singular(5);
+    for i in range(0, 3i32) {
+        let mut item = text_format_test_data::TestMessage::new();
+        item.set_value(i * 10);
+        m.add_test_message_repeated(item);
+    }
+    match field_path::get(&m, "test_message_repeated[2].value").unwrap() {
+        reflect::ReflectI32Ref(v) => assert_eq!(20, v),
+        _ => fail!(),
+    }
+    match field_path::get(&m, "int32_singular").unwrap() {
+        reflect::ReflectI32Ref(v) => assert_eq!(5, v),
+        _ => fail!(),
+    }
+    match field_path::get(&m, "test_message_singular.value").unwrap() {
+        reflect::ReflectI32Ref(v) => assert_eq!(0, v),
+        _ => fail!(),
+    }
+
+    assert_eq!("test_message_repeated[3].value: index 3 is out of range, field test_message_repeated has 3 elements",
+        field_path_error(field_path::get(&m, "test_message_repeated[3].value")).as_slice());
+    assert_eq!("test_message_repeated.value: field test_message_repeated is repeated, index is required",
+        field_path_error(field_path::get(&m, "test_message_repeated.value")).as_slice());
+    assert_eq!("int32_singular[0]: field int32_singular is not repeated",
+        field_path_error(field_path::get(&m, "int32_singular[0]")).as_slice());
+    assert_eq!("int32_singular.value: field int32_singular is not message",
+        field_path_error(field_path::get(&m, "int32_singular.value")).as_slice());
+    assert_eq!("test_message_singular.x: field x is not found in TestMessage",
+        field_path_error(field_path::get(&m, "test_message_singular.x")).as_slice());
+    assert_eq!("a..b: malformed segment: ",
+        field_path_error(field_path::get(&m, "a..b")).as_slice());
+    assert_eq!("a[x]: malformed segment: a[x]",
+        field_path_error(field_path::get(&m, "a[x]")).as_slice());
+}
+
+#[test]
+fn test_field_path_set() {
+    let mut m = text_format_test_data::TestTypes::new();
+    field_path::set(&mut m, "test_message_singular.value", reflect::ReflectI32(7)).unwrap();
+    assert_eq!(7, m.get_test_message_singular().get_value());
+
+    // intermediate message is appended
+    field_path::set(&mut m, "test_message_repeated[0].value", reflect::ReflectI32(1)).unwrap();
+    field_path::set(&mut m, "test_message_repeated[1].value", reflect::ReflectI32(2)).unwrap();
+    field_path::set(&mut m, "test_message_repeated[0].value", reflect::ReflectI32(3)).unwrap();
+    let values: Vec<i32> = m.get_test_message_repeated().iter().map(|t| t.get_value()).collect();
+    assert_eq!(vec!(3, 2), values);
+
+    field_path::set(&mut m, "string_repeated[0]", reflect::ReflectString("a".to_string())).unwrap();
+    field_path::set(&mut m, "string_repeated[0]", reflect::ReflectString("b".to_string())).unwrap();
+    assert_eq!(["b".to_string()].as_slice(), m.get_string_repeated());
+
+    m.set_test_enum_repeated(vec!(text_format_test_data::DARK, text_format_test_data::DARK));
+    let light = reflect::EnumDescriptor::for_type::<text_format_test_data::TestEnum>().find_value_by_name("LIGHT").unwrap();
+    field_path::set(&mut m, "test_enum_repeated[1]", reflect::ReflectEnum(light)).unwrap();
+    assert_eq!([text_format_test_data::DARK, text_format_test_data::LIGHT].as_slice(), m.get_test_enum_repeated());
+
+    let before = m.clone();
+    assert_eq!("test_message_repeated[3].value: index 3 is out of range, field test_message_repeated has 2 elements",
+        field_path_error(field_path::set(&mut m, "test_message_repeated[3].value", reflect::ReflectI32(1))).as_slice());
+    let mut empty = text_format_test_data::TestTypes::new();
+    assert_eq!("test_message_repeated[1].value: index 1 is out of range, field test_message_repeated has 0 elements",
+        field_path_error(field_path::set(&mut empty, "test_message_repeated[1].value", reflect::ReflectI32(1))).as_slice());
+    assert_eq!("int32_singular: value type is not compatible with field int32_singular",
+        field_path_error(field_path::set(&mut m, "int32_singular", reflect::ReflectBool(true))).as_slice());
+    assert!(before == m);
+
+    let mut option = descriptor::UninterpretedOption::new();
+    assert_eq!("name[0]: message NamePart is missing required fields: name_part, is_extension",
+        field_path_error(field_path::set(&mut option, "name[0]",
+            reflect::ReflectMessage(box descriptor::UninterpretedOption_NamePart::new()))).as_slice());
+    assert_eq!(0, option.get_name().len());
+
+    // enum value and message of other types
+    let mut field = descriptor::FieldDescriptorProto::new();
+    assert_eq!("label: value type is not compatible with field label",
+        field_path_error(field_path::set(&mut field, "label",
+            reflect::ReflectEnum(descriptor::FieldDescriptorProto_TYPE_INT32.descriptor()))).as_slice());
+    assert!(!field.has_label());
+    // field is found by name in .proto, not by Rust name `field_type`
+    field_path::set(&mut field, "type",
+        reflect::ReflectEnum(descriptor::FieldDescriptorProto_TYPE_INT32.descriptor())).unwrap();
+    assert_eq!(descriptor::FieldDescriptorProto_TYPE_INT32, field.get_field_type());
+    let mut file = descriptor::FileDescriptorProto::new();
+    let mut options = descriptor::MessageOptions::new();
+    options.set_message_set_wire_format(true);
+    assert_eq!("options: value type is not compatible with field options",
+        field_path_error(field_path::set(&mut file, "options", reflect::ReflectMessage(box options))).as_slice());
+    assert!(!file.has_options());
 }
@@ -15,7 +15,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::descriptor::FileDescriptorProto };
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data)
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
@@ -26,6 +26,28 @@ pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescripto
     }
 }
 
+static mut file_descriptor_lazy: ::protobuf::lazy::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::FileDescriptor };
+
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    unsafe {
+        file_descriptor_lazy.get(|| {
+            ::protobuf::reflect::FileDescriptor::new(
+                file_descriptor_proto(),
+                vec!(
+                ),
+                vec!(
+                    ::protobuf::reflect::MessageDescriptor::for_type::<MessageA>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<MessageB>(),
+                ),
+                vec!(
+                    ::protobuf::reflect::EnumDescriptor::for_type::<MessageA_EnumA>(),
+                    ::protobuf::reflect::EnumDescriptor::for_type::<MessageB_EnumB>(),
+                ),
+            )
+        })
+    }
+}
+
 #[deriving(Clone,PartialEq,Default)]
 pub struct MessageA {
     unknown_fields: Option<Box<::protobuf::UnknownFields>>,
@@ -63,16 +85,26 @@ impl ::protobuf::Message for MessageA {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &MessageA) {
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -88,7 +120,7 @@ impl ::protobuf::Message for MessageA {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -121,7 +153,8 @@ impl ::protobuf::Message for MessageA {
                 ::protobuf::reflect::MessageDescriptor::new::<MessageA>(
                     "MessageA",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -152,9 +185,16 @@ pub enum MessageA_EnumA {
 
 impl MessageA_EnumA {
     pub fn new(value: i32) -> MessageA_EnumA {
+        match MessageA_EnumA::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum MessageA_EnumA", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<MessageA_EnumA> {
         match value {
-            0 => MessageA_FOO,
-            _ => fail!()
+            0 => Some(MessageA_FOO),
+            _ => None
         }
     }
 }
@@ -164,11 +204,15 @@ impl ::protobuf::ProtobufEnum for MessageA_EnumA {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<MessageA_EnumA> {
+        MessageA_EnumA::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<MessageA_EnumA>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("MessageA_EnumA", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("MessageA_EnumA", file_descriptor_proto(), file_descriptor)
             })
         }
     }
@@ -211,16 +255,26 @@ impl ::protobuf::Message for MessageB {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &MessageB) {
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -236,7 +290,7 @@ impl ::protobuf::Message for MessageB {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -269,7 +323,8 @@ impl ::protobuf::Message for MessageB {
                 ::protobuf::reflect::MessageDescriptor::new::<MessageB>(
                     "MessageB",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -300,9 +355,16 @@ pub enum MessageB_EnumB {
 
 impl MessageB_EnumB {
     pub fn new(value: i32) -> MessageB_EnumB {
+        match MessageB_EnumB::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum MessageB_EnumB", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<MessageB_EnumB> {
         match value {
-            0 => MessageB_FOO,
-            _ => fail!()
+            0 => Some(MessageB_FOO),
+            _ => None
         }
     }
 }
@@ -312,11 +374,15 @@ impl ::protobuf::ProtobufEnum for MessageB_EnumB {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<MessageB_EnumB> {
+        MessageB_EnumB::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<MessageB_EnumB>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("MessageB_EnumB", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("MessageB_EnumB", file_descriptor_proto(), file_descriptor)
             })
         }
     }
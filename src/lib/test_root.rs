@@ -14,7 +14,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::descriptor::FileDescriptorProto };
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data)
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
@@ -25,6 +25,26 @@ pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescripto
     }
 }
 
+static mut file_descriptor_lazy: ::protobuf::lazy::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::FileDescriptor };
+
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    unsafe {
+        file_descriptor_lazy.get(|| {
+            ::protobuf::reflect::FileDescriptor::new(
+                file_descriptor_proto(),
+                vec!(
+                ),
+                vec!(
+                    ::protobuf::reflect::MessageDescriptor::for_type::<Root>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<Root_Nested>(),
+                ),
+                vec!(
+                ),
+            )
+        })
+    }
+}
+
 #[deriving(Clone,PartialEq,Default)]
 pub struct Root {
     nested: ::protobuf::RepeatedField<Root_Nested>,
@@ -88,24 +108,48 @@ impl ::protobuf::Message for Root {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.nested.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.nested.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Root) {
+        use protobuf::{Message};
+        for v in other.nested.iter() {
+            self.nested.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -125,7 +169,7 @@ impl ::protobuf::Message for Root {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -159,7 +203,8 @@ impl ::protobuf::Message for Root {
                 ::protobuf::reflect::MessageDescriptor::new::<Root>(
                     "Root",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -192,6 +237,10 @@ impl ::protobuf::reflect::FieldAccessor<Root> for Root_nested_acc {
         "nested"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<Root_Nested>()
+    }
+
     fn len_field(&self, m: &Root) -> uint {
         m.get_nested().len()
     }
@@ -199,6 +248,18 @@ impl ::protobuf::reflect::FieldAccessor<Root> for Root_nested_acc {
     fn get_rep_message_item<'a>(&self, m: &'a Root, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_nested()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut Root) {
+        m.clear_nested();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut Root) -> &'a mut ::protobuf::Message {
+        m.mut_nested().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut Root, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_nested().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -238,16 +299,26 @@ impl ::protobuf::Message for Root_Nested {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Root_Nested) {
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -263,7 +334,7 @@ impl ::protobuf::Message for Root_Nested {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -296,7 +367,8 @@ impl ::protobuf::Message for Root_Nested {
                 ::protobuf::reflect::MessageDescriptor::new::<Root_Nested>(
                     "Root_Nested",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1,6 +1,33 @@
 use std::fmt;
+use std::io::BufReader;
+use std::default::Default;
 use core::Message;
+use core::CodedInputStream;
 use descriptor::*;
+use error::ProtobufResult;
+use reflect::FieldDescriptor;
+use reflect::ExtensionDescriptor;
+use reflect::ReflectValueRef;
+use reflect::ReflectMessageRef;
+use reflect::ReflectEnumRef;
+use reflect::ReflectStrRef;
+use reflect::ReflectBytesRef;
+use reflect::ReflectI32Ref;
+use reflect::ReflectI64Ref;
+use reflect::ReflectU32Ref;
+use reflect::ReflectU64Ref;
+use reflect::ReflectBoolRef;
+use reflect::ReflectF32Ref;
+use reflect::ReflectF64Ref;
+use strx::remove_to;
+use types::*;
+use unknown::UnknownFields;
+use unknown::UnknownValues;
+use unknown::UnknownFixed32Ref;
+use unknown::UnknownFixed64Ref;
+use unknown::UnknownVarintRef;
+use unknown::UnknownLengthDelimitedRef;
+use unknown::UnknownGroupRef;
 
 fn print_bytes_to(bytes: &[u8], buf: &mut String) {
     buf.push_char('"');
@@ -22,138 +49,225 @@ fn print_str_to(s: &str, buf: &mut String) {
     print_bytes_to(s.as_bytes(), buf);
 }
 
+// Group fields are printed with group type name, like in C++ implementation
+fn field_name<'a>(f: &'a FieldDescriptor) -> &'a str {
+    match f.proto().get_field_type() {
+        FieldDescriptorProto_TYPE_GROUP => remove_to(f.proto().get_type_name(), '.'),
+        _ => f.name(),
+    }
+}
+
+fn parse_unknown_fields(bytes: &[u8]) -> ProtobufResult<UnknownFields> {
+    let mut reader = BufReader::new(bytes);
+    let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+    let mut fields: UnknownFields = Default::default();
+    while !try!(is.eof()) {
+        let (field_number, wire_type) = try!(is.read_tag_unpack());
+        let value = try!(is.read_unknown(field_number, wire_type));
+        fields.add_value(field_number, value);
+    }
+    Ok(fields)
+}
+
+// Fields are printed with numbers instead of names, like in C++ implementation
+fn print_unknown_fields_to(fields: &UnknownFields, buf: &mut String) {
+    let mut numbers: Vec<u32> = fields.iter().map(|(number, _)| number).collect();
+    numbers.sort();
+    let mut first = true;
+    for &number in numbers.iter() {
+        for value in fields.get(number).unwrap().iter() {
+            if !first {
+                buf.push_str(" ");
+            }
+            first = false;
+            buf.push_str(number.to_str().as_slice());
+            match value {
+                UnknownVarintRef(varint) => {
+                    buf.push_str(": ");
+                    buf.push_str(varint.to_str().as_slice());
+                },
+                UnknownFixed32Ref(fixed32) => {
+                    buf.push_str(format!(": 0x{:08x}", fixed32).as_slice());
+                },
+                UnknownFixed64Ref(fixed64) => {
+                    buf.push_str(format!(": 0x{:016x}", fixed64).as_slice());
+                },
+                UnknownLengthDelimitedRef(bytes) => {
+                    buf.push_str(": ");
+                    print_bytes_to(bytes, buf);
+                },
+                UnknownGroupRef(group) => {
+                    buf.push_str(" {");
+                    print_unknown_fields_to(group, buf);
+                    buf.push_str("}");
+                },
+            }
+        }
+    }
+}
+
+fn extension_values<V, T : ProtobufType<V>>(e: &ExtensionDescriptor, t: T, unknown: &UnknownValues) -> Vec<V> {
+    if e.is_repeated() {
+        t.get_repeated_from_unknown(unknown)
+    } else {
+        t.get_from_unknown(unknown).move_iter().collect()
+    }
+}
+
+fn extension_values_to_str<V : ToStr, T : ProtobufType<V>>(e: &ExtensionDescriptor, t: T, unknown: &UnknownValues)
+    -> Vec<String>
+{
+    extension_values(e, t, unknown).iter().map(|v| format!(": {}", v.to_str())).collect()
+}
+
+// Message type of extension may be unknown, so content is printed as unknown fields
+fn print_extension_message_to(bytes: &[u8], buf: &mut String) {
+    match parse_unknown_fields(bytes) {
+        Ok(fields) => {
+            buf.push_str(" {");
+            print_unknown_fields_to(&fields, buf);
+            buf.push_str("}");
+        },
+        Err(..) => {
+            buf.push_str(": ");
+            print_bytes_to(bytes, buf);
+        },
+    }
+}
+
+// Values of extension, printed after extension name
+fn print_extension_values(e: &ExtensionDescriptor, unknown: &UnknownValues) -> Vec<String> {
+    match e.proto().get_field_type() {
+        FieldDescriptorProto_TYPE_MESSAGE => {
+            let values = if e.is_repeated() {
+                unknown.length_delimited.clone()
+            } else if unknown.length_delimited.is_empty() {
+                Vec::new()
+            } else {
+                // occurrences of singular message are merged
+                vec!(unknown.length_delimited.as_slice().concat_vec())
+            };
+            values.iter().map(|bytes| {
+                let mut buf = String::new();
+                print_extension_message_to(bytes.as_slice(), &mut buf);
+                buf
+            }).collect()
+        },
+        FieldDescriptorProto_TYPE_GROUP => {
+            unknown.group.iter().map(|group| {
+                let mut buf = " {".to_string();
+                print_unknown_fields_to(group, &mut buf);
+                buf.push_str("}");
+                buf
+            }).collect()
+        },
+        FieldDescriptorProto_TYPE_ENUM => {
+            extension_values(e, ProtobufTypeInt32, unknown).iter().map(|&v| {
+                match e.enum_value_name(v) {
+                    Some(name) => format!(": {}", name),
+                    None => format!(": {}", v),
+                }
+            }).collect()
+        },
+        FieldDescriptorProto_TYPE_STRING => {
+            extension_values(e, ProtobufTypeString, unknown).iter().map(|s| {
+                let mut buf = ": ".to_string();
+                print_str_to(s.as_slice(), &mut buf);
+                buf
+            }).collect()
+        },
+        FieldDescriptorProto_TYPE_BYTES => {
+            extension_values(e, ProtobufTypeBytes, unknown).iter().map(|b| {
+                let mut buf = ": ".to_string();
+                print_bytes_to(b.as_slice(), &mut buf);
+                buf
+            }).collect()
+        },
+        FieldDescriptorProto_TYPE_INT32    => extension_values_to_str(e, ProtobufTypeInt32, unknown),
+        FieldDescriptorProto_TYPE_SINT32   => extension_values_to_str(e, ProtobufTypeSint32, unknown),
+        FieldDescriptorProto_TYPE_SFIXED32 => extension_values_to_str(e, ProtobufTypeSfixed32, unknown),
+        FieldDescriptorProto_TYPE_INT64    => extension_values_to_str(e, ProtobufTypeInt64, unknown),
+        FieldDescriptorProto_TYPE_SINT64   => extension_values_to_str(e, ProtobufTypeSint64, unknown),
+        FieldDescriptorProto_TYPE_SFIXED64 => extension_values_to_str(e, ProtobufTypeSfixed64, unknown),
+        FieldDescriptorProto_TYPE_UINT32   => extension_values_to_str(e, ProtobufTypeUint32, unknown),
+        FieldDescriptorProto_TYPE_FIXED32  => extension_values_to_str(e, ProtobufTypeFixed32, unknown),
+        FieldDescriptorProto_TYPE_UINT64   => extension_values_to_str(e, ProtobufTypeUint64, unknown),
+        FieldDescriptorProto_TYPE_FIXED64  => extension_values_to_str(e, ProtobufTypeFixed64, unknown),
+        FieldDescriptorProto_TYPE_BOOL     => extension_values_to_str(e, ProtobufTypeBool, unknown),
+        FieldDescriptorProto_TYPE_FLOAT    => extension_values_to_str(e, ProtobufTypeFloat, unknown),
+        FieldDescriptorProto_TYPE_DOUBLE   => extension_values_to_str(e, ProtobufTypeDouble, unknown),
+    }
+}
+
+fn print_value_to(value: ReflectValueRef, buf: &mut String) {
+    match value {
+        ReflectMessageRef(m) => {
+            buf.push_str("{");
+            print_to(m, buf);
+            buf.push_str("}");
+        },
+        ReflectEnumRef(e) => buf.push_str(e.name()),
+        ReflectStrRef(s) => print_str_to(s, buf),
+        ReflectBytesRef(b) => print_bytes_to(b, buf),
+        ReflectI32Ref(v)  => buf.push_str(format!("{}", v).as_slice()),
+        ReflectI64Ref(v)  => buf.push_str(format!("{}", v).as_slice()),
+        ReflectU32Ref(v)  => buf.push_str(format!("{}", v).as_slice()),
+        ReflectU64Ref(v)  => buf.push_str(format!("{}", v).as_slice()),
+        ReflectBoolRef(v) => buf.push_str(format!("{}", v).as_slice()),
+        ReflectF32Ref(v)  => buf.push_str(format!("{}", v).as_slice()),
+        ReflectF64Ref(v)  => buf.push_str(format!("{}", v).as_slice()),
+    }
+}
+
+fn print_field_to(f: &FieldDescriptor, value: ReflectValueRef, buf: &mut String) {
+    buf.push_str(field_name(f));
+    match value {
+        ReflectMessageRef(..) => buf.push_str(" "),
+        _ => buf.push_str(": "),
+    }
+    print_value_to(value, buf);
+}
+
+// Value as printed in text format, message is printed in braces
+pub fn value_to_str(value: ReflectValueRef) -> String {
+    let mut r = String::new();
+    print_value_to(value, &mut r);
+    r
+}
+
 pub fn print_to(m: &Message, buf: &mut String) {
     let d = m.descriptor();
     let mut first = true;
     for f in d.fields().iter() {
-        if f.is_repeated() {
-            for i in range(0, f.len_field(m)) {
-                if !first {
-                    buf.push_str(" ");
-                }
-                first = false;
-                buf.push_str(f.name());
-                match f.proto().get_field_type() {
-                    FieldDescriptorProto_TYPE_MESSAGE => {
-                        buf.push_str(" {");
-                        print_to(f.get_rep_message_item(m, i), buf);
-                        buf.push_str("}");
-                    },
-                    FieldDescriptorProto_TYPE_ENUM => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_enum_item(m, i).name());
-                    },
-                    FieldDescriptorProto_TYPE_STRING => {
-                        buf.push_str(": ");
-                        print_str_to(f.get_rep_str_item(m, i), buf);
-                    },
-                    FieldDescriptorProto_TYPE_BYTES => {
-                        buf.push_str(": ");
-                        print_bytes_to(f.get_rep_bytes_item(m, i), buf);
-                    },
-                    FieldDescriptorProto_TYPE_INT32 |
-                    FieldDescriptorProto_TYPE_SINT32 |
-                    FieldDescriptorProto_TYPE_SFIXED32 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_i32(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_INT64 |
-                    FieldDescriptorProto_TYPE_SINT64 |
-                    FieldDescriptorProto_TYPE_SFIXED64 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_i64(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_UINT32 |
-                    FieldDescriptorProto_TYPE_FIXED32 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_u32(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_UINT64 |
-                    FieldDescriptorProto_TYPE_FIXED64 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_u64(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_BOOL => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_bool(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_FLOAT => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_f32(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_DOUBLE => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_rep_f64(m)[i].to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_GROUP => {
-                        buf.push_str(": <TYPE_GROUP>");
-                    }
-                }
-            }
+        let values: Vec<ReflectValueRef> = if f.is_repeated() {
+            f.get_repeated(m).collect()
         } else {
-            if f.has_field(m) {
-                if !first {
-                    buf.push_str(" ");
-                }
-                first = false;
-                buf.push_str(f.name());
-                match f.proto().get_field_type() {
-                    FieldDescriptorProto_TYPE_MESSAGE => {
-                        buf.push_str(" {");
-                        print_to(f.get_message(m), buf);
-                        buf.push_str("}");
-                    },
-                    FieldDescriptorProto_TYPE_ENUM => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_enum(m).name());
-                    },
-                    FieldDescriptorProto_TYPE_STRING => {
-                        buf.push_str(": ");
-                        print_str_to(f.get_str(m), buf);
-                    },
-                    FieldDescriptorProto_TYPE_BYTES => {
-                        buf.push_str(": ");
-                        print_bytes_to(f.get_bytes(m), buf);
-                    },
-                    FieldDescriptorProto_TYPE_INT32 |
-                    FieldDescriptorProto_TYPE_SINT32 |
-                    FieldDescriptorProto_TYPE_SFIXED32 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_i32(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_INT64 |
-                    FieldDescriptorProto_TYPE_SINT64 |
-                    FieldDescriptorProto_TYPE_SFIXED64 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_i64(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_UINT32 |
-                    FieldDescriptorProto_TYPE_FIXED32 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_u32(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_UINT64 |
-                    FieldDescriptorProto_TYPE_FIXED64 => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_u64(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_BOOL => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_bool(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_FLOAT => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_f32(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_DOUBLE => {
-                        buf.push_str(": ");
-                        buf.push_str(f.get_f64(m).to_str().as_slice());
-                    },
-                    FieldDescriptorProto_TYPE_GROUP => {
-                        buf.push_str(": <TYPE_GROUP>");
-                    }
-                }
+            f.get_singular(m).move_iter().collect()
+        };
+        for value in values.move_iter() {
+            if !first {
+                buf.push_str(" ");
+            }
+            first = false;
+            print_field_to(f, value, buf);
+        }
+    }
+
+    // only extensions declared in the same file as message are known
+    for e in d.extensions().iter() {
+        let unknown = match m.get_unknown_fields().get(e.number()) {
+            Some(unknown) => unknown,
+            None => continue,
+        };
+        for value in print_extension_values(e, unknown).iter() {
+            if !first {
+                buf.push_str(" ");
             }
+            first = false;
+            buf.push_str("[");
+            buf.push_str(e.full_name());
+            buf.push_str("]");
+            buf.push_str(value.as_slice());
         }
     }
 
@@ -204,6 +318,7 @@ mod test {
         t("bytes_singular: \"def\"",  |m| m.set_bytes_singular(Vec::from_slice("def".as_bytes())));
         t("test_enum_singular: DARK", |m| m.set_test_enum_singular(DARK));
         t("test_message_singular {}", |m| { m.mut_test_message_singular(); });
+        t("TestGroupSingular {}",     |m| { m.mut_testgroupsingular(); });
     }
 
     #[test]
@@ -226,6 +341,7 @@ mod test {
         t("bytes_repeated: \"def\"",  |m| m.add_bytes_repeated(Vec::from_slice("def".as_bytes())));
         t("test_enum_repeated: DARK", |m| m.add_test_enum_repeated(DARK));
         t("test_message_repeated {}", |m| { m.add_test_message_repeated(Default::default()); });
+        t("TestGroupRepeated {}",     |m| { m.add_testgrouprepeated(Default::default()); });
     }
 
     #[test]
@@ -240,6 +356,7 @@ mod test {
     #[test]
     fn test_complex_message() {
         t("test_message_singular {value: 30}", |m| m.mut_test_message_singular().set_value(30));
+        t("TestGroupSingular {value: 40}", |m| m.mut_testgroupsingular().set_value(40));
     }
 
     #[test]
@@ -8,7 +8,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x6d, 0x61, 0x74, 0x5f, 0x74, 0x65, 0x73, 0x74, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x70, 0x72,
     0x6f, 0x74, 0x6f, 0x22, 0x1c, 0x0a, 0x0b, 0x54, 0x65, 0x73, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61,
     0x67, 0x65, 0x12, 0x0d, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x0a, 0x20, 0x01, 0x28,
-    0x05, 0x22, 0xa1, 0x07, 0x0a, 0x09, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x12,
+    0x05, 0x22, 0xdb, 0x08, 0x0a, 0x09, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65, 0x73, 0x12,
     0x17, 0x0a, 0x0f, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x73, 0x69, 0x6e, 0x67, 0x75, 0x6c,
     0x61, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x01, 0x12, 0x16, 0x0a, 0x0e, 0x66, 0x6c, 0x6f, 0x61,
     0x74, 0x5f, 0x73, 0x69, 0x6e, 0x67, 0x75, 0x6c, 0x61, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x02,
@@ -37,44 +37,55 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x28, 0x0e, 0x32, 0x09, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x12, 0x2b, 0x0a,
     0x15, 0x74, 0x65, 0x73, 0x74, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x73, 0x69,
     0x6e, 0x67, 0x75, 0x6c, 0x61, 0x72, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x54,
-    0x65, 0x73, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x17, 0x0a, 0x0f, 0x64, 0x6f,
-    0x75, 0x62, 0x6c, 0x65, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x1f, 0x20,
-    0x03, 0x28, 0x01, 0x12, 0x16, 0x0a, 0x0e, 0x66, 0x6c, 0x6f, 0x61, 0x74, 0x5f, 0x72, 0x65, 0x70,
-    0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x20, 0x20, 0x03, 0x28, 0x02, 0x12, 0x16, 0x0a, 0x0e, 0x69,
-    0x6e, 0x74, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x21, 0x20,
-    0x03, 0x28, 0x05, 0x12, 0x16, 0x0a, 0x0e, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70,
-    0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x22, 0x20, 0x03, 0x28, 0x03, 0x12, 0x17, 0x0a, 0x0f, 0x75,
-    0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x23,
-    0x20, 0x03, 0x28, 0x0d, 0x12, 0x17, 0x0a, 0x0f, 0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x72,
-    0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x24, 0x20, 0x03, 0x28, 0x04, 0x12, 0x17, 0x0a,
-    0x0f, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64,
-    0x18, 0x25, 0x20, 0x03, 0x28, 0x11, 0x12, 0x17, 0x0a, 0x0f, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34,
-    0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x26, 0x20, 0x03, 0x28, 0x12, 0x12,
-    0x18, 0x0a, 0x10, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61,
-    0x74, 0x65, 0x64, 0x18, 0x27, 0x20, 0x03, 0x28, 0x07, 0x12, 0x18, 0x0a, 0x10, 0x66, 0x69, 0x78,
-    0x65, 0x64, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x28, 0x20,
-    0x03, 0x28, 0x06, 0x12, 0x19, 0x0a, 0x11, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f,
-    0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x29, 0x20, 0x03, 0x28, 0x0f, 0x12, 0x19,
-    0x0a, 0x11, 0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61,
-    0x74, 0x65, 0x64, 0x18, 0x2a, 0x20, 0x03, 0x28, 0x10, 0x12, 0x15, 0x0a, 0x0d, 0x62, 0x6f, 0x6f,
-    0x6c, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x2b, 0x20, 0x03, 0x28, 0x08,
-    0x12, 0x17, 0x0a, 0x0f, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61,
-    0x74, 0x65, 0x64, 0x18, 0x2c, 0x20, 0x03, 0x28, 0x09, 0x12, 0x16, 0x0a, 0x0e, 0x62, 0x79, 0x74,
-    0x65, 0x73, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x2d, 0x20, 0x03, 0x28,
-    0x0c, 0x12, 0x25, 0x0a, 0x12, 0x74, 0x65, 0x73, 0x74, 0x5f, 0x65, 0x6e, 0x75, 0x6d, 0x5f, 0x72,
-    0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x2e, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x09, 0x2e,
-    0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x12, 0x2b, 0x0a, 0x15, 0x74, 0x65, 0x73, 0x74,
-    0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65,
-    0x64, 0x18, 0x2f, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x4d, 0x65,
-    0x73, 0x73, 0x61, 0x67, 0x65, 0x2a, 0x1f, 0x0a, 0x08, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75,
-    0x6d, 0x12, 0x08, 0x0a, 0x04, 0x44, 0x41, 0x52, 0x4b, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x4c,
-    0x49, 0x47, 0x48, 0x54, 0x10, 0x02,
+    0x65, 0x73, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x37, 0x0a, 0x11, 0x74, 0x65,
+    0x73, 0x74, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x73, 0x69, 0x6e, 0x67, 0x75, 0x6c, 0x61, 0x72, 0x18,
+    0x12, 0x20, 0x01, 0x28, 0x0a, 0x32, 0x1c, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x54, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x53, 0x69, 0x6e, 0x67, 0x75,
+    0x6c, 0x61, 0x72, 0x12, 0x17, 0x0a, 0x0f, 0x64, 0x6f, 0x75, 0x62, 0x6c, 0x65, 0x5f, 0x72, 0x65,
+    0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x1f, 0x20, 0x03, 0x28, 0x01, 0x12, 0x16, 0x0a, 0x0e,
+    0x66, 0x6c, 0x6f, 0x61, 0x74, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x20,
+    0x20, 0x03, 0x28, 0x02, 0x12, 0x16, 0x0a, 0x0e, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x72, 0x65,
+    0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x21, 0x20, 0x03, 0x28, 0x05, 0x12, 0x16, 0x0a, 0x0e,
+    0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x22,
+    0x20, 0x03, 0x28, 0x03, 0x12, 0x17, 0x0a, 0x0f, 0x75, 0x69, 0x6e, 0x74, 0x33, 0x32, 0x5f, 0x72,
+    0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x23, 0x20, 0x03, 0x28, 0x0d, 0x12, 0x17, 0x0a,
+    0x0f, 0x75, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x18, 0x24, 0x20, 0x03, 0x28, 0x04, 0x12, 0x17, 0x0a, 0x0f, 0x73, 0x69, 0x6e, 0x74, 0x33, 0x32,
+    0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x25, 0x20, 0x03, 0x28, 0x11, 0x12,
+    0x17, 0x0a, 0x0f, 0x73, 0x69, 0x6e, 0x74, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74,
+    0x65, 0x64, 0x18, 0x26, 0x20, 0x03, 0x28, 0x12, 0x12, 0x18, 0x0a, 0x10, 0x66, 0x69, 0x78, 0x65,
+    0x64, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x27, 0x20, 0x03,
+    0x28, 0x07, 0x12, 0x18, 0x0a, 0x10, 0x66, 0x69, 0x78, 0x65, 0x64, 0x36, 0x34, 0x5f, 0x72, 0x65,
+    0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x28, 0x20, 0x03, 0x28, 0x06, 0x12, 0x19, 0x0a, 0x11,
+    0x73, 0x66, 0x69, 0x78, 0x65, 0x64, 0x33, 0x32, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65,
+    0x64, 0x18, 0x29, 0x20, 0x03, 0x28, 0x0f, 0x12, 0x19, 0x0a, 0x11, 0x73, 0x66, 0x69, 0x78, 0x65,
+    0x64, 0x36, 0x34, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x2a, 0x20, 0x03,
+    0x28, 0x10, 0x12, 0x15, 0x0a, 0x0d, 0x62, 0x6f, 0x6f, 0x6c, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61,
+    0x74, 0x65, 0x64, 0x18, 0x2b, 0x20, 0x03, 0x28, 0x08, 0x12, 0x17, 0x0a, 0x0f, 0x73, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x2c, 0x20, 0x03,
+    0x28, 0x09, 0x12, 0x16, 0x0a, 0x0e, 0x62, 0x79, 0x74, 0x65, 0x73, 0x5f, 0x72, 0x65, 0x70, 0x65,
+    0x61, 0x74, 0x65, 0x64, 0x18, 0x2d, 0x20, 0x03, 0x28, 0x0c, 0x12, 0x25, 0x0a, 0x12, 0x74, 0x65,
+    0x73, 0x74, 0x5f, 0x65, 0x6e, 0x75, 0x6d, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x18, 0x2e, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x09, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75,
+    0x6d, 0x12, 0x2b, 0x0a, 0x15, 0x74, 0x65, 0x73, 0x74, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x5f, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x18, 0x2f, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x0c, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x4d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x37,
+    0x0a, 0x11, 0x74, 0x65, 0x73, 0x74, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x72, 0x65, 0x70, 0x65, 0x61,
+    0x74, 0x65, 0x64, 0x18, 0x30, 0x20, 0x03, 0x28, 0x0a, 0x32, 0x1c, 0x2e, 0x54, 0x65, 0x73, 0x74,
+    0x54, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x54, 0x65, 0x73, 0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x52,
+    0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x1a, 0x22, 0x0a, 0x11, 0x54, 0x65, 0x73, 0x74, 0x47,
+    0x72, 0x6f, 0x75, 0x70, 0x53, 0x69, 0x6e, 0x67, 0x75, 0x6c, 0x61, 0x72, 0x12, 0x0d, 0x0a, 0x05,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x1a, 0x22, 0x0a, 0x11, 0x54,
+    0x65, 0x73, 0x74, 0x47, 0x72, 0x6f, 0x75, 0x70, 0x52, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64,
+    0x12, 0x0d, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x05, 0x2a,
+    0x1f, 0x0a, 0x08, 0x54, 0x65, 0x73, 0x74, 0x45, 0x6e, 0x75, 0x6d, 0x12, 0x08, 0x0a, 0x04, 0x44,
+    0x41, 0x52, 0x4b, 0x10, 0x01, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x49, 0x47, 0x48, 0x54, 0x10, 0x02,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::descriptor::FileDescriptorProto };
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data)
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
@@ -85,6 +96,29 @@ pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescripto
     }
 }
 
+static mut file_descriptor_lazy: ::protobuf::lazy::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::FileDescriptor };
+
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    unsafe {
+        file_descriptor_lazy.get(|| {
+            ::protobuf::reflect::FileDescriptor::new(
+                file_descriptor_proto(),
+                vec!(
+                ),
+                vec!(
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestMessage>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestTypes>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestTypes_TestGroupSingular>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<TestTypes_TestGroupRepeated>(),
+                ),
+                vec!(
+                    ::protobuf::reflect::EnumDescriptor::for_type::<TestEnum>(),
+                ),
+            )
+        })
+    }
+}
+
 #[deriving(Clone,PartialEq,Default)]
 pub struct TestMessage {
     value: Option<i32>,
@@ -156,21 +190,38 @@ impl ::protobuf::Message for TestMessage {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.value = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestMessage) {
+        if other.value.is_some() {
+            self.value = other.value.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -189,7 +240,7 @@ impl ::protobuf::Message for TestMessage {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -223,7 +274,8 @@ impl ::protobuf::Message for TestMessage {
                 ::protobuf::reflect::MessageDescriptor::new::<TestMessage>(
                     "TestMessage",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -263,6 +315,14 @@ impl ::protobuf::reflect::FieldAccessor<TestMessage> for TestMessage_value_acc {
     fn get_i32(&self, m: &TestMessage) -> i32 {
         m.get_value()
     }
+
+    fn clear_field(&self, m: &mut TestMessage) {
+        m.clear_value();
+    }
+
+    fn set_i32(&self, m: &mut TestMessage, v: i32) {
+        m.set_value(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -284,6 +344,7 @@ pub struct TestTypes {
     bytes_singular: Option<Vec<u8>>,
     test_enum_singular: Option<TestEnum>,
     test_message_singular: ::protobuf::SingularField<TestMessage>,
+    testgroupsingular: ::protobuf::SingularField<TestTypes_TestGroupSingular>,
     double_repeated: Vec<f64>,
     float_repeated: Vec<f32>,
     int32_repeated: Vec<i32>,
@@ -301,6 +362,7 @@ pub struct TestTypes {
     bytes_repeated: Vec<Vec<u8>>,
     test_enum_repeated: Vec<TestEnum>,
     test_message_repeated: ::protobuf::RepeatedField<TestMessage>,
+    testgrouprepeated: ::protobuf::RepeatedField<TestTypes_TestGroupRepeated>,
     unknown_fields: Option<Box<::protobuf::UnknownFields>>,
 }
 
@@ -331,6 +393,7 @@ impl<'a> TestTypes {
                     bytes_singular: None,
                     test_enum_singular: None,
                     test_message_singular: ::protobuf::SingularField::none(),
+                    testgroupsingular: ::protobuf::SingularField::none(),
                     double_repeated: Vec::new(),
                     float_repeated: Vec::new(),
                     int32_repeated: Vec::new(),
@@ -348,6 +411,7 @@ impl<'a> TestTypes {
                     bytes_repeated: Vec::new(),
                     test_enum_repeated: Vec::new(),
                     test_message_repeated: ::protobuf::RepeatedField::new(),
+                    testgrouprepeated: ::protobuf::RepeatedField::new(),
                     unknown_fields: None,
                 }
             })
@@ -461,6 +525,15 @@ impl<'a> TestTypes {
             },
             None => {},
         };
+        match self.testgroupsingular.as_ref() {
+            Some(ref v) => {
+                os.write_tag(18, ::protobuf::wire_format::WireTypeStartGroup);
+                *sizes_pos += 1;
+                v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+                os.write_tag(18, ::protobuf::wire_format::WireTypeEndGroup);
+            },
+            None => {},
+        };
         for v in self.double_repeated.iter() {
             os.write_double(31, *v);
         };
@@ -515,6 +588,12 @@ impl<'a> TestTypes {
             *sizes_pos += 1;
             v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
         };
+        for v in self.testgrouprepeated.iter() {
+            os.write_tag(48, ::protobuf::wire_format::WireTypeStartGroup);
+            *sizes_pos += 1;
+            v.write_to_with_computed_sizes(os, sizes.as_slice(), sizes_pos);
+            os.write_tag(48, ::protobuf::wire_format::WireTypeEndGroup);
+        };
         os.write_unknown_fields(self.get_unknown_fields());
     }
 
@@ -931,13 +1010,13 @@ impl<'a> TestTypes {
     // If field is not initialized, it is initialized with default value first.
     pub fn mut_test_enum_singular(&'a mut self) -> &'a mut TestEnum {
         if self.test_enum_singular.is_none() {
-            self.test_enum_singular = Some(TestEnum::new(0));
+            self.test_enum_singular = Some(DARK);
         };
         self.test_enum_singular.get_mut_ref()
     }
 
     pub fn get_test_enum_singular(&self) -> TestEnum {
-        self.test_enum_singular.unwrap_or_else(|| TestEnum::new(0))
+        self.test_enum_singular.unwrap_or_else(|| DARK)
     }
 
     pub fn clear_test_message_singular(&mut self) {
@@ -966,6 +1045,32 @@ impl<'a> TestTypes {
         self.test_message_singular.as_ref().unwrap_or_else(|| TestMessage::default_instance())
     }
 
+    pub fn clear_testgroupsingular(&mut self) {
+        self.testgroupsingular.clear();
+    }
+
+    pub fn has_testgroupsingular(&self) -> bool {
+        self.testgroupsingular.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_testgroupsingular(&mut self, v: TestTypes_TestGroupSingular) {
+        self.testgroupsingular = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_testgroupsingular(&'a mut self) -> &'a mut TestTypes_TestGroupSingular {
+        if self.testgroupsingular.is_none() {
+            self.testgroupsingular.set_default();
+        };
+        self.testgroupsingular.get_mut_ref()
+    }
+
+    pub fn get_testgroupsingular(&'a self) -> &'a TestTypes_TestGroupSingular {
+        self.testgroupsingular.as_ref().unwrap_or_else(|| TestTypes_TestGroupSingular::default_instance())
+    }
+
     pub fn clear_double_repeated(&mut self) {
         self.double_repeated.clear();
     }
@@ -1339,6 +1444,28 @@ impl<'a> TestTypes {
     pub fn add_test_message_repeated(&mut self, v: TestMessage) {
         self.test_message_repeated.push(v);
     }
+
+    pub fn clear_testgrouprepeated(&mut self) {
+        self.testgrouprepeated.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_testgrouprepeated(&mut self, v: ::protobuf::RepeatedField<TestTypes_TestGroupRepeated>) {
+        self.testgrouprepeated = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_testgrouprepeated(&'a mut self) -> &'a mut ::protobuf::RepeatedField<TestTypes_TestGroupRepeated> {
+        &mut self.testgrouprepeated
+    }
+
+    pub fn get_testgrouprepeated(&'a self) -> &'a [TestTypes_TestGroupRepeated] {
+        self.testgrouprepeated.as_slice()
+    }
+
+    pub fn add_testgrouprepeated(&mut self, v: TestTypes_TestGroupRepeated) {
+        self.testgrouprepeated.push(v);
+    }
 }
 
 impl ::protobuf::Message for TestTypes {
@@ -1347,301 +1474,586 @@ impl ::protobuf::Message for TestTypes {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.test_message_singular.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.testgroupsingular.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.test_message_repeated.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
+        for v in self.testgrouprepeated.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_double();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_double());
                     self.double_singular = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                    let tmp = is.read_float();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_float());
                     self.float_singular = Some(tmp);
                 },
                 3 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
                     self.int32_singular = Some(tmp);
                 },
                 4 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_int64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int64());
                     self.int64_singular = Some(tmp);
                 },
                 5 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint32());
                     self.uint32_singular = Some(tmp);
                 },
                 6 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_uint64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_uint64());
                     self.uint64_singular = Some(tmp);
                 },
                 7 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_sint32();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sint32());
                     self.sint32_singular = Some(tmp);
                 },
                 8 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_sint64();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sint64());
                     self.sint64_singular = Some(tmp);
                 },
                 9 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                    let tmp = is.read_fixed32();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_fixed32());
                     self.fixed32_singular = Some(tmp);
                 },
                 10 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_fixed64();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_fixed64());
                     self.fixed64_singular = Some(tmp);
                 },
                 11 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                    let tmp = is.read_sfixed32();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sfixed32());
                     self.sfixed32_singular = Some(tmp);
                 },
                 12 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                    let tmp = is.read_sfixed64();
+                    if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_sfixed64());
                     self.sfixed64_singular = Some(tmp);
                 },
                 13 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = is.read_bool();
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bool());
                     self.bool_singular = Some(tmp);
                 },
                 14 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.string_singular = Some(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_bytes();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
                     self.bytes_singular = Some(tmp);
                 },
                 16 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                    let tmp = TestEnum::new(is.read_int32());
-                    self.test_enum_singular = Some(tmp);
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    match TestEnum::from_i32(tmp) {
+                        Some(tmp) => {
+                            self.test_enum_singular = Some(tmp);
+                        },
+                        None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                    };
                 },
                 17 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.test_message_singular.set_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeStartGroup {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.testgroupsingular.set_default();
+                    try!(is.merge_group(field_number, tmp));
                 },
                 31 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.double_repeated.push(is.read_double());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.double_repeated.push(try!(is.read_double()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.double_repeated.push(is.read_double());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.double_repeated.push(try!(is.read_double()));
                     }
                 },
                 32 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.float_repeated.push(is.read_float());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.float_repeated.push(try!(is.read_float()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.float_repeated.push(is.read_float());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.float_repeated.push(try!(is.read_float()));
                     }
                 },
                 33 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.int32_repeated.push(is.read_int32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.int32_repeated.push(try!(is.read_int32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.int32_repeated.push(is.read_int32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.int32_repeated.push(try!(is.read_int32()));
                     }
                 },
                 34 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.int64_repeated.push(is.read_int64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.int64_repeated.push(try!(is.read_int64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.int64_repeated.push(is.read_int64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.int64_repeated.push(try!(is.read_int64()));
                     }
                 },
                 35 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.uint32_repeated.push(is.read_uint32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.uint32_repeated.push(try!(is.read_uint32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.uint32_repeated.push(is.read_uint32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.uint32_repeated.push(try!(is.read_uint32()));
                     }
                 },
                 36 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.uint64_repeated.push(is.read_uint64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.uint64_repeated.push(try!(is.read_uint64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.uint64_repeated.push(is.read_uint64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.uint64_repeated.push(try!(is.read_uint64()));
                     }
                 },
                 37 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sint32_repeated.push(is.read_sint32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sint32_repeated.push(try!(is.read_sint32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.sint32_repeated.push(is.read_sint32());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sint32_repeated.push(try!(is.read_sint32()));
                     }
                 },
                 38 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sint64_repeated.push(is.read_sint64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sint64_repeated.push(try!(is.read_sint64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.sint64_repeated.push(is.read_sint64());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sint64_repeated.push(try!(is.read_sint64()));
                     }
                 },
                 39 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.fixed32_repeated.push(is.read_fixed32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.fixed32_repeated.push(try!(is.read_fixed32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.fixed32_repeated.push(is.read_fixed32());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.fixed32_repeated.push(try!(is.read_fixed32()));
                     }
                 },
                 40 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.fixed64_repeated.push(is.read_fixed64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.fixed64_repeated.push(try!(is.read_fixed64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.fixed64_repeated.push(is.read_fixed64());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.fixed64_repeated.push(try!(is.read_fixed64()));
                     }
                 },
                 41 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sfixed32_repeated.push(is.read_sfixed32());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sfixed32_repeated.push(try!(is.read_sfixed32()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed32, wire_type);
-                        self.sfixed32_repeated.push(is.read_sfixed32());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed32 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sfixed32_repeated.push(try!(is.read_sfixed32()));
                     }
                 },
                 42 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.sfixed64_repeated.push(is.read_sfixed64());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.sfixed64_repeated.push(try!(is.read_sfixed64()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeFixed64, wire_type);
-                        self.sfixed64_repeated.push(is.read_sfixed64());
+                        if wire_type != ::protobuf::wire_format::WireTypeFixed64 {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.sfixed64_repeated.push(try!(is.read_sfixed64()));
                     }
                 },
                 43 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.bool_repeated.push(is.read_bool());
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            self.bool_repeated.push(try!(is.read_bool()));
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.bool_repeated.push(is.read_bool());
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        self.bool_repeated.push(try!(is.read_bool()));
                     }
                 },
                 44 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.string_repeated.push(tmp);
                 },
                 45 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_bytes();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_bytes());
                     self.bytes_repeated.push(tmp);
                 },
                 46 => {
                     if wire_type == ::protobuf::wire_format::WireTypeLengthDelimited {
-                        let len = is.read_raw_varint32();
-                        let old_limit = is.push_limit(len);
-                        while !is.eof() {
-                            self.test_enum_repeated.push(TestEnum::new(is.read_int32()));
+                        let len = try!(is.read_raw_varint32());
+                        let old_limit = try!(is.push_limit(len));
+                        while !try!(is.eof()) {
+                            let tmp = try!(is.read_int32());
+                            match TestEnum::from_i32(tmp) {
+                                Some(tmp) => {
+                                    self.test_enum_repeated.push(tmp);
+                                },
+                                None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                            };
                         }
-                        is.pop_limit(old_limit);
+                        try!(is.pop_limit(old_limit));
                     } else {
-                        assert_eq!(::protobuf::wire_format::WireTypeVarint, wire_type);
-                        self.test_enum_repeated.push(TestEnum::new(is.read_int32()));
+                        if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                            let unknown = try!(is.read_unknown(field_number, wire_type));
+                            self.mut_unknown_fields().add_value(field_number, unknown);
+                            continue;
+                        };
+                        let tmp = try!(is.read_int32());
+                        match TestEnum::from_i32(tmp) {
+                            Some(tmp) => {
+                                self.test_enum_repeated.push(tmp);
+                            },
+                            None => self.mut_unknown_fields().add_varint(field_number, tmp as u64),
+                        };
                     }
                 },
                 47 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.test_message_repeated.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
+                },
+                48 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeStartGroup {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = self.testgrouprepeated.push_default();
+                    try!(is.merge_group(field_number, tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestTypes) {
+        use protobuf::{Message};
+        if other.double_singular.is_some() {
+            self.double_singular = other.double_singular.clone();
+        };
+        if other.float_singular.is_some() {
+            self.float_singular = other.float_singular.clone();
+        };
+        if other.int32_singular.is_some() {
+            self.int32_singular = other.int32_singular.clone();
+        };
+        if other.int64_singular.is_some() {
+            self.int64_singular = other.int64_singular.clone();
+        };
+        if other.uint32_singular.is_some() {
+            self.uint32_singular = other.uint32_singular.clone();
+        };
+        if other.uint64_singular.is_some() {
+            self.uint64_singular = other.uint64_singular.clone();
+        };
+        if other.sint32_singular.is_some() {
+            self.sint32_singular = other.sint32_singular.clone();
+        };
+        if other.sint64_singular.is_some() {
+            self.sint64_singular = other.sint64_singular.clone();
+        };
+        if other.fixed32_singular.is_some() {
+            self.fixed32_singular = other.fixed32_singular.clone();
+        };
+        if other.fixed64_singular.is_some() {
+            self.fixed64_singular = other.fixed64_singular.clone();
+        };
+        if other.sfixed32_singular.is_some() {
+            self.sfixed32_singular = other.sfixed32_singular.clone();
+        };
+        if other.sfixed64_singular.is_some() {
+            self.sfixed64_singular = other.sfixed64_singular.clone();
+        };
+        if other.bool_singular.is_some() {
+            self.bool_singular = other.bool_singular.clone();
+        };
+        if other.string_singular.is_some() {
+            self.string_singular = other.string_singular.clone();
+        };
+        if other.bytes_singular.is_some() {
+            self.bytes_singular = other.bytes_singular.clone();
+        };
+        if other.test_enum_singular.is_some() {
+            self.test_enum_singular = other.test_enum_singular.clone();
+        };
+        if other.test_message_singular.is_some() {
+            if self.test_message_singular.is_none() {
+                self.test_message_singular.set_default();
+            };
+            self.test_message_singular.get_mut_ref().merge_from_message(other.test_message_singular.get_ref());
+        };
+        if other.testgroupsingular.is_some() {
+            if self.testgroupsingular.is_none() {
+                self.testgroupsingular.set_default();
+            };
+            self.testgroupsingular.get_mut_ref().merge_from_message(other.testgroupsingular.get_ref());
+        };
+        self.double_repeated.push_all(other.double_repeated.as_slice());
+        self.float_repeated.push_all(other.float_repeated.as_slice());
+        self.int32_repeated.push_all(other.int32_repeated.as_slice());
+        self.int64_repeated.push_all(other.int64_repeated.as_slice());
+        self.uint32_repeated.push_all(other.uint32_repeated.as_slice());
+        self.uint64_repeated.push_all(other.uint64_repeated.as_slice());
+        self.sint32_repeated.push_all(other.sint32_repeated.as_slice());
+        self.sint64_repeated.push_all(other.sint64_repeated.as_slice());
+        self.fixed32_repeated.push_all(other.fixed32_repeated.as_slice());
+        self.fixed64_repeated.push_all(other.fixed64_repeated.as_slice());
+        self.sfixed32_repeated.push_all(other.sfixed32_repeated.as_slice());
+        self.sfixed64_repeated.push_all(other.sfixed64_repeated.as_slice());
+        self.bool_repeated.push_all(other.bool_repeated.as_slice());
+        self.string_repeated.push_all(other.string_repeated.as_slice());
+        self.bytes_repeated.push_all(other.bytes_repeated.as_slice());
+        self.test_enum_repeated.push_all(other.test_enum_repeated.as_slice());
+        for v in other.test_message_repeated.iter() {
+            self.test_message_repeated.push(v.clone());
+        };
+        for v in other.testgrouprepeated.iter() {
+            self.testgrouprepeated.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -1702,6 +2114,9 @@ impl ::protobuf::Message for TestTypes {
             let len = value.compute_sizes(sizes);
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.testgroupsingular.iter() {
+            my_size += 4 + value.compute_sizes(sizes);
+        };
         my_size += 10 * self.double_repeated.len() as u32;
         my_size += 6 * self.float_repeated.len() as u32;
         for value in self.int32_repeated.iter() {
@@ -1740,6 +2155,9 @@ impl ::protobuf::Message for TestTypes {
             let len = value.compute_sizes(sizes);
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        for value in self.testgrouprepeated.iter() {
+            my_size += 4 + value.compute_sizes(sizes);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         *sizes.get_mut(pos) = my_size;
         // value is returned for convenience
@@ -1747,7 +2165,7 @@ impl ::protobuf::Message for TestTypes {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -1794,6 +2212,7 @@ impl ::protobuf::Message for TestTypes {
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_bytes_singular_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_test_enum_singular_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_test_message_singular_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_testgroupsingular_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_double_repeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_float_repeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_int32_repeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
@@ -1811,10 +2230,12 @@ impl ::protobuf::Message for TestTypes {
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_bytes_repeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_test_enum_repeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_test_message_repeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
+                fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_testgrouprepeated_acc as &::protobuf::reflect::FieldAccessor<TestTypes>) });
                 ::protobuf::reflect::MessageDescriptor::new::<TestTypes>(
                     "TestTypes",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -1844,6 +2265,7 @@ impl ::protobuf::Clear for TestTypes {
         self.clear_bytes_singular();
         self.clear_test_enum_singular();
         self.clear_test_message_singular();
+        self.clear_testgroupsingular();
         self.clear_double_repeated();
         self.clear_float_repeated();
         self.clear_int32_repeated();
@@ -1861,6 +2283,7 @@ impl ::protobuf::Clear for TestTypes {
         self.clear_bytes_repeated();
         self.clear_test_enum_repeated();
         self.clear_test_message_repeated();
+        self.clear_testgrouprepeated();
     }
 }
 
@@ -1887,6 +2310,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_double_singular
     fn get_f64(&self, m: &TestTypes) -> f64 {
         m.get_double_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_double_singular();
+    }
+
+    fn set_f64(&self, m: &mut TestTypes, v: f64) {
+        m.set_double_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1904,6 +2335,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_float_singular_
     fn get_f32(&self, m: &TestTypes) -> f32 {
         m.get_float_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_float_singular();
+    }
+
+    fn set_f32(&self, m: &mut TestTypes, v: f32) {
+        m.set_float_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1921,6 +2360,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_int32_singular_
     fn get_i32(&self, m: &TestTypes) -> i32 {
         m.get_int32_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_int32_singular();
+    }
+
+    fn set_i32(&self, m: &mut TestTypes, v: i32) {
+        m.set_int32_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1938,6 +2385,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_int64_singular_
     fn get_i64(&self, m: &TestTypes) -> i64 {
         m.get_int64_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_int64_singular();
+    }
+
+    fn set_i64(&self, m: &mut TestTypes, v: i64) {
+        m.set_int64_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1955,6 +2410,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_uint32_singular
     fn get_u32(&self, m: &TestTypes) -> u32 {
         m.get_uint32_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_uint32_singular();
+    }
+
+    fn set_u32(&self, m: &mut TestTypes, v: u32) {
+        m.set_uint32_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1972,6 +2435,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_uint64_singular
     fn get_u64(&self, m: &TestTypes) -> u64 {
         m.get_uint64_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_uint64_singular();
+    }
+
+    fn set_u64(&self, m: &mut TestTypes, v: u64) {
+        m.set_uint64_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -1989,6 +2460,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sint32_singular
     fn get_i32(&self, m: &TestTypes) -> i32 {
         m.get_sint32_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sint32_singular();
+    }
+
+    fn set_i32(&self, m: &mut TestTypes, v: i32) {
+        m.set_sint32_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2006,6 +2485,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sint64_singular
     fn get_i64(&self, m: &TestTypes) -> i64 {
         m.get_sint64_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sint64_singular();
+    }
+
+    fn set_i64(&self, m: &mut TestTypes, v: i64) {
+        m.set_sint64_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2023,6 +2510,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_fixed32_singula
     fn get_u32(&self, m: &TestTypes) -> u32 {
         m.get_fixed32_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_fixed32_singular();
+    }
+
+    fn set_u32(&self, m: &mut TestTypes, v: u32) {
+        m.set_fixed32_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2040,6 +2535,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_fixed64_singula
     fn get_u64(&self, m: &TestTypes) -> u64 {
         m.get_fixed64_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_fixed64_singular();
+    }
+
+    fn set_u64(&self, m: &mut TestTypes, v: u64) {
+        m.set_fixed64_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2057,6 +2560,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sfixed32_singul
     fn get_i32(&self, m: &TestTypes) -> i32 {
         m.get_sfixed32_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sfixed32_singular();
+    }
+
+    fn set_i32(&self, m: &mut TestTypes, v: i32) {
+        m.set_sfixed32_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2074,6 +2585,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sfixed64_singul
     fn get_i64(&self, m: &TestTypes) -> i64 {
         m.get_sfixed64_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sfixed64_singular();
+    }
+
+    fn set_i64(&self, m: &mut TestTypes, v: i64) {
+        m.set_sfixed64_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2091,6 +2610,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_bool_singular_a
     fn get_bool(&self, m: &TestTypes) -> bool {
         m.get_bool_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_bool_singular();
+    }
+
+    fn set_bool(&self, m: &mut TestTypes, v: bool) {
+        m.set_bool_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2108,6 +2635,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_string_singular
     fn get_str<'a>(&self, m: &'a TestTypes) -> &'a str {
         m.get_string_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_string_singular();
+    }
+
+    fn set_str(&self, m: &mut TestTypes, v: String) {
+        m.set_string_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2125,6 +2660,14 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_bytes_singular_
     fn get_bytes<'a>(&self, m: &'a TestTypes) -> &'a [u8] {
         m.get_bytes_singular()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_bytes_singular();
+    }
+
+    fn set_bytes(&self, m: &mut TestTypes, v: Vec<u8>) {
+        m.set_bytes_singular(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2135,6 +2678,10 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_enum_singu
         "test_enum_singular"
     }
 
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<TestEnum>()
+    }
+
     fn has_field(&self, m: &TestTypes) -> bool {
         m.has_test_enum_singular()
     }
@@ -2143,6 +2690,17 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_enum_singu
         use protobuf::{ProtobufEnum};
         m.get_test_enum_singular().descriptor()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_test_enum_singular();
+    }
+
+    fn set_enum(&self, m: &mut TestTypes, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match TestEnum::from_i32(v.value()) {
+            Some(e) => m.set_test_enum_singular(e),
+            None => fail!("unknown value {} of enum TestEnum", v.value()),
+        }
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2153,6 +2711,10 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_message_si
         "test_message_singular"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestMessage>()
+    }
+
     fn has_field(&self, m: &TestTypes) -> bool {
         m.has_test_message_singular()
     }
@@ -2160,6 +2722,43 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_message_si
     fn get_message<'a>(&self, m: &'a TestTypes) -> &'a ::protobuf::Message {
         m.get_test_message_singular() as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_test_message_singular();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestTypes) -> &'a mut ::protobuf::Message {
+        m.mut_test_message_singular() as &'a mut ::protobuf::Message
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestTypes_testgroupsingular_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_testgroupsingular_acc {
+    fn name(&self) -> &'static str {
+        "testgroupsingular"
+    }
+
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestTypes_TestGroupSingular>()
+    }
+
+    fn has_field(&self, m: &TestTypes) -> bool {
+        m.has_testgroupsingular()
+    }
+
+    fn get_message<'a>(&self, m: &'a TestTypes) -> &'a ::protobuf::Message {
+        m.get_testgroupsingular() as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_testgroupsingular();
+    }
+
+    fn mut_message<'a>(&self, m: &'a mut TestTypes) -> &'a mut ::protobuf::Message {
+        m.mut_testgroupsingular() as &'a mut ::protobuf::Message
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2177,6 +2776,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_double_repeated
     fn get_rep_f64<'a>(&self, m: &'a TestTypes) -> &'a [f64] {
         m.get_double_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_double_repeated();
+    }
+
+    fn add_f64(&self, m: &mut TestTypes, v: f64) {
+        m.add_double_repeated(v);
+    }
+
+    fn mut_rep_f64<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<f64> {
+        m.mut_double_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2194,6 +2805,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_float_repeated_
     fn get_rep_f32<'a>(&self, m: &'a TestTypes) -> &'a [f32] {
         m.get_float_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_float_repeated();
+    }
+
+    fn add_f32(&self, m: &mut TestTypes, v: f32) {
+        m.add_float_repeated(v);
+    }
+
+    fn mut_rep_f32<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<f32> {
+        m.mut_float_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2211,6 +2834,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_int32_repeated_
     fn get_rep_i32<'a>(&self, m: &'a TestTypes) -> &'a [i32] {
         m.get_int32_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_int32_repeated();
+    }
+
+    fn add_i32(&self, m: &mut TestTypes, v: i32) {
+        m.add_int32_repeated(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<i32> {
+        m.mut_int32_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2228,6 +2863,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_int64_repeated_
     fn get_rep_i64<'a>(&self, m: &'a TestTypes) -> &'a [i64] {
         m.get_int64_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_int64_repeated();
+    }
+
+    fn add_i64(&self, m: &mut TestTypes, v: i64) {
+        m.add_int64_repeated(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<i64> {
+        m.mut_int64_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2245,6 +2892,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_uint32_repeated
     fn get_rep_u32<'a>(&self, m: &'a TestTypes) -> &'a [u32] {
         m.get_uint32_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_uint32_repeated();
+    }
+
+    fn add_u32(&self, m: &mut TestTypes, v: u32) {
+        m.add_uint32_repeated(v);
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<u32> {
+        m.mut_uint32_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2262,6 +2921,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_uint64_repeated
     fn get_rep_u64<'a>(&self, m: &'a TestTypes) -> &'a [u64] {
         m.get_uint64_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_uint64_repeated();
+    }
+
+    fn add_u64(&self, m: &mut TestTypes, v: u64) {
+        m.add_uint64_repeated(v);
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<u64> {
+        m.mut_uint64_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2279,6 +2950,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sint32_repeated
     fn get_rep_i32<'a>(&self, m: &'a TestTypes) -> &'a [i32] {
         m.get_sint32_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sint32_repeated();
+    }
+
+    fn add_i32(&self, m: &mut TestTypes, v: i32) {
+        m.add_sint32_repeated(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<i32> {
+        m.mut_sint32_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2296,6 +2979,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sint64_repeated
     fn get_rep_i64<'a>(&self, m: &'a TestTypes) -> &'a [i64] {
         m.get_sint64_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sint64_repeated();
+    }
+
+    fn add_i64(&self, m: &mut TestTypes, v: i64) {
+        m.add_sint64_repeated(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<i64> {
+        m.mut_sint64_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2313,6 +3008,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_fixed32_repeate
     fn get_rep_u32<'a>(&self, m: &'a TestTypes) -> &'a [u32] {
         m.get_fixed32_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_fixed32_repeated();
+    }
+
+    fn add_u32(&self, m: &mut TestTypes, v: u32) {
+        m.add_fixed32_repeated(v);
+    }
+
+    fn mut_rep_u32<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<u32> {
+        m.mut_fixed32_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2330,6 +3037,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_fixed64_repeate
     fn get_rep_u64<'a>(&self, m: &'a TestTypes) -> &'a [u64] {
         m.get_fixed64_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_fixed64_repeated();
+    }
+
+    fn add_u64(&self, m: &mut TestTypes, v: u64) {
+        m.add_fixed64_repeated(v);
+    }
+
+    fn mut_rep_u64<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<u64> {
+        m.mut_fixed64_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2347,6 +3066,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sfixed32_repeat
     fn get_rep_i32<'a>(&self, m: &'a TestTypes) -> &'a [i32] {
         m.get_sfixed32_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sfixed32_repeated();
+    }
+
+    fn add_i32(&self, m: &mut TestTypes, v: i32) {
+        m.add_sfixed32_repeated(v);
+    }
+
+    fn mut_rep_i32<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<i32> {
+        m.mut_sfixed32_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2364,6 +3095,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_sfixed64_repeat
     fn get_rep_i64<'a>(&self, m: &'a TestTypes) -> &'a [i64] {
         m.get_sfixed64_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_sfixed64_repeated();
+    }
+
+    fn add_i64(&self, m: &mut TestTypes, v: i64) {
+        m.add_sfixed64_repeated(v);
+    }
+
+    fn mut_rep_i64<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<i64> {
+        m.mut_sfixed64_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2381,12 +3124,24 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_bool_repeated_a
     fn get_rep_bool<'a>(&self, m: &'a TestTypes) -> &'a [bool] {
         m.get_bool_repeated()
     }
-}
 
-#[allow(non_camel_case_types)]
-struct TestTypes_string_repeated_acc;
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_bool_repeated();
+    }
 
-impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_string_repeated_acc {
+    fn add_bool(&self, m: &mut TestTypes, v: bool) {
+        m.add_bool_repeated(v);
+    }
+
+    fn mut_rep_bool<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<bool> {
+        m.mut_bool_repeated()
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestTypes_string_repeated_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_string_repeated_acc {
     fn name(&self) -> &'static str {
         "string_repeated"
     }
@@ -2398,6 +3153,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_string_repeated
     fn get_rep_str<'a>(&self, m: &'a TestTypes) -> &'a [String] {
         m.get_string_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_string_repeated();
+    }
+
+    fn add_str(&self, m: &mut TestTypes, v: String) {
+        m.add_string_repeated(v);
+    }
+
+    fn mut_rep_str<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<String> {
+        m.mut_string_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2415,6 +3182,18 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_bytes_repeated_
     fn get_rep_bytes<'a>(&self, m: &'a TestTypes) -> &'a [Vec<u8>] {
         m.get_bytes_repeated()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_bytes_repeated();
+    }
+
+    fn add_bytes(&self, m: &mut TestTypes, v: Vec<u8>) {
+        m.add_bytes_repeated(v);
+    }
+
+    fn mut_rep_bytes<'a>(&self, m: &'a mut TestTypes) -> &'a mut Vec<Vec<u8>> {
+        m.mut_bytes_repeated()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2425,6 +3204,10 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_enum_repea
         "test_enum_repeated"
     }
 
+    fn enum_descriptor(&self) -> &'static ::protobuf::reflect::EnumDescriptor {
+        ::protobuf::reflect::EnumDescriptor::for_type::<TestEnum>()
+    }
+
     fn len_field(&self, m: &TestTypes) -> uint {
         m.get_test_enum_repeated().len()
     }
@@ -2433,6 +3216,17 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_enum_repea
         use protobuf::{ProtobufEnum};
         m.get_test_enum_repeated()[index].descriptor()
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_test_enum_repeated();
+    }
+
+    fn add_enum(&self, m: &mut TestTypes, v: &::protobuf::reflect::EnumValueDescriptor) {
+        match TestEnum::from_i32(v.value()) {
+            Some(e) => m.add_test_enum_repeated(e),
+            None => fail!("unknown value {} of enum TestEnum", v.value()),
+        }
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -2443,6 +3237,10 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_message_re
         "test_message_repeated"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestMessage>()
+    }
+
     fn len_field(&self, m: &TestTypes) -> uint {
         m.get_test_message_repeated().len()
     }
@@ -2450,6 +3248,463 @@ impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_test_message_re
     fn get_rep_message_item<'a>(&self, m: &'a TestTypes, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_test_message_repeated()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_test_message_repeated();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut TestTypes) -> &'a mut ::protobuf::Message {
+        m.mut_test_message_repeated().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut TestTypes, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_test_message_repeated().get_mut(index) as &'a mut ::protobuf::Message
+    }
+}
+
+#[allow(non_camel_case_types)]
+struct TestTypes_testgrouprepeated_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestTypes> for TestTypes_testgrouprepeated_acc {
+    fn name(&self) -> &'static str {
+        "testgrouprepeated"
+    }
+
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<TestTypes_TestGroupRepeated>()
+    }
+
+    fn len_field(&self, m: &TestTypes) -> uint {
+        m.get_testgrouprepeated().len()
+    }
+
+    fn get_rep_message_item<'a>(&self, m: &'a TestTypes, index: uint) -> &'a ::protobuf::Message {
+        &'a m.get_testgrouprepeated()[index] as &'a ::protobuf::Message
+    }
+
+    fn clear_field(&self, m: &mut TestTypes) {
+        m.clear_testgrouprepeated();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut TestTypes) -> &'a mut ::protobuf::Message {
+        m.mut_testgrouprepeated().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut TestTypes, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_testgrouprepeated().get_mut(index) as &'a mut ::protobuf::Message
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestTypes_TestGroupSingular {
+    value: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestTypes_TestGroupSingular {
+    pub fn new() -> TestTypes_TestGroupSingular {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestTypes_TestGroupSingular {
+        static mut instance: ::protobuf::lazy::Lazy<TestTypes_TestGroupSingular> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestTypes_TestGroupSingular };
+        unsafe {
+            instance.get(|| {
+                TestTypes_TestGroupSingular {
+                    value: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.value {
+            Some(ref v) => {
+                os.write_int32(1, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_value(&mut self) {
+        self.value = None;
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: i32) {
+        self.value = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&'a mut self) -> &'a mut i32 {
+        if self.value.is_none() {
+            self.value = Some(0);
+        };
+        self.value.get_mut_ref()
+    }
+
+    pub fn get_value(&self) -> i32 {
+        self.value.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestTypes_TestGroupSingular {
+    fn new() -> TestTypes_TestGroupSingular {
+        TestTypes_TestGroupSingular::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.value = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestTypes_TestGroupSingular) {
+        if other.value.is_some() {
+            self.value = other.value.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestTypes_TestGroupSingular>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestTypes_TestGroupSingular>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_TestGroupSingular_value_acc as &::protobuf::reflect::FieldAccessor<TestTypes_TestGroupSingular>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestTypes_TestGroupSingular>(
+                    "TestTypes_TestGroupSingular",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestTypes_TestGroupSingular>()
+    }
+}
+
+impl ::protobuf::Clear for TestTypes_TestGroupSingular {
+    fn clear(&mut self) {
+        self.clear_value();
+    }
+}
+
+impl ::std::fmt::Show for TestTypes_TestGroupSingular {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestTypes_TestGroupSingular_value_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestTypes_TestGroupSingular> for TestTypes_TestGroupSingular_value_acc {
+    fn name(&self) -> &'static str {
+        "value"
+    }
+
+    fn has_field(&self, m: &TestTypes_TestGroupSingular) -> bool {
+        m.has_value()
+    }
+
+    fn get_i32(&self, m: &TestTypes_TestGroupSingular) -> i32 {
+        m.get_value()
+    }
+
+    fn clear_field(&self, m: &mut TestTypes_TestGroupSingular) {
+        m.clear_value();
+    }
+
+    fn set_i32(&self, m: &mut TestTypes_TestGroupSingular, v: i32) {
+        m.set_value(v);
+    }
+}
+
+#[deriving(Clone,PartialEq,Default)]
+pub struct TestTypes_TestGroupRepeated {
+    value: Option<i32>,
+    unknown_fields: Option<Box<::protobuf::UnknownFields>>,
+}
+
+impl<'a> TestTypes_TestGroupRepeated {
+    pub fn new() -> TestTypes_TestGroupRepeated {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static TestTypes_TestGroupRepeated {
+        static mut instance: ::protobuf::lazy::Lazy<TestTypes_TestGroupRepeated> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *TestTypes_TestGroupRepeated };
+        unsafe {
+            instance.get(|| {
+                TestTypes_TestGroupRepeated {
+                    value: None,
+                    unknown_fields: None,
+                }
+            })
+        }
+    }
+
+    #[allow(unused_variable)]
+    pub fn write_to_with_computed_sizes(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &[u32], sizes_pos: &mut uint) {
+        use protobuf::{Message};
+        match self.value {
+            Some(ref v) => {
+                os.write_int32(1, *v);
+            },
+            None => {},
+        };
+        os.write_unknown_fields(self.get_unknown_fields());
+    }
+
+    pub fn clear_value(&mut self) {
+        self.value = None;
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: i32) {
+        self.value = Some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&'a mut self) -> &'a mut i32 {
+        if self.value.is_none() {
+            self.value = Some(0);
+        };
+        self.value.get_mut_ref()
+    }
+
+    pub fn get_value(&self) -> i32 {
+        self.value.unwrap_or_else(|| 0)
+    }
+}
+
+impl ::protobuf::Message for TestTypes_TestGroupRepeated {
+    fn new() -> TestTypes_TestGroupRepeated {
+        TestTypes_TestGroupRepeated::new()
+    }
+
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_int32());
+                    self.value = Some(tmp);
+                },
+                _ => {
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
+                    self.mut_unknown_fields().add_value(field_number, unknown);
+                },
+            };
+        }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &TestTypes_TestGroupRepeated) {
+        if other.value.is_some() {
+            self.value = other.value.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
+    }
+
+    // Compute sizes of nested messages
+    fn compute_sizes(&self, sizes: &mut Vec<u32>) -> u32 {
+        use protobuf::{Message};
+        let pos = sizes.len();
+        sizes.push(0);
+        let mut my_size = 0;
+        for value in self.value.iter() {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        *sizes.get_mut(pos) = my_size;
+        // value is returned for convenience
+        my_size
+    }
+
+    fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
+        self.check_initialized().unwrap();
+        let mut sizes: Vec<u32> = Vec::new();
+        self.compute_sizes(&mut sizes);
+        let mut sizes_pos = 1; // first element is self
+        self.write_to_with_computed_sizes(os, sizes.as_slice(), &mut sizes_pos);
+        assert_eq!(sizes_pos, sizes.len());
+        // TODO: assert we've written same number of bytes as computed
+    }
+
+    fn get_unknown_fields<'s>(&'s self) -> &'s ::protobuf::UnknownFields {
+        if self.unknown_fields.is_some() {
+            &**self.unknown_fields.get_ref()
+        } else {
+            ::protobuf::UnknownFields::default_instance()
+        }
+    }
+
+    fn mut_unknown_fields<'s>(&'s mut self) -> &'s mut ::protobuf::UnknownFields {
+        if self.unknown_fields.is_none() {
+            self.unknown_fields = Some(::std::default::Default::default())
+        }
+        &mut **self.unknown_fields.get_mut_ref()
+    }
+
+    #[allow(unused_unsafe,unused_mut)]
+    fn descriptor_static(_: Option<TestTypes_TestGroupRepeated>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::MessageDescriptor };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields: Vec<&'static ::protobuf::reflect::FieldAccessor<TestTypes_TestGroupRepeated>> = Vec::new();
+                fields.push(unsafe { ::std::mem::transmute(&'static TestTypes_TestGroupRepeated_value_acc as &::protobuf::reflect::FieldAccessor<TestTypes_TestGroupRepeated>) });
+                ::protobuf::reflect::MessageDescriptor::new::<TestTypes_TestGroupRepeated>(
+                    "TestTypes_TestGroupRepeated",
+                    fields,
+                    file_descriptor_proto(),
+                    file_descriptor
+                )
+            })
+        }
+    }
+
+    fn type_id(&self) -> ::std::intrinsics::TypeId {
+        ::std::intrinsics::TypeId::of::<TestTypes_TestGroupRepeated>()
+    }
+}
+
+impl ::protobuf::Clear for TestTypes_TestGroupRepeated {
+    fn clear(&mut self) {
+        self.clear_value();
+    }
+}
+
+impl ::std::fmt::Show for TestTypes_TestGroupRepeated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        use protobuf::{Message};
+        self.fmt_impl(f)
+    }
+}
+
+
+#[allow(non_camel_case_types)]
+struct TestTypes_TestGroupRepeated_value_acc;
+
+impl ::protobuf::reflect::FieldAccessor<TestTypes_TestGroupRepeated> for TestTypes_TestGroupRepeated_value_acc {
+    fn name(&self) -> &'static str {
+        "value"
+    }
+
+    fn has_field(&self, m: &TestTypes_TestGroupRepeated) -> bool {
+        m.has_value()
+    }
+
+    fn get_i32(&self, m: &TestTypes_TestGroupRepeated) -> i32 {
+        m.get_value()
+    }
+
+    fn clear_field(&self, m: &mut TestTypes_TestGroupRepeated) {
+        m.clear_value();
+    }
+
+    fn set_i32(&self, m: &mut TestTypes_TestGroupRepeated, v: i32) {
+        m.set_value(v);
+    }
 }
 
 #[deriving(Clone,PartialEq,Eq,Show)]
@@ -2460,10 +3715,17 @@ pub enum TestEnum {
 
 impl TestEnum {
     pub fn new(value: i32) -> TestEnum {
+        match TestEnum::from_i32(value) {
+            Some(v) => v,
+            None => fail!("unknown value {} of enum TestEnum", value),
+        }
+    }
+
+    pub fn from_i32(value: i32) -> Option<TestEnum> {
         match value {
-            1 => DARK,
-            2 => LIGHT,
-            _ => fail!()
+            1 => Some(DARK),
+            2 => Some(LIGHT),
+            _ => None
         }
     }
 }
@@ -2473,11 +3735,15 @@ impl ::protobuf::ProtobufEnum for TestEnum {
         *self as i32
     }
 
+    fn from_i32(value: i32) -> Option<TestEnum> {
+        TestEnum::from_i32(value)
+    }
+
     fn enum_descriptor_static(_: Option<TestEnum>) -> &'static ::protobuf::reflect::EnumDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::EnumDescriptor };
         unsafe {
             descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("TestEnum", file_descriptor_proto())
+                ::protobuf::reflect::EnumDescriptor::new("TestEnum", file_descriptor_proto(), file_descriptor)
             })
         }
     }
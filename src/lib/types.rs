@@ -0,0 +1,412 @@
+// Protobuf field types as Rust types.
+// Used to decode extension values, which are stored in unknown fields.
+
+use std::mem;
+use std::default::Default;
+use std::io::BufReader;
+
+use core::Message;
+use core::ProtobufEnum;
+use core::CodedInputStream;
+use core::CodedOutputStream;
+use core::wire_format;
+use error::ProtobufResult;
+use misc::VecWriter;
+use unknown::UnknownFields;
+use unknown::UnknownValue;
+use unknown::UnknownValues;
+use unknown::UnknownFixed32;
+use unknown::UnknownFixed64;
+use unknown::UnknownVarint;
+use unknown::UnknownLengthDelimited;
+use unknown::UnknownGroup;
+use unknown::UnknownLengthDelimitedRef;
+use zigzag::encode_zig_zag_32;
+use zigzag::encode_zig_zag_64;
+
+pub trait ProtobufType<V> {
+    fn wire_type(&self) -> wire_format::WireType;
+
+    // read value without tag
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<V>;
+
+    fn to_unknown(&self, value: V) -> UnknownValue;
+
+    // All values of field, including packed values.
+    // Values that cannot be decoded are skipped.
+    fn get_repeated_from_unknown(&self, unknown: &UnknownValues) -> Vec<V> {
+        let mut r = Vec::new();
+        for value in unknown.iter() {
+            let bytes = match value {
+                UnknownLengthDelimitedRef(bytes) if self.wire_type() != wire_format::WireTypeLengthDelimited => {
+                    // packed
+                    Vec::from_slice(bytes)
+                }
+                _ if value.wire_type() == self.wire_type() => {
+                    let mut writer = VecWriter::new();
+                    {
+                        let mut os = CodedOutputStream::new(&mut writer as &mut Writer);
+                        os.write_unknown_no_tag(value);
+                        os.flush();
+                    }
+                    writer.vec
+                }
+                _ => continue,
+            };
+            let mut reader = BufReader::new(bytes.as_slice());
+            let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+            loop {
+                match is.eof() {
+                    Ok(false) => {},
+                    _ => break,
+                };
+                match self.read(&mut is) {
+                    Ok(v) => r.push(v),
+                    Err(..) => break,
+                };
+            }
+        }
+        r
+    }
+
+    // last value wins, like when parsing singular field
+    fn get_from_unknown(&self, unknown: &UnknownValues) -> Option<V> {
+        self.get_repeated_from_unknown(unknown).pop()
+    }
+}
+
+pub struct ProtobufTypeFloat;
+pub struct ProtobufTypeDouble;
+pub struct ProtobufTypeInt32;
+pub struct ProtobufTypeInt64;
+pub struct ProtobufTypeUint32;
+pub struct ProtobufTypeUint64;
+pub struct ProtobufTypeSint32;
+pub struct ProtobufTypeSint64;
+pub struct ProtobufTypeFixed32;
+pub struct ProtobufTypeFixed64;
+pub struct ProtobufTypeSfixed32;
+pub struct ProtobufTypeSfixed64;
+pub struct ProtobufTypeBool;
+pub struct ProtobufTypeString;
+pub struct ProtobufTypeBytes;
+pub struct ProtobufTypeEnum<E>;
+pub struct ProtobufTypeMessage<M>;
+pub struct ProtobufTypeGroup<M>;
+
+impl ProtobufType<f32> for ProtobufTypeFloat {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeFixed32
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<f32> {
+        is.read_float()
+    }
+
+    fn to_unknown(&self, value: f32) -> UnknownValue {
+        UnknownFixed32(unsafe { mem::transmute::<f32, u32>(value) })
+    }
+}
+
+impl ProtobufType<f64> for ProtobufTypeDouble {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeFixed64
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<f64> {
+        is.read_double()
+    }
+
+    fn to_unknown(&self, value: f64) -> UnknownValue {
+        UnknownFixed64(unsafe { mem::transmute::<f64, u64>(value) })
+    }
+}
+
+impl ProtobufType<i32> for ProtobufTypeInt32 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<i32> {
+        is.read_int32()
+    }
+
+    fn to_unknown(&self, value: i32) -> UnknownValue {
+        UnknownVarint(value as i64 as u64)
+    }
+}
+
+impl ProtobufType<i64> for ProtobufTypeInt64 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<i64> {
+        is.read_int64()
+    }
+
+    fn to_unknown(&self, value: i64) -> UnknownValue {
+        UnknownVarint(value as u64)
+    }
+}
+
+impl ProtobufType<u32> for ProtobufTypeUint32 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<u32> {
+        is.read_uint32()
+    }
+
+    fn to_unknown(&self, value: u32) -> UnknownValue {
+        UnknownVarint(value as u64)
+    }
+}
+
+impl ProtobufType<u64> for ProtobufTypeUint64 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<u64> {
+        is.read_uint64()
+    }
+
+    fn to_unknown(&self, value: u64) -> UnknownValue {
+        UnknownVarint(value)
+    }
+}
+
+impl ProtobufType<i32> for ProtobufTypeSint32 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<i32> {
+        is.read_sint32()
+    }
+
+    fn to_unknown(&self, value: i32) -> UnknownValue {
+        UnknownVarint(encode_zig_zag_32(value) as u64)
+    }
+}
+
+impl ProtobufType<i64> for ProtobufTypeSint64 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<i64> {
+        is.read_sint64()
+    }
+
+    fn to_unknown(&self, value: i64) -> UnknownValue {
+        UnknownVarint(encode_zig_zag_64(value))
+    }
+}
+
+impl ProtobufType<u32> for ProtobufTypeFixed32 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeFixed32
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<u32> {
+        is.read_fixed32()
+    }
+
+    fn to_unknown(&self, value: u32) -> UnknownValue {
+        UnknownFixed32(value)
+    }
+}
+
+impl ProtobufType<u64> for ProtobufTypeFixed64 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeFixed64
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<u64> {
+        is.read_fixed64()
+    }
+
+    fn to_unknown(&self, value: u64) -> UnknownValue {
+        UnknownFixed64(value)
+    }
+}
+
+impl ProtobufType<i32> for ProtobufTypeSfixed32 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeFixed32
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<i32> {
+        is.read_sfixed32()
+    }
+
+    fn to_unknown(&self, value: i32) -> UnknownValue {
+        UnknownFixed32(value as u32)
+    }
+}
+
+impl ProtobufType<i64> for ProtobufTypeSfixed64 {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeFixed64
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<i64> {
+        is.read_sfixed64()
+    }
+
+    fn to_unknown(&self, value: i64) -> UnknownValue {
+        UnknownFixed64(value as u64)
+    }
+}
+
+impl ProtobufType<bool> for ProtobufTypeBool {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<bool> {
+        is.read_bool()
+    }
+
+    fn to_unknown(&self, value: bool) -> UnknownValue {
+        UnknownVarint(if value { 1 } else { 0 })
+    }
+}
+
+impl ProtobufType<String> for ProtobufTypeString {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeLengthDelimited
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<String> {
+        is.read_string()
+    }
+
+    fn to_unknown(&self, value: String) -> UnknownValue {
+        UnknownLengthDelimited(value.into_bytes())
+    }
+}
+
+impl ProtobufType<Vec<u8>> for ProtobufTypeBytes {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeLengthDelimited
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<Vec<u8>> {
+        is.read_bytes()
+    }
+
+    fn to_unknown(&self, value: Vec<u8>) -> UnknownValue {
+        UnknownLengthDelimited(value)
+    }
+}
+
+impl<E : ProtobufEnum> ProtobufType<E> for ProtobufTypeEnum<E> {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeVarint
+    }
+
+    // unknown enum values are skipped
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<E> {
+        loop {
+            let value = try!(is.read_int32());
+            match ProtobufEnum::from_i32(value) {
+                Some(e) => return Ok(e),
+                None => {},
+            };
+        }
+    }
+
+    fn to_unknown(&self, value: E) -> UnknownValue {
+        UnknownVarint(value.value() as i64 as u64)
+    }
+}
+
+impl<M : Message> ProtobufType<M> for ProtobufTypeMessage<M> {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeLengthDelimited
+    }
+
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<M> {
+        is.read_message()
+    }
+
+    fn to_unknown(&self, value: M) -> UnknownValue {
+        UnknownLengthDelimited(value.write_to_bytes())
+    }
+
+    // multiple occurrences of singular message are merged
+    fn get_from_unknown(&self, unknown: &UnknownValues) -> Option<M> {
+        if unknown.length_delimited.is_empty() {
+            return None;
+        }
+        let mut m: M = Message::new();
+        for bytes in unknown.length_delimited.iter() {
+            let mut reader = BufReader::new(bytes.as_slice());
+            let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+            match m.merge_from(&mut is) {
+                Ok(()) => {},
+                Err(..) => return None,
+            };
+        }
+        Some(m)
+    }
+}
+
+// Fields of serialized message as unknown fields
+fn unknown_fields_from_bytes(bytes: &[u8]) -> UnknownFields {
+    let mut r: UnknownFields = Default::default();
+    let mut reader = BufReader::new(bytes);
+    let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+    // bytes are written by this library, so they are valid
+    while !is.eof().unwrap() {
+        let (number, wire_type) = is.read_tag_unpack().unwrap();
+        let value = is.read_unknown(number, wire_type).unwrap();
+        r.add_value(number, value);
+    }
+    r
+}
+
+impl<M : Message> ProtobufType<M> for ProtobufTypeGroup<M> {
+    fn wire_type(&self) -> wire_format::WireType {
+        wire_format::WireTypeStartGroup
+    }
+
+    // read group fields up to end of input, without end group tag
+    fn read(&self, is: &mut CodedInputStream) -> ProtobufResult<M> {
+        let mut m: M = Message::new();
+        try!(m.merge_from(is));
+        try!(m.check_initialized());
+        Ok(m)
+    }
+
+    fn to_unknown(&self, value: M) -> UnknownValue {
+        UnknownGroup(unknown_fields_from_bytes(value.write_to_bytes().as_slice()))
+    }
+
+    // multiple occurrences of singular group are merged
+    fn get_from_unknown(&self, unknown: &UnknownValues) -> Option<M> {
+        if unknown.group.is_empty() {
+            return None;
+        }
+        let mut m: M = Message::new();
+        for fields in unknown.group.iter() {
+            let mut writer = VecWriter::new();
+            {
+                let mut os = CodedOutputStream::new(&mut writer as &mut Writer);
+                os.write_unknown_fields(fields);
+                os.flush();
+            }
+            let mut reader = BufReader::new(writer.vec.as_slice());
+            let mut is = CodedInputStream::new(&mut reader as &mut Reader);
+            match m.merge_from(&mut is) {
+                Ok(()) => {},
+                Err(..) => return None,
+            };
+        }
+        Some(m)
+    }
+}
@@ -10,6 +10,7 @@ pub enum UnknownValue {
     UnknownFixed64(u64),
     UnknownVarint(u64),
     UnknownLengthDelimited(Vec<u8>),
+    UnknownGroup(UnknownFields),
 }
 
 impl UnknownValue {
@@ -23,6 +24,7 @@ impl UnknownValue {
             UnknownFixed64(fixed64) => UnknownFixed64Ref(fixed64),
             UnknownVarint(varint) => UnknownVarintRef(varint),
             UnknownLengthDelimited(ref bytes) => UnknownLengthDelimitedRef(bytes.as_slice()),
+            UnknownGroup(ref fields) => UnknownGroupRef(fields),
         }
     }
 }
@@ -32,6 +34,7 @@ pub enum UnknownValueRef<'o> {
     UnknownFixed64Ref(u64),
     UnknownVarintRef(u64),
     UnknownLengthDelimitedRef(&'o [u8]),
+    UnknownGroupRef(&'o UnknownFields),
 }
 
 impl<'o> UnknownValueRef<'o> {
@@ -41,6 +44,7 @@ impl<'o> UnknownValueRef<'o> {
             UnknownFixed64Ref(_) => wire_format::WireTypeFixed64,
             UnknownVarintRef(_) => wire_format::WireTypeVarint,
             UnknownLengthDelimitedRef(_) => wire_format::WireTypeLengthDelimited,
+            UnknownGroupRef(_) => wire_format::WireTypeStartGroup,
         }
     }
 }
@@ -51,6 +55,7 @@ pub struct UnknownValues {
     pub fixed64: Vec<u64>,
     pub varint: Vec<u64>,
     pub length_delimited: Vec<Vec<u8>>,
+    pub group: Vec<UnknownFields>,
 }
 
 impl UnknownValues {
@@ -60,6 +65,7 @@ impl UnknownValues {
             UnknownFixed32(fixed32) => self.fixed32.push(fixed32),
             UnknownVarint(varint) => self.varint.push(varint),
             UnknownLengthDelimited(length_delimited) => self.length_delimited.push(length_delimited),
+            UnknownGroup(group) => self.group.push(group),
         };
     }
 
@@ -69,6 +75,7 @@ impl UnknownValues {
             fixed64: self.fixed64.iter(),
             varint: self.varint.iter(),
             length_delimited: self.length_delimited.iter(),
+            group: self.group.iter(),
         }
     }
 }
@@ -78,6 +85,7 @@ pub struct UnknownValuesIter<'o> {
     fixed64: slice::Items<'o, u64>,
     varint: slice::Items<'o, u64>,
     length_delimited: slice::Items<'o, Vec<u8>>,
+    group: slice::Items<'o, UnknownFields>,
 }
 
 impl<'o> Iterator<UnknownValueRef<'o>> for UnknownValuesIter<'o> {
@@ -98,6 +106,10 @@ impl<'o> Iterator<UnknownValueRef<'o>> for UnknownValuesIter<'o> {
         if length_delimited.is_some() {
             return Some(UnknownLengthDelimitedRef(length_delimited.get_ref().as_slice()))
         }
+        let group = self.group.next();
+        if group.is_some() {
+            return Some(UnknownGroupRef(group.unwrap()))
+        }
         None
     }
 }
@@ -149,6 +161,39 @@ impl UnknownFields {
         self.find_field(number).add_value(value);
     }
 
+    pub fn remove(&mut self, number: u32) {
+        match self.fields {
+            Some(ref mut map) => { map.remove(&number); },
+            None => {},
+        }
+    }
+
+    // append values of all fields of `other`
+    pub fn merge_from(&mut self, other: &UnknownFields) {
+        for (number, values) in other.iter() {
+            let field = self.find_field(number);
+            field.fixed32.push_all(values.fixed32.as_slice());
+            field.fixed64.push_all(values.fixed64.as_slice());
+            field.varint.push_all(values.varint.as_slice());
+            field.length_delimited.push_all(values.length_delimited.as_slice());
+            field.group.push_all(values.group.as_slice());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self.fields {
+            Some(ref map) => map.is_empty(),
+            None => true,
+        }
+    }
+
+    pub fn get<'s>(&'s self, number: u32) -> Option<&'s UnknownValues> {
+        match self.fields {
+            Some(ref map) => map.find(&number),
+            None => None,
+        }
+    }
+
     pub fn iter<'s>(&'s self) -> UnknownFieldIter<'s> {
         UnknownFieldIter {
             entries: self.fields.as_ref().map(|m| m.iter())
@@ -38,7 +38,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::descriptor::FileDescriptorProto };
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
-    ::protobuf::parse_from_bytes(file_descriptor_proto_data)
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
@@ -49,6 +49,28 @@ pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescripto
     }
 }
 
+static mut file_descriptor_lazy: ::protobuf::lazy::Lazy<::protobuf::reflect::FileDescriptor> = ::protobuf::lazy::Lazy { lock: ::protobuf::lazy::ONCE_INIT, ptr: 0 as *::protobuf::reflect::FileDescriptor };
+
+pub fn file_descriptor() -> &'static ::protobuf::reflect::FileDescriptor {
+    unsafe {
+        file_descriptor_lazy.get(|| {
+            ::protobuf::reflect::FileDescriptor::new(
+                file_descriptor_proto(),
+                vec!(
+                    ::descriptor::file_descriptor(),
+                ),
+                vec!(
+                    ::protobuf::reflect::MessageDescriptor::for_type::<CodeGeneratorRequest>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<CodeGeneratorResponse>(),
+                    ::protobuf::reflect::MessageDescriptor::for_type::<CodeGeneratorResponse_File>(),
+                ),
+                vec!(
+                ),
+            )
+        })
+    }
+}
+
 #[deriving(Clone,PartialEq,Default)]
 pub struct CodeGeneratorRequest {
     file_to_generate: Vec<String>,
@@ -176,34 +198,70 @@ impl ::protobuf::Message for CodeGeneratorRequest {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.proto_file.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.file_to_generate.push(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.parameter = Some(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.proto_file.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &CodeGeneratorRequest) {
+        use protobuf::{Message};
+        self.file_to_generate.push_all(other.file_to_generate.as_slice());
+        if other.parameter.is_some() {
+            self.parameter = other.parameter.clone();
+        };
+        for v in other.proto_file.iter() {
+            self.proto_file.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -229,7 +287,7 @@ impl ::protobuf::Message for CodeGeneratorRequest {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -265,7 +323,8 @@ impl ::protobuf::Message for CodeGeneratorRequest {
                 ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorRequest>(
                     "CodeGeneratorRequest",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -307,6 +366,18 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorRequest> for CodeGeneratorR
     fn get_rep_str<'a>(&self, m: &'a CodeGeneratorRequest) -> &'a [String] {
         m.get_file_to_generate()
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorRequest) {
+        m.clear_file_to_generate();
+    }
+
+    fn add_str(&self, m: &mut CodeGeneratorRequest, v: String) {
+        m.add_file_to_generate(v);
+    }
+
+    fn mut_rep_str<'a>(&self, m: &'a mut CodeGeneratorRequest) -> &'a mut Vec<String> {
+        m.mut_file_to_generate()
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -324,6 +395,14 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorRequest> for CodeGeneratorR
     fn get_str<'a>(&self, m: &'a CodeGeneratorRequest) -> &'a str {
         m.get_parameter()
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorRequest) {
+        m.clear_parameter();
+    }
+
+    fn set_str(&self, m: &mut CodeGeneratorRequest, v: String) {
+        m.set_parameter(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -334,6 +413,10 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorRequest> for CodeGeneratorR
         "proto_file"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<FileDescriptorProto>()
+    }
+
     fn len_field(&self, m: &CodeGeneratorRequest) -> uint {
         m.get_proto_file().len()
     }
@@ -341,6 +424,18 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorRequest> for CodeGeneratorR
     fn get_rep_message_item<'a>(&self, m: &'a CodeGeneratorRequest, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_proto_file()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorRequest) {
+        m.clear_proto_file();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut CodeGeneratorRequest) -> &'a mut ::protobuf::Message {
+        m.mut_proto_file().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut CodeGeneratorRequest, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_proto_file().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -443,29 +538,60 @@ impl ::protobuf::Message for CodeGeneratorResponse {
     }
 
     fn is_initialized(&self) -> bool {
+        use protobuf::{Message};
+        for v in self.file.iter() {
+            if !v.is_initialized() {
+                return false;
+            };
+        };
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.error = Some(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
                     let tmp = self.file.push_default();
-                    is.merge_message(tmp)
+                    try!(is.merge_message(tmp));
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &CodeGeneratorResponse) {
+        use protobuf::{Message};
+        if other.error.is_some() {
+            self.error = other.error.clone();
+        };
+        for v in other.file.iter() {
+            self.file.push(v.clone());
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -488,7 +614,7 @@ impl ::protobuf::Message for CodeGeneratorResponse {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -523,7 +649,8 @@ impl ::protobuf::Message for CodeGeneratorResponse {
                 ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorResponse>(
                     "CodeGeneratorResponse",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -564,6 +691,14 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorResponse> for CodeGenerator
     fn get_str<'a>(&self, m: &'a CodeGeneratorResponse) -> &'a str {
         m.get_error()
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorResponse) {
+        m.clear_error();
+    }
+
+    fn set_str(&self, m: &mut CodeGeneratorResponse, v: String) {
+        m.set_error(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -574,6 +709,10 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorResponse> for CodeGenerator
         "file"
     }
 
+    fn message_descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::reflect::MessageDescriptor::for_type::<CodeGeneratorResponse_File>()
+    }
+
     fn len_field(&self, m: &CodeGeneratorResponse) -> uint {
         m.get_file().len()
     }
@@ -581,6 +720,18 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorResponse> for CodeGenerator
     fn get_rep_message_item<'a>(&self, m: &'a CodeGeneratorResponse, index: uint) -> &'a ::protobuf::Message {
         &'a m.get_file()[index] as &'a ::protobuf::Message
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorResponse) {
+        m.clear_file();
+    }
+
+    fn add_message<'a>(&self, m: &'a mut CodeGeneratorResponse) -> &'a mut ::protobuf::Message {
+        m.mut_file().push_default() as &'a mut ::protobuf::Message
+    }
+
+    fn mut_rep_message_item<'a>(&self, m: &'a mut CodeGeneratorResponse, index: uint) -> &'a mut ::protobuf::Message {
+        m.mut_file().get_mut(index) as &'a mut ::protobuf::Message
+    }
 }
 
 #[deriving(Clone,PartialEq,Default)]
@@ -731,31 +882,62 @@ impl ::protobuf::Message for CodeGeneratorResponse_File {
         true
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) {
-        while !is.eof() {
-            let (field_number, wire_type) = is.read_tag_unpack();
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !try!(is.eof()) {
+            let (field_number, wire_type) = try!(is.read_tag_unpack());
+            if wire_type == ::protobuf::wire_format::WireTypeEndGroup {
+                return is.end_group(field_number);
+            };
             match field_number {
                 1 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.name = Some(tmp);
                 },
                 2 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.insertion_point = Some(tmp);
                 },
                 15 => {
-                    assert_eq!(::protobuf::wire_format::WireTypeLengthDelimited, wire_type);
-                    let tmp = is.read_string();
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        let unknown = try!(is.read_unknown(field_number, wire_type));
+                        self.mut_unknown_fields().add_value(field_number, unknown);
+                        continue;
+                    };
+                    let tmp = try!(is.read_string());
                     self.content = Some(tmp);
                 },
                 _ => {
-                    let unknown = is.read_unknown(wire_type);
+                    let unknown = try!(is.read_unknown(field_number, wire_type));
                     self.mut_unknown_fields().add_value(field_number, unknown);
                 },
             };
         }
+        Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &CodeGeneratorResponse_File) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        };
+        if other.insertion_point.is_some() {
+            self.insertion_point = other.insertion_point.clone();
+        };
+        if other.content.is_some() {
+            self.content = other.content.clone();
+        };
+        if other.unknown_fields.is_some() {
+            self.mut_unknown_fields().merge_from(other.get_unknown_fields());
+        };
     }
 
     // Compute sizes of nested messages
@@ -780,7 +962,7 @@ impl ::protobuf::Message for CodeGeneratorResponse_File {
     }
 
     fn write_to(&self, os: &mut ::protobuf::CodedOutputStream) {
-        self.check_initialized();
+        self.check_initialized().unwrap();
         let mut sizes: Vec<u32> = Vec::new();
         self.compute_sizes(&mut sizes);
         let mut sizes_pos = 1; // first element is self
@@ -816,7 +998,8 @@ impl ::protobuf::Message for CodeGeneratorResponse_File {
                 ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorResponse_File>(
                     "CodeGeneratorResponse_File",
                     fields,
-                    file_descriptor_proto()
+                    file_descriptor_proto(),
+                    file_descriptor
                 )
             })
         }
@@ -858,6 +1041,14 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorResponse_File> for CodeGene
     fn get_str<'a>(&self, m: &'a CodeGeneratorResponse_File) -> &'a str {
         m.get_name()
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorResponse_File) {
+        m.clear_name();
+    }
+
+    fn set_str(&self, m: &mut CodeGeneratorResponse_File, v: String) {
+        m.set_name(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -875,6 +1066,14 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorResponse_File> for CodeGene
     fn get_str<'a>(&self, m: &'a CodeGeneratorResponse_File) -> &'a str {
         m.get_insertion_point()
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorResponse_File) {
+        m.clear_insertion_point();
+    }
+
+    fn set_str(&self, m: &mut CodeGeneratorResponse_File, v: String) {
+        m.set_insertion_point(v);
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -892,4 +1091,12 @@ impl ::protobuf::reflect::FieldAccessor<CodeGeneratorResponse_File> for CodeGene
     fn get_str<'a>(&self, m: &'a CodeGeneratorResponse_File) -> &'a str {
         m.get_content()
     }
+
+    fn clear_field(&self, m: &mut CodeGeneratorResponse_File) {
+        m.clear_content();
+    }
+
+    fn set_str(&self, m: &mut CodeGeneratorResponse_File, v: String) {
+        m.set_content(v);
+    }
 }